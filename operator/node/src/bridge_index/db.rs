@@ -0,0 +1,625 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Tables of the bridge index and the queries keeping them up to date.
+//!
+//! Block numbers and nonces are stored as `BIGINT`, extrinsic indexes as `INTEGER` and hashes as
+//! their raw bytes.
+
+use datahaven_runtime_common::bridge_tracking::{BridgeEvent, BridgeEventRecord};
+use diesel::prelude::*;
+use diesel_async::{
+    scoped_futures::ScopedFutureExt, AsyncConnection, AsyncPgConnection, RunQueryDsl,
+};
+use diesel_migrations::{embed_migrations, EmbeddedMigrations, MigrationHarness};
+use serde::Serialize;
+
+/// Migrations creating and updating the tables of the index.
+pub const MIGRATIONS: EmbeddedMigrations = embed_migrations!("src/bridge_index/migrations");
+
+/// Key of the single row of `bridge_index_state`.
+const STATE_ID: i32 = 0;
+
+diesel::table! {
+    bridge_index_state (id) {
+        id -> Int4,
+        last_indexed_block -> Nullable<Int8>,
+        beefy_block -> Nullable<Int8>,
+    }
+}
+
+diesel::table! {
+    bridge_outbound_messages (message_id) {
+        message_id -> Bytea,
+        queued_block -> Nullable<Int8>,
+        queued_extrinsic -> Nullable<Int4>,
+        nonce -> Nullable<Int8>,
+        committed_block -> Nullable<Int8>,
+        delivered_block -> Nullable<Int8>,
+        delivery_success -> Nullable<Bool>,
+    }
+}
+
+diesel::table! {
+    bridge_outbound_commitments (block_number) {
+        block_number -> Int8,
+        block_hash -> Bytea,
+        root -> Bytea,
+        message_count -> Int8,
+        beefy_block -> Nullable<Int8>,
+    }
+}
+
+diesel::table! {
+    bridge_inbound_messages (nonce) {
+        nonce -> Int8,
+        block_number -> Int8,
+        extrinsic_index -> Nullable<Int4>,
+        status -> Text,
+        message_id -> Nullable<Bytea>,
+        error -> Nullable<Text>,
+    }
+}
+
+/// An outbound message, from the extrinsic queuing it to its delivery receipt.
+#[derive(Queryable, Selectable)]
+#[diesel(table_name = bridge_outbound_messages, check_for_backend(diesel::pg::Pg))]
+pub struct OutboundMessage {
+    pub message_id: Vec<u8>,
+    pub queued_block: Option<i64>,
+    pub queued_extrinsic: Option<i32>,
+    pub nonce: Option<i64>,
+    pub committed_block: Option<i64>,
+    pub delivered_block: Option<i64>,
+    pub delivery_success: Option<bool>,
+}
+
+/// The commitment of the outbound messages accepted in a block.
+#[derive(Queryable, Selectable)]
+#[diesel(table_name = bridge_outbound_commitments, check_for_backend(diesel::pg::Pg))]
+pub struct OutboundCommitment {
+    pub block_number: i64,
+    pub block_hash: Vec<u8>,
+    pub root: Vec<u8>,
+    pub message_count: i64,
+    /// First BEEFY finalized block seen whose MMR includes the commitment.
+    pub beefy_block: Option<i64>,
+}
+
+/// An inbound message and the latest outcome of its processing.
+#[derive(Queryable, Selectable)]
+#[diesel(table_name = bridge_inbound_messages, check_for_backend(diesel::pg::Pg))]
+pub struct InboundMessage {
+    pub nonce: i64,
+    pub block_number: i64,
+    pub extrinsic_index: Option<i32>,
+    pub status: String,
+    pub message_id: Option<Vec<u8>>,
+    pub error: Option<String>,
+}
+
+/// Processing status of an inbound message.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum InboundStatus {
+    /// The message was processed, possibly after being retried as a dead letter.
+    Processed,
+    /// The message failed processing and was kept as a dead letter.
+    DeadLetter,
+    /// The assets of the dead letter were given back.
+    Refunded,
+}
+
+impl InboundStatus {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Processed => "processed",
+            Self::DeadLetter => "dead_letter",
+            Self::Refunded => "refunded",
+        }
+    }
+
+    pub fn from_column(status: &str) -> Option<Self> {
+        match status {
+            "processed" => Some(Self::Processed),
+            "dead_letter" => Some(Self::DeadLetter),
+            "refunded" => Some(Self::Refunded),
+            _ => None,
+        }
+    }
+}
+
+/// Applies the migrations not applied yet to the database at `database_url`.
+pub fn run_migrations(database_url: &str) -> Result<(), String> {
+    let mut conn = PgConnection::establish(database_url).map_err(|e| e.to_string())?;
+    conn.run_pending_migrations(MIGRATIONS)
+        .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+/// Number of the last indexed block, if any.
+pub async fn last_indexed_block(conn: &mut AsyncPgConnection) -> QueryResult<Option<i64>> {
+    Ok(bridge_index_state::table
+        .find(STATE_ID)
+        .select(bridge_index_state::last_indexed_block)
+        .first::<Option<i64>>(conn)
+        .await
+        .optional()?
+        .flatten())
+}
+
+/// Records the bridge events of a block, and the block as the last indexed one.
+pub async fn index_block(
+    conn: &mut AsyncPgConnection,
+    block_number: i64,
+    block_hash: &[u8],
+    events: Vec<BridgeEventRecord>,
+) -> QueryResult<()> {
+    conn.transaction(|conn| {
+        async move {
+            for BridgeEventRecord {
+                extrinsic_index,
+                event,
+            } in events
+            {
+                let extrinsic_index = extrinsic_index.map(|index| index as i32);
+                index_event(conn, block_number, block_hash, extrinsic_index, event).await?;
+            }
+
+            diesel::insert_into(bridge_index_state::table)
+                .values((
+                    bridge_index_state::id.eq(STATE_ID),
+                    bridge_index_state::last_indexed_block.eq(block_number),
+                ))
+                .on_conflict(bridge_index_state::id)
+                .do_update()
+                .set(bridge_index_state::last_indexed_block.eq(block_number))
+                .execute(conn)
+                .await?;
+            Ok(())
+        }
+        .scope_boxed()
+    })
+    .await
+}
+
+async fn index_event(
+    conn: &mut AsyncPgConnection,
+    block_number: i64,
+    block_hash: &[u8],
+    extrinsic_index: Option<i32>,
+    event: BridgeEvent,
+) -> QueryResult<()> {
+    use bridge_inbound_messages as inbound;
+    use bridge_outbound_messages as outbound;
+
+    match event {
+        BridgeEvent::OutboundMessageQueued { id } => {
+            diesel::insert_into(outbound::table)
+                .values((
+                    outbound::message_id.eq(id.as_bytes()),
+                    outbound::queued_block.eq(block_number),
+                    outbound::queued_extrinsic.eq(extrinsic_index),
+                ))
+                .on_conflict(outbound::message_id)
+                .do_update()
+                .set((
+                    outbound::queued_block.eq(block_number),
+                    outbound::queued_extrinsic.eq(extrinsic_index),
+                ))
+                .execute(conn)
+                .await?;
+        }
+        BridgeEvent::OutboundMessageAccepted { id, nonce } => {
+            diesel::insert_into(outbound::table)
+                .values((
+                    outbound::message_id.eq(id.as_bytes()),
+                    outbound::nonce.eq(nonce as i64),
+                    outbound::committed_block.eq(block_number),
+                ))
+                .on_conflict(outbound::message_id)
+                .do_update()
+                .set((
+                    outbound::nonce.eq(nonce as i64),
+                    outbound::committed_block.eq(block_number),
+                ))
+                .execute(conn)
+                .await?;
+        }
+        BridgeEvent::OutboundMessagesCommitted { root, count } => {
+            // The commitment is in the MMR of the BEEFY blocks after it
+            let beefy_block = beefy_block(conn)
+                .await?
+                .filter(|beefy_block| *beefy_block > block_number);
+            diesel::insert_into(bridge_outbound_commitments::table)
+                .values((
+                    bridge_outbound_commitments::block_number.eq(block_number),
+                    bridge_outbound_commitments::block_hash.eq(block_hash),
+                    bridge_outbound_commitments::root.eq(root.as_bytes()),
+                    bridge_outbound_commitments::message_count.eq(count as i64),
+                    bridge_outbound_commitments::beefy_block.eq(beefy_block),
+                ))
+                .on_conflict(bridge_outbound_commitments::block_number)
+                .do_nothing()
+                .execute(conn)
+                .await?;
+        }
+        BridgeEvent::OutboundMessageDelivered { nonce } => {
+            diesel::update(outbound::table.filter(outbound::nonce.eq(nonce as i64)))
+                .set((
+                    outbound::delivered_block.eq(block_number),
+                    outbound::delivery_success.eq(true),
+                ))
+                .execute(conn)
+                .await?;
+        }
+        // Deposited along with the delivery of the message
+        BridgeEvent::OutboundMessageDispatchFailed { nonce } => {
+            diesel::update(outbound::table.filter(outbound::nonce.eq(nonce as i64)))
+                .set(outbound::delivery_success.eq(false))
+                .execute(conn)
+                .await?;
+        }
+        BridgeEvent::InboundMessageReceived { nonce, message_id }
+        | BridgeEvent::InboundDeadLetterRetried { nonce, message_id } => {
+            diesel::insert_into(inbound::table)
+                .values((
+                    inbound::nonce.eq(nonce as i64),
+                    inbound::block_number.eq(block_number),
+                    inbound::extrinsic_index.eq(extrinsic_index),
+                    inbound::status.eq(InboundStatus::Processed.as_str()),
+                    inbound::message_id.eq(message_id.as_slice()),
+                ))
+                .on_conflict(inbound::nonce)
+                .do_update()
+                .set((
+                    inbound::block_number.eq(block_number),
+                    inbound::extrinsic_index.eq(extrinsic_index),
+                    inbound::status.eq(InboundStatus::Processed.as_str()),
+                    inbound::message_id.eq(message_id.as_slice()),
+                ))
+                .execute(conn)
+                .await?;
+        }
+        BridgeEvent::InboundMessageFailed { nonce, reason } => {
+            let error = format!("{reason:?}");
+            diesel::insert_into(inbound::table)
+                .values((
+                    inbound::nonce.eq(nonce as i64),
+                    inbound::block_number.eq(block_number),
+                    inbound::extrinsic_index.eq(extrinsic_index),
+                    inbound::status.eq(InboundStatus::DeadLetter.as_str()),
+                    inbound::error.eq(&error),
+                ))
+                .on_conflict(inbound::nonce)
+                .do_update()
+                .set((
+                    inbound::block_number.eq(block_number),
+                    inbound::extrinsic_index.eq(extrinsic_index),
+                    inbound::status.eq(InboundStatus::DeadLetter.as_str()),
+                    inbound::error.eq(&error),
+                ))
+                .execute(conn)
+                .await?;
+        }
+        BridgeEvent::InboundDeadLetterRefunded { nonce } => {
+            diesel::update(inbound::table.find(nonce as i64))
+                .set((
+                    inbound::block_number.eq(block_number),
+                    inbound::extrinsic_index.eq(extrinsic_index),
+                    inbound::status.eq(InboundStatus::Refunded.as_str()),
+                ))
+                .execute(conn)
+                .await?;
+        }
+    }
+
+    Ok(())
+}
+
+async fn beefy_block(conn: &mut AsyncPgConnection) -> QueryResult<Option<i64>> {
+    Ok(bridge_index_state::table
+        .find(STATE_ID)
+        .select(bridge_index_state::beefy_block)
+        .first::<Option<i64>>(conn)
+        .await
+        .optional()?
+        .flatten())
+}
+
+/// Records a BEEFY finalized block, making the commitments of the blocks before it provable.
+pub async fn note_beefy_block(conn: &mut AsyncPgConnection, block_number: i64) -> QueryResult<()> {
+    conn.transaction(|conn| {
+        async move {
+            diesel::insert_into(bridge_index_state::table)
+                .values((
+                    bridge_index_state::id.eq(STATE_ID),
+                    bridge_index_state::beefy_block.eq(block_number),
+                ))
+                .on_conflict(bridge_index_state::id)
+                .do_update()
+                .set(bridge_index_state::beefy_block.eq(block_number))
+                .execute(conn)
+                .await?;
+
+            diesel::update(
+                bridge_outbound_commitments::table
+                    .filter(bridge_outbound_commitments::beefy_block.is_null())
+                    .filter(bridge_outbound_commitments::block_number.lt(block_number)),
+            )
+            .set(bridge_outbound_commitments::beefy_block.eq(block_number))
+            .execute(conn)
+            .await?;
+            Ok(())
+        }
+        .scope_boxed()
+    })
+    .await
+}
+
+/// The outbound message with the given nonce.
+pub async fn outbound_message_by_nonce(
+    conn: &mut AsyncPgConnection,
+    nonce: i64,
+) -> QueryResult<Option<OutboundMessage>> {
+    bridge_outbound_messages::table
+        .filter(bridge_outbound_messages::nonce.eq(nonce))
+        .select(OutboundMessage::as_select())
+        .first(conn)
+        .await
+        .optional()
+}
+
+/// The outbound message with the given id.
+pub async fn outbound_message_by_id(
+    conn: &mut AsyncPgConnection,
+    message_id: &[u8],
+) -> QueryResult<Option<OutboundMessage>> {
+    bridge_outbound_messages::table
+        .find(message_id)
+        .select(OutboundMessage::as_select())
+        .first(conn)
+        .await
+        .optional()
+}
+
+/// The outbound messages queued by an extrinsic.
+pub async fn outbound_messages_by_extrinsic(
+    conn: &mut AsyncPgConnection,
+    block_number: i64,
+    extrinsic_index: i32,
+) -> QueryResult<Vec<OutboundMessage>> {
+    bridge_outbound_messages::table
+        .filter(bridge_outbound_messages::queued_block.eq(block_number))
+        .filter(bridge_outbound_messages::queued_extrinsic.eq(extrinsic_index))
+        .order(bridge_outbound_messages::nonce.asc())
+        .select(OutboundMessage::as_select())
+        .load(conn)
+        .await
+}
+
+/// The commitment made at the given block.
+pub async fn outbound_commitment(
+    conn: &mut AsyncPgConnection,
+    block_number: i64,
+) -> QueryResult<Option<OutboundCommitment>> {
+    bridge_outbound_commitments::table
+        .find(block_number)
+        .select(OutboundCommitment::as_select())
+        .first(conn)
+        .await
+        .optional()
+}
+
+/// The inbound message with the given nonce.
+pub async fn inbound_message(
+    conn: &mut AsyncPgConnection,
+    nonce: i64,
+) -> QueryResult<Option<InboundMessage>> {
+    bridge_inbound_messages::table
+        .find(nonce)
+        .select(InboundMessage::as_select())
+        .first(conn)
+        .await
+        .optional()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use diesel_migrations::MigrationSource;
+    use sp_core::H256;
+    use sp_runtime::DispatchError;
+
+    /// Database the tests run against, in a transaction rolled back at the end of each test.
+    const DATABASE_URL_VAR: &str = "BRIDGE_INDEX_TEST_DATABASE_URL";
+
+    async fn connection() -> AsyncPgConnection {
+        let database_url = std::env::var(DATABASE_URL_VAR)
+            .unwrap_or_else(|_| panic!("{DATABASE_URL_VAR} must point to a Postgres database"));
+        run_migrations(&database_url).unwrap();
+        let mut conn = AsyncPgConnection::establish(&database_url).await.unwrap();
+        conn.begin_test_transaction().await.unwrap();
+        conn
+    }
+
+    fn record(extrinsic_index: Option<u32>, event: BridgeEvent) -> BridgeEventRecord {
+        BridgeEventRecord {
+            extrinsic_index,
+            event,
+        }
+    }
+
+    #[test]
+    fn inbound_status_round_trips_through_its_column() {
+        for status in [
+            InboundStatus::Processed,
+            InboundStatus::DeadLetter,
+            InboundStatus::Refunded,
+        ] {
+            assert_eq!(InboundStatus::from_column(status.as_str()), Some(status));
+        }
+        assert_eq!(InboundStatus::from_column("unknown"), None);
+    }
+
+    #[test]
+    fn migrations_are_embedded() {
+        let migrations = MigrationSource::<diesel::pg::Pg>::migrations(&MIGRATIONS).unwrap();
+        assert!(!migrations.is_empty());
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a Postgres database at BRIDGE_INDEX_TEST_DATABASE_URL"]
+    async fn outbound_message_is_followed_until_its_delivery() {
+        let mut conn = connection().await;
+        let id = H256::repeat_byte(1);
+
+        index_block(
+            &mut conn,
+            10,
+            &[10; 32],
+            vec![record(Some(2), BridgeEvent::OutboundMessageQueued { id })],
+        )
+        .await
+        .unwrap();
+        let queued = outbound_message_by_id(&mut conn, id.as_bytes())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(queued.queued_block, Some(10));
+        assert_eq!(queued.queued_extrinsic, Some(2));
+        assert_eq!(queued.nonce, None);
+        assert_eq!(
+            outbound_messages_by_extrinsic(&mut conn, 10, 2)
+                .await
+                .unwrap()
+                .len(),
+            1
+        );
+
+        index_block(
+            &mut conn,
+            11,
+            &[11; 32],
+            vec![
+                record(None, BridgeEvent::OutboundMessageAccepted { id, nonce: 7 }),
+                record(
+                    None,
+                    BridgeEvent::OutboundMessagesCommitted {
+                        root: H256::repeat_byte(2),
+                        count: 1,
+                    },
+                ),
+            ],
+        )
+        .await
+        .unwrap();
+        let committed = outbound_message_by_nonce(&mut conn, 7)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(committed.committed_block, Some(11));
+        let commitment = outbound_commitment(&mut conn, 11).await.unwrap().unwrap();
+        assert_eq!(commitment.block_hash, vec![11; 32]);
+        assert_eq!(commitment.root, H256::repeat_byte(2).as_bytes());
+        assert_eq!(commitment.message_count, 1);
+        assert_eq!(commitment.beefy_block, None);
+
+        // Only the BEEFY blocks after the commitment include it in their MMR
+        note_beefy_block(&mut conn, 11).await.unwrap();
+        let commitment = outbound_commitment(&mut conn, 11).await.unwrap().unwrap();
+        assert_eq!(commitment.beefy_block, None);
+        note_beefy_block(&mut conn, 12).await.unwrap();
+        let commitment = outbound_commitment(&mut conn, 11).await.unwrap().unwrap();
+        assert_eq!(commitment.beefy_block, Some(12));
+
+        index_block(
+            &mut conn,
+            20,
+            &[20; 32],
+            vec![
+                record(Some(1), BridgeEvent::OutboundMessageDelivered { nonce: 7 }),
+                record(
+                    Some(1),
+                    BridgeEvent::OutboundMessageDispatchFailed { nonce: 7 },
+                ),
+            ],
+        )
+        .await
+        .unwrap();
+        let delivered = outbound_message_by_nonce(&mut conn, 7)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(delivered.delivered_block, Some(20));
+        assert_eq!(delivered.delivery_success, Some(false));
+        assert_eq!(last_indexed_block(&mut conn).await.unwrap(), Some(20));
+    }
+
+    #[tokio::test]
+    #[ignore = "requires a Postgres database at BRIDGE_INDEX_TEST_DATABASE_URL"]
+    async fn inbound_message_keeps_its_latest_outcome() {
+        let mut conn = connection().await;
+
+        index_block(
+            &mut conn,
+            30,
+            &[30; 32],
+            vec![
+                record(
+                    Some(1),
+                    BridgeEvent::InboundMessageReceived {
+                        nonce: 1,
+                        message_id: [1; 32],
+                    },
+                ),
+                record(
+                    Some(2),
+                    BridgeEvent::InboundMessageFailed {
+                        nonce: 2,
+                        reason: DispatchError::Other("failed"),
+                    },
+                ),
+            ],
+        )
+        .await
+        .unwrap();
+        let processed = inbound_message(&mut conn, 1).await.unwrap().unwrap();
+        assert_eq!(processed.status, "processed");
+        assert_eq!(processed.message_id, Some(vec![1; 32]));
+        let failed = inbound_message(&mut conn, 2).await.unwrap().unwrap();
+        assert_eq!(failed.status, "dead_letter");
+        assert_eq!(failed.extrinsic_index, Some(2));
+        assert!(failed.error.is_some());
+
+        index_block(
+            &mut conn,
+            31,
+            &[31; 32],
+            vec![record(
+                Some(3),
+                BridgeEvent::InboundDeadLetterRefunded { nonce: 2 },
+            )],
+        )
+        .await
+        .unwrap();
+        let refunded = inbound_message(&mut conn, 2).await.unwrap().unwrap();
+        assert_eq!(refunded.status, "refunded");
+        assert_eq!(refunded.block_number, 31);
+        assert_eq!(refunded.extrinsic_index, Some(3));
+        assert!(inbound_message(&mut conn, 3).await.unwrap().is_none());
+    }
+}
@@ -0,0 +1,4 @@
+DROP TABLE IF EXISTS bridge_inbound_messages;
+DROP TABLE IF EXISTS bridge_outbound_commitments;
+DROP TABLE IF EXISTS bridge_outbound_messages;
+DROP TABLE IF EXISTS bridge_index_state;
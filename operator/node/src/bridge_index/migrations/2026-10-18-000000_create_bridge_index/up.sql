@@ -0,0 +1,36 @@
+-- The tables may already exist, created by the nodes which set up the index without migrations
+CREATE TABLE IF NOT EXISTS bridge_index_state (
+    id INTEGER PRIMARY KEY,
+    last_indexed_block BIGINT,
+    beefy_block BIGINT
+);
+
+CREATE TABLE IF NOT EXISTS bridge_outbound_messages (
+    message_id BYTEA PRIMARY KEY,
+    queued_block BIGINT,
+    queued_extrinsic INTEGER,
+    nonce BIGINT UNIQUE,
+    committed_block BIGINT,
+    delivered_block BIGINT,
+    delivery_success BOOLEAN
+);
+
+CREATE INDEX IF NOT EXISTS bridge_outbound_messages_extrinsic
+    ON bridge_outbound_messages (queued_block, queued_extrinsic);
+
+CREATE TABLE IF NOT EXISTS bridge_outbound_commitments (
+    block_number BIGINT PRIMARY KEY,
+    block_hash BYTEA NOT NULL,
+    root BYTEA NOT NULL,
+    message_count BIGINT NOT NULL,
+    beefy_block BIGINT
+);
+
+CREATE TABLE IF NOT EXISTS bridge_inbound_messages (
+    nonce BIGINT PRIMARY KEY,
+    block_number BIGINT NOT NULL,
+    extrinsic_index INTEGER,
+    status TEXT NOT NULL,
+    message_id BYTEA,
+    error TEXT
+);
@@ -0,0 +1,160 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Index of the messages crossing the Snowbridge bridge, kept in a Postgres database.
+//!
+//! The finalized blocks are indexed in order from the bridge events returned by the runtime. An
+//! outbound message is followed from the extrinsic queuing it to its nonce, the commitment of its
+//! block, the first BEEFY block including that commitment in its MMR, and its delivery receipt.
+//! Inbound messages are followed by nonce through their processing and dead letter outcomes.
+//!
+//! On its first run the index starts at the finalized block, the state of older blocks being
+//! possibly pruned. Blocks whose runtime has no [`BridgeTrackingApi`] are indexed without events.
+//! The messages are looked up through the `bridge_*` RPC methods.
+//!
+//! The tables are created and updated by the migrations of [`db::MIGRATIONS`], applied when the
+//! node starts.
+
+pub mod db;
+pub mod rpc;
+
+use datahaven_runtime_common::{bridge_tracking::BridgeTrackingApi, Block, BlockNumber};
+use futures::StreamExt;
+use log::{error, info, warn};
+use sc_client_api::BlockchainEvents;
+use sc_consensus_beefy::communication::notification::BeefyVersionedFinalityProofStream;
+use sc_service::SpawnTaskHandle;
+use shc_indexer_db::DbPool;
+use sp_api::{ApiExt, ProvideRuntimeApi};
+use sp_blockchain::HeaderBackend;
+use sp_consensus_beefy::{ecdsa_crypto::AuthorityId as BeefyId, VersionedFinalityProof};
+use std::sync::Arc;
+
+const LOG_TARGET: &str = "bridge-index";
+
+/// Queue size above which the notification streams warn about a slow consumer.
+const QUEUE_SIZE_WARNING: usize = 100;
+
+/// Spawns the task indexing the finalized blocks and the BEEFY finality into the database.
+pub fn spawn<C>(
+    spawn_handle: SpawnTaskHandle,
+    client: Arc<C>,
+    pool: DbPool,
+    beefy_proofs: BeefyVersionedFinalityProofStream<Block, BeefyId>,
+) where
+    C: ProvideRuntimeApi<Block>
+        + HeaderBackend<Block>
+        + BlockchainEvents<Block>
+        + Send
+        + Sync
+        + 'static,
+    C::Api: BridgeTrackingApi<Block>,
+{
+    spawn_handle.spawn("bridge-index", Some("bridge-index"), async move {
+        let indexer = BridgeIndexer { client, pool };
+        if let Err(e) = indexer.run(beefy_proofs).await {
+            error!(target: LOG_TARGET, "Bridge index stopped: {e}");
+        }
+    });
+}
+
+struct BridgeIndexer<C> {
+    client: Arc<C>,
+    pool: DbPool,
+}
+
+impl<C> BridgeIndexer<C>
+where
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + BlockchainEvents<Block>,
+    C::Api: BridgeTrackingApi<Block>,
+{
+    async fn run(
+        self,
+        beefy_proofs: BeefyVersionedFinalityProofStream<Block, BeefyId>,
+    ) -> Result<(), String> {
+        info!(target: LOG_TARGET, "🌉 Bridge index started");
+
+        let mut finality_notifications = self.client.finality_notification_stream().fuse();
+        let mut beefy_proofs = beefy_proofs.subscribe(QUEUE_SIZE_WARNING).fuse();
+        log_error(self.index_finalized().await);
+
+        loop {
+            futures::select! {
+                notification = finality_notifications.next() => match notification {
+                    Some(_) => log_error(self.index_finalized().await),
+                    None => break,
+                },
+                proof = beefy_proofs.next() => match proof {
+                    Some(VersionedFinalityProof::V1(signed_commitment)) => log_error(
+                        self.note_beefy_block(signed_commitment.commitment.block_number).await,
+                    ),
+                    None => break,
+                },
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Indexes the finalized blocks after the last indexed one.
+    async fn index_finalized(&self) -> Result<(), String> {
+        let finalized = self.client.info().finalized_number;
+        let mut conn = self.pool.get().await.map_err(|e| e.to_string())?;
+        let next = match db::last_indexed_block(&mut conn)
+            .await
+            .map_err(|e| e.to_string())?
+        {
+            Some(block_number) => block_number as BlockNumber + 1,
+            None => finalized,
+        };
+
+        for block_number in next..=finalized {
+            let hash = self
+                .client
+                .hash(block_number)
+                .map_err(|e| e.to_string())?
+                .ok_or_else(|| format!("Finalized block #{block_number} not found"))?;
+            let runtime_api = self.client.runtime_api();
+            let events = if runtime_api
+                .has_api::<dyn BridgeTrackingApi<Block>>(hash)
+                .map_err(|e| e.to_string())?
+            {
+                runtime_api.bridge_events(hash).map_err(|e| e.to_string())?
+            } else {
+                Vec::new()
+            };
+            db::index_block(&mut conn, block_number.into(), hash.as_bytes(), events)
+                .await
+                .map_err(|e| e.to_string())?;
+        }
+
+        Ok(())
+    }
+
+    async fn note_beefy_block(&self, block_number: BlockNumber) -> Result<(), String> {
+        let mut conn = self.pool.get().await.map_err(|e| e.to_string())?;
+        db::note_beefy_block(&mut conn, block_number.into())
+            .await
+            .map_err(|e| e.to_string())
+    }
+}
+
+/// Failures are retried on the next notification, the index resuming from its last block.
+fn log_error(result: Result<(), String>) {
+    if let Err(e) = result {
+        warn!(target: LOG_TARGET, "Failed to update the bridge index: {e}");
+    }
+}
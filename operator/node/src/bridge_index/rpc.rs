@@ -0,0 +1,354 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! The `bridge_*` RPC methods looking up the status of the messages in the bridge index.
+
+use super::db::{self, InboundMessage, InboundStatus, OutboundCommitment, OutboundMessage};
+use datahaven_runtime_common::BlockNumber;
+use diesel_async::AsyncPgConnection;
+use jsonrpsee::{
+    core::RpcResult,
+    proc_macros::rpc,
+    types::error::{ErrorObject, ErrorObjectOwned},
+};
+use serde::Serialize;
+use shc_indexer_db::DbPool;
+use sp_core::H256;
+
+/// Error code returned when the index can't be read.
+const INDEX_ERROR: i32 = 1;
+
+/// Progress of an outbound message.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum OutboundStatus {
+    /// The message waits in the message queue for its nonce.
+    Queued,
+    /// The message was committed, its commitment waiting for BEEFY finality.
+    Committed,
+    /// The commitment of the message is provable to Ethereum through a BEEFY finalized MMR.
+    BeefyFinalized,
+    /// The message was dispatched successfully on Ethereum.
+    Delivered,
+    /// The message was delivered but failed on Ethereum.
+    DeliveryFailed,
+}
+
+/// Commitment including an outbound message.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Commitment {
+    pub block_number: BlockNumber,
+    pub block_hash: H256,
+    pub root: H256,
+    pub message_count: u64,
+    pub beefy_block: Option<BlockNumber>,
+}
+
+/// Status of an outbound message.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OutboundMessageStatus {
+    pub id: H256,
+    pub status: OutboundStatus,
+    pub nonce: Option<u64>,
+    pub queued_block: Option<BlockNumber>,
+    pub queued_extrinsic: Option<u32>,
+    pub commitment: Option<Commitment>,
+    pub delivered_block: Option<BlockNumber>,
+}
+
+/// Status of an inbound message.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InboundMessageStatus {
+    pub nonce: u64,
+    pub status: InboundStatus,
+    pub block_number: BlockNumber,
+    pub extrinsic_index: Option<u32>,
+    pub message_id: Option<H256>,
+    pub error: Option<String>,
+}
+
+#[rpc(server)]
+pub trait BridgeApi {
+    /// Status of the outbound message with the given nonce.
+    #[method(name = "bridge_outboundMessageByNonce")]
+    async fn outbound_message_by_nonce(
+        &self,
+        nonce: u64,
+    ) -> RpcResult<Option<OutboundMessageStatus>>;
+
+    /// Status of the outbound message with the given id.
+    #[method(name = "bridge_outboundMessageById")]
+    async fn outbound_message_by_id(&self, id: H256) -> RpcResult<Option<OutboundMessageStatus>>;
+
+    /// Status of the outbound messages queued by an extrinsic.
+    #[method(name = "bridge_outboundMessagesByExtrinsic")]
+    async fn outbound_messages_by_extrinsic(
+        &self,
+        block_number: BlockNumber,
+        extrinsic_index: u32,
+    ) -> RpcResult<Vec<OutboundMessageStatus>>;
+
+    /// Status of the inbound message with the given nonce.
+    #[method(name = "bridge_inboundMessage")]
+    async fn inbound_message(&self, nonce: u64) -> RpcResult<Option<InboundMessageStatus>>;
+
+    /// Number of the last finalized block indexed.
+    #[method(name = "bridge_indexedBlock")]
+    async fn indexed_block(&self) -> RpcResult<Option<BlockNumber>>;
+}
+
+/// Implementation of [`BridgeApiServer`] reading the bridge index database.
+pub struct BridgeIndex {
+    pool: DbPool,
+}
+
+impl BridgeIndex {
+    pub fn new(pool: DbPool) -> Self {
+        Self { pool }
+    }
+
+    async fn outbound_status(
+        conn: &mut AsyncPgConnection,
+        message: OutboundMessage,
+    ) -> RpcResult<OutboundMessageStatus> {
+        let commitment = match message.committed_block {
+            Some(block_number) => db::outbound_commitment(conn, block_number)
+                .await
+                .map_err(index_error)?,
+            None => None,
+        };
+        Ok(outbound_message_status(message, commitment))
+    }
+
+    async fn maybe_outbound_status(
+        conn: &mut AsyncPgConnection,
+        message: Option<OutboundMessage>,
+    ) -> RpcResult<Option<OutboundMessageStatus>> {
+        match message {
+            Some(message) => Self::outbound_status(conn, message).await.map(Some),
+            None => Ok(None),
+        }
+    }
+}
+
+fn index_error(message: impl ToString) -> ErrorObjectOwned {
+    ErrorObject::owned(INDEX_ERROR, message.to_string(), None::<()>)
+}
+
+fn outbound_message_status(
+    message: OutboundMessage,
+    commitment: Option<OutboundCommitment>,
+) -> OutboundMessageStatus {
+    let beefy_finalized = commitment
+        .as_ref()
+        .is_some_and(|commitment| commitment.beefy_block.is_some());
+    let status = match message.delivery_success {
+        Some(true) => OutboundStatus::Delivered,
+        Some(false) => OutboundStatus::DeliveryFailed,
+        None if beefy_finalized => OutboundStatus::BeefyFinalized,
+        None if message.nonce.is_some() => OutboundStatus::Committed,
+        None => OutboundStatus::Queued,
+    };
+
+    OutboundMessageStatus {
+        id: H256::from_slice(&message.message_id),
+        status,
+        nonce: message.nonce.map(|nonce| nonce as u64),
+        queued_block: message.queued_block.map(|block| block as BlockNumber),
+        queued_extrinsic: message.queued_extrinsic.map(|index| index as u32),
+        commitment: commitment.map(|commitment| Commitment {
+            block_number: commitment.block_number as BlockNumber,
+            block_hash: H256::from_slice(&commitment.block_hash),
+            root: H256::from_slice(&commitment.root),
+            message_count: commitment.message_count as u64,
+            beefy_block: commitment.beefy_block.map(|block| block as BlockNumber),
+        }),
+        delivered_block: message.delivered_block.map(|block| block as BlockNumber),
+    }
+}
+
+fn inbound_status(message: InboundMessage) -> RpcResult<InboundMessageStatus> {
+    let status = InboundStatus::from_column(&message.status)
+        .ok_or_else(|| index_error(format!("Unknown inbound status {}", message.status)))?;
+
+    Ok(InboundMessageStatus {
+        nonce: message.nonce as u64,
+        status,
+        block_number: message.block_number as BlockNumber,
+        extrinsic_index: message.extrinsic_index.map(|index| index as u32),
+        message_id: message.message_id.map(|id| H256::from_slice(&id)),
+        error: message.error,
+    })
+}
+
+#[jsonrpsee::core::async_trait]
+impl BridgeApiServer for BridgeIndex {
+    async fn outbound_message_by_nonce(
+        &self,
+        nonce: u64,
+    ) -> RpcResult<Option<OutboundMessageStatus>> {
+        let mut conn = self.pool.get().await.map_err(index_error)?;
+        let message = db::outbound_message_by_nonce(&mut conn, nonce as i64)
+            .await
+            .map_err(index_error)?;
+        Self::maybe_outbound_status(&mut conn, message).await
+    }
+
+    async fn outbound_message_by_id(&self, id: H256) -> RpcResult<Option<OutboundMessageStatus>> {
+        let mut conn = self.pool.get().await.map_err(index_error)?;
+        let message = db::outbound_message_by_id(&mut conn, id.as_bytes())
+            .await
+            .map_err(index_error)?;
+        Self::maybe_outbound_status(&mut conn, message).await
+    }
+
+    async fn outbound_messages_by_extrinsic(
+        &self,
+        block_number: BlockNumber,
+        extrinsic_index: u32,
+    ) -> RpcResult<Vec<OutboundMessageStatus>> {
+        let mut conn = self.pool.get().await.map_err(index_error)?;
+        let messages = db::outbound_messages_by_extrinsic(
+            &mut conn,
+            block_number.into(),
+            extrinsic_index as i32,
+        )
+        .await
+        .map_err(index_error)?;
+
+        let mut statuses = Vec::with_capacity(messages.len());
+        for message in messages {
+            statuses.push(Self::outbound_status(&mut conn, message).await?);
+        }
+        Ok(statuses)
+    }
+
+    async fn inbound_message(&self, nonce: u64) -> RpcResult<Option<InboundMessageStatus>> {
+        let mut conn = self.pool.get().await.map_err(index_error)?;
+        db::inbound_message(&mut conn, nonce as i64)
+            .await
+            .map_err(index_error)?
+            .map(inbound_status)
+            .transpose()
+    }
+
+    async fn indexed_block(&self) -> RpcResult<Option<BlockNumber>> {
+        let mut conn = self.pool.get().await.map_err(index_error)?;
+        let block_number = db::last_indexed_block(&mut conn)
+            .await
+            .map_err(index_error)?;
+        Ok(block_number.map(|block_number| block_number as BlockNumber))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn message(nonce: Option<i64>, delivery_success: Option<bool>) -> OutboundMessage {
+        OutboundMessage {
+            message_id: vec![1; 32],
+            queued_block: Some(10),
+            queued_extrinsic: Some(2),
+            nonce,
+            committed_block: nonce.map(|_| 11),
+            delivered_block: delivery_success.map(|_| 20),
+            delivery_success,
+        }
+    }
+
+    fn commitment(beefy_block: Option<i64>) -> OutboundCommitment {
+        OutboundCommitment {
+            block_number: 11,
+            block_hash: vec![2; 32],
+            root: vec![3; 32],
+            message_count: 4,
+            beefy_block,
+        }
+    }
+
+    #[test]
+    fn outbound_status_follows_the_message_through_the_bridge() {
+        let queued = outbound_message_status(message(None, None), None);
+        assert_eq!(queued.status, OutboundStatus::Queued);
+        assert_eq!(queued.id, H256::repeat_byte(1));
+        assert_eq!(queued.queued_block, Some(10));
+        assert_eq!(queued.queued_extrinsic, Some(2));
+        assert!(queued.commitment.is_none());
+
+        let committed = outbound_message_status(message(Some(0), None), Some(commitment(None)));
+        assert_eq!(committed.status, OutboundStatus::Committed);
+        assert_eq!(committed.nonce, Some(0));
+
+        let finalized = outbound_message_status(message(Some(0), None), Some(commitment(Some(12))));
+        assert_eq!(finalized.status, OutboundStatus::BeefyFinalized);
+        let finalized_commitment = finalized.commitment.unwrap();
+        assert_eq!(finalized_commitment.block_number, 11);
+        assert_eq!(finalized_commitment.block_hash, H256::repeat_byte(2));
+        assert_eq!(finalized_commitment.root, H256::repeat_byte(3));
+        assert_eq!(finalized_commitment.message_count, 4);
+        assert_eq!(finalized_commitment.beefy_block, Some(12));
+
+        let delivered =
+            outbound_message_status(message(Some(0), Some(true)), Some(commitment(Some(12))));
+        assert_eq!(delivered.status, OutboundStatus::Delivered);
+        assert_eq!(delivered.delivered_block, Some(20));
+
+        let failed =
+            outbound_message_status(message(Some(0), Some(false)), Some(commitment(Some(12))));
+        assert_eq!(failed.status, OutboundStatus::DeliveryFailed);
+    }
+
+    #[test]
+    fn inbound_status_is_read_from_its_column() {
+        let message = InboundMessage {
+            nonce: 5,
+            block_number: 30,
+            extrinsic_index: Some(1),
+            status: "dead_letter".into(),
+            message_id: None,
+            error: Some("Module error".into()),
+        };
+        let status = inbound_status(message).unwrap();
+        assert_eq!(status.nonce, 5);
+        assert_eq!(status.status, InboundStatus::DeadLetter);
+        assert_eq!(status.block_number, 30);
+        assert_eq!(status.extrinsic_index, Some(1));
+        assert_eq!(status.error.as_deref(), Some("Module error"));
+
+        let message = InboundMessage {
+            nonce: 5,
+            block_number: 30,
+            extrinsic_index: None,
+            status: "lost".into(),
+            message_id: None,
+            error: None,
+        };
+        assert!(inbound_status(message).is_err());
+    }
+
+    #[test]
+    fn statuses_are_serialized_in_camel_case() {
+        let status = outbound_message_status(message(Some(0), None), Some(commitment(Some(12))));
+        let value = serde_json::to_value(status).unwrap();
+        assert_eq!(value["status"], "beefyFinalized");
+        assert_eq!(value["queuedExtrinsic"], 2);
+        assert_eq!(value["commitment"]["beefyBlock"], 12);
+    }
+}
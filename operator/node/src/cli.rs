@@ -28,15 +28,48 @@ use shc_indexer_db::models::{FileFiltering, FileOrdering};
 use shc_indexer_service::IndexerMode;
 use shc_rpc::RpcConfig;
 use shp_types::StorageDataUnit;
+use std::str::FromStr;
 
 // Available Sealing methods.
-#[derive(Copy, Clone, Debug, Default, ValueEnum)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
 pub enum Sealing {
     /// Seal using rpc method.
     #[default]
     Manual,
     /// Seal when transaction is executed.
     Instant,
+    /// Seal a block every given number of milliseconds.
+    Interval(u64),
+    /// Seal when transaction is executed, and otherwise every given number of milliseconds.
+    Hybrid(u64),
+}
+
+impl FromStr for Sealing {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mode = s.to_lowercase();
+        match mode.as_str() {
+            "manual" => return Ok(Self::Manual),
+            "instant" => return Ok(Self::Instant),
+            _ => {}
+        }
+
+        let (mode, interval) = mode.split_once(':').ok_or_else(|| {
+            format!("Unknown sealing mode `{s}`, expected `manual`, `instant`, `interval:<ms>` or `hybrid:<ms>`")
+        })?;
+        let interval = interval
+            .parse::<u64>()
+            .ok()
+            .filter(|interval| *interval > 0)
+            .ok_or_else(|| format!("Invalid sealing interval `{interval}`"))?;
+
+        match mode {
+            "interval" => Ok(Self::Interval(interval)),
+            "hybrid" => Ok(Self::Hybrid(interval)),
+            _ => Err(format!("Unknown sealing mode `{s}`")),
+        }
+    }
 }
 
 #[derive(Debug, Parser)]
@@ -48,8 +81,8 @@ pub struct Cli {
     #[command(flatten)]
     pub run: RunCmd,
 
-    /// Choose sealing method.
-    #[arg(long, value_enum, ignore_case = true)]
+    /// Choose sealing method: `manual`, `instant`, `interval:<ms>` or `hybrid:<ms>`.
+    #[arg(long)]
     pub sealing: Option<Sealing>,
 
     #[command(flatten)]
@@ -80,6 +113,10 @@ pub struct Cli {
     #[command(flatten)]
     pub indexer_config: IndexerConfigurations,
 
+    /// Bridge index configurations
+    #[command(flatten)]
+    pub bridge_index_config: BridgeIndexConfigurations,
+
     /// Fisherman configurations
     #[command(flatten)]
     pub fisherman_config: FishermanConfigurations,
@@ -718,6 +755,38 @@ impl IndexerConfigurations {
     }
 }
 
+#[derive(Debug, Parser, Clone)]
+pub struct BridgeIndexConfigurations {
+    /// Enable the index of the messages crossing the bridge, and the `bridge_*` RPC methods.
+    #[arg(long)]
+    pub bridge_index: bool,
+
+    /// Postgres database URL of the bridge index.
+    ///
+    /// If not provided, the bridge index will use the `BRIDGE_INDEX_DATABASE_URL` environment
+    /// variable. If the environment variable is not set, the node will abort.
+    #[arg(
+        long("bridge-index-database-url"),
+        env = "BRIDGE_INDEX_DATABASE_URL",
+        required_if_eq("bridge_index", "true")
+    )]
+    pub bridge_index_database_url: Option<String>,
+}
+
+impl BridgeIndexConfigurations {
+    pub fn database_url(&self) -> Option<String> {
+        if self.bridge_index {
+            Some(
+                self.bridge_index_database_url
+                    .clone()
+                    .expect("Bridge index database URL is required"),
+            )
+        } else {
+            None
+        }
+    }
+}
+
 /// Filtering strategy for fisherman pending deletion queries.
 #[derive(ValueEnum, Clone, Debug, Default)]
 pub enum FishermanFiltering {
@@ -400,6 +400,8 @@ pub fn run() -> sc_cli::Result<()> {
                 ));
             };
 
+            let bridge_index_database_url = cli.bridge_index_config.database_url();
+
             if let Some(logical_cpus) = std::thread::available_parallelism().map(|n| n.get()).ok() {
                 log::info!(
                     "💻 DataHaven node starting with {} logical CPU(s) visible to the process",
@@ -430,7 +432,12 @@ pub fn run() -> sc_cli::Result<()> {
                                     datahaven_mainnet_runtime::RuntimeApi,
                                     sc_network::NetworkWorker<_, _>,
                                 >(
-                                    config, cli.eth, role_options, indexer_options, sealing_mode
+                                    config,
+                                    cli.eth,
+                                    role_options,
+                                    indexer_options,
+                                    bridge_index_database_url,
+                                    sealing_mode,
                                 )
                                 .await
                             }
@@ -440,7 +447,12 @@ pub fn run() -> sc_cli::Result<()> {
                                     datahaven_testnet_runtime::RuntimeApi,
                                     sc_network::NetworkWorker<_, _>,
                                 >(
-                                    config, cli.eth, role_options, indexer_options, sealing_mode
+                                    config,
+                                    cli.eth,
+                                    role_options,
+                                    indexer_options,
+                                    bridge_index_database_url,
+                                    sealing_mode,
                                 )
                                 .await
                             }
@@ -450,7 +462,12 @@ pub fn run() -> sc_cli::Result<()> {
                                     datahaven_stagenet_runtime::RuntimeApi,
                                     sc_network::NetworkWorker<_, _>,
                                 >(
-                                    config, cli.eth, role_options, indexer_options, sealing_mode
+                                    config,
+                                    cli.eth,
+                                    role_options,
+                                    indexer_options,
+                                    bridge_index_database_url,
+                                    sealing_mode,
                                 )
                                 .await
                             }
@@ -465,7 +482,12 @@ pub fn run() -> sc_cli::Result<()> {
                                     datahaven_mainnet_runtime::RuntimeApi,
                                     sc_network::Litep2pNetworkBackend,
                                 >(
-                                    config, cli.eth, role_options, indexer_options, sealing_mode
+                                    config,
+                                    cli.eth,
+                                    role_options,
+                                    indexer_options,
+                                    bridge_index_database_url,
+                                    sealing_mode,
                                 )
                                 .await
                             }
@@ -475,7 +497,12 @@ pub fn run() -> sc_cli::Result<()> {
                                     datahaven_testnet_runtime::RuntimeApi,
                                     sc_network::Litep2pNetworkBackend,
                                 >(
-                                    config, cli.eth, role_options, indexer_options, sealing_mode
+                                    config,
+                                    cli.eth,
+                                    role_options,
+                                    indexer_options,
+                                    bridge_index_database_url,
+                                    sealing_mode,
                                 )
                                 .await
                             }
@@ -485,7 +512,12 @@ pub fn run() -> sc_cli::Result<()> {
                                     datahaven_stagenet_runtime::RuntimeApi,
                                     sc_network::Litep2pNetworkBackend,
                                 >(
-                                    config, cli.eth, role_options, indexer_options, sealing_mode
+                                    config,
+                                    cli.eth,
+                                    role_options,
+                                    indexer_options,
+                                    bridge_index_database_url,
+                                    sealing_mode,
                                 )
                                 .await
                             }
@@ -0,0 +1,188 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! The `dev_*` RPC methods of the nodes sealing their own blocks.
+//!
+//! Sessions follow the BABE epochs, which end after a number of slots. Blocks are sealed with a
+//! mock timestamp advancing one slot per block, so a session is forced by skipping the slots left
+//! in the current epoch before sealing the next block.
+
+use crate::service::{mock_slot, skip_mock_slots};
+use datahaven_runtime_common::{AccountId, Block, Hash};
+use futures::{
+    channel::{mpsc, oneshot},
+    SinkExt,
+};
+use jsonrpsee::{
+    core::RpcResult,
+    proc_macros::rpc,
+    types::error::{ErrorObject, ErrorObjectOwned},
+};
+use pallet_external_validators_rpc::ExternalValidatorsRuntimeApi;
+use pallet_external_validators_runtime_api::EraIndex;
+use sc_consensus_manual_seal::rpc::EngineCommand;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_consensus_babe::{BabeApi, SlotDuration};
+use std::sync::Arc;
+
+/// Error code returned when a block can't be sealed.
+const SEALING_ERROR: i32 = 1;
+
+/// Maximum number of blocks sealed by one call.
+const MAX_SEALED_BLOCKS: u32 = 1000;
+
+#[rpc(server)]
+pub trait DevApi {
+    /// Seal `count` blocks, returning the hash of the last one.
+    #[method(name = "dev_sealBlocks")]
+    async fn seal_blocks(&self, count: u32) -> RpcResult<Hash>;
+
+    /// Seal a block starting a new session, returning its hash.
+    #[method(name = "dev_forceSession")]
+    async fn force_session(&self) -> RpcResult<Hash>;
+
+    /// Seal blocks starting new sessions until a new era is active, returning its index.
+    #[method(name = "dev_forceEra")]
+    async fn force_era(&self) -> RpcResult<EraIndex>;
+}
+
+/// Implementation of [`DevApiServer`] sending its blocks to the manual seal task.
+pub struct Dev<C> {
+    client: Arc<C>,
+    command_sink: mpsc::Sender<EngineCommand<Hash>>,
+    slot_duration: SlotDuration,
+}
+
+impl<C> Dev<C> {
+    pub fn new(
+        client: Arc<C>,
+        command_sink: mpsc::Sender<EngineCommand<Hash>>,
+        slot_duration: SlotDuration,
+    ) -> Self {
+        Self {
+            client,
+            command_sink,
+            slot_duration,
+        }
+    }
+}
+
+fn sealing_error(message: impl ToString) -> ErrorObjectOwned {
+    ErrorObject::owned(SEALING_ERROR, message.to_string(), None::<()>)
+}
+
+impl<C> Dev<C>
+where
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: BabeApi<Block> + ExternalValidatorsRuntimeApi<Block, AccountId>,
+{
+    async fn seal_block(&self) -> RpcResult<Hash> {
+        let (sender, receiver) = oneshot::channel();
+        self.command_sink
+            .clone()
+            .send(EngineCommand::SealNewBlock {
+                create_empty: true,
+                finalize: false,
+                parent_hash: None,
+                sender: Some(sender),
+            })
+            .await
+            .map_err(sealing_error)?;
+
+        let block = receiver
+            .await
+            .map_err(sealing_error)?
+            .map_err(sealing_error)?;
+        Ok(block.hash)
+    }
+
+    async fn seal_session_block(&self) -> RpcResult<Hash> {
+        let best_hash = self.client.info().best_hash;
+        let epoch = self
+            .client
+            .runtime_api()
+            .current_epoch(best_hash)
+            .map_err(sealing_error)?;
+
+        // The next block starts a new session once it is in the slot ending the epoch
+        let next_epoch_slot = *epoch.start_slot + epoch.duration;
+        let next_slot = *mock_slot(self.slot_duration) + 1;
+        skip_mock_slots(
+            next_epoch_slot.saturating_sub(next_slot),
+            self.slot_duration,
+        );
+
+        self.seal_block().await
+    }
+
+    fn active_era(&self) -> RpcResult<Option<EraIndex>> {
+        let best_hash = self.client.info().best_hash;
+        let summary = self
+            .client
+            .runtime_api()
+            .era_summary(best_hash)
+            .map_err(sealing_error)?;
+        Ok(summary.active_era)
+    }
+}
+
+#[jsonrpsee::core::async_trait]
+impl<C> DevApiServer for Dev<C>
+where
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: BabeApi<Block> + ExternalValidatorsRuntimeApi<Block, AccountId>,
+{
+    async fn seal_blocks(&self, count: u32) -> RpcResult<Hash> {
+        if count == 0 || count > MAX_SEALED_BLOCKS {
+            return Err(sealing_error(format!(
+                "count must be between 1 and {MAX_SEALED_BLOCKS}"
+            )));
+        }
+
+        let mut hash = self.seal_block().await?;
+        for _ in 1..count {
+            hash = self.seal_block().await?;
+        }
+        Ok(hash)
+    }
+
+    async fn force_session(&self) -> RpcResult<Hash> {
+        self.seal_session_block().await
+    }
+
+    async fn force_era(&self) -> RpcResult<EraIndex> {
+        let best_hash = self.client.info().best_hash;
+        let summary = self
+            .client
+            .runtime_api()
+            .era_summary(best_hash)
+            .map_err(sealing_error)?;
+        let active_era = summary.active_era;
+
+        // The era rotates once `SessionsPerEra` sessions ended, a new era being planned one
+        // session before it becomes active
+        for _ in 0..summary.sessions_per_era.saturating_add(1) {
+            self.seal_session_block().await?;
+            match self.active_era()? {
+                Some(era) if Some(era) != active_era => return Ok(era),
+                _ => {}
+            }
+        }
+
+        Err(sealing_error("No new era started"))
+    }
+}
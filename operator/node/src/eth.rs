@@ -60,6 +60,15 @@ pub enum BackendType {
     Sql,
 }
 
+/// Ethereum RPC namespaces served on top of the default ones.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, clap::ValueEnum)]
+pub enum EthApi {
+    /// The `debug_trace*` methods.
+    Debug,
+    /// The `trace_filter` method.
+    Trace,
+}
+
 /// The ethereum-compatibility configuration used to run a node.
 #[derive(Clone, Debug, clap::Parser)]
 pub struct EthConfiguration {
@@ -111,6 +120,24 @@ pub struct EthConfiguration {
     /// Default value is 200MB.
     #[arg(long, default_value = "209715200")]
     pub frontier_sql_backend_cache_size: u64,
+
+    /// Additional Ethereum RPC namespaces to serve (`debug`, `trace`).
+    /// They replay transactions with the tracers of a runtime built with
+    /// `--features evm-tracing`, which the node runs through `--wasm-runtime-overrides`.
+    #[arg(long, value_delimiter = ',')]
+    pub ethapi: Vec<EthApi>,
+
+    /// Maximum number of tracing requests replayed at the same time.
+    #[arg(long, default_value = "10")]
+    pub ethapi_max_permits: u32,
+
+    /// Maximum number of traces returned by `trace_filter`.
+    #[arg(long, default_value = "500")]
+    pub ethapi_trace_max_count: u32,
+
+    /// Maximum number of blocks replayed by a `trace_filter` request.
+    #[arg(long, default_value = "100")]
+    pub ethapi_trace_max_block_range: u32,
 }
 
 pub struct FrontierPartialComponents {
@@ -0,0 +1,797 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! The `debug_trace*` and `trace_filter` RPC methods.
+//!
+//! Transactions are replayed by the runtime through [`EvmTracingApi`], which only traces when
+//! the node runs a runtime built with the `evm-tracing` feature. Replaying is heavy, so it runs
+//! on blocking tasks, a limited number at a time.
+
+use datahaven_runtime_common::Block;
+use dhp_evm_tracing::{
+    CallTrace, CallType, EvmTracingApi, RawConfig, TracerKind, TransactionTrace,
+};
+use fc_rpc::{frontier_backend_client, internal_err};
+use fc_rpc_core::types::BlockNumberOrHash;
+use fp_rpc::EthereumRuntimeRPCApi;
+use futures::channel::oneshot;
+use jsonrpsee::{core::RpcResult, proc_macros::rpc};
+use sc_client_api::BlockBackend;
+use sc_service::SpawnTaskHandle;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::{Bytes, H160, H256, U256};
+use sp_runtime::traits::{Block as BlockT, Header as HeaderT, UniqueSaturatedInto};
+use std::sync::Arc;
+use tokio::sync::Semaphore;
+
+/// Options of the `debug_trace*` methods, as accepted by geth.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TraceParams {
+    /// `callTracer` or `prestateTracer`, the opcodes are traced when missing.
+    pub tracer: Option<String>,
+    pub disable_storage: Option<bool>,
+    pub disable_memory: Option<bool>,
+    pub disable_stack: Option<bool>,
+}
+
+/// Call traced by `debug_traceCall`.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TraceCallRequest {
+    pub from: Option<H160>,
+    pub to: H160,
+    pub gas: Option<U256>,
+    pub max_fee_per_gas: Option<U256>,
+    pub max_priority_fee_per_gas: Option<U256>,
+    pub value: Option<U256>,
+    #[serde(alias = "input")]
+    pub data: Option<Bytes>,
+    pub nonce: Option<U256>,
+}
+
+/// Trace of a transaction of a block.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockTransactionTrace {
+    pub tx_hash: H256,
+    pub result: TransactionTrace,
+}
+
+/// Filter of `trace_filter`.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TraceFilter {
+    pub from_block: Option<BlockNumberOrHash>,
+    pub to_block: Option<BlockNumberOrHash>,
+    pub from_address: Option<Vec<H160>>,
+    pub to_address: Option<Vec<H160>>,
+    pub after: Option<u32>,
+    pub count: Option<u32>,
+}
+
+#[rpc(server)]
+pub trait DebugApi {
+    /// Replay an Ethereum transaction with a tracer.
+    #[method(name = "debug_traceTransaction")]
+    async fn trace_transaction(
+        &self,
+        transaction_hash: H256,
+        params: Option<TraceParams>,
+    ) -> RpcResult<TransactionTrace>;
+
+    /// Replay the Ethereum transactions of a block with a tracer.
+    #[method(name = "debug_traceBlockByNumber")]
+    async fn trace_block_by_number(
+        &self,
+        number: BlockNumberOrHash,
+        params: Option<TraceParams>,
+    ) -> RpcResult<Vec<BlockTransactionTrace>>;
+
+    /// Replay the Ethereum transactions of a block with a tracer.
+    #[method(name = "debug_traceBlockByHash")]
+    async fn trace_block_by_hash(
+        &self,
+        hash: H256,
+        params: Option<TraceParams>,
+    ) -> RpcResult<Vec<BlockTransactionTrace>>;
+
+    /// Trace a call on top of a block, without submitting it.
+    #[method(name = "debug_traceCall")]
+    async fn trace_call(
+        &self,
+        request: TraceCallRequest,
+        number: Option<BlockNumberOrHash>,
+        params: Option<TraceParams>,
+    ) -> RpcResult<TransactionTrace>;
+}
+
+#[rpc(server)]
+pub trait TraceApi {
+    /// Calls made by the Ethereum transactions of a range of blocks, in the Parity format.
+    #[method(name = "trace_filter")]
+    async fn filter(&self, filter: TraceFilter) -> RpcResult<Vec<Value>>;
+}
+
+/// Limits of the tracing methods, shared by all the RPC connections.
+#[derive(Clone)]
+pub struct EvmTracingConfig {
+    /// Serve the `debug_trace*` methods.
+    pub debug: bool,
+    /// Serve the `trace_filter` method.
+    pub trace: bool,
+    /// Replays allowed to run at the same time.
+    pub permits: Arc<Semaphore>,
+    /// Maximum number of traces returned by `trace_filter`.
+    pub max_count: u32,
+    /// Maximum number of blocks replayed by `trace_filter`.
+    pub max_block_range: u32,
+    /// Spawner of the blocking replay tasks.
+    pub spawn_handle: SpawnTaskHandle,
+}
+
+/// Implementation of [`DebugApiServer`] and [`TraceApiServer`] backed by the runtime API.
+pub struct EvmTracing<C> {
+    client: Arc<C>,
+    frontier_backend: Arc<dyn fc_api::Backend<Block>>,
+    config: EvmTracingConfig,
+}
+
+impl<C> EvmTracing<C> {
+    pub fn new(
+        client: Arc<C>,
+        frontier_backend: Arc<dyn fc_api::Backend<Block>>,
+        config: EvmTracingConfig,
+    ) -> Self {
+        Self {
+            client,
+            frontier_backend,
+            config,
+        }
+    }
+}
+
+impl<C> EvmTracing<C>
+where
+    C: ProvideRuntimeApi<Block>
+        + HeaderBackend<Block>
+        + BlockBackend<Block>
+        + Send
+        + Sync
+        + 'static,
+    C::Api: EvmTracingApi<Block> + EthereumRuntimeRPCApi<Block>,
+{
+    /// Run `replay` on a blocking task once a permit is available.
+    async fn replay<R: Send + 'static>(
+        &self,
+        replay: impl FnOnce(&C) -> RpcResult<R> + Send + 'static,
+    ) -> RpcResult<R> {
+        let _permit = self
+            .config
+            .permits
+            .acquire()
+            .await
+            .map_err(|_| internal_err("Tracing is shutting down"))?;
+
+        let client = self.client.clone();
+        let (sender, receiver) = oneshot::channel();
+        self.config
+            .spawn_handle
+            .spawn_blocking("evm-tracing", Some("rpc"), async move {
+                let _ = sender.send(replay(&client));
+            });
+
+        receiver
+            .await
+            .map_err(|_| internal_err("Tracing task was cancelled"))?
+    }
+
+    /// Substrate hash of the block `number`.
+    async fn block_hash(&self, number: BlockNumberOrHash) -> RpcResult<H256> {
+        let id = frontier_backend_client::native_block_id::<Block, C>(
+            &self.client,
+            self.frontier_backend.as_ref(),
+            Some(number),
+        )
+        .await?
+        .ok_or_else(|| internal_err("Block not found"))?;
+
+        self.client
+            .expect_block_hash_from_id(&id)
+            .map_err(|err| internal_err(format!("Block not found: {err}")))
+    }
+
+    /// Substrate hash of the block with Ethereum hash `hash`.
+    async fn block_hash_from_ethereum(&self, hash: H256) -> RpcResult<H256> {
+        frontier_backend_client::load_hash::<Block, C>(
+            &self.client,
+            self.frontier_backend.as_ref(),
+            hash,
+        )
+        .await?
+        .ok_or_else(|| internal_err("Block not found"))
+    }
+
+    async fn trace_block(
+        &self,
+        hash: H256,
+        tracer: TracerKind,
+    ) -> RpcResult<Vec<(H256, TransactionTrace)>> {
+        self.replay(move |client| {
+            let (parent_hash, header, extrinsics) = block_to_replay(client, hash)?;
+            runtime_result(client.runtime_api().trace_block(
+                parent_hash,
+                extrinsics,
+                &header,
+                tracer,
+            ))
+        })
+        .await
+    }
+}
+
+#[jsonrpsee::core::async_trait]
+impl<C> DebugApiServer for EvmTracing<C>
+where
+    C: ProvideRuntimeApi<Block>
+        + HeaderBackend<Block>
+        + BlockBackend<Block>
+        + Send
+        + Sync
+        + 'static,
+    C::Api: EvmTracingApi<Block> + EthereumRuntimeRPCApi<Block>,
+{
+    async fn trace_transaction(
+        &self,
+        transaction_hash: H256,
+        params: Option<TraceParams>,
+    ) -> RpcResult<TransactionTrace> {
+        let tracer = tracer_kind(params)?;
+        let (ethereum_block_hash, _) = frontier_backend_client::load_transactions::<Block, C>(
+            &self.client,
+            self.frontier_backend.as_ref(),
+            transaction_hash,
+            true,
+        )
+        .await?
+        .ok_or_else(|| internal_err("Transaction not found"))?;
+        let hash = self.block_hash_from_ethereum(ethereum_block_hash).await?;
+
+        self.replay(move |client| {
+            let (parent_hash, header, extrinsics) = block_to_replay(client, hash)?;
+            runtime_result(client.runtime_api().trace_transaction(
+                parent_hash,
+                extrinsics,
+                transaction_hash,
+                &header,
+                tracer,
+            ))
+        })
+        .await
+    }
+
+    async fn trace_block_by_number(
+        &self,
+        number: BlockNumberOrHash,
+        params: Option<TraceParams>,
+    ) -> RpcResult<Vec<BlockTransactionTrace>> {
+        let tracer = tracer_kind(params)?;
+        let hash = self.block_hash(number).await?;
+        let traces = self.trace_block(hash, tracer).await?;
+
+        Ok(traces
+            .into_iter()
+            .map(|(tx_hash, result)| BlockTransactionTrace { tx_hash, result })
+            .collect())
+    }
+
+    async fn trace_block_by_hash(
+        &self,
+        hash: H256,
+        params: Option<TraceParams>,
+    ) -> RpcResult<Vec<BlockTransactionTrace>> {
+        let tracer = tracer_kind(params)?;
+        let hash = self.block_hash_from_ethereum(hash).await?;
+        let traces = self.trace_block(hash, tracer).await?;
+
+        Ok(traces
+            .into_iter()
+            .map(|(tx_hash, result)| BlockTransactionTrace { tx_hash, result })
+            .collect())
+    }
+
+    async fn trace_call(
+        &self,
+        request: TraceCallRequest,
+        number: Option<BlockNumberOrHash>,
+        params: Option<TraceParams>,
+    ) -> RpcResult<TransactionTrace> {
+        let tracer = tracer_kind(params)?;
+        let hash = self
+            .block_hash(number.unwrap_or(BlockNumberOrHash::Latest))
+            .await?;
+
+        self.replay(move |client| {
+            let header = client
+                .header(hash)
+                .map_err(|err| internal_err(format!("Block not found: {err}")))?
+                .ok_or_else(|| internal_err("Block not found"))?;
+            // The call is traced in a new block on top of `hash`
+            let header = <Block as BlockT>::Header::new(
+                header.number() + 1,
+                Default::default(),
+                Default::default(),
+                hash,
+                Default::default(),
+            );
+
+            runtime_result(client.runtime_api().trace_call(
+                hash,
+                &header,
+                request.from.unwrap_or_default(),
+                request.to,
+                request.data.map(|data| data.0).unwrap_or_default(),
+                request.value.unwrap_or_default(),
+                request.gas.unwrap_or(U256::from(u64::MAX)),
+                request.max_fee_per_gas,
+                request.max_priority_fee_per_gas,
+                request.nonce,
+                tracer,
+            ))
+        })
+        .await
+    }
+}
+
+#[jsonrpsee::core::async_trait]
+impl<C> TraceApiServer for EvmTracing<C>
+where
+    C: ProvideRuntimeApi<Block>
+        + HeaderBackend<Block>
+        + BlockBackend<Block>
+        + Send
+        + Sync
+        + 'static,
+    C::Api: EvmTracingApi<Block> + EthereumRuntimeRPCApi<Block>,
+{
+    async fn filter(&self, filter: TraceFilter) -> RpcResult<Vec<Value>> {
+        let count = filter.count.unwrap_or(self.config.max_count);
+        if count > self.config.max_count {
+            return Err(internal_err(format!(
+                "count ({count}) can't be greater than maximum ({})",
+                self.config.max_count
+            )));
+        }
+
+        let from = self
+            .block_hash(filter.from_block.unwrap_or(BlockNumberOrHash::Latest))
+            .await?;
+        let to = self
+            .block_hash(filter.to_block.unwrap_or(BlockNumberOrHash::Latest))
+            .await?;
+        let from = block_number(&*self.client, from)?;
+        let to = block_number(&*self.client, to)?;
+        if to < from {
+            return Err(internal_err("fromBlock must not be after toBlock"));
+        }
+        if to - from >= self.config.max_block_range {
+            return Err(internal_err(format!(
+                "block range can't be greater than maximum ({})",
+                self.config.max_block_range
+            )));
+        }
+
+        let mut traces = Vec::new();
+        for number in from..=to {
+            let hash = self
+                .client
+                .hash(number)
+                .map_err(|err| internal_err(format!("Block not found: {err}")))?
+                .ok_or_else(|| internal_err("Block not found"))?;
+            let Some(ethereum_block) = self
+                .client
+                .runtime_api()
+                .current_block(hash)
+                .map_err(|err| internal_err(format!("Runtime API error: {err}")))?
+            else {
+                continue;
+            };
+            if ethereum_block.transactions.is_empty() {
+                continue;
+            }
+            let ethereum_block_hash = ethereum_block.header.hash();
+
+            for (transaction_hash, trace) in self.trace_block(hash, TracerKind::Call).await? {
+                let TransactionTrace::Call(call) = trace else {
+                    continue;
+                };
+                let position = ethereum_block
+                    .transactions
+                    .iter()
+                    .position(|transaction| transaction.hash() == transaction_hash)
+                    .unwrap_or_default();
+
+                let mut calls = Vec::new();
+                flatten(call, Vec::new(), &mut calls);
+                for (call, trace_address, subtraces) in calls {
+                    if !matches_filter(&filter, &call) {
+                        continue;
+                    }
+                    traces.push(parity_trace(
+                        call,
+                        trace_address,
+                        subtraces,
+                        ethereum_block_hash,
+                        number,
+                        transaction_hash,
+                        position,
+                    ));
+                }
+            }
+
+            if traces.len() >= filter.after.unwrap_or_default().saturating_add(count) as usize {
+                break;
+            }
+        }
+
+        Ok(traces
+            .into_iter()
+            .skip(filter.after.unwrap_or_default() as usize)
+            .take(count as usize)
+            .collect())
+    }
+}
+
+fn tracer_kind(params: Option<TraceParams>) -> RpcResult<TracerKind> {
+    let params = params.unwrap_or_default();
+    match params.tracer.as_deref() {
+        Some("callTracer") => Ok(TracerKind::Call),
+        Some("prestateTracer") => Ok(TracerKind::Prestate),
+        Some(tracer) => Err(internal_err(format!("Unsupported tracer: {tracer}"))),
+        None => Ok(TracerKind::Raw(RawConfig {
+            disable_storage: params.disable_storage.unwrap_or_default(),
+            disable_memory: params.disable_memory.unwrap_or_default(),
+            disable_stack: params.disable_stack.unwrap_or_default(),
+        })),
+    }
+}
+
+/// Parent hash, header and extrinsics of the block `hash`.
+fn block_to_replay<C>(
+    client: &C,
+    hash: H256,
+) -> RpcResult<(
+    H256,
+    <Block as BlockT>::Header,
+    Vec<<Block as BlockT>::Extrinsic>,
+)>
+where
+    C: HeaderBackend<Block> + BlockBackend<Block>,
+{
+    let header = client
+        .header(hash)
+        .map_err(|err| internal_err(format!("Block not found: {err}")))?
+        .ok_or_else(|| internal_err("Block not found"))?;
+    let extrinsics = client
+        .block_body(hash)
+        .map_err(|err| internal_err(format!("Block body not found: {err}")))?
+        .ok_or_else(|| internal_err("Block body not found"))?;
+
+    Ok((*header.parent_hash(), header, extrinsics))
+}
+
+fn block_number<C: HeaderBackend<Block>>(client: &C, hash: H256) -> RpcResult<u32> {
+    client
+        .number(hash)
+        .map_err(|err| internal_err(format!("Block not found: {err}")))?
+        .map(UniqueSaturatedInto::unique_saturated_into)
+        .ok_or_else(|| internal_err("Block not found"))
+}
+
+fn runtime_result<T>(
+    result: Result<Result<T, sp_runtime::DispatchError>, sp_api::ApiError>,
+) -> RpcResult<T> {
+    result
+        .map_err(|err| internal_err(format!("Runtime API error: {err}")))?
+        .map_err(|err| internal_err(format!("Tracing failed: {err:?}")))
+}
+
+/// Calls of the tree of `call` in depth-first order, with their address in the tree and their
+/// number of subcalls.
+fn flatten(
+    mut call: CallTrace,
+    address: Vec<usize>,
+    calls: &mut Vec<(CallTrace, Vec<usize>, usize)>,
+) {
+    let subcalls = std::mem::take(&mut call.calls);
+    calls.push((call, address.clone(), subcalls.len()));
+
+    for (index, subcall) in subcalls.into_iter().enumerate() {
+        let mut subaddress = address.clone();
+        subaddress.push(index);
+        flatten(subcall, subaddress, calls);
+    }
+}
+
+fn matches_filter(filter: &TraceFilter, call: &CallTrace) -> bool {
+    let from_matches = filter
+        .from_address
+        .as_ref()
+        .is_none_or(|addresses| addresses.is_empty() || addresses.contains(&call.from));
+    let to_matches = filter
+        .to_address
+        .as_ref()
+        .is_none_or(|addresses| addresses.is_empty() || addresses.contains(&call.to));
+
+    from_matches && to_matches
+}
+
+fn parity_trace(
+    call: CallTrace,
+    trace_address: Vec<usize>,
+    subtraces: usize,
+    block_hash: H256,
+    block_number: u32,
+    transaction_hash: H256,
+    transaction_position: usize,
+) -> Value {
+    let is_create = matches!(call.call_type, CallType::Create | CallType::Create2);
+    let (action, result) = if is_create {
+        (
+            json!({
+                "from": call.from,
+                "gas": U256::from(call.gas),
+                "init": Bytes(call.input),
+                "value": call.value,
+            }),
+            json!({
+                "address": call.to,
+                "code": Bytes(call.output),
+                "gasUsed": U256::from(call.gas_used),
+            }),
+        )
+    } else {
+        let call_type = match call.call_type {
+            CallType::StaticCall => "staticcall",
+            CallType::DelegateCall => "delegatecall",
+            CallType::CallCode => "callcode",
+            _ => "call",
+        };
+        (
+            json!({
+                "callType": call_type,
+                "from": call.from,
+                "gas": U256::from(call.gas),
+                "input": Bytes(call.input),
+                "to": call.to,
+                "value": call.value,
+            }),
+            json!({
+                "gasUsed": U256::from(call.gas_used),
+                "output": Bytes(call.output),
+            }),
+        )
+    };
+
+    let mut trace = json!({
+        "action": action,
+        "blockHash": block_hash,
+        "blockNumber": block_number,
+        "subtraces": subtraces,
+        "traceAddress": trace_address,
+        "transactionHash": transaction_hash,
+        "transactionPosition": transaction_position,
+        "type": if is_create { "create" } else { "call" },
+    });
+    match call.error {
+        Some(error) => trace["error"] = json!(error),
+        None => trace["result"] = result,
+    }
+
+    trace
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn call(call_type: CallType, from: u8, to: u8, calls: Vec<CallTrace>) -> CallTrace {
+        CallTrace {
+            call_type,
+            from: H160::repeat_byte(from),
+            to: H160::repeat_byte(to),
+            value: U256::zero(),
+            gas: 100,
+            gas_used: 50,
+            input: vec![from],
+            output: vec![to],
+            error: None,
+            revert_reason: None,
+            calls,
+        }
+    }
+
+    fn filter(from_address: Option<Vec<H160>>, to_address: Option<Vec<H160>>) -> TraceFilter {
+        TraceFilter {
+            from_block: None,
+            to_block: None,
+            from_address,
+            to_address,
+            after: None,
+            count: None,
+        }
+    }
+
+    #[test]
+    fn tracer_is_picked_from_the_params() {
+        assert_eq!(
+            tracer_kind(None).unwrap(),
+            TracerKind::Raw(RawConfig::default())
+        );
+        let params = TraceParams {
+            tracer: Some("callTracer".into()),
+            ..Default::default()
+        };
+        assert_eq!(tracer_kind(Some(params)).unwrap(), TracerKind::Call);
+        let params = TraceParams {
+            tracer: Some("prestateTracer".into()),
+            ..Default::default()
+        };
+        assert_eq!(tracer_kind(Some(params)).unwrap(), TracerKind::Prestate);
+        let params = TraceParams {
+            disable_memory: Some(true),
+            disable_stack: Some(true),
+            ..Default::default()
+        };
+        assert_eq!(
+            tracer_kind(Some(params)).unwrap(),
+            TracerKind::Raw(RawConfig {
+                disable_storage: false,
+                disable_memory: true,
+                disable_stack: true,
+            })
+        );
+
+        let params = TraceParams {
+            tracer: Some("4byteTracer".into()),
+            ..Default::default()
+        };
+        assert!(tracer_kind(Some(params)).is_err());
+    }
+
+    #[test]
+    fn trace_params_are_deserialized_as_in_geth() {
+        let params: TraceParams =
+            serde_json::from_value(json!({ "tracer": "callTracer", "disableStorage": true }))
+                .unwrap();
+        assert_eq!(params.tracer.as_deref(), Some("callTracer"));
+        assert_eq!(params.disable_storage, Some(true));
+    }
+
+    #[test]
+    fn nested_calls_are_flattened_depth_first() {
+        let root = call(
+            CallType::Call,
+            1,
+            2,
+            vec![
+                call(
+                    CallType::DelegateCall,
+                    2,
+                    3,
+                    vec![call(CallType::StaticCall, 2, 4, vec![])],
+                ),
+                call(CallType::Call, 2, 5, vec![]),
+            ],
+        );
+
+        let mut calls = Vec::new();
+        flatten(root, vec![], &mut calls);
+
+        let flattened: Vec<_> = calls
+            .into_iter()
+            .map(|(call, address, subtraces)| {
+                assert!(call.calls.is_empty());
+                (call.to, address, subtraces)
+            })
+            .collect();
+        assert_eq!(
+            flattened,
+            vec![
+                (H160::repeat_byte(2), vec![], 2),
+                (H160::repeat_byte(3), vec![0], 1),
+                (H160::repeat_byte(4), vec![0, 0], 0),
+                (H160::repeat_byte(5), vec![1], 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn calls_are_filtered_by_address() {
+        let trace = call(CallType::Call, 1, 2, vec![]);
+
+        assert!(matches_filter(&filter(None, None), &trace));
+        assert!(matches_filter(&filter(Some(vec![]), Some(vec![])), &trace));
+        assert!(matches_filter(
+            &filter(
+                Some(vec![H160::repeat_byte(1)]),
+                Some(vec![H160::repeat_byte(2)])
+            ),
+            &trace
+        ));
+        assert!(!matches_filter(
+            &filter(Some(vec![H160::repeat_byte(2)]), None),
+            &trace
+        ));
+        assert!(!matches_filter(
+            &filter(None, Some(vec![H160::repeat_byte(1)])),
+            &trace
+        ));
+    }
+
+    #[test]
+    fn calls_are_shown_as_parity_traces() {
+        let block_hash = H256::repeat_byte(0xbb);
+        let transaction_hash = H256::repeat_byte(0xcc);
+        let trace = parity_trace(
+            call(CallType::StaticCall, 1, 2, vec![]),
+            vec![0, 1],
+            3,
+            block_hash,
+            7,
+            transaction_hash,
+            2,
+        );
+
+        assert_eq!(
+            trace,
+            json!({
+                "action": {
+                    "callType": "staticcall",
+                    "from": H160::repeat_byte(1),
+                    "gas": "0x64",
+                    "input": "0x01",
+                    "to": H160::repeat_byte(2),
+                    "value": "0x0",
+                },
+                "blockHash": block_hash,
+                "blockNumber": 7,
+                "result": { "gasUsed": "0x32", "output": "0x02" },
+                "subtraces": 3,
+                "traceAddress": [0, 1],
+                "transactionHash": transaction_hash,
+                "transactionPosition": 2,
+                "type": "call",
+            })
+        );
+    }
+
+    #[test]
+    fn failed_creates_are_shown_with_their_error() {
+        let mut create = call(CallType::Create2, 1, 2, vec![]);
+        create.error = Some("execution reverted".into());
+
+        let trace = parity_trace(create, vec![], 0, H256::zero(), 1, H256::zero(), 0);
+
+        assert_eq!(trace["type"], "create");
+        assert_eq!(trace["action"]["init"], "0x01");
+        assert_eq!(trace["error"], "execution reverted");
+        assert!(trace.get("result").is_none());
+    }
+}
@@ -0,0 +1,227 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Collects the GRANDPA justifications and BEEFY commitments seen by the node, so the blocks it
+//! authors note them in the finality proofs inherent of the validator rewards.
+//!
+//! The inherent data is created on every slot, whether the node authors a block in it or not, so
+//! the proofs are kept until newer ones are seen. The runtime leaves out of the inherent the
+//! proofs it already noted.
+
+use datahaven_runtime_common::{finality_liveness::FinalityProof, Block};
+use futures::StreamExt;
+use pallet_external_validators_rewards::inherent::InherentDataProvider;
+use sc_consensus_beefy::communication::notification::BeefyVersionedFinalityProofStream;
+use sc_consensus_grandpa::GrandpaJustificationStream;
+use sc_service::SpawnTaskHandle;
+use sp_consensus_beefy::{ecdsa_crypto::AuthorityId as BeefyId, VersionedFinalityProof};
+use std::sync::{Arc, Mutex};
+
+/// Queue size above which the notification streams warn about a slow consumer.
+const QUEUE_SIZE_WARNING: usize = 100;
+
+/// Latest proof of each gadget.
+#[derive(Default)]
+struct PendingProofs {
+    grandpa: Option<FinalityProof>,
+    beefy: Option<FinalityProof>,
+}
+
+/// Keeps the latest finality proof of each gadget, until a newer one replaces it.
+#[derive(Clone, Default)]
+pub struct FinalityProofCollector {
+    pending: Arc<Mutex<PendingProofs>>,
+}
+
+impl FinalityProofCollector {
+    /// Spawns the tasks following the GRANDPA and BEEFY justification streams.
+    pub fn spawn(
+        spawn_handle: SpawnTaskHandle,
+        grandpa_justifications: GrandpaJustificationStream<Block>,
+        beefy_proofs: BeefyVersionedFinalityProofStream<Block, BeefyId>,
+    ) -> Self {
+        let collector = Self::default();
+
+        let pending = collector.pending.clone();
+        spawn_handle.spawn(
+            "finality-liveness-grandpa",
+            Some("finality-liveness"),
+            grandpa_justifications
+                .subscribe(QUEUE_SIZE_WARNING)
+                .for_each(move |justification| {
+                    if let Ok(mut pending) = pending.lock() {
+                        pending.grandpa = Some(FinalityProof::Grandpa(justification.justification));
+                    }
+                    futures::future::ready(())
+                }),
+        );
+
+        let pending = collector.pending.clone();
+        spawn_handle.spawn(
+            "finality-liveness-beefy",
+            Some("finality-liveness"),
+            beefy_proofs
+                .subscribe(QUEUE_SIZE_WARNING)
+                .for_each(move |proof| {
+                    let VersionedFinalityProof::V1(signed_commitment) = proof;
+                    if let Ok(mut pending) = pending.lock() {
+                        pending.beefy = Some(FinalityProof::Beefy(signed_commitment.into()));
+                    }
+                    futures::future::ready(())
+                }),
+        );
+
+        collector
+    }
+
+    /// Copies the pending proofs into an inherent data provider for the block of the slot.
+    pub fn inherent_data_provider(&self) -> InherentDataProvider<FinalityProof> {
+        let proofs = match self.pending.lock() {
+            Ok(pending) => [pending.grandpa.clone(), pending.beefy.clone()]
+                .into_iter()
+                .flatten()
+                .collect(),
+            Err(_) => Vec::new(),
+        };
+
+        InherentDataProvider(proofs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use datahaven_runtime_common::Header;
+    use pallet_external_validators_rewards::inherent::INHERENT_IDENTIFIER;
+    use sc_service::TaskManager;
+    use sp_consensus_beefy::{
+        ecdsa_crypto::Signature as BeefySignature, known_payloads::MMR_ROOT_ID, Commitment,
+        Payload, SignedCommitment,
+    };
+    use sp_consensus_grandpa::GrandpaJustification;
+    use sp_inherents::{InherentData, InherentDataProvider as _};
+    use std::time::Duration;
+
+    fn grandpa_justification(target_number: u32) -> GrandpaJustification<Header> {
+        GrandpaJustification {
+            round: 1,
+            commit: sp_consensus_grandpa::Commit::<Header> {
+                target_hash: Default::default(),
+                target_number,
+                precommits: vec![],
+            },
+            votes_ancestries: vec![],
+        }
+    }
+
+    fn beefy_commitment(block_number: u32) -> SignedCommitment<u32, BeefySignature> {
+        SignedCommitment {
+            commitment: Commitment {
+                payload: Payload::from_single_entry(MMR_ROOT_ID, vec![]),
+                block_number,
+                validator_set_id: 0,
+            },
+            signatures: vec![None],
+        }
+    }
+
+    /// Waits until the collector tasks handled the notifications sent so far.
+    async fn wait_for(collector: &FinalityProofCollector, done: impl Fn(&PendingProofs) -> bool) {
+        for _ in 0..100 {
+            if done(&collector.pending.lock().unwrap()) {
+                return;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        panic!("Finality proofs were not collected");
+    }
+
+    #[tokio::test]
+    async fn latest_proof_of_each_gadget_is_provided_until_replaced() {
+        let task_manager = TaskManager::new(tokio::runtime::Handle::current(), None).unwrap();
+        let (grandpa_sender, grandpa_stream) = GrandpaJustificationStream::<Block>::channel();
+        let (beefy_sender, beefy_stream) =
+            BeefyVersionedFinalityProofStream::<Block, BeefyId>::channel();
+        let collector = FinalityProofCollector::spawn(
+            task_manager.spawn_handle(),
+            grandpa_stream,
+            beefy_stream,
+        );
+
+        for target_number in [1, 2] {
+            grandpa_sender
+                .notify(|| Ok::<_, ()>(grandpa_justification(target_number).into()))
+                .unwrap();
+        }
+        // Proofs are not verified by the node: the runtime drops the invalid ones
+        beefy_sender
+            .notify(|| Ok::<_, ()>(VersionedFinalityProof::V1(beefy_commitment(3))))
+            .unwrap();
+        let expected = vec![
+            FinalityProof::Grandpa(grandpa_justification(2)),
+            FinalityProof::Beefy(beefy_commitment(3).into()),
+        ];
+        wait_for(&collector, |pending| {
+            pending.grandpa.as_ref() == Some(&expected[0])
+                && pending.beefy.as_ref() == Some(&expected[1])
+        })
+        .await;
+
+        assert_eq!(collector.inherent_data_provider().0, expected);
+        // Slots the node doesn't author a block in don't consume the proofs
+        assert_eq!(collector.inherent_data_provider().0, expected);
+
+        grandpa_sender
+            .notify(|| Ok::<_, ()>(grandpa_justification(4).into()))
+            .unwrap();
+        let newer = FinalityProof::Grandpa(grandpa_justification(4));
+        wait_for(&collector, |pending| {
+            pending.grandpa.as_ref() == Some(&newer)
+        })
+        .await;
+        assert_eq!(
+            collector.inherent_data_provider().0,
+            vec![newer, expected[1].clone()]
+        );
+    }
+
+    #[tokio::test]
+    async fn proofs_are_put_in_the_inherent_data() {
+        let proofs = vec![FinalityProof::Grandpa(grandpa_justification(1))];
+        let mut inherent_data = InherentData::new();
+        InherentDataProvider(proofs.clone())
+            .provide_inherent_data(&mut inherent_data)
+            .await
+            .unwrap();
+        assert_eq!(
+            inherent_data
+                .get_data::<Vec<FinalityProof>>(&INHERENT_IDENTIFIER)
+                .unwrap(),
+            Some(proofs)
+        );
+
+        // Without proofs the inherent is left out
+        let mut inherent_data = InherentData::new();
+        InherentDataProvider::<FinalityProof>(vec![])
+            .provide_inherent_data(&mut inherent_data)
+            .await
+            .unwrap();
+        assert!(inherent_data
+            .get_data::<Vec<FinalityProof>>(&INHERENT_IDENTIFIER)
+            .unwrap()
+            .is_none());
+    }
+}
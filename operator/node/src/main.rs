@@ -19,13 +19,18 @@
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
+mod bridge_index;
 mod chain_spec;
 mod cli;
 mod client;
 mod command;
 mod config;
 mod consensus;
+mod dev_rpc;
 mod eth;
+mod evm_tracing;
+mod finality_liveness;
+mod outbound_proof_rpc;
 mod rpc;
 mod service;
 
@@ -0,0 +1,198 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! The `bridge_outboundMessageProof` RPC method proving historical outbound messages.
+//!
+//! The outbound queue writes the leaves and messages of each commitment to the offchain storage
+//! through offchain indexing, so the node must run with `--enable-offchain-indexing true`. Those
+//! writes happen for every imported block, so the commitment is checked against the digest of the
+//! canonical block before proving it.
+
+use bridge_hub_common::CustomDigestItem;
+use codec::Decode;
+use datahaven_runtime_common::{Block, BlockNumber};
+use jsonrpsee::{
+    core::RpcResult,
+    proc_macros::rpc,
+    types::error::{ErrorObject, ErrorObjectOwned},
+};
+use serde::Serialize;
+use snowbridge_merkle_tree::merkle_proof;
+use snowbridge_outbound_queue_primitives::v2::OutboundMessage;
+use snowbridge_pallet_outbound_queue_v2::offchain::{commitment_key, nonce_key, CommittedMessages};
+use sp_blockchain::HeaderBackend;
+use sp_core::{
+    offchain::{OffchainStorage, STORAGE_PREFIX},
+    Bytes, H256,
+};
+use sp_runtime::{
+    traits::{Header as HeaderT, Keccak256},
+    DigestItem,
+};
+use std::sync::Arc;
+
+/// Error code returned when a message can't be proven.
+const PROOF_ERROR: i32 = 1;
+
+/// A command of an outbound message, as executed by the Gateway.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Command {
+    pub kind: u8,
+    pub gas: u64,
+    pub payload: Bytes,
+}
+
+/// An outbound message, as committed in the merkle tree.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Message {
+    pub origin: H256,
+    pub nonce: u64,
+    pub topic: H256,
+    pub commands: Vec<Command>,
+}
+
+impl From<OutboundMessage> for Message {
+    fn from(message: OutboundMessage) -> Self {
+        Self {
+            origin: message.origin,
+            nonce: message.nonce,
+            topic: message.topic,
+            commands: message
+                .commands
+                .into_iter()
+                .map(|command| Command {
+                    kind: command.kind,
+                    gas: command.gas,
+                    payload: command.payload.into(),
+                })
+                .collect(),
+        }
+    }
+}
+
+/// An outbound message and its merkle proof against the root committed in its block.
+#[derive(Clone, Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MessageProof {
+    pub block_number: BlockNumber,
+    pub block_hash: H256,
+    pub message: Message,
+    pub leaf_index: u64,
+    pub leaf: H256,
+    pub root: H256,
+    pub proof: Vec<H256>,
+    pub number_of_leaves: u64,
+}
+
+#[rpc(server)]
+pub trait OutboundProofApi {
+    /// The outbound message with the given nonce and its merkle proof, if it was committed.
+    #[method(name = "bridge_outboundMessageProof")]
+    fn outbound_message_proof(&self, nonce: u64) -> RpcResult<Option<MessageProof>>;
+}
+
+/// Implementation of [`OutboundProofApiServer`] reading the offchain storage.
+pub struct OutboundProof<C, S> {
+    client: Arc<C>,
+    offchain_storage: S,
+}
+
+impl<C, S> OutboundProof<C, S> {
+    pub fn new(client: Arc<C>, offchain_storage: S) -> Self {
+        Self {
+            client,
+            offchain_storage,
+        }
+    }
+}
+
+fn proof_error(message: impl ToString) -> ErrorObjectOwned {
+    ErrorObject::owned(PROOF_ERROR, message.to_string(), None::<()>)
+}
+
+impl<C, S> OutboundProof<C, S>
+where
+    S: OffchainStorage,
+{
+    fn offchain_get<T: Decode>(&self, key: &[u8]) -> RpcResult<Option<T>> {
+        self.offchain_storage
+            .get(STORAGE_PREFIX, key)
+            .map(|value| T::decode(&mut &value[..]).map_err(proof_error))
+            .transpose()
+    }
+}
+
+impl<C, S> OutboundProofApiServer for OutboundProof<C, S>
+where
+    C: HeaderBackend<Block> + Send + Sync + 'static,
+    S: OffchainStorage + 'static,
+{
+    fn outbound_message_proof(&self, nonce: u64) -> RpcResult<Option<MessageProof>> {
+        let Some(block_number) = self.offchain_get::<BlockNumber>(&nonce_key(nonce))? else {
+            return Ok(None);
+        };
+        let commitment = self
+            .offchain_get::<CommittedMessages>(&commitment_key(block_number))?
+            .ok_or_else(|| proof_error(format!("No commitment indexed for #{block_number}")))?;
+
+        // A block of another fork at the same height may have overwritten the indexed data
+        let block_hash = self
+            .client
+            .hash(block_number)
+            .map_err(proof_error)?
+            .ok_or_else(|| proof_error(format!("Block #{block_number} not found")))?;
+        let header = self
+            .client
+            .header(block_hash)
+            .map_err(proof_error)?
+            .ok_or_else(|| proof_error(format!("Header of #{block_number} not found")))?;
+        let digest_item: DigestItem = CustomDigestItem::SnowbridgeV2(commitment.root).into();
+        if !header.digest().logs().contains(&digest_item) {
+            return Err(proof_error(format!(
+                "Indexed commitment of #{block_number} is not on the canonical chain"
+            )));
+        }
+
+        let leaf_index = commitment
+            .leaf_index(nonce)
+            .ok_or_else(|| proof_error(format!("Message {nonce} not in its commitment")))?;
+        let proof = merkle_proof::<Keccak256, _>(commitment.leaves.into_iter(), leaf_index);
+        if proof.root != commitment.root {
+            return Err(proof_error(format!(
+                "Indexed leaves of #{block_number} don't match the committed root"
+            )));
+        }
+
+        let message = commitment
+            .messages
+            .into_iter()
+            .nth(leaf_index as usize)
+            .ok_or_else(|| proof_error(format!("Message {nonce} not in its commitment")))?;
+
+        Ok(Some(MessageProof {
+            block_number,
+            block_hash,
+            message: message.into(),
+            leaf_index,
+            leaf: proof.leaf,
+            root: proof.root,
+            proof: proof.proof,
+            number_of_leaves: proof.number_of_leaves,
+        }))
+    }
+}
@@ -21,9 +21,13 @@
 
 #![warn(missing_docs)]
 
+use crate::bridge_index::rpc::{BridgeApiServer, BridgeIndex};
 use crate::consensus::BabeConsensusDataProvider;
+use crate::dev_rpc::{Dev, DevApiServer};
 use crate::eth::DefaultEthConfig;
-use datahaven_runtime_common::{time::SLOT_DURATION, Block, BlockNumber, Hash};
+use crate::evm_tracing::{DebugApiServer, EvmTracing, EvmTracingConfig, TraceApiServer};
+use crate::outbound_proof_rpc::{OutboundProof, OutboundProofApiServer};
+use datahaven_runtime_common::{time::SLOT_DURATION, AccountId, Block, BlockNumber, Hash};
 use fc_rpc::{Eth, EthBlockDataCacheTask, EthFilter, Net, Web3};
 use fc_rpc::{EthPubSub, TxPool};
 use fc_rpc_core::types::{FeeHistoryCache, FilterPool};
@@ -34,6 +38,13 @@ use fc_rpc_core::{
 use fc_storage::StorageOverride;
 use fp_rpc::EthereumRuntimeRPCApi;
 use jsonrpsee::RpcModule;
+use pallet_external_validators_rewards_rpc::{
+    ExternalValidatorsRewards, ExternalValidatorsRewardsApiServer,
+    ExternalValidatorsRewardsRuntimeApi,
+};
+use pallet_external_validators_rpc::{
+    ExternalValidators, ExternalValidatorsApiServer, ExternalValidatorsRuntimeApi,
+};
 use sc_client_api::{Backend, StateBackend, StorageProvider};
 use sc_consensus_beefy::communication::notification::{
     BeefyBestBlockStream, BeefyVersionedFinalityProofStream,
@@ -48,6 +59,7 @@ use shc_common::traits::StorageEnableRuntimeApi;
 use shc_common::types::OpaqueBlock;
 use shc_common::types::StorageHubClient;
 use shc_forest_manager::traits::ForestStorageHandler;
+use shc_indexer_db::DbPool;
 use shc_rpc::StorageHubClientApiServer;
 use shc_rpc::StorageHubClientRpc;
 use shc_rpc::StorageHubClientRpcConfig;
@@ -109,6 +121,10 @@ where
     pub forced_parent_hashes: Option<BTreeMap<H256, H256>>,
     /// Storage Hub RPC config
     pub maybe_storage_hub_client_config: Option<StorageHubClientRpcConfig<FL, FS, Runtime>>,
+    /// EVM tracing RPC config, when the `debug` or `trace` namespaces are enabled
+    pub evm_tracing: Option<EvmTracingConfig>,
+    /// Bridge index database, when the bridge index is enabled
+    pub bridge_index: Option<DbPool>,
 }
 
 /// Instantiate all full RPC extensions.
@@ -135,7 +151,10 @@ where
             BlockNumber,
         > + EthereumRuntimeRPCApi<Block>
                         + BabeApi<Block>
-                        + fp_rpc::ConvertTransactionRuntimeApi<Block>,
+                        + fp_rpc::ConvertTransactionRuntimeApi<Block>
+                        + ExternalValidatorsRuntimeApi<Block, AccountId>
+                        + ExternalValidatorsRewardsRuntimeApi<Block>
+                        + dhp_evm_tracing::EvmTracingApi<Block>,
     >,
     StorageHubClient<Runtime::RuntimeApi>: StorageProvider<Block, BE>,
     FL: FileStorageT,
@@ -166,10 +185,14 @@ where
         command_sink,
         forced_parent_hashes,
         maybe_storage_hub_client_config,
+        evm_tracing,
+        bridge_index,
     } = deps;
 
     module.merge(System::new(Arc::clone(&client), Arc::clone(&pool)).into_rpc())?;
     module.merge(TransactionPayment::new(client.clone()).into_rpc())?;
+    module.merge(ExternalValidators::new(client.clone()).into_rpc())?;
+    module.merge(ExternalValidatorsRewards::new(client.clone()).into_rpc())?;
     module.merge(
         Beefy::<Block, AuthorityId>::new(
             beefy.beefy_finality_proof_stream,
@@ -187,6 +210,15 @@ where
         )
         .into_rpc(),
     )?;
+    module.merge(
+        OutboundProof::new(
+            client.clone(),
+            backend
+                .offchain_storage()
+                .ok_or("Backend doesn't provide the required offchain storage")?,
+        )
+        .into_rpc(),
+    )?;
 
     if let Some(storage_hub_client_config) = maybe_storage_hub_client_config {
         module.merge(
@@ -288,12 +320,41 @@ where
         module.merge(
             // We provide the rpc handler with the sending end of the channel to allow the rpc
             // send EngineCommands to the background block authorship task.
-            ManualSeal::new(command_sink).into_rpc(),
+            ManualSeal::new(command_sink.clone()).into_rpc(),
+        )?;
+        module.merge(
+            Dev::new(
+                client.clone(),
+                command_sink,
+                SlotDuration::from_millis(SLOT_DURATION),
+            )
+            .into_rpc(),
         )?;
     };
 
     let tx_pool = TxPool::new(client.clone(), graph.clone());
     module.merge(tx_pool.into_rpc())?;
 
+    if let Some(evm_tracing) = evm_tracing {
+        if evm_tracing.debug {
+            module.merge(DebugApiServer::into_rpc(EvmTracing::new(
+                client.clone(),
+                frontier_backend.clone(),
+                evm_tracing.clone(),
+            )))?;
+        }
+        if evm_tracing.trace {
+            module.merge(TraceApiServer::into_rpc(EvmTracing::new(
+                client.clone(),
+                frontier_backend.clone(),
+                evm_tracing,
+            )))?;
+        }
+    }
+
+    if let Some(bridge_index) = bridge_index {
+        module.merge(BridgeIndex::new(bridge_index).into_rpc())?;
+    }
+
     Ok(module)
 }
@@ -22,7 +22,9 @@ use crate::eth::{
     new_frontier_partial, spawn_frontier_tasks, BackendType, FrontierBackend,
     FrontierPartialComponents, FrontierTasksParams,
 };
-use crate::eth::{EthConfiguration, StorageOverrideHandler};
+use crate::eth::{EthApi, EthConfiguration, StorageOverrideHandler};
+use crate::evm_tracing::EvmTracingConfig;
+use crate::finality_liveness::FinalityProofCollector;
 use crate::rpc::BeefyDeps;
 use async_channel::Receiver;
 use datahaven_runtime_common::{AccountId, Balance, Block, BlockNumber, Hash, Nonce};
@@ -30,14 +32,15 @@ use fc_consensus::FrontierBlockImport;
 use fc_db::DatabaseSource;
 use fc_storage::StorageOverride;
 use futures::channel::mpsc;
-use futures::FutureExt;
+use futures::{stream, FutureExt, Stream, StreamExt};
 use log::info;
+use pallet_external_validators_rewards::inherent::InherentDataProvider;
 use sc_client_api::{AuxStore, Backend, BlockBackend, StateBackend, StorageProvider};
 use sc_consensus_babe::ImportQueueParams;
 use sc_consensus_grandpa::SharedVoterState;
 use sc_consensus_manual_seal::consensus::babe::BabeConsensusDataProvider;
 use sc_consensus_manual_seal::rpc::EngineCommand;
-use sc_consensus_manual_seal::{self, InstantSealParams, ManualSealParams};
+use sc_consensus_manual_seal::{self, ManualSealParams};
 use sc_executor::{HeapAllocStrategy, WasmExecutor, DEFAULT_HEAP_ALLOC_STRATEGY};
 use sc_network::request_responses::IncomingRequest;
 use sc_network::service::traits::NetworkService;
@@ -77,7 +80,7 @@ use sp_runtime::traits::BlakeTwo256;
 use sp_runtime::SaturatedConversion;
 use std::path::PathBuf;
 use std::sync::atomic::{AtomicU64, Ordering};
-use std::{default::Default, path::Path, sync::Arc, time::Duration};
+use std::{default::Default, path::Path, pin::Pin, sync::Arc, time::Duration};
 use substrate_prometheus_endpoint::Registry;
 
 pub(crate) type FullClient<RuntimeApi> = StorageHubClient<RuntimeApi>;
@@ -125,6 +128,10 @@ pub(crate) trait FullRuntimeApi:
     + sp_consensus_grandpa::GrandpaApi<Block>
     + fp_rpc::ConvertTransactionRuntimeApi<Block>
     + fp_rpc::EthereumRuntimeRPCApi<Block>
+    + pallet_external_validators_runtime_api::ExternalValidatorsApi<Block, AccountId>
+    + pallet_external_validators_rewards_runtime_api::ExternalValidatorsRewardsApi<Block>
+    + dhp_evm_tracing::EvmTracingApi<Block>
+    + datahaven_runtime_common::bridge_tracking::BridgeTrackingApi<Block>
 {
 }
 
@@ -145,6 +152,10 @@ impl<T> FullRuntimeApi for T where
         + sp_consensus_grandpa::GrandpaApi<Block>
         + fp_rpc::ConvertTransactionRuntimeApi<Block>
         + fp_rpc::EthereumRuntimeRPCApi<Block>
+        + pallet_external_validators_runtime_api::ExternalValidatorsApi<Block, AccountId>
+        + pallet_external_validators_rewards_runtime_api::ExternalValidatorsRewardsApi<Block>
+        + dhp_evm_tracing::EvmTracingApi<Block>
+        + datahaven_runtime_common::bridge_tracking::BridgeTrackingApi<Block>
 {
 }
 
@@ -260,6 +271,36 @@ where
     Ok(frontier_backend)
 }
 
+/// Slot of the latest block sealed with the mock timestamp.
+pub(crate) fn mock_slot(slot_duration: sp_consensus_babe::SlotDuration) -> sp_consensus_babe::Slot {
+    sp_consensus_babe::Slot::from_timestamp(
+        MOCK_TIMESTAMP.load(Ordering::SeqCst).into(),
+        slot_duration,
+    )
+}
+
+/// Skip `slots` slots before the next block sealed with the mock timestamp.
+pub(crate) fn skip_mock_slots(slots: u64, slot_duration: sp_consensus_babe::SlotDuration) {
+    MOCK_TIMESTAMP.fetch_add(
+        slots.saturating_mul(slot_duration.as_millis()),
+        Ordering::SeqCst,
+    );
+}
+
+/// Commands sealing an empty block every `millis` milliseconds.
+fn interval_commands(millis: u64) -> impl Stream<Item = EngineCommand<Hash>> + Send {
+    stream::unfold((), move |()| async move {
+        futures_timer::Delay::new(Duration::from_millis(millis)).await;
+        let command = EngineCommand::SealNewBlock {
+            create_empty: true,
+            finalize: false,
+            parent_hash: None,
+            sender: None,
+        };
+        Some((command, ()))
+    })
+}
+
 fn build_babe_inherent_providers(
     slot_duration: sp_consensus_babe::SlotDuration,
     use_mock_timestamp: bool,
@@ -443,6 +484,7 @@ pub async fn new_full_impl<
     mut eth_config: EthConfiguration,
     role_options: Option<RoleOptions>,
     indexer_options: Option<IndexerOptions>,
+    bridge_index_database_url: Option<String>,
     sealing: Option<Sealing>,
 ) -> Result<TaskManager, ServiceError>
 where
@@ -631,6 +673,30 @@ where
     let backoff_authoring_blocks: Option<()> = None;
     let name = config.network.node_name.clone();
     let enable_grandpa = sealing.is_none() && !config.disable_grandpa;
+    // Finality proofs noted by the authored blocks for the validators liveness.
+    let finality_proofs = (is_authority && enable_grandpa).then(|| {
+        FinalityProofCollector::spawn(
+            task_manager.spawn_handle(),
+            grandpa_link.justification_stream(),
+            beefy_rpc_links.from_voter_justif_stream.clone(),
+        )
+    });
+    // Index of the messages crossing the bridge, read by the `bridge_*` RPC methods.
+    let bridge_index = match bridge_index_database_url {
+        Some(database_url) => {
+            crate::bridge_index::db::run_migrations(&database_url)
+                .map_err(|e| ServiceError::Other(format!("Bridge index migrations failed: {e}")))?;
+            let db_pool = setup_database_pool(database_url).await?;
+            crate::bridge_index::spawn(
+                task_manager.spawn_handle(),
+                client.clone(),
+                db_pool.clone(),
+                beefy_rpc_links.from_voter_justif_stream.clone(),
+            );
+            Some(db_pool)
+        }
+        None => None,
+    };
     let prometheus_registry = config.prometheus_registry().cloned();
     let overrides = Arc::new(StorageOverrideHandler::new(client.clone()));
 
@@ -643,7 +709,7 @@ where
     ));
 
     let mut manual_commands_stream: Option<mpsc::Receiver<EngineCommand<Hash>>> = None;
-    let command_sink = if matches!(sealing, Some(Sealing::Manual)) {
+    let command_sink = if sealing.is_some() {
         let (sink, stream) = mpsc::channel::<EngineCommand<Hash>>(1000);
         manual_commands_stream = Some(stream);
         Some(sink)
@@ -692,6 +758,17 @@ where
         let block_data_cache = block_data_cache.clone();
         let fee_history_limit = eth_config.fee_history_limit;
         let sync = sync_service.clone();
+        let evm_tracing = (!eth_config.ethapi.is_empty()).then(|| EvmTracingConfig {
+            debug: eth_config.ethapi.contains(&EthApi::Debug),
+            trace: eth_config.ethapi.contains(&EthApi::Trace),
+            permits: Arc::new(tokio::sync::Semaphore::new(
+                eth_config.ethapi_max_permits as usize,
+            )),
+            max_count: eth_config.ethapi_trace_max_count,
+            max_block_range: eth_config.ethapi_trace_max_block_range,
+            spawn_handle: task_manager.spawn_handle(),
+        });
+        let bridge_index = bridge_index.clone();
 
         Box::new(
             move |subscription_executor: sc_rpc::SubscriptionTaskExecutor| {
@@ -725,6 +802,8 @@ where
                     },
                     forced_parent_hashes: None,
                     maybe_storage_hub_client_config: maybe_storage_hub_client_rpc_config.clone(),
+                    evm_tracing: evm_tracing.clone(),
+                    bridge_index: bridge_index.clone(),
                 };
                 crate::rpc::create_full(
                     deps,
@@ -786,49 +865,53 @@ where
                 ))
             };
 
-            match mode {
-                Sealing::Manual => {
-                    let commands_stream = manual_commands_stream.take().ok_or_else(|| {
-                        ServiceError::Other(
-                            "Manual sealing requested but command channel is unavailable".into(),
-                        )
-                    })?;
-
-                    let future = sc_consensus_manual_seal::run_manual_seal(ManualSealParams {
-                        block_import,
-                        env: proposer_factory,
-                        client: client.clone(),
-                        pool: transaction_pool.clone(),
-                        commands_stream,
-                        select_chain,
-                        consensus_data_provider: Some(consensus_data_provider()?),
-                        create_inherent_data_providers,
-                    });
-
-                    task_manager.spawn_essential_handle().spawn_blocking(
-                        "manual-seal",
-                        Some("block-authoring"),
-                        future,
-                    );
-                }
-                Sealing::Instant => {
-                    let future = sc_consensus_manual_seal::run_instant_seal(InstantSealParams {
-                        block_import,
-                        env: proposer_factory,
-                        client: client.clone(),
-                        pool: transaction_pool.clone(),
-                        select_chain,
-                        consensus_data_provider: Some(consensus_data_provider()?),
-                        create_inherent_data_providers,
-                    });
-
-                    task_manager.spawn_essential_handle().spawn_blocking(
-                        "manual-seal",
-                        Some("block-authoring"),
-                        future,
-                    );
-                }
-            }
+            // Every mode also accepts the sealing commands of the RPC
+            let rpc_commands = manual_commands_stream.take().ok_or_else(|| {
+                ServiceError::Other(
+                    "Manual sealing requested but command channel is unavailable".into(),
+                )
+            })?;
+            let transaction_commands = || {
+                transaction_pool
+                    .import_notification_stream()
+                    .map(|_| EngineCommand::SealNewBlock {
+                        create_empty: false,
+                        finalize: false,
+                        parent_hash: None,
+                        sender: None,
+                    })
+            };
+            let commands_stream: Pin<Box<dyn Stream<Item = EngineCommand<Hash>> + Send>> =
+                match mode {
+                    Sealing::Manual => Box::pin(rpc_commands),
+                    Sealing::Instant => {
+                        Box::pin(stream::select(rpc_commands, transaction_commands()))
+                    }
+                    Sealing::Interval(millis) => {
+                        Box::pin(stream::select(rpc_commands, interval_commands(millis)))
+                    }
+                    Sealing::Hybrid(millis) => Box::pin(stream::select(
+                        rpc_commands,
+                        stream::select(transaction_commands(), interval_commands(millis)),
+                    )),
+                };
+
+            let future = sc_consensus_manual_seal::run_manual_seal(ManualSealParams {
+                block_import,
+                env: proposer_factory,
+                client: client.clone(),
+                pool: transaction_pool.clone(),
+                commands_stream,
+                select_chain,
+                consensus_data_provider: Some(consensus_data_provider()?),
+                create_inherent_data_providers,
+            });
+
+            task_manager.spawn_essential_handle().spawn_blocking(
+                "manual-seal",
+                Some("block-authoring"),
+                future,
+            );
 
             log::info!("Manual sealing enabled (mode: {:?})", mode);
         } else {
@@ -842,9 +925,16 @@ where
 
             let slot_duration = babe_link.clone().config().slot_duration();
             let create_inherent_data_providers = move |_, ()| {
-                std::future::ready(Ok::<_, Box<dyn std::error::Error + Send + Sync>>(
-                    build_babe_inherent_providers(slot_duration, false),
-                ))
+                let (slot, timestamp) = build_babe_inherent_providers(slot_duration, false);
+                let finality_proofs = finality_proofs
+                    .as_ref()
+                    .map(FinalityProofCollector::inherent_data_provider)
+                    .unwrap_or_else(|| InherentDataProvider(Vec::new()));
+                std::future::ready(Ok::<_, Box<dyn std::error::Error + Send + Sync>>((
+                    slot,
+                    timestamp,
+                    finality_proofs,
+                )))
             };
             let babe_config = sc_consensus_babe::BabeParams {
                 keystore: keystore_container.keystore(),
@@ -1009,6 +1099,7 @@ pub async fn new_full<
     eth_config: EthConfiguration,
     role_options: Option<RoleOptions>,
     indexer_options: Option<IndexerOptions>,
+    bridge_index_database_url: Option<String>,
     sealing: Option<Sealing>,
 ) -> Result<TaskManager, ServiceError>
 where
@@ -1028,6 +1119,7 @@ where
                     eth_config,
                     Some(role_options),
                     indexer_options,
+                    bridge_index_database_url,
                     sealing,
                 )
                 .await;
@@ -1042,6 +1134,7 @@ where
                     eth_config,
                     Some(role_options),
                     indexer_options,
+                    bridge_index_database_url,
                     sealing,
                 )
                 .await;
@@ -1056,6 +1149,7 @@ where
                     eth_config,
                     Some(role_options),
                     indexer_options,
+                    bridge_index_database_url,
                     sealing,
                 )
                 .await;
@@ -1070,6 +1164,7 @@ where
                     eth_config,
                     Some(role_options),
                     indexer_options,
+                    bridge_index_database_url,
                     sealing,
                 )
                 .await;
@@ -1080,6 +1175,7 @@ where
                     eth_config,
                     Some(role_options),
                     indexer_options,
+                    bridge_index_database_url,
                     sealing,
                 )
                 .await;
@@ -1091,6 +1187,7 @@ where
             eth_config,
             None,
             indexer_options,
+            bridge_index_database_url,
             sealing,
         )
         .await;
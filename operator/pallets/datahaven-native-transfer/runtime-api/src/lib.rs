@@ -0,0 +1,51 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Runtime API for the DataHaven native transfer pallet.
+//!
+//! Lets wallets follow the transfers to Ethereum that are waiting for their delivery receipt,
+//! or for the retry of their failed refund.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet_datahaven_native_transfer::{PendingTransfer, TransferStatus};
+use {parity_scale_codec::Codec, sp_core::H256, sp_std::vec::Vec};
+
+sp_api::decl_runtime_apis! {
+    #[api_version(1)]
+    pub trait NativeTransferApi<AccountId, Balance, BlockNumber>
+    where
+        AccountId: Codec,
+        Balance: Codec,
+        BlockNumber: Codec,
+    {
+        /// Transfers sent by `account` that are still tracked, keyed by their outbound message id,
+        /// or transfer id for batched transfers.
+        ///
+        /// Returns a page of at most `limit` transfers, capped by the runtime, starting after the
+        /// transfer `start_after`, e.g. the last one of the previous page.
+        fn pending_transfers(
+            account: AccountId,
+            start_after: Option<H256>,
+            limit: u32,
+        ) -> Vec<(H256, PendingTransfer<AccountId, Balance, BlockNumber>)>;
+
+        /// Transfer sent with the outbound message `message_id`, if it is still tracked.
+        fn pending_transfer(
+            message_id: H256,
+        ) -> Option<PendingTransfer<AccountId, Balance, BlockNumber>>;
+    }
+}
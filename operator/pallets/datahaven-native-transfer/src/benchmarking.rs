@@ -21,6 +21,7 @@ use frame_benchmarking::v2::*;
 use frame_support::traits::{fungible::Mutate, EnsureOrigin};
 use frame_system::RawOrigin;
 use sp_core::H160;
+use sp_runtime::traits::Bounded;
 
 // Helper function to create a funded account
 fn create_funded_account<T: Config>(seed: u32, amount: BalanceOf<T>) -> T::AccountId {
@@ -29,11 +30,54 @@ fn create_funded_account<T: Config>(seed: u32, amount: BalanceOf<T>) -> T::Accou
     account
 }
 
+// Helper function to create limits that are checked but never reached
+fn unlimited_transfer_limits<T: Config>() -> TransferLimits<BalanceOf<T>> {
+    let max = Some(BalanceOf::<T>::max_value());
+    TransferLimits {
+        outbound_per_block: max,
+        outbound_per_era: max,
+        outbound_per_account: max,
+        inbound_per_block: max,
+        inbound_per_era: max,
+        circuit_breaker_threshold: max,
+    }
+}
+
 // Helper function to create an Ethereum address
 fn ethereum_address(seed: u8) -> H160 {
     H160::from_low_u64_be(seed as u64)
 }
 
+// Helper function to fill the current batch with n transfers whose tokens are locked
+fn fill_batch<T: Config>(n: u32, amount: BalanceOf<T>) -> Vec<T::AccountId>
+where
+    BalanceOf<T>: From<u128>,
+{
+    let _ = T::Currency::mint_into(
+        &T::EthereumSovereignAccount::get(),
+        T::Currency::minimum_balance() + amount * BalanceOf::<T>::from(n as u128),
+    );
+    ExpectedWrappedSupply::<T>::put(amount * BalanceOf::<T>::from(n as u128));
+
+    let mut senders = Vec::new();
+    let mut transfers = Vec::new();
+    for i in 0..n {
+        let sender = create_funded_account::<T>(i, T::Currency::minimum_balance());
+        transfers.push(BatchedTransfer {
+            id: H256::from_low_u64_be(i as u64),
+            sender: sender.clone(),
+            recipient: ethereum_address(42),
+            amount,
+            fee: (1_000_000_000u128).into(),
+        });
+        senders.push(sender);
+    }
+    CurrentBatch::<T>::put(BoundedVec::<_, T::MaxBatchSize>::truncate_from(transfers));
+    CurrentBatchOpenedAt::<T>::put(frame_system::Pallet::<T>::block_number());
+
+    senders
+}
+
 #[benchmarks(
     where
         T: Config,
@@ -62,6 +106,9 @@ mod benchmarks {
         // Ensure pallet is not paused
         Paused::<T>::put(false);
 
+        // Worst case: every limit is checked
+        Limits::<T>::put(unlimited_transfer_limits::<T>());
+
         #[extrinsic_call]
         transfer_to_ethereum(RawOrigin::Signed(sender.clone()), recipient, amount, fee);
 
@@ -114,6 +161,290 @@ mod benchmarks {
         Ok(())
     }
 
+    #[benchmark]
+    fn set_transfer_limits() -> Result<(), BenchmarkError> {
+        // Setup
+        let pause_origin =
+            T::PauseOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let limits = unlimited_transfer_limits::<T>();
+
+        #[extrinsic_call]
+        set_transfer_limits(pause_origin as T::RuntimeOrigin, limits.clone());
+
+        // Verify
+        assert_eq!(Limits::<T>::get(), limits);
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn check_locked_balance() -> Result<(), BenchmarkError> {
+        // Setup: the expected wrapped supply is not covered, so the pallet gets paused
+        Paused::<T>::put(false);
+        ExpectedWrappedSupply::<T>::put(BalanceOf::<T>::max_value());
+
+        #[block]
+        {
+            Pallet::<T>::check_locked_balance();
+        }
+
+        // Verify
+        assert!(Paused::<T>::get());
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn refund_expired_transfers(n: Linear<0, 100>) -> Result<(), BenchmarkError> {
+        // Setup: n transfers of dropped messages checked at the same block, each refunded to its
+        // own sender
+        let amount: BalanceOf<T> = (1_000 * 1_000_000_000u128).into(); // 1k units
+        let expires_at = frame_system::Pallet::<T>::block_number();
+        let sovereign_account = T::EthereumSovereignAccount::get();
+        let _ = T::Currency::mint_into(
+            &sovereign_account,
+            T::Currency::minimum_balance() + amount * BalanceOf::<T>::from(n as u128),
+        );
+        ExpectedWrappedSupply::<T>::put(amount * BalanceOf::<T>::from(n as u128));
+
+        let mut message_ids = Vec::new();
+        for i in 0..n {
+            let sender = create_funded_account::<T>(i, T::Currency::minimum_balance());
+            let message_id = H256::from_low_u64_be(i as u64);
+            PendingTransfers::<T>::insert(
+                message_id,
+                PendingTransfer {
+                    sender,
+                    recipient: ethereum_address(42),
+                    amount,
+                    nonce: None,
+                    message_index: Some(i as u64),
+                    expires_at,
+                    status: TransferStatus::Pending,
+                },
+            );
+            message_ids.push(message_id);
+        }
+        TransferTimeouts::<T>::insert(
+            expires_at,
+            BoundedVec::<H256, T::MaxTransfersPerBlock>::truncate_from(message_ids),
+        );
+        // A message sent after them was accepted
+        LastAcceptedMessageIndex::<T>::put(n as u64);
+
+        #[block]
+        {
+            Pallet::<T>::refund_expired_transfers(expires_at);
+        }
+
+        // Verify
+        assert!(TransferTimeouts::<T>::get(expires_at).is_empty());
+        assert_eq!(
+            ExpectedWrappedSupply::<T>::get(),
+            BalanceOf::<T>::from(0u128)
+        );
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn on_message_accepted() -> Result<(), BenchmarkError> {
+        // Setup
+        let message_id = H256::from_low_u64_be(1);
+        PendingTransfers::<T>::insert(
+            message_id,
+            PendingTransfer {
+                sender: create_funded_account::<T>(1, T::Currency::minimum_balance()),
+                recipient: ethereum_address(42),
+                amount: (1_000 * 1_000_000_000u128).into(),
+                nonce: None,
+                message_index: Some(0),
+                expires_at: frame_system::Pallet::<T>::block_number(),
+                status: TransferStatus::Pending,
+            },
+        );
+
+        #[block]
+        {
+            <Pallet<T> as MessageDeliveryHandler>::on_message_accepted(message_id, 1);
+        }
+
+        // Verify
+        assert_eq!(PendingTransferIds::<T>::get(1), Some(message_id));
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn on_message_delivered() -> Result<(), BenchmarkError> {
+        // Setup: worst case is a failed delivery, refunding the sender
+        let amount: BalanceOf<T> = (1_000 * 1_000_000_000u128).into(); // 1k units
+        let sender = create_funded_account::<T>(1, T::Currency::minimum_balance());
+        let message_id = H256::from_low_u64_be(1);
+        let expires_at = frame_system::Pallet::<T>::block_number();
+        let _ = T::Currency::mint_into(
+            &T::EthereumSovereignAccount::get(),
+            T::Currency::minimum_balance() + amount,
+        );
+        ExpectedWrappedSupply::<T>::put(amount);
+
+        PendingTransfers::<T>::insert(
+            message_id,
+            PendingTransfer {
+                sender: sender.clone(),
+                recipient: ethereum_address(42),
+                amount,
+                nonce: Some(1),
+                message_index: Some(0),
+                expires_at,
+                status: TransferStatus::Pending,
+            },
+        );
+        PendingTransferIds::<T>::insert(1, message_id);
+        TransferTimeouts::<T>::insert(
+            expires_at,
+            BoundedVec::<H256, T::MaxTransfersPerBlock>::truncate_from(vec![message_id]),
+        );
+
+        #[block]
+        {
+            <Pallet<T> as MessageDeliveryHandler>::on_message_delivered(1, false);
+        }
+
+        // Verify
+        assert!(PendingTransfers::<T>::get(message_id).is_none());
+        assert_eq!(
+            T::Currency::balance(&sender),
+            T::Currency::minimum_balance() + amount
+        );
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn release_held_tokens() -> Result<(), BenchmarkError> {
+        // Setup
+        let pause_origin =
+            T::PauseOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let amount: BalanceOf<T> = (1_000 * 1_000_000_000u128).into(); // 1k units
+        let beneficiary: T::AccountId = account("beneficiary", 0, 0);
+        let _ = T::Currency::mint_into(&T::HoldingAccount::get(), amount);
+        HeldTransfers::<T>::insert(
+            0,
+            HeldTransfer {
+                claimer: BoundedVec::truncate_from(vec![0u8; MAX_HELD_CLAIMER_LENGTH as usize]),
+                amount,
+                held_at: frame_system::Pallet::<T>::block_number(),
+            },
+        );
+
+        #[extrinsic_call]
+        release_held_tokens(pause_origin as T::RuntimeOrigin, 0, beneficiary.clone());
+
+        // Verify
+        assert!(HeldTransfers::<T>::get(0).is_none());
+        assert_eq!(T::Currency::balance(&beneficiary), amount);
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn set_batching_period() -> Result<(), BenchmarkError> {
+        // Setup
+        let pause_origin =
+            T::PauseOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let period: BlockNumberFor<T> = 10u32.into();
+
+        #[extrinsic_call]
+        set_batching_period(pause_origin as T::RuntimeOrigin, Some(period));
+
+        // Verify
+        assert_eq!(BatchingPeriod::<T>::get(), Some(period));
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn send_batch(n: Linear<1, { T::MaxBatchSize::get() }>) -> Result<(), BenchmarkError> {
+        // Setup
+        let pause_origin =
+            T::PauseOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        fill_batch::<T>(n, (1_000 * 1_000_000_000u128).into());
+
+        #[extrinsic_call]
+        send_batch(pause_origin as T::RuntimeOrigin);
+
+        // Verify
+        assert!(CurrentBatch::<T>::get().is_empty());
+        assert!(PendingTransfers::<T>::get(H256::from_low_u64_be(0)).is_some());
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn cancel_batch(n: Linear<1, { T::MaxBatchSize::get() }>) -> Result<(), BenchmarkError> {
+        // Setup
+        let pause_origin =
+            T::PauseOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+        let amount: BalanceOf<T> = (1_000 * 1_000_000_000u128).into(); // 1k units
+        let senders = fill_batch::<T>(n, amount);
+
+        #[extrinsic_call]
+        cancel_batch(pause_origin as T::RuntimeOrigin);
+
+        // Verify
+        assert!(CurrentBatch::<T>::get().is_empty());
+        for sender in senders {
+            assert_eq!(
+                T::Currency::balance(&sender),
+                T::Currency::minimum_balance() + amount
+            );
+        }
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn retry_refund() -> Result<(), BenchmarkError> {
+        // Setup: a transfer whose refund failed, paid for with a payment
+        let amount: BalanceOf<T> = (1_000 * 1_000_000_000u128).into(); // 1k units
+        let caller: T::AccountId = whitelisted_caller();
+        let sender = create_funded_account::<T>(1, T::Currency::minimum_balance());
+        let transfer_id = H256::from_low_u64_be(1);
+        let _ = T::Currency::mint_into(
+            &T::EthereumSovereignAccount::get(),
+            T::Currency::minimum_balance() + amount,
+        );
+        ExpectedWrappedSupply::<T>::put(amount);
+
+        PendingTransfers::<T>::insert(
+            transfer_id,
+            PendingTransfer {
+                sender: sender.clone(),
+                recipient: ethereum_address(42),
+                amount,
+                nonce: Some(1),
+                message_index: Some(0),
+                expires_at: frame_system::Pallet::<T>::block_number(),
+                status: TransferStatus::RefundFailed(RefundReason::DeliveryFailed),
+            },
+        );
+        PendingTransfersBySender::<T>::insert(&sender, transfer_id, ());
+        PaymentOutcomes::<T>::insert(transfer_id, PaymentOutcome::Pending);
+
+        #[extrinsic_call]
+        retry_refund(RawOrigin::Signed(caller), transfer_id);
+
+        // Verify
+        assert!(PendingTransfers::<T>::get(transfer_id).is_none());
+        assert_eq!(
+            T::Currency::balance(&sender),
+            T::Currency::minimum_balance() + amount
+        );
+
+        Ok(())
+    }
+
     impl_benchmark_test_suite!(
         DataHavenNativeTransfer,
         crate::mock::new_test_ext(),
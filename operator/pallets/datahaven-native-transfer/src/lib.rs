@@ -25,23 +25,104 @@
 //! - Lock tokens during outbound transfers
 //! - Unlock tokens when they return from Ethereum
 //! - Integration with Snowbridge outbound queue for message passing
+//! - Rate limits on the bridged amounts and a circuit breaker pausing the bridge
+//! - Optional batching of the transfers to Ethereum into shared outbound messages
 //!
 //! It uses a dedicated Ethereum sovereign account to hold locked tokens during transfers.
+//!
+//! ## Rate Limits and Circuit Breaker
+//!
+//! The [`TransferLimits`] set by the `PauseOrigin` cap the total amount bridged per block and
+//! per era in each direction, and the amount each account sends to Ethereum per era. Transfers
+//! exceeding a cap are rejected. Unset caps are unlimited.
+//!
+//! The pallet pauses itself, in both directions, when:
+//! - The amount bridged in a direction during the era goes above `circuit_breaker_threshold`.
+//! - The locked balance falls below the expected supply of wrapped tokens on Ethereum, i.e. the
+//!   amount locked minus the amount unlocked since the tracking started.
+//!
+//! Governance must then unpause it.
+//!
+//! ## Degraded Mode
+//!
+//! Transfers to Ethereum are rejected while `DegradedMode` reports the bridge as degraded, i.e.
+//! while the Ethereum light client is falling behind: their delivery receipts could not be
+//! verified, so their tokens would stay locked until it catches up. Tokens coming back from
+//! Ethereum are still unlocked.
+//!
+//! ## Refunds
+//!
+//! Transfers to Ethereum are tracked by outbound message until their delivery receipt is
+//! received, see [`MessageDeliveryHandler`]. The locked amount is unlocked back to the sender
+//! only when the transfer provably did not mint on Ethereum:
+//! - The delivery receipt reports that the mint failed.
+//! - The message was dropped by the outbound queue without being committed. The queue processes
+//!   the messages in order, so a message not accepted once a message sent after it is accepted
+//!   was dropped. This is checked `TransferTimeout` blocks after sending, then again every
+//!   `TransferTimeout` blocks while the message is neither accepted nor known to be dropped.
+//!
+//! A committed message is never refunded on timeout, its tokens stay locked until its receipt.
+//!
+//! When the tokens can't be unlocked, the transfer stays tracked with its refund failed, and
+//! anyone can retry the refund with `retry_refund`.
+//!
+//! ## Batching
+//!
+//! When the `PauseOrigin` sets a `BatchingPeriod`, transfers to Ethereum are locked and collected
+//! in the current batch instead of being sent on their own. The batch is sent as one outbound
+//! message, with a mint command per transfer and the sum of their fees, once it has been open for
+//! `BatchingPeriod` blocks or as soon as it holds `MaxBatchSize` transfers.
+//!
+//! Each batched transfer gets its own id, given in the `TransferBatched` event. It replaces the
+//! outbound message id in the `PendingTransfers` and in the events of the transfer: the transfers
+//! of a batch are confirmed or refunded together, on the delivery receipt of their message.
+//!
+//! A batch that can't be sent, e.g. while the pallet is paused, stays open and is retried every
+//! block, `BatchSendFailed` being only emitted on its first failure. The `PauseOrigin` can force
+//! it out with `send_batch`, or refund it with `cancel_batch`.
+//!
+//! ## Held Tokens and Follow-up Calls
+//!
+//! Tokens coming back from Ethereum whose recipient cannot be decoded are moved to the
+//! `HoldingAccount` instead of failing the message, see [`Pallet::hold_tokens`]. The
+//! `PauseOrigin` releases them to the right account once it is known.
+//!
+//! A message may also carry a follow-up call, dispatched on behalf of the recipient once the
+//! tokens are unlocked, see [`Pallet::dispatch_follow_up_call`]. It is only dispatched when the
+//! recipient is the Ethereum sender of the message, allowed by `FollowUpCallFilter` and within
+//! `MaxFollowUpCallWeight`. The tokens are unlocked whether the call succeeds or not. Its weight
+//! is charged to the submission of the message, see [`Pallet::follow_up_call_weight`].
+//!
+//! ## Payments
+//!
+//! [`PayToEthereum`] implements [`Pay`] with transfers to Ethereum, e.g. for treasury payouts to
+//! Ethereum addresses. The outcome of these transfers is kept in [`PaymentOutcomes`] once they
+//! are confirmed or refunded, until payers check the status of their payments.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use frame_support::{
+    dispatch::{GetDispatchInfo, PostDispatchInfo},
     pallet_prelude::*,
+    storage::with_storage_layer,
     traits::{
         fungible::{Inspect, Mutate},
-        tokens::Preservation,
+        tokens::{Pay, PaymentStatus, Preservation},
+        Contains,
     },
 };
-use snowbridge_core::TokenId;
-use snowbridge_outbound_queue_primitives::v2::{Command, Message as OutboundMessage, SendMessage};
+use pallet_external_validators::traits::{EraIndex, EraIndexProvider};
+use parity_scale_codec::DecodeLimit;
+use snowbridge_core::{operating_mode::DegradedModeQuery, TokenId};
+use snowbridge_outbound_queue_primitives::v2::{
+    Command, Message as OutboundMessage, MessageDeliveryHandler, SendMessage, MAX_COMMANDS,
+};
 use sp_core::{H160, H256};
-use sp_runtime::{traits::Saturating, BoundedVec};
-use sp_std::vec;
+use sp_runtime::{
+    traits::{Dispatchable, Saturating},
+    BoundedVec, DispatchError,
+};
+use sp_std::{vec, vec::Vec};
 
 pub use pallet::*;
 
@@ -59,6 +140,221 @@ pub use weights::WeightInfo;
 type BalanceOf<T> =
     <<T as Config>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
 
+/// Maximum length of the claimer kept with held tokens, longer claimers are truncated.
+pub const MAX_HELD_CLAIMER_LENGTH: u32 = 512;
+
+/// Maximum number of pending transfers returned at once by [`Pallet::pending_transfers`].
+pub const MAX_PENDING_TRANSFERS_PAGE: u32 = 100;
+
+/// Maximum nesting depth when decoding a follow-up call.
+const FOLLOW_UP_CALL_DECODE_DEPTH: u32 = 8;
+
+/// Caps on the amount bridged, `None` meaning unlimited.
+#[derive(
+    Clone,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    PartialEq,
+    Eq,
+    RuntimeDebug,
+    TypeInfo,
+    MaxEncodedLen,
+    Default,
+)]
+pub struct TransferLimits<Balance> {
+    /// Total amount sent to Ethereum per block.
+    pub outbound_per_block: Option<Balance>,
+    /// Total amount sent to Ethereum per era.
+    pub outbound_per_era: Option<Balance>,
+    /// Amount sent to Ethereum by a single account per era.
+    pub outbound_per_account: Option<Balance>,
+    /// Total amount unlocked from Ethereum per block.
+    pub inbound_per_block: Option<Balance>,
+    /// Total amount unlocked from Ethereum per era.
+    pub inbound_per_era: Option<Balance>,
+    /// Amount bridged in one direction during an era above which the pallet pauses itself.
+    pub circuit_breaker_threshold: Option<Balance>,
+}
+
+/// Amount bridged during a period (block or era).
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default)]
+pub struct TransferVolume<Period, Balance> {
+    pub period: Period,
+    pub amount: Balance,
+}
+
+impl<Period: PartialEq, Balance: Saturating + Zero + Copy> TransferVolume<Period, Balance> {
+    /// Amount bridged in `period` once `amount` is added.
+    fn add(&self, period: Period, amount: Balance) -> Self {
+        let current = if self.period == period {
+            self.amount
+        } else {
+            Zero::zero()
+        };
+
+        Self {
+            period,
+            amount: current.saturating_add(amount),
+        }
+    }
+}
+
+/// State of a tracked transfer to Ethereum.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum TransferStatus {
+    /// The tokens are locked until the receipt is received, or until the message is known to be
+    /// dropped without being committed.
+    Pending,
+    /// The tokens could not be unlocked back to the sender, the refund can be retried with
+    /// `retry_refund`.
+    RefundFailed(RefundReason),
+}
+
+/// Outcome of a transfer to Ethereum paid through [`PayToEthereum`].
+#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum PaymentOutcome {
+    /// The transfer waits to be sent or for its delivery receipt.
+    Pending,
+    /// The tokens were minted on Ethereum.
+    Delivered,
+    /// The tokens were unlocked back to the payer.
+    Refunded,
+}
+
+/// Transfer to Ethereum waiting for its delivery receipt.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct PendingTransfer<AccountId, Balance, BlockNumber> {
+    /// Account the tokens were locked from
+    pub sender: AccountId,
+    /// Ethereum recipient of the minted tokens
+    pub recipient: H160,
+    /// Locked amount
+    pub amount: Balance,
+    /// Outbound nonce, assigned once the outbound queue commits the message
+    pub nonce: Option<u64>,
+    /// Index of the message among the messages sent by the pallet, `None` for a batched transfer
+    /// that was never sent
+    pub message_index: Option<u64>,
+    /// Block at which the transfer is refunded if its message was dropped by then, checked again
+    /// every `TransferTimeout` blocks until the message is committed or known to be dropped
+    pub expires_at: BlockNumber,
+    /// Whether the tokens are still locked
+    pub status: TransferStatus,
+}
+
+/// Transfer to Ethereum waiting in the current batch.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct BatchedTransfer<AccountId, Balance> {
+    /// Identifier of the transfer, in place of its outbound message id
+    pub id: H256,
+    /// Account the tokens were locked from
+    pub sender: AccountId,
+    /// Ethereum recipient of the minted tokens
+    pub recipient: H160,
+    /// Locked amount
+    pub amount: Balance,
+    /// Share of the message fee paid by the sender
+    pub fee: Balance,
+}
+
+/// Why locked tokens were unlocked back to the sender.
+#[derive(
+    Clone,
+    Copy,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    PartialEq,
+    Eq,
+    RuntimeDebug,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub enum RefundReason {
+    /// The mint failed on Ethereum.
+    DeliveryFailed,
+    /// The outbound queue dropped the message without committing it.
+    Dropped,
+    /// The batch of the transfer was cancelled before being sent.
+    BatchCancelled,
+}
+
+/// Direction of a transfer.
+#[derive(
+    Clone,
+    Copy,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    PartialEq,
+    Eq,
+    RuntimeDebug,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub enum TransferDirection {
+    /// From DataHaven to Ethereum.
+    Outbound,
+    /// From Ethereum to DataHaven.
+    Inbound,
+}
+
+/// Tokens from Ethereum whose recipient could not be decoded, kept in the holding account.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct HeldTransfer<Balance, BlockNumber> {
+    /// Claimer of the inbound message, as received
+    pub claimer: BoundedVec<u8, ConstU32<MAX_HELD_CLAIMER_LENGTH>>,
+    /// Amount moved to the holding account
+    pub amount: Balance,
+    /// Block at which the tokens were received
+    pub held_at: BlockNumber,
+}
+
+/// Why a follow-up call was not dispatched.
+#[derive(
+    Clone,
+    Copy,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    PartialEq,
+    Eq,
+    RuntimeDebug,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub enum FollowUpCallError {
+    /// The recipient is not the Ethereum sender of the message.
+    Unauthorized,
+    /// The call could not be decoded.
+    Undecodable,
+    /// The call is not allowed by `FollowUpCallFilter`.
+    Filtered,
+    /// The call weighs more than `MaxFollowUpCallWeight`.
+    Overweight,
+}
+
+/// Why the circuit breaker paused the pallet.
+#[derive(
+    Clone,
+    Copy,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    PartialEq,
+    Eq,
+    RuntimeDebug,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub enum CircuitBreakerReason {
+    /// The amount bridged in a direction during the era crossed the threshold.
+    VolumeThresholdCrossed(TransferDirection),
+    /// The locked balance is below the expected wrapped supply on Ethereum.
+    LockedBalanceMismatch,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
@@ -91,11 +387,49 @@ pub mod pallet {
         /// Weight information
         type WeightInfo: WeightInfo;
 
-        /// Origin that can pause/unpause the pallet
+        /// Origin that can pause/unpause the pallet, set its limits and release held tokens
         type PauseOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
         /// Provides the native token ID if registered, None if not registered
         type NativeTokenId: Get<Option<TokenId>>;
+
+        /// Provides the active era, the period of the per-era limits
+        type EraIndexProvider: EraIndexProvider;
+
+        /// Number of blocks after which a transfer whose message was not committed is checked for
+        /// a refund, and between two checks
+        #[pallet::constant]
+        type TransferTimeout: Get<BlockNumberFor<Self>>;
+
+        /// Maximum number of transfers to Ethereum expiring in the same block
+        #[pallet::constant]
+        type MaxTransfersPerBlock: Get<u32>;
+
+        /// Account keeping the tokens from Ethereum whose recipient could not be decoded
+        #[pallet::constant]
+        type HoldingAccount: Get<Self::AccountId>;
+
+        /// The runtime call type, for the follow-up calls of inbound transfers
+        type RuntimeCall: Parameter
+            + Dispatchable<RuntimeOrigin = Self::RuntimeOrigin, PostInfo = PostDispatchInfo>
+            + GetDispatchInfo;
+
+        /// Calls that can follow an inbound transfer
+        type FollowUpCallFilter: Contains<<Self as Config>::RuntimeCall>;
+
+        /// Maximum weight of a follow-up call
+        #[pallet::constant]
+        type MaxFollowUpCallWeight: Get<Weight>;
+
+        /// Whether the bridge runs in degraded mode, rejecting transfers to Ethereum
+        type DegradedMode: DegradedModeQuery;
+
+        /// Maximum number of transfers sent in one batched message, at most `MAX_COMMANDS`
+        #[pallet::constant]
+        type MaxBatchSize: Get<u32>;
+
+        /// Fee suggested to senders for each transfer to Ethereum, covering its relaying costs
+        type SuggestedFee: Get<BalanceOf<Self>>;
     }
 
     #[pallet::storage]
@@ -103,6 +437,141 @@ pub mod pallet {
     /// Whether the pallet is paused
     pub type Paused<T> = StorageValue<_, bool, ValueQuery>;
 
+    /// Caps on the amounts bridged
+    #[pallet::storage]
+    pub type Limits<T: Config> = StorageValue<_, TransferLimits<BalanceOf<T>>, ValueQuery>;
+
+    /// Amount sent to Ethereum in the current block
+    #[pallet::storage]
+    pub type OutboundBlockVolume<T: Config> =
+        StorageValue<_, TransferVolume<BlockNumberFor<T>, BalanceOf<T>>, ValueQuery>;
+
+    /// Amount sent to Ethereum in the current era
+    #[pallet::storage]
+    pub type OutboundEraVolume<T: Config> =
+        StorageValue<_, TransferVolume<EraIndex, BalanceOf<T>>, ValueQuery>;
+
+    /// Amount sent to Ethereum by each account in the last era it transferred
+    #[pallet::storage]
+    pub type AccountOutboundVolume<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        TransferVolume<EraIndex, BalanceOf<T>>,
+        ValueQuery,
+    >;
+
+    /// Amount unlocked from Ethereum in the current block
+    #[pallet::storage]
+    pub type InboundBlockVolume<T: Config> =
+        StorageValue<_, TransferVolume<BlockNumberFor<T>, BalanceOf<T>>, ValueQuery>;
+
+    /// Amount unlocked from Ethereum in the current era
+    #[pallet::storage]
+    pub type InboundEraVolume<T: Config> =
+        StorageValue<_, TransferVolume<EraIndex, BalanceOf<T>>, ValueQuery>;
+
+    /// Transfers to Ethereum waiting for their delivery receipt, by outbound message id, or by
+    /// transfer id for batched transfers
+    #[pallet::storage]
+    pub type PendingTransfers<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        H256,
+        PendingTransfer<T::AccountId, BalanceOf<T>, BlockNumberFor<T>>,
+        OptionQuery,
+    >;
+
+    /// Pending transfers of each sender, by outbound message id, or by transfer id for batched
+    /// transfers
+    #[pallet::storage]
+    pub type PendingTransfersBySender<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        H256,
+        (),
+        OptionQuery,
+    >;
+
+    /// Outbound message id of the pending transfers, by outbound nonce
+    #[pallet::storage]
+    pub type PendingTransferIds<T: Config> = StorageMap<_, Twox64Concat, u64, H256, OptionQuery>;
+
+    /// Index of the next message sent to Ethereum by the pallet
+    #[pallet::storage]
+    pub type NextMessageIndex<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+    /// Index of the last message of the pallet accepted by the outbound queue. The messages sent
+    /// before it that were not accepted were dropped.
+    #[pallet::storage]
+    pub type LastAcceptedMessageIndex<T: Config> = StorageValue<_, u64, OptionQuery>;
+
+    /// Messages checked for a refund at each block, by outbound message id
+    #[pallet::storage]
+    pub type TransferTimeouts<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        BlockNumberFor<T>,
+        BoundedVec<H256, T::MaxTransfersPerBlock>,
+        ValueQuery,
+    >;
+
+    /// Tokens from Ethereum kept in the holding account until they are released
+    #[pallet::storage]
+    pub type HeldTransfers<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        u64,
+        HeldTransfer<BalanceOf<T>, BlockNumberFor<T>>,
+        OptionQuery,
+    >;
+
+    /// Identifier of the next held transfer
+    #[pallet::storage]
+    pub type NextHeldTransferId<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+    /// Expected supply of wrapped tokens on Ethereum: the amount locked minus the amount
+    /// unlocked. The locked balance must never be below it.
+    #[pallet::storage]
+    pub type ExpectedWrappedSupply<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+    /// Number of blocks the transfers to Ethereum are collected for before being sent in one
+    /// message. Transfers are sent on their own when unset.
+    #[pallet::storage]
+    pub type BatchingPeriod<T: Config> = StorageValue<_, BlockNumberFor<T>, OptionQuery>;
+
+    /// Transfers to Ethereum waiting to be sent in the next batched message
+    #[pallet::storage]
+    pub type CurrentBatch<T: Config> = StorageValue<
+        _,
+        BoundedVec<BatchedTransfer<T::AccountId, BalanceOf<T>>, T::MaxBatchSize>,
+        ValueQuery,
+    >;
+
+    /// Block at which the first transfer of the current batch was received
+    #[pallet::storage]
+    pub type CurrentBatchOpenedAt<T: Config> = StorageValue<_, BlockNumberFor<T>, OptionQuery>;
+
+    /// Whether sending the current batch already failed
+    #[pallet::storage]
+    pub type CurrentBatchSendFailed<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+    /// Index of the next batched transfer, which its id is derived from
+    #[pallet::storage]
+    pub type NextBatchedTransferIndex<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+    /// Transfer ids of the sent batches waiting for their delivery receipt, by outbound message id
+    #[pallet::storage]
+    pub type SentBatches<T: Config> =
+        StorageMap<_, Blake2_128Concat, H256, BoundedVec<H256, T::MaxBatchSize>, OptionQuery>;
+
+    /// Outcome of the transfers paid through [`PayToEthereum`], by transfer id
+    #[pallet::storage]
+    pub type PaymentOutcomes<T: Config> =
+        StorageMap<_, Blake2_128Concat, H256, PaymentOutcome, OptionQuery>;
+
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
@@ -130,6 +599,84 @@ pub mod pallet {
 
         /// Pallet unpaused
         Unpaused,
+
+        /// Transfer limits updated
+        TransferLimitsSet {
+            limits: TransferLimits<BalanceOf<T>>,
+        },
+
+        /// Pallet paused by the circuit breaker
+        CircuitBreakerTriggered { reason: CircuitBreakerReason },
+
+        /// The tokens of a transfer were minted on Ethereum
+        TransferConfirmed { message_id: H256, nonce: u64 },
+
+        /// The tokens of a transfer were unlocked back to the sender
+        TransferRefunded {
+            message_id: H256,
+            account: T::AccountId,
+            amount: BalanceOf<T>,
+            reason: RefundReason,
+        },
+
+        /// The tokens of a transfer could not be unlocked back to the sender
+        TransferRefundFailed {
+            message_id: H256,
+            account: T::AccountId,
+            amount: BalanceOf<T>,
+            reason: RefundReason,
+        },
+
+        /// Tokens from Ethereum with an undecodable recipient were moved to the holding account
+        TokensHeld { id: u64, amount: BalanceOf<T> },
+
+        /// Held tokens were released to their recipient
+        HeldTokensReleased {
+            id: u64,
+            account: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+
+        /// The follow-up call of an inbound transfer was dispatched
+        FollowUpCallDispatched {
+            account: T::AccountId,
+            result: DispatchResult,
+        },
+
+        /// The follow-up call of an inbound transfer was not dispatched
+        FollowUpCallSkipped {
+            account: T::AccountId,
+            reason: FollowUpCallError,
+        },
+
+        /// Batching period updated, `None` disabling batching
+        BatchingPeriodSet { period: Option<BlockNumberFor<T>> },
+
+        /// Tokens locked for transfer to Ethereum and added to the current batch
+        TransferBatched {
+            transfer_id: H256,
+            from: T::AccountId,
+            to: H160,
+            amount: BalanceOf<T>,
+            fee: BalanceOf<T>,
+        },
+
+        /// A batched transfer was sent to Ethereum
+        BatchedTransferSent { transfer_id: H256, message_id: H256 },
+
+        /// The current batch was sent to Ethereum in one message
+        BatchSent {
+            message_id: H256,
+            transfers: u32,
+            fee: BalanceOf<T>,
+        },
+
+        /// The current batch could not be sent, it is retried on every block. Only emitted on the
+        /// first failure of the batch.
+        BatchSendFailed { error: DispatchError },
+
+        /// The current batch was cancelled and its transfers refunded
+        BatchCancelled { transfers: u32 },
     }
 
     #[pallet::error]
@@ -152,6 +699,43 @@ pub mod pallet {
         TokenNotRegistered,
         /// Insufficient balance in Ethereum sovereign account
         InsufficientSovereignBalance,
+        /// The transfer exceeds the per-block limit
+        BlockLimitExceeded,
+        /// The transfer exceeds the per-era limit
+        EraLimitExceeded,
+        /// The transfer exceeds the per-account limit
+        AccountLimitExceeded,
+        /// Too many transfers to Ethereum in this block
+        TooManyPendingTransfers,
+        /// No held tokens with this identifier
+        UnknownHeldTransfer,
+        /// The bridge runs in degraded mode
+        BridgeDegraded,
+        /// No transfer waits in the current batch
+        EmptyBatch,
+        /// No tracked transfer with this identifier
+        UnknownTransfer,
+        /// The refund of the transfer did not fail
+        RefundNotFailed,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+            let expired = Self::refund_expired_transfers(n);
+            Self::check_locked_balance();
+            let batched = Self::send_due_batch(n);
+            T::WeightInfo::refund_expired_transfers(expired)
+                .saturating_add(T::WeightInfo::check_locked_balance())
+                .saturating_add(T::WeightInfo::send_batch(batched))
+        }
+
+        fn integrity_test() {
+            assert!(
+                T::MaxBatchSize::get() <= MAX_COMMANDS,
+                "A batch must fit in one outbound message"
+            );
+        }
     }
 
     #[pallet::call]
@@ -159,7 +743,8 @@ pub mod pallet {
         /// Transfer DataHaven native tokens to Ethereum
         ///
         /// Locks the tokens in the vault and sends a message through Snowbridge
-        /// to mint the equivalent tokens on Ethereum.
+        /// to mint the equivalent tokens on Ethereum. While batching is enabled, the transfer
+        /// is added to the current batch instead.
         ///
         /// Parameters:
         /// - `origin`: The account initiating the transfer
@@ -167,46 +752,21 @@ pub mod pallet {
         /// - `amount`: The amount of tokens to transfer
         /// - `fee`: The fee to incentivize relayers (in native tokens)
         #[pallet::call_index(0)]
-        #[pallet::weight(T::WeightInfo::transfer_to_ethereum())]
+        #[pallet::weight(
+            Pallet::<T>::transfer_to_ethereum_weight()
+                .saturating_add(T::WeightInfo::send_batch(T::MaxBatchSize::get()))
+        )]
         pub fn transfer_to_ethereum(
             origin: OriginFor<T>,
             recipient: H160,
             amount: BalanceOf<T>,
             fee: BalanceOf<T>,
-        ) -> DispatchResult {
+        ) -> DispatchResultWithPostInfo {
             let who = ensure_signed(origin)?;
 
-            ensure!(!Paused::<T>::get(), Error::<T>::TransfersDisabled);
-
-            // Get the token ID - fails if not registered
-            let token_id = T::NativeTokenId::get().ok_or(Error::<T>::TokenNotRegistered)?;
-
-            ensure!(amount > Zero::zero(), Error::<T>::InvalidAmount);
-            ensure!(fee > Zero::zero(), Error::<T>::ZeroFee);
-            ensure!(
-                recipient != H160::zero(),
-                Error::<T>::InvalidEthereumAddress
-            );
-
-            // Transfer fee to recipient
-            T::Currency::transfer(&who, &T::FeeRecipient::get(), fee, Preservation::Preserve)?;
-
-            // Lock tokens in the sovereign account
-            Self::lock_tokens(&who, amount)?;
-
-            // Build and send the message
-            let message = Self::build_mint_message(token_id, recipient, amount, fee)?;
-            T::OutboundQueue::validate(&message)
-                .and_then(|ticket| T::OutboundQueue::deliver(ticket))
-                .map_err(|_| Error::<T>::SendMessageFailed)?;
-
-            Self::deposit_event(Event::TokensTransferredToEthereum {
-                from: who,
-                to: recipient,
-                amount,
-            });
+            let (_, actual_weight) = Self::do_transfer_to_ethereum(&who, recipient, amount, fee)?;
 
-            Ok(())
+            Ok(actual_weight.into())
         }
 
         /// Pause the pallet, preventing all transfers
@@ -234,30 +794,234 @@ pub mod pallet {
 
             Ok(())
         }
+
+        /// Set the caps on the amounts bridged and the circuit breaker threshold
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::set_transfer_limits())]
+        pub fn set_transfer_limits(
+            origin: OriginFor<T>,
+            limits: TransferLimits<BalanceOf<T>>,
+        ) -> DispatchResult {
+            T::PauseOrigin::ensure_origin(origin)?;
+
+            Limits::<T>::put(&limits);
+
+            Self::deposit_event(Event::TransferLimitsSet { limits });
+
+            Ok(())
+        }
+
+        /// Release tokens kept in the holding account to their recipient
+        ///
+        /// Parameters:
+        /// - `origin`: Must be `PauseOrigin`
+        /// - `id`: The held transfer, as in the `TokensHeld` event
+        /// - `beneficiary`: The account the tokens were meant for
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::release_held_tokens())]
+        pub fn release_held_tokens(
+            origin: OriginFor<T>,
+            id: u64,
+            beneficiary: T::AccountId,
+        ) -> DispatchResult {
+            T::PauseOrigin::ensure_origin(origin)?;
+
+            let held = HeldTransfers::<T>::take(id).ok_or(Error::<T>::UnknownHeldTransfer)?;
+
+            T::Currency::transfer(
+                &T::HoldingAccount::get(),
+                &beneficiary,
+                held.amount,
+                Preservation::Expendable,
+            )?;
+
+            Self::deposit_event(Event::HeldTokensReleased {
+                id,
+                account: beneficiary,
+                amount: held.amount,
+            });
+
+            Ok(())
+        }
+
+        /// Set the number of blocks transfers to Ethereum are batched for
+        ///
+        /// Parameters:
+        /// - `origin`: Must be `PauseOrigin`
+        /// - `period`: The batching period, `None` to send transfers on their own. An open batch
+        ///   is then sent on the next block.
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::set_batching_period())]
+        pub fn set_batching_period(
+            origin: OriginFor<T>,
+            period: Option<BlockNumberFor<T>>,
+        ) -> DispatchResult {
+            T::PauseOrigin::ensure_origin(origin)?;
+
+            BatchingPeriod::<T>::set(period);
+
+            Self::deposit_event(Event::BatchingPeriodSet { period });
+
+            Ok(())
+        }
+
+        /// Send the current batch to Ethereum without waiting for the end of its period
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::WeightInfo::send_batch(T::MaxBatchSize::get()))]
+        pub fn send_batch(origin: OriginFor<T>) -> DispatchResult {
+            T::PauseOrigin::ensure_origin(origin)?;
+
+            Self::flush_batch()?;
+
+            Ok(())
+        }
+
+        /// Refund the transfers of the current batch to their senders
+        ///
+        /// The fees of the transfers are not refunded.
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::cancel_batch(T::MaxBatchSize::get()))]
+        pub fn cancel_batch(origin: OriginFor<T>) -> DispatchResult {
+            T::PauseOrigin::ensure_origin(origin)?;
+
+            let transfers = CurrentBatch::<T>::take();
+            ensure!(!transfers.is_empty(), Error::<T>::EmptyBatch);
+            CurrentBatchOpenedAt::<T>::kill();
+            CurrentBatchSendFailed::<T>::kill();
+
+            let now = frame_system::Pallet::<T>::block_number();
+            for transfer in transfers.iter() {
+                Self::refund(
+                    transfer.id,
+                    PendingTransfer {
+                        sender: transfer.sender.clone(),
+                        recipient: transfer.recipient,
+                        amount: transfer.amount,
+                        nonce: None,
+                        message_index: None,
+                        expires_at: now,
+                        status: TransferStatus::Pending,
+                    },
+                    RefundReason::BatchCancelled,
+                );
+            }
+
+            Self::deposit_event(Event::BatchCancelled {
+                transfers: transfers.len() as u32,
+            });
+
+            Ok(())
+        }
+
+        /// Retry the refund of a transfer whose tokens could not be unlocked back to the sender
+        ///
+        /// Parameters:
+        /// - `origin`: Any signed account
+        /// - `transfer_id`: The transfer, as in the `TransferRefundFailed` event
+        #[pallet::call_index(8)]
+        #[pallet::weight(T::WeightInfo::retry_refund())]
+        pub fn retry_refund(origin: OriginFor<T>, transfer_id: H256) -> DispatchResult {
+            ensure_signed(origin)?;
+
+            let transfer =
+                PendingTransfers::<T>::get(transfer_id).ok_or(Error::<T>::UnknownTransfer)?;
+            let TransferStatus::RefundFailed(reason) = transfer.status else {
+                return Err(Error::<T>::RefundNotFailed.into());
+            };
+
+            Self::unlock_refund(transfer_id, &transfer.sender, transfer.amount, reason)?;
+            Self::untrack_transfer(transfer_id, &transfer.sender);
+
+            Ok(())
+        }
     }
 
     impl<T: Config> Pallet<T> {
-        /// Build outbound message for Snowbridge
-        fn build_mint_message(
-            token_id: TokenId,
+        /// Transfer tokens of `who` to Ethereum, returns the id of the transfer and the actual
+        /// weight of the call, `None` when it sent the current batch
+        pub fn do_transfer_to_ethereum(
+            who: &T::AccountId,
             recipient: H160,
             amount: BalanceOf<T>,
             fee: BalanceOf<T>,
+        ) -> Result<(H256, Option<Weight>), DispatchError> {
+            ensure!(!Paused::<T>::get(), Error::<T>::TransfersDisabled);
+            ensure!(!T::DegradedMode::is_degraded(), Error::<T>::BridgeDegraded);
+
+            // Get the token ID - fails if not registered
+            let token_id = T::NativeTokenId::get().ok_or(Error::<T>::TokenNotRegistered)?;
+
+            ensure!(amount > Zero::zero(), Error::<T>::InvalidAmount);
+            ensure!(fee > Zero::zero(), Error::<T>::ZeroFee);
+            ensure!(
+                recipient != H160::zero(),
+                Error::<T>::InvalidEthereumAddress
+            );
+
+            Self::record_outbound_volume(who, amount)?;
+
+            // Transfer fee to recipient
+            T::Currency::transfer(who, &T::FeeRecipient::get(), fee, Preservation::Preserve)?;
+
+            // Lock tokens in the sovereign account
+            Self::lock_tokens(who, amount)?;
+
+            if BatchingPeriod::<T>::get().is_some() {
+                // The transfer filling the batch pays for sending it
+                let (transfer_id, batch_sent) = Self::batch_transfer(who, recipient, amount, fee)?;
+                return Ok((
+                    transfer_id,
+                    (!batch_sent).then(Self::transfer_to_ethereum_weight),
+                ));
+            }
+
+            // Build and send the message
+            let message = Self::build_mint_message(token_id, &[(recipient, amount)], fee)?;
+            let (message_id, message_index) = Self::send_message(message)?;
+
+            let expires_at = Self::schedule_timeout(message_id)?;
+            Self::track_transfer(
+                message_id,
+                who,
+                recipient,
+                amount,
+                message_index,
+                expires_at,
+            );
+
+            Self::deposit_event(Event::TokensTransferredToEthereum {
+                from: who.clone(),
+                to: recipient,
+                amount,
+            });
+
+            Ok((message_id, Some(Self::transfer_to_ethereum_weight())))
+        }
+
+        /// Build outbound message for Snowbridge, with a mint command per recipient
+        fn build_mint_message(
+            token_id: TokenId,
+            mints: &[(H160, BalanceOf<T>)],
+            fee: BalanceOf<T>,
         ) -> Result<OutboundMessage, Error<T>> {
             // Convert amounts to u128
-            let amount_u128: u128 = amount.try_into().map_err(|_| Error::<T>::Overflow)?;
             let fee_u128: u128 = fee.try_into().map_err(|_| Error::<T>::Overflow)?;
 
-            // Create the mint command
-            let command = Command::MintForeignToken {
-                token_id,
-                recipient,
-                amount: amount_u128,
-            };
+            // Create the mint commands
+            let commands = mints
+                .iter()
+                .map(|(recipient, amount)| {
+                    Ok(Command::MintForeignToken {
+                        token_id,
+                        recipient: *recipient,
+                        amount: (*amount).try_into().map_err(|_| Error::<T>::Overflow)?,
+                    })
+                })
+                .collect::<Result<Vec<_>, Error<T>>>()?;
 
             // Create bounded vector of commands
             let commands =
-                BoundedVec::try_from(vec![command]).map_err(|_| Error::<T>::SendMessageFailed)?;
+                BoundedVec::try_from(commands).map_err(|_| Error::<T>::SendMessageFailed)?;
 
             // Build the outbound message
             Ok(OutboundMessage {
@@ -268,6 +1032,143 @@ pub mod pallet {
             })
         }
 
+        /// Send a message through the outbound queue, returns its id and its index among the
+        /// messages sent by the pallet
+        fn send_message(message: OutboundMessage) -> Result<(H256, u64), Error<T>> {
+            let message_id = T::OutboundQueue::validate(&message)
+                .and_then(|ticket| T::OutboundQueue::deliver(ticket))
+                .map_err(|_| Error::<T>::SendMessageFailed)?;
+
+            let message_index = NextMessageIndex::<T>::mutate(|index| {
+                let message_index = *index;
+                index.saturating_inc();
+                message_index
+            });
+
+            Ok((message_id, message_index))
+        }
+
+        /// Add a transfer to the current batch, sending the batch once full. Returns the id of the
+        /// transfer and whether the batch was sent.
+        fn batch_transfer(
+            sender: &T::AccountId,
+            recipient: H160,
+            amount: BalanceOf<T>,
+            fee: BalanceOf<T>,
+        ) -> Result<(H256, bool), DispatchError> {
+            let transfer_id =
+                H256::from_low_u64_be(NextBatchedTransferIndex::<T>::mutate(|index| {
+                    let transfer_index = *index;
+                    index.saturating_inc();
+                    transfer_index
+                }));
+            let batch_full = CurrentBatch::<T>::try_mutate(|batch| {
+                batch
+                    .try_push(BatchedTransfer {
+                        id: transfer_id,
+                        sender: sender.clone(),
+                        recipient,
+                        amount,
+                        fee,
+                    })
+                    .map_err(|_| Error::<T>::TooManyPendingTransfers)?;
+                Ok::<_, DispatchError>(batch.len() as u32 >= T::MaxBatchSize::get())
+            })?;
+            if CurrentBatchOpenedAt::<T>::get().is_none() {
+                CurrentBatchOpenedAt::<T>::put(frame_system::Pallet::<T>::block_number());
+            }
+
+            Self::deposit_event(Event::TransferBatched {
+                transfer_id,
+                from: sender.clone(),
+                to: recipient,
+                amount,
+                fee,
+            });
+
+            if batch_full {
+                Self::flush_batch()?;
+            }
+
+            Ok((transfer_id, batch_full))
+        }
+
+        /// Send the current batch in one outbound message, returns how many transfers were sent
+        pub(crate) fn flush_batch() -> Result<u32, DispatchError> {
+            let transfers = CurrentBatch::<T>::get();
+            ensure!(!transfers.is_empty(), Error::<T>::EmptyBatch);
+            let token_id = T::NativeTokenId::get().ok_or(Error::<T>::TokenNotRegistered)?;
+
+            let mut fee: BalanceOf<T> = Zero::zero();
+            let mut mints = Vec::with_capacity(transfers.len());
+            for transfer in transfers.iter() {
+                fee.saturating_accrue(transfer.fee);
+                mints.push((transfer.recipient, transfer.amount));
+            }
+            let message = Self::build_mint_message(token_id, &mints, fee)?;
+            let (message_id, message_index) = Self::send_message(message)?;
+
+            let expires_at = Self::schedule_timeout(message_id)?;
+            for transfer in transfers.iter() {
+                Self::track_transfer(
+                    transfer.id,
+                    &transfer.sender,
+                    transfer.recipient,
+                    transfer.amount,
+                    message_index,
+                    expires_at,
+                );
+                Self::deposit_event(Event::BatchedTransferSent {
+                    transfer_id: transfer.id,
+                    message_id,
+                });
+            }
+            SentBatches::<T>::insert(
+                message_id,
+                BoundedVec::truncate_from(transfers.iter().map(|transfer| transfer.id).collect()),
+            );
+            CurrentBatch::<T>::kill();
+            CurrentBatchOpenedAt::<T>::kill();
+            CurrentBatchSendFailed::<T>::kill();
+
+            let sent = transfers.len() as u32;
+            Self::deposit_event(Event::BatchSent {
+                message_id,
+                transfers: sent,
+                fee,
+            });
+
+            Ok(sent)
+        }
+
+        /// Send the current batch if its period is over, returns the size of the batch if it was
+        /// attempted. Batches are held while the pallet is paused or the bridge degraded.
+        pub(crate) fn send_due_batch(n: BlockNumberFor<T>) -> u32 {
+            let Some(opened_at) = CurrentBatchOpenedAt::<T>::get() else {
+                return 0;
+            };
+            // An open batch is sent right away once batching is disabled
+            let due = BatchingPeriod::<T>::get()
+                .map_or(true, |period| n >= opened_at.saturating_add(period));
+            if !due || Paused::<T>::get() || T::DegradedMode::is_degraded() {
+                return 0;
+            }
+
+            let size = CurrentBatch::<T>::decode_len().unwrap_or_default() as u32;
+            if let Err(error) = with_storage_layer(Self::flush_batch) {
+                log::warn!(
+                    target: "datahaven-native-transfer",
+                    "Failed to send the current batch: {:?}",
+                    error
+                );
+                if !CurrentBatchSendFailed::<T>::get() {
+                    CurrentBatchSendFailed::<T>::put(true);
+                    Self::deposit_event(Event::BatchSendFailed { error });
+                }
+            }
+            size
+        }
+
         /// Lock tokens for transfer to Ethereum
         ///
         /// Transfers tokens from a user to the Ethereum sovereign account and updates tracking
@@ -280,6 +1181,8 @@ pub mod pallet {
                 Preservation::Preserve,
             )?;
 
+            ExpectedWrappedSupply::<T>::mutate(|supply| supply.saturating_accrue(amount));
+
             Self::deposit_event(Event::TokensLocked {
                 account: who.clone(),
                 amount,
@@ -290,8 +1193,24 @@ pub mod pallet {
 
         /// Unlock tokens returning from Ethereum
         ///
-        /// Transfers tokens from the Ethereum sovereign account back to user
+        /// Transfers tokens from the Ethereum sovereign account back to user, within the inbound
+        /// limits and as long as the pallet is not paused
         pub fn unlock_tokens(who: &T::AccountId, amount: BalanceOf<T>) -> DispatchResult {
+            Self::unlock_tokens_to(who, amount)?;
+
+            Self::deposit_event(Event::TokensUnlocked {
+                account: who.clone(),
+                amount,
+            });
+
+            Ok(())
+        }
+
+        fn unlock_tokens_to(who: &T::AccountId, amount: BalanceOf<T>) -> DispatchResult {
+            ensure!(!Paused::<T>::get(), Error::<T>::TransfersDisabled);
+
+            Self::record_inbound_volume(amount)?;
+
             let sovereign = T::EthereumSovereignAccount::get();
             let balance = T::Currency::balance(&sovereign);
             let minimum_balance = T::Currency::minimum_balance();
@@ -306,24 +1225,568 @@ pub mod pallet {
             // Transfer from the Ethereum sovereign account
             T::Currency::transfer(&sovereign, who, amount, Preservation::Preserve)?;
 
-            Self::deposit_event(Event::TokensUnlocked {
-                account: who.clone(),
-                amount,
-            });
+            ExpectedWrappedSupply::<T>::mutate(|supply| supply.saturating_reduce(amount));
+
+            Ok(())
+        }
+
+        /// Move tokens returning from Ethereum to the holding account
+        ///
+        /// Used when the recipient cannot be decoded from `claimer`, the tokens are then kept
+        /// until the `PauseOrigin` releases them. Subject to the same checks as `unlock_tokens`.
+        pub fn hold_tokens(claimer: &[u8], amount: BalanceOf<T>) -> DispatchResult {
+            let id = NextHeldTransferId::<T>::get();
+
+            Self::unlock_tokens_to(&T::HoldingAccount::get(), amount)?;
+
+            HeldTransfers::<T>::insert(
+                id,
+                HeldTransfer {
+                    claimer: BoundedVec::truncate_from(claimer.to_vec()),
+                    amount,
+                    held_at: frame_system::Pallet::<T>::block_number(),
+                },
+            );
+            NextHeldTransferId::<T>::put(id.saturating_add(1));
+
+            Self::deposit_event(Event::TokensHeld { id, amount });
 
             Ok(())
         }
 
+        /// Dispatch the follow-up call of an inbound transfer on behalf of `beneficiary`
+        ///
+        /// `sender` is the account of the Ethereum sender of the message: the call is only
+        /// dispatched if it is the beneficiary. The outcome is reported through events and never
+        /// fails the transfer.
+        pub fn dispatch_follow_up_call(
+            sender: &T::AccountId,
+            beneficiary: &T::AccountId,
+            encoded_call: &[u8],
+        ) {
+            let call = match Self::check_follow_up_call(sender, beneficiary, encoded_call) {
+                Ok(call) => call,
+                Err(reason) => {
+                    Self::deposit_event(Event::FollowUpCallSkipped {
+                        account: beneficiary.clone(),
+                        reason,
+                    });
+                    return;
+                }
+            };
+
+            let result = call
+                .dispatch(frame_system::RawOrigin::Signed(beneficiary.clone()).into())
+                .map(|_| ())
+                .map_err(|e| e.error);
+
+            Self::deposit_event(Event::FollowUpCallDispatched {
+                account: beneficiary.clone(),
+                result,
+            });
+        }
+
+        /// Weight of the follow-up call of an inbound transfer, at most `MaxFollowUpCallWeight`
+        ///
+        /// Zero when the call would not be dispatched.
+        pub fn follow_up_call_weight(
+            sender: &T::AccountId,
+            beneficiary: &T::AccountId,
+            encoded_call: &[u8],
+        ) -> Weight {
+            Self::check_follow_up_call(sender, beneficiary, encoded_call)
+                .map(|call| call.get_dispatch_info().call_weight)
+                .unwrap_or_default()
+        }
+
+        fn check_follow_up_call(
+            sender: &T::AccountId,
+            beneficiary: &T::AccountId,
+            encoded_call: &[u8],
+        ) -> Result<<T as Config>::RuntimeCall, FollowUpCallError> {
+            ensure!(sender == beneficiary, FollowUpCallError::Unauthorized);
+
+            let call = <T as Config>::RuntimeCall::decode_all_with_depth_limit(
+                FOLLOW_UP_CALL_DECODE_DEPTH,
+                &mut &encoded_call[..],
+            )
+            .map_err(|_| FollowUpCallError::Undecodable)?;
+
+            ensure!(
+                T::FollowUpCallFilter::contains(&call),
+                FollowUpCallError::Filtered
+            );
+            ensure!(
+                call.get_dispatch_info()
+                    .call_weight
+                    .all_lte(T::MaxFollowUpCallWeight::get()),
+                FollowUpCallError::Overweight
+            );
+
+            Ok(call)
+        }
+
         /// Get the balance of locked tokens in the Ethereum sovereign account
         /// This represents the total amount of tokens locked for transfers to Ethereum
         pub fn total_locked_balance() -> BalanceOf<T> {
             <T::Currency as Inspect<T::AccountId>>::balance(&T::EthereumSovereignAccount::get())
         }
 
+        /// Weight of a transfer to Ethereum, including its delivery notifications from the
+        /// outbound queue, which are paid for upfront
+        pub fn transfer_to_ethereum_weight() -> Weight {
+            T::WeightInfo::transfer_to_ethereum()
+                .saturating_add(T::WeightInfo::on_message_accepted())
+                .saturating_add(T::WeightInfo::on_message_delivered())
+        }
+
+        /// Schedule the first check for the refund of the transfers of a message, returns the block
+        /// it happens at
+        fn schedule_timeout(message_id: H256) -> Result<BlockNumberFor<T>, Error<T>> {
+            let expires_at =
+                frame_system::Pallet::<T>::block_number().saturating_add(T::TransferTimeout::get());
+
+            TransferTimeouts::<T>::try_mutate(expires_at, |message_ids| {
+                message_ids
+                    .try_push(message_id)
+                    .map_err(|_| Error::<T>::TooManyPendingTransfers)
+            })?;
+
+            Ok(expires_at)
+        }
+
+        /// Track a transfer until its delivery receipt is received or its message is dropped
+        fn track_transfer(
+            transfer_id: H256,
+            sender: &T::AccountId,
+            recipient: H160,
+            amount: BalanceOf<T>,
+            message_index: u64,
+            expires_at: BlockNumberFor<T>,
+        ) {
+            PendingTransfers::<T>::insert(
+                transfer_id,
+                PendingTransfer {
+                    sender: sender.clone(),
+                    recipient,
+                    amount,
+                    nonce: None,
+                    message_index: Some(message_index),
+                    expires_at,
+                    status: TransferStatus::Pending,
+                },
+            );
+            PendingTransfersBySender::<T>::insert(sender, transfer_id, ());
+        }
+
+        /// Stop tracking a transfer
+        fn untrack_transfer(transfer_id: H256, sender: &T::AccountId) {
+            PendingTransfers::<T>::remove(transfer_id);
+            PendingTransfersBySender::<T>::remove(sender, transfer_id);
+        }
+
+        /// Ids of the transfers sent in a message: the message id itself, or the transfer ids of
+        /// a batch
+        pub(crate) fn transfer_ids(message_id: H256) -> Vec<H256> {
+            match SentBatches::<T>::get(message_id) {
+                Some(transfer_ids) => transfer_ids.into_inner(),
+                None => vec![message_id],
+            }
+        }
+
+        /// Check the messages whose timeout is at `n`, returns how many transfers were checked
+        ///
+        /// The transfers of a dropped message are refunded. A message not accepted yet is checked
+        /// again `TransferTimeout` blocks later, and a committed one waits for its receipt.
+        pub(crate) fn refund_expired_transfers(n: BlockNumberFor<T>) -> u32 {
+            let mut message_ids = TransferTimeouts::<T>::take(n);
+            let last_accepted = LastAcceptedMessageIndex::<T>::get();
+            let next_check = n.saturating_add(T::TransferTimeout::get());
+            let mut checked = 0u32;
+
+            message_ids.retain(|message_id| {
+                let transfer_ids = Self::transfer_ids(*message_id);
+                checked.saturating_accrue(transfer_ids.len() as u32);
+
+                // The transfers of a message are sent, accepted and settled together
+                let Some(message) = transfer_ids.first().and_then(PendingTransfers::<T>::get)
+                else {
+                    return false;
+                };
+                if message.nonce.is_some() || message.status != TransferStatus::Pending {
+                    return false;
+                }
+
+                let dropped = message
+                    .message_index
+                    .zip(last_accepted)
+                    .is_some_and(|(index, last_accepted)| index < last_accepted);
+                if dropped {
+                    SentBatches::<T>::remove(message_id);
+                }
+                for transfer_id in transfer_ids {
+                    let Some(mut transfer) = PendingTransfers::<T>::get(transfer_id) else {
+                        continue;
+                    };
+                    if dropped {
+                        Self::refund(transfer_id, transfer, RefundReason::Dropped);
+                    } else {
+                        transfer.expires_at = next_check;
+                        PendingTransfers::<T>::insert(transfer_id, transfer);
+                    }
+                }
+
+                !dropped
+            });
+
+            // Nothing else is scheduled at `next_check` before the transfers of this block
+            if !message_ids.is_empty() {
+                TransferTimeouts::<T>::insert(next_check, message_ids);
+            }
+
+            checked
+        }
+
+        /// Settle a transfer sent in message `message_id` on its delivery receipt
+        pub(crate) fn settle_transfer(
+            message_id: H256,
+            transfer_id: H256,
+            nonce: u64,
+            transfer: PendingTransfer<T::AccountId, BalanceOf<T>, BlockNumberFor<T>>,
+            success: bool,
+        ) {
+            if transfer.status != TransferStatus::Pending {
+                return;
+            }
+            TransferTimeouts::<T>::mutate(transfer.expires_at, |message_ids| {
+                message_ids.retain(|id| *id != message_id)
+            });
+
+            if success {
+                Self::untrack_transfer(transfer_id, &transfer.sender);
+                Self::record_payment_outcome(transfer_id, PaymentOutcome::Delivered);
+                Self::deposit_event(Event::TransferConfirmed {
+                    message_id: transfer_id,
+                    nonce,
+                });
+            } else {
+                Self::refund(transfer_id, transfer, RefundReason::DeliveryFailed);
+            }
+        }
+
+        /// Unlock the tokens of a transfer back to the sender and stop tracking it
+        ///
+        /// If the tokens can't be unlocked, the transfer stays tracked with its refund failed, to
+        /// be retried with `retry_refund`.
+        pub(crate) fn refund(
+            transfer_id: H256,
+            transfer: PendingTransfer<T::AccountId, BalanceOf<T>, BlockNumberFor<T>>,
+            reason: RefundReason,
+        ) {
+            match Self::unlock_refund(transfer_id, &transfer.sender, transfer.amount, reason) {
+                Ok(()) => Self::untrack_transfer(transfer_id, &transfer.sender),
+                Err(e) => {
+                    log::error!(
+                        target: "datahaven-native-transfer",
+                        "Failed to refund transfer {:?}: {:?}",
+                        transfer_id,
+                        e
+                    );
+                    Self::deposit_event(Event::TransferRefundFailed {
+                        message_id: transfer_id,
+                        account: transfer.sender.clone(),
+                        amount: transfer.amount,
+                        reason,
+                    });
+                    PendingTransfersBySender::<T>::insert(&transfer.sender, transfer_id, ());
+                    PendingTransfers::<T>::insert(
+                        transfer_id,
+                        PendingTransfer {
+                            status: TransferStatus::RefundFailed(reason),
+                            ..transfer
+                        },
+                    );
+                }
+            }
+        }
+
+        /// Unlock the refunded tokens of a transfer from the sovereign account to the sender
+        fn unlock_refund(
+            transfer_id: H256,
+            sender: &T::AccountId,
+            amount: BalanceOf<T>,
+            reason: RefundReason,
+        ) -> DispatchResult {
+            T::Currency::transfer(
+                &T::EthereumSovereignAccount::get(),
+                sender,
+                amount,
+                Preservation::Preserve,
+            )?;
+
+            ExpectedWrappedSupply::<T>::mutate(|supply| supply.saturating_reduce(amount));
+            Self::record_payment_outcome(transfer_id, PaymentOutcome::Refunded);
+            Self::deposit_event(Event::TransferRefunded {
+                message_id: transfer_id,
+                account: sender.clone(),
+                amount,
+                reason,
+            });
+
+            Ok(())
+        }
+
+        /// Update the outcome of a transfer if it was paid through [`PayToEthereum`]
+        fn record_payment_outcome(transfer_id: H256, outcome: PaymentOutcome) {
+            PaymentOutcomes::<T>::mutate_extant(transfer_id, |payment| *payment = outcome);
+        }
+
+        /// Tracked transfers sent by `account`, with their outbound message id, or transfer id
+        /// for batched transfers
+        ///
+        /// Returns at most `limit` transfers, and never more than [`MAX_PENDING_TRANSFERS_PAGE`],
+        /// starting after the transfer `start_after` when given, e.g. the last transfer of the
+        /// previous page.
+        pub fn pending_transfers(
+            account: &T::AccountId,
+            start_after: Option<H256>,
+            limit: u32,
+        ) -> Vec<(
+            H256,
+            PendingTransfer<T::AccountId, BalanceOf<T>, BlockNumberFor<T>>,
+        )> {
+            let transfer_ids = match start_after {
+                Some(transfer_id) => PendingTransfersBySender::<T>::iter_key_prefix_from(
+                    account,
+                    PendingTransfersBySender::<T>::hashed_key_for(account, transfer_id),
+                ),
+                None => PendingTransfersBySender::<T>::iter_key_prefix(account),
+            };
+
+            transfer_ids
+                .take(limit.min(MAX_PENDING_TRANSFERS_PAGE) as usize)
+                .filter_map(|transfer_id| {
+                    PendingTransfers::<T>::get(transfer_id).map(|transfer| (transfer_id, transfer))
+                })
+                .collect()
+        }
+
+        /// Whether the locked balance covers the expected wrapped supply on Ethereum
+        pub fn is_locked_balance_sufficient() -> bool {
+            Self::total_locked_balance() >= ExpectedWrappedSupply::<T>::get()
+        }
+
+        /// Pause the pallet if the locked balance does not cover the expected wrapped supply
+        pub(crate) fn check_locked_balance() {
+            if !Paused::<T>::get() && !Self::is_locked_balance_sufficient() {
+                Self::trip_circuit_breaker(CircuitBreakerReason::LockedBalanceMismatch);
+            }
+        }
+
+        /// Account a transfer to Ethereum against the outbound limits
+        fn record_outbound_volume(who: &T::AccountId, amount: BalanceOf<T>) -> DispatchResult {
+            let limits = Limits::<T>::get();
+            let block_number = frame_system::Pallet::<T>::block_number();
+            let era = T::EraIndexProvider::active_era().index;
+
+            let block_volume = OutboundBlockVolume::<T>::get().add(block_number, amount);
+            Self::ensure_within(
+                &block_volume,
+                limits.outbound_per_block,
+                Error::<T>::BlockLimitExceeded,
+            )?;
+            let era_volume = OutboundEraVolume::<T>::get().add(era, amount);
+            Self::ensure_within(
+                &era_volume,
+                limits.outbound_per_era,
+                Error::<T>::EraLimitExceeded,
+            )?;
+            let account_volume = AccountOutboundVolume::<T>::get(who).add(era, amount);
+            Self::ensure_within(
+                &account_volume,
+                limits.outbound_per_account,
+                Error::<T>::AccountLimitExceeded,
+            )?;
+
+            OutboundBlockVolume::<T>::put(block_volume);
+            AccountOutboundVolume::<T>::insert(who, account_volume);
+            Self::check_volume_threshold(
+                &era_volume,
+                limits.circuit_breaker_threshold,
+                TransferDirection::Outbound,
+            );
+            OutboundEraVolume::<T>::put(era_volume);
+
+            Ok(())
+        }
+
+        /// Account an unlock from Ethereum against the inbound limits
+        fn record_inbound_volume(amount: BalanceOf<T>) -> DispatchResult {
+            let limits = Limits::<T>::get();
+            let block_number = frame_system::Pallet::<T>::block_number();
+            let era = T::EraIndexProvider::active_era().index;
+
+            let block_volume = InboundBlockVolume::<T>::get().add(block_number, amount);
+            Self::ensure_within(
+                &block_volume,
+                limits.inbound_per_block,
+                Error::<T>::BlockLimitExceeded,
+            )?;
+            let era_volume = InboundEraVolume::<T>::get().add(era, amount);
+            Self::ensure_within(
+                &era_volume,
+                limits.inbound_per_era,
+                Error::<T>::EraLimitExceeded,
+            )?;
+
+            InboundBlockVolume::<T>::put(block_volume);
+            Self::check_volume_threshold(
+                &era_volume,
+                limits.circuit_breaker_threshold,
+                TransferDirection::Inbound,
+            );
+            InboundEraVolume::<T>::put(era_volume);
+
+            Ok(())
+        }
+
+        fn ensure_within<Period>(
+            volume: &TransferVolume<Period, BalanceOf<T>>,
+            limit: Option<BalanceOf<T>>,
+            error: Error<T>,
+        ) -> DispatchResult {
+            match limit {
+                Some(limit) if volume.amount > limit => Err(error.into()),
+                _ => Ok(()),
+            }
+        }
+
+        /// Pause the pallet once the era volume goes above the threshold. The transfer crossing
+        /// it still goes through, so the pause is not reverted along with a failed transfer.
+        fn check_volume_threshold(
+            era_volume: &TransferVolume<EraIndex, BalanceOf<T>>,
+            threshold: Option<BalanceOf<T>>,
+            direction: TransferDirection,
+        ) {
+            if threshold.is_some_and(|threshold| era_volume.amount > threshold) {
+                Self::trip_circuit_breaker(CircuitBreakerReason::VolumeThresholdCrossed(direction));
+            }
+        }
+
+        fn trip_circuit_breaker(reason: CircuitBreakerReason) {
+            log::warn!(
+                target: "datahaven-native-transfer",
+                "Circuit breaker triggered: {:?}",
+                reason
+            );
+            Paused::<T>::put(true);
+            Self::deposit_event(Event::CircuitBreakerTriggered { reason });
+        }
+
         /// Get the Ethereum sovereign account address
         /// Useful for monitoring and debugging
         pub fn ethereum_sovereign_account() -> T::AccountId {
             T::EthereumSovereignAccount::get()
         }
+
+        /// Fee suggested for `transfers` transfers to Ethereum
+        pub fn estimate_fee(transfers: u32) -> BalanceOf<T> {
+            T::SuggestedFee::get().saturating_mul(transfers.into())
+        }
+    }
+}
+
+impl<T: Config> MessageDeliveryHandler for Pallet<T> {
+    fn on_message_accepted(id: H256, nonce: u64) {
+        let mut message_index = None;
+        for transfer_id in Self::transfer_ids(id) {
+            PendingTransfers::<T>::mutate(transfer_id, |maybe_transfer| {
+                if let Some(transfer) = maybe_transfer {
+                    transfer.nonce = Some(nonce);
+                    message_index = transfer.message_index;
+                }
+            });
+        }
+        if let Some(message_index) = message_index {
+            PendingTransferIds::<T>::insert(nonce, id);
+            LastAcceptedMessageIndex::<T>::mutate(|last_accepted| {
+                *last_accepted = Some(last_accepted.map_or(message_index, |i| i.max(message_index)))
+            });
+        }
+    }
+
+    fn on_message_delivered(nonce: u64, success: bool) {
+        let Some(message_id) = PendingTransferIds::<T>::take(nonce) else {
+            return;
+        };
+        let transfer_ids = Self::transfer_ids(message_id);
+        SentBatches::<T>::remove(message_id);
+
+        for transfer_id in transfer_ids {
+            if let Some(transfer) = PendingTransfers::<T>::get(transfer_id) {
+                Self::settle_transfer(message_id, transfer_id, nonce, transfer, success);
+            }
+        }
+    }
+}
+
+/// Pays from the `Source` account to an Ethereum address, with a transfer to Ethereum.
+///
+/// `Source` also pays the suggested fee of the transfer. Payments are identified by the id of
+/// their transfer, whose outcome is kept in [`PaymentOutcomes`]. Transfers are only refunded once
+/// known not to be minted on Ethereum, so a refunded payment failed for good, while a payment
+/// past the transfer timeout stays in progress until its delivery receipt arrives.
+///
+/// The outcome of a payment is pruned once its success or failure is reported by
+/// `check_status`, the payment being unknown afterwards.
+pub struct PayToEthereum<T, Source>(PhantomData<(T, Source)>);
+
+impl<T: Config, Source: Get<T::AccountId>> Pay for PayToEthereum<T, Source> {
+    type Balance = BalanceOf<T>;
+    type Beneficiary = H160;
+    type AssetKind = ();
+    type Id = H256;
+    type Error = DispatchError;
+
+    fn pay(
+        who: &Self::Beneficiary,
+        _asset_kind: Self::AssetKind,
+        amount: Self::Balance,
+    ) -> Result<Self::Id, Self::Error> {
+        let (transfer_id, _) = Pallet::<T>::do_transfer_to_ethereum(
+            &Source::get(),
+            *who,
+            amount,
+            Pallet::<T>::estimate_fee(1),
+        )?;
+        PaymentOutcomes::<T>::insert(transfer_id, PaymentOutcome::Pending);
+
+        Ok(transfer_id)
+    }
+
+    fn check_status(id: Self::Id) -> PaymentStatus {
+        let status = match PaymentOutcomes::<T>::get(id) {
+            Some(PaymentOutcome::Pending) => PaymentStatus::InProgress,
+            Some(PaymentOutcome::Delivered) => PaymentStatus::Success,
+            Some(PaymentOutcome::Refunded) => PaymentStatus::Failure,
+            None => PaymentStatus::Unknown,
+        };
+        if matches!(status, PaymentStatus::Success | PaymentStatus::Failure) {
+            PaymentOutcomes::<T>::remove(id);
+        }
+        status
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn ensure_successful(_: &Self::Beneficiary, _: Self::AssetKind, amount: Self::Balance) {
+        let _ = T::Currency::mint_into(
+            &Source::get(),
+            amount.saturating_add(Pallet::<T>::estimate_fee(1)),
+        );
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn ensure_concluded(id: Self::Id) {
+        PaymentOutcomes::<T>::insert(id, PaymentOutcome::Delivered);
     }
 }
@@ -16,11 +16,15 @@
 
 use {
     crate::{self as pallet_datahaven_native_transfer},
+    core::cell::RefCell,
     frame_support::{
         parameter_types,
-        traits::{ConstU32, Everything, Get},
+        traits::{ConstU128, ConstU32, Contains, Everything, Get},
+        weights::Weight,
     },
     frame_system::EnsureRoot,
+    pallet_external_validators::traits::{ActiveEraInfo, EraIndex, EraIndexProvider},
+    snowbridge_core::operating_mode::DegradedModeQuery,
     snowbridge_outbound_queue_primitives::v2::{Message as OutboundMessage, SendMessage},
     sp_core::H256,
     sp_runtime::{
@@ -104,9 +108,9 @@ impl SendMessage for MockOkOutboundQueue {
     }
 
     fn deliver(
-        _ticket: Self::Ticket,
+        ticket: Self::Ticket,
     ) -> Result<H256, snowbridge_outbound_queue_primitives::SendError> {
-        Ok(H256::zero())
+        Ok(ticket.id)
     }
 }
 
@@ -125,6 +129,25 @@ parameter_types! {
     pub const DataHavenTokenId: H256 = H256::repeat_byte(0x01);
     pub const FeeRecipientAccount: u64 = 1000;
     pub storage IsTokenRegistered: bool = true; // Default to registered for most tests
+    pub const TransferTimeout: u64 = 10;
+    pub const HoldingAccount: u64 = 1001;
+    pub storage MaxFollowUpCallWeight: Weight = Weight::from_parts(1_000_000_000, 100_000);
+    pub storage IsBridgeDegraded: bool = false;
+}
+
+pub struct MockDegradedMode;
+impl DegradedModeQuery for MockDegradedMode {
+    fn is_degraded() -> bool {
+        IsBridgeDegraded::get()
+    }
+}
+
+/// Only balance transfers can follow an inbound transfer
+pub struct MockFollowUpCallFilter;
+impl Contains<RuntimeCall> for MockFollowUpCallFilter {
+    fn contains(call: &RuntimeCall) -> bool {
+        matches!(call, RuntimeCall::Balances(_))
+    }
 }
 
 pub struct MockNativeTokenId;
@@ -138,6 +161,31 @@ impl Get<Option<H256>> for MockNativeTokenId {
     }
 }
 
+thread_local! {
+    pub static ERA_INDEX: RefCell<EraIndex> = const { RefCell::new(0) };
+}
+
+pub struct MockEraIndexProvider;
+
+impl MockEraIndexProvider {
+    pub fn with_era(era_index: EraIndex) {
+        ERA_INDEX.with(|r| *r.borrow_mut() = era_index);
+    }
+}
+
+impl EraIndexProvider for MockEraIndexProvider {
+    fn active_era() -> ActiveEraInfo {
+        ActiveEraInfo {
+            index: ERA_INDEX.with(|q| *q.borrow()),
+            start: None,
+        }
+    }
+
+    fn era_to_session_start(_era_index: EraIndex) -> Option<u32> {
+        None
+    }
+}
+
 impl crate::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type Currency = Balances;
@@ -147,6 +195,16 @@ impl crate::Config for Test {
     type FeeRecipient = FeeRecipientAccount;
     type WeightInfo = ();
     type PauseOrigin = EnsureRoot<u64>;
+    type EraIndexProvider = MockEraIndexProvider;
+    type TransferTimeout = TransferTimeout;
+    type MaxTransfersPerBlock = ConstU32<100>;
+    type HoldingAccount = HoldingAccount;
+    type RuntimeCall = RuntimeCall;
+    type FollowUpCallFilter = MockFollowUpCallFilter;
+    type MaxFollowUpCallWeight = MaxFollowUpCallWeight;
+    type DegradedMode = MockDegradedMode;
+    type MaxBatchSize = ConstU32<3>;
+    type SuggestedFee = ConstU128<10>;
 }
 
 pub const ALICE: u64 = 1;
@@ -154,6 +212,7 @@ pub const BOB: u64 = 2;
 pub const CHARLIE: u64 = 3;
 pub const ETHEREUM_SOVEREIGN: u64 = 999;
 pub const FEE_RECIPIENT: u64 = 1000;
+pub const HOLDING_ACCOUNT: u64 = 1001;
 pub const INITIAL_BALANCE: u128 = 10_000;
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
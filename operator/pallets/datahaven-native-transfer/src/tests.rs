@@ -15,12 +15,27 @@
 // along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
 
 use {
-    crate::{mock::*, Error, Pallet as DataHavenNativeTransfer, Paused},
+    crate::{
+        mock::*, BatchingPeriod, CircuitBreakerReason, CurrentBatch, CurrentBatchSendFailed, Error,
+        ExpectedWrappedSupply, FollowUpCallError, HeldTransfers, LastAcceptedMessageIndex, Limits,
+        NextBatchedTransferIndex, NextHeldTransferId, Pallet as DataHavenNativeTransfer, Paused,
+        PayToEthereum, PaymentOutcome, PaymentOutcomes, PendingTransferIds, PendingTransfers,
+        PendingTransfersBySender, RefundReason, SentBatches, TransferDirection, TransferLimits,
+        TransferStatus, TransferTimeouts, MAX_PENDING_TRANSFERS_PAGE,
+    },
     frame_support::{
         assert_noop, assert_ok,
-        traits::fungible::{Inspect, Mutate},
+        dispatch::GetDispatchInfo,
+        traits::{
+            fungible::{Inspect, Mutate},
+            tokens::{Pay, PaymentStatus},
+            ConstU64, Get, Hooks,
+        },
+        weights::Weight,
     },
-    sp_core::H160,
+    parity_scale_codec::Encode,
+    snowbridge_outbound_queue_primitives::v2::MessageDeliveryHandler,
+    sp_core::{H160, H256},
     sp_runtime::DispatchError,
 };
 
@@ -63,6 +78,15 @@ fn transfer_to_ethereum_works() {
     });
 }
 
+#[test]
+fn estimate_fee_scales_with_transfers() {
+    new_test_ext().execute_with(|| {
+        assert_eq!(DataHavenNativeTransfer::<Test>::estimate_fee(0), 0);
+        assert_eq!(DataHavenNativeTransfer::<Test>::estimate_fee(1), 10);
+        assert_eq!(DataHavenNativeTransfer::<Test>::estimate_fee(3), 30);
+    });
+}
+
 #[test]
 fn transfer_zero_amount_fails() {
     new_test_ext().execute_with(|| {
@@ -126,6 +150,27 @@ fn transfer_when_paused_fails() {
     });
 }
 
+#[test]
+fn transfer_when_bridge_degraded_fails() {
+    new_test_ext().execute_with(|| {
+        IsBridgeDegraded::set(&true);
+
+        assert_noop!(
+            DataHavenNativeTransfer::<Test>::transfer_to_ethereum(
+                RuntimeOrigin::signed(ALICE),
+                ethereum_address(),
+                1000,
+                100
+            ),
+            Error::<Test>::BridgeDegraded
+        );
+
+        // Tokens coming back from Ethereum are still unlocked
+        assert_ok!(DataHavenNativeTransfer::<Test>::lock_tokens(&ALICE, 1000));
+        assert_ok!(DataHavenNativeTransfer::<Test>::unlock_tokens(&BOB, 999));
+    });
+}
+
 // Test removed: transfer_with_send_message_failure
 // Cannot test message failures without mock state
 
@@ -410,6 +455,1212 @@ fn pause_unpause_cycle_works() {
     });
 }
 
+// ===========================
+// Rate Limit & Circuit Breaker Tests
+// ===========================
+
+fn set_limits(limits: TransferLimits<u128>) {
+    assert_ok!(DataHavenNativeTransfer::<Test>::set_transfer_limits(
+        RuntimeOrigin::root(),
+        limits
+    ));
+}
+
+fn transfer(who: u64, amount: u128) -> sp_runtime::DispatchResultWithPostInfo {
+    DataHavenNativeTransfer::<Test>::transfer_to_ethereum(
+        RuntimeOrigin::signed(who),
+        ethereum_address(),
+        amount,
+        10,
+    )
+}
+
+#[test]
+fn set_transfer_limits_works() {
+    new_test_ext().execute_with(|| {
+        let limits = TransferLimits {
+            outbound_per_block: Some(1000),
+            inbound_per_era: Some(5000),
+            ..Default::default()
+        };
+
+        set_limits(limits.clone());
+
+        assert_eq!(Limits::<Test>::get(), limits);
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::DataHavenNativeTransfer(crate::Event::TransferLimitsSet { limits })
+        );
+    });
+}
+
+#[test]
+fn set_transfer_limits_unauthorized_fails() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            DataHavenNativeTransfer::<Test>::set_transfer_limits(
+                RuntimeOrigin::signed(ALICE),
+                TransferLimits::default()
+            ),
+            DispatchError::BadOrigin
+        );
+    });
+}
+
+#[test]
+fn outbound_block_limit_is_enforced() {
+    new_test_ext().execute_with(|| {
+        set_limits(TransferLimits {
+            outbound_per_block: Some(1500),
+            ..Default::default()
+        });
+
+        assert_ok!(transfer(ALICE, 1000));
+        assert_noop!(transfer(BOB, 600), Error::<Test>::BlockLimitExceeded);
+
+        // The limit applies to the next block again
+        System::set_block_number(2);
+        assert_ok!(transfer(BOB, 600));
+    });
+}
+
+#[test]
+fn outbound_era_and_account_limits_are_enforced() {
+    new_test_ext().execute_with(|| {
+        set_limits(TransferLimits {
+            outbound_per_era: Some(3000),
+            outbound_per_account: Some(1500),
+            ..Default::default()
+        });
+
+        assert_ok!(transfer(ALICE, 1000));
+        assert_noop!(transfer(ALICE, 600), Error::<Test>::AccountLimitExceeded);
+        assert_ok!(transfer(BOB, 1500));
+
+        // Account limits are per account, but the era limit is global
+        System::set_block_number(2);
+        assert_noop!(transfer(CHARLIE, 600), Error::<Test>::EraLimitExceeded);
+        assert_ok!(transfer(CHARLIE, 500));
+
+        // A new era resets both
+        MockEraIndexProvider::with_era(1);
+        assert_ok!(transfer(ALICE, 1500));
+    });
+}
+
+#[test]
+fn inbound_limits_are_enforced() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(DataHavenNativeTransfer::<Test>::lock_tokens(&ALICE, 5000));
+        set_limits(TransferLimits {
+            inbound_per_block: Some(1000),
+            inbound_per_era: Some(1500),
+            ..Default::default()
+        });
+
+        assert_ok!(DataHavenNativeTransfer::<Test>::unlock_tokens(&BOB, 1000));
+        assert_noop!(
+            DataHavenNativeTransfer::<Test>::unlock_tokens(&BOB, 1),
+            Error::<Test>::BlockLimitExceeded
+        );
+
+        System::set_block_number(2);
+        assert_noop!(
+            DataHavenNativeTransfer::<Test>::unlock_tokens(&BOB, 600),
+            Error::<Test>::EraLimitExceeded
+        );
+        assert_ok!(DataHavenNativeTransfer::<Test>::unlock_tokens(&BOB, 500));
+
+        // Outbound transfers have their own limits
+        assert_ok!(transfer(ALICE, 2000));
+    });
+}
+
+#[test]
+fn unlock_when_paused_fails() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(DataHavenNativeTransfer::<Test>::lock_tokens(&ALICE, 5000));
+        assert_ok!(DataHavenNativeTransfer::<Test>::pause(RuntimeOrigin::root()));
+
+        assert_noop!(
+            DataHavenNativeTransfer::<Test>::unlock_tokens(&BOB, 1000),
+            Error::<Test>::TransfersDisabled
+        );
+    });
+}
+
+#[test]
+fn expected_wrapped_supply_tracks_locks_and_unlocks() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(transfer(ALICE, 3000));
+        assert_ok!(DataHavenNativeTransfer::<Test>::lock_tokens(&BOB, 2000));
+        assert_eq!(ExpectedWrappedSupply::<Test>::get(), 5000);
+
+        assert_ok!(DataHavenNativeTransfer::<Test>::unlock_tokens(
+            &CHARLIE, 1500
+        ));
+        assert_eq!(ExpectedWrappedSupply::<Test>::get(), 3500);
+        assert!(DataHavenNativeTransfer::<Test>::is_locked_balance_sufficient());
+    });
+}
+
+#[test]
+fn circuit_breaker_trips_on_volume_threshold() {
+    new_test_ext().execute_with(|| {
+        set_limits(TransferLimits {
+            circuit_breaker_threshold: Some(1500),
+            ..Default::default()
+        });
+
+        assert_ok!(transfer(ALICE, 1000));
+        assert!(!Paused::<Test>::get());
+
+        // The transfer crossing the threshold goes through, then the pallet is paused
+        assert_ok!(transfer(BOB, 1000));
+        assert!(Paused::<Test>::get());
+        assert!(System::events().iter().any(|record| record.event
+            == RuntimeEvent::DataHavenNativeTransfer(crate::Event::CircuitBreakerTriggered {
+                reason: CircuitBreakerReason::VolumeThresholdCrossed(TransferDirection::Outbound),
+            })));
+
+        // Both directions are stopped
+        assert_noop!(transfer(CHARLIE, 100), Error::<Test>::TransfersDisabled);
+        assert_noop!(
+            DataHavenNativeTransfer::<Test>::unlock_tokens(&CHARLIE, 100),
+            Error::<Test>::TransfersDisabled
+        );
+    });
+}
+
+#[test]
+fn circuit_breaker_trips_on_inbound_volume_threshold() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(DataHavenNativeTransfer::<Test>::lock_tokens(&ALICE, 5000));
+        set_limits(TransferLimits {
+            circuit_breaker_threshold: Some(1500),
+            ..Default::default()
+        });
+
+        assert_ok!(DataHavenNativeTransfer::<Test>::unlock_tokens(&BOB, 1000));
+        assert_ok!(DataHavenNativeTransfer::<Test>::unlock_tokens(&BOB, 1000));
+
+        assert!(Paused::<Test>::get());
+        assert!(System::events().iter().any(|record| record.event
+            == RuntimeEvent::DataHavenNativeTransfer(crate::Event::CircuitBreakerTriggered {
+                reason: CircuitBreakerReason::VolumeThresholdCrossed(TransferDirection::Inbound),
+            })));
+    });
+}
+
+#[test]
+fn circuit_breaker_trips_when_locked_balance_is_insufficient() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(transfer(ALICE, 1000));
+
+        // Nothing happens while the locked balance covers the wrapped supply
+        DataHavenNativeTransfer::<Test>::on_initialize(2);
+        assert!(!Paused::<Test>::get());
+
+        // Funds leave the sovereign account without going through the pallet
+        <Balances as Mutate<_>>::set_balance(&ETHEREUM_SOVEREIGN, 500);
+        assert!(!DataHavenNativeTransfer::<Test>::is_locked_balance_sufficient());
+
+        DataHavenNativeTransfer::<Test>::on_initialize(3);
+        assert!(Paused::<Test>::get());
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::DataHavenNativeTransfer(crate::Event::CircuitBreakerTriggered {
+                reason: CircuitBreakerReason::LockedBalanceMismatch,
+            })
+        );
+    });
+}
+
+// ===========================
+// Refund Tests
+// ===========================
+
+fn pending_transfer_id(who: u64) -> H256 {
+    let transfers = DataHavenNativeTransfer::<Test>::pending_transfers(&who, None, 10);
+    assert_eq!(transfers.len(), 1);
+    transfers[0].0
+}
+
+#[test]
+fn transfer_is_tracked_until_confirmed() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(transfer(ALICE, 1000));
+        let message_id = pending_transfer_id(ALICE);
+
+        let pending = PendingTransfers::<Test>::get(message_id).unwrap();
+        assert_eq!(pending.recipient, ethereum_address());
+        assert_eq!(pending.amount, 1000);
+        assert_eq!(pending.nonce, None);
+        assert_eq!(pending.message_index, Some(0));
+        assert_eq!(pending.expires_at, 1 + TransferTimeout::get());
+        assert_eq!(pending.status, TransferStatus::Pending);
+        assert_eq!(
+            TransferTimeouts::<Test>::get(pending.expires_at).into_inner(),
+            vec![message_id]
+        );
+
+        DataHavenNativeTransfer::<Test>::on_message_accepted(message_id, 7);
+        assert_eq!(
+            PendingTransfers::<Test>::get(message_id).unwrap().nonce,
+            Some(7)
+        );
+        assert_eq!(PendingTransferIds::<Test>::get(7), Some(message_id));
+        assert_eq!(LastAcceptedMessageIndex::<Test>::get(), Some(0));
+
+        DataHavenNativeTransfer::<Test>::on_message_delivered(7, true);
+        assert!(PendingTransfers::<Test>::get(message_id).is_none());
+        assert!(!PendingTransfersBySender::<Test>::contains_key(
+            ALICE, message_id
+        ));
+        assert!(PendingTransferIds::<Test>::get(7).is_none());
+        assert!(TransferTimeouts::<Test>::get(pending.expires_at).is_empty());
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::DataHavenNativeTransfer(crate::Event::TransferConfirmed {
+                message_id,
+                nonce: 7,
+            })
+        );
+
+        // The tokens stay locked
+        assert_eq!(Balances::balance(&ETHEREUM_SOVEREIGN), 1000);
+        assert_eq!(ExpectedWrappedSupply::<Test>::get(), 1000);
+    });
+}
+
+#[test]
+fn failed_delivery_is_refunded() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(transfer(ALICE, 1000));
+        assert_ok!(transfer(BOB, 500));
+        let message_id = pending_transfer_id(ALICE);
+
+        DataHavenNativeTransfer::<Test>::on_message_accepted(message_id, 1);
+        DataHavenNativeTransfer::<Test>::on_message_delivered(1, false);
+
+        assert!(PendingTransfers::<Test>::get(message_id).is_none());
+        // The fee is not refunded
+        assert_eq!(Balances::balance(&ALICE), INITIAL_BALANCE - 10);
+        assert_eq!(Balances::balance(&ETHEREUM_SOVEREIGN), 500);
+        assert_eq!(ExpectedWrappedSupply::<Test>::get(), 500);
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::DataHavenNativeTransfer(crate::Event::TransferRefunded {
+                message_id,
+                account: ALICE,
+                amount: 1000,
+                reason: RefundReason::DeliveryFailed,
+            })
+        );
+    });
+}
+
+#[test]
+fn unknown_delivery_receipt_is_ignored() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(transfer(ALICE, 1000));
+        let message_id = pending_transfer_id(ALICE);
+
+        // Messages not sent by the pallet are not tracked
+        DataHavenNativeTransfer::<Test>::on_message_accepted(H256::repeat_byte(0x02), 1);
+        DataHavenNativeTransfer::<Test>::on_message_delivered(1, false);
+
+        assert!(PendingTransferIds::<Test>::get(1).is_none());
+        assert_eq!(
+            PendingTransfers::<Test>::get(message_id).unwrap().status,
+            TransferStatus::Pending
+        );
+        assert_eq!(Balances::balance(&ETHEREUM_SOVEREIGN), 1000);
+    });
+}
+
+#[test]
+fn committed_transfer_is_not_refunded_on_timeout() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(transfer(ALICE, 1000));
+        let message_id = pending_transfer_id(ALICE);
+        let expires_at = 1 + TransferTimeout::get();
+
+        DataHavenNativeTransfer::<Test>::on_message_accepted(message_id, 1);
+        DataHavenNativeTransfer::<Test>::on_initialize(expires_at);
+
+        // The message may still be minted on Ethereum: the tokens wait for its receipt
+        assert_eq!(Balances::balance(&ETHEREUM_SOVEREIGN), 1000);
+        assert_eq!(ExpectedWrappedSupply::<Test>::get(), 1000);
+        assert_eq!(
+            PendingTransfers::<Test>::get(message_id).unwrap().status,
+            TransferStatus::Pending
+        );
+        assert!(TransferTimeouts::<Test>::get(expires_at).is_empty());
+        assert!(TransferTimeouts::<Test>::get(expires_at + TransferTimeout::get()).is_empty());
+
+        DataHavenNativeTransfer::<Test>::on_message_delivered(1, true);
+        assert!(PendingTransfers::<Test>::get(message_id).is_none());
+        assert_eq!(Balances::balance(&ETHEREUM_SOVEREIGN), 1000);
+        assert!(!Paused::<Test>::get());
+    });
+}
+
+#[test]
+fn dropped_transfer_is_refunded() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(transfer(ALICE, 1000));
+        let dropped_id = pending_transfer_id(ALICE);
+        let expires_at = 1 + TransferTimeout::get();
+
+        // Nothing happens before the timeout
+        DataHavenNativeTransfer::<Test>::on_initialize(expires_at - 1);
+        assert_eq!(Balances::balance(&ETHEREUM_SOVEREIGN), 1000);
+
+        // Not accepted yet, but maybe later: checked again
+        DataHavenNativeTransfer::<Test>::on_initialize(expires_at);
+        let next_check = expires_at + TransferTimeout::get();
+        assert_eq!(Balances::balance(&ETHEREUM_SOVEREIGN), 1000);
+        assert_eq!(
+            PendingTransfers::<Test>::get(dropped_id)
+                .unwrap()
+                .expires_at,
+            next_check
+        );
+        assert_eq!(
+            TransferTimeouts::<Test>::get(next_check).into_inner(),
+            vec![dropped_id]
+        );
+
+        // A message sent afterwards is accepted before it
+        System::set_block_number(expires_at);
+        assert_ok!(transfer(BOB, 500));
+        let accepted_id = pending_transfer_id(BOB);
+        DataHavenNativeTransfer::<Test>::on_message_accepted(accepted_id, 1);
+
+        DataHavenNativeTransfer::<Test>::on_initialize(next_check);
+        assert!(PendingTransfers::<Test>::get(dropped_id).is_none());
+        assert!(DataHavenNativeTransfer::<Test>::pending_transfers(&ALICE, None, 10).is_empty());
+        assert_eq!(Balances::balance(&ALICE), INITIAL_BALANCE - 10);
+        assert_eq!(Balances::balance(&ETHEREUM_SOVEREIGN), 500);
+        assert_eq!(ExpectedWrappedSupply::<Test>::get(), 500);
+        assert!(TransferTimeouts::<Test>::get(next_check).is_empty());
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::DataHavenNativeTransfer(crate::Event::TransferRefunded {
+                message_id: dropped_id,
+                account: ALICE,
+                amount: 1000,
+                reason: RefundReason::Dropped,
+            })
+        );
+
+        // The accepted transfer still waits for its receipt
+        assert_eq!(
+            PendingTransfers::<Test>::get(accepted_id).unwrap().nonce,
+            Some(1)
+        );
+    });
+}
+
+#[test]
+fn failed_refund_can_be_retried() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(transfer(ALICE, 1000));
+        assert_ok!(transfer(BOB, 500));
+        let message_id = pending_transfer_id(ALICE);
+
+        // Funds leave the sovereign account without going through the pallet
+        <Balances as Mutate<_>>::set_balance(&ETHEREUM_SOVEREIGN, 500);
+
+        DataHavenNativeTransfer::<Test>::on_message_accepted(message_id, 1);
+        DataHavenNativeTransfer::<Test>::on_message_delivered(1, false);
+
+        assert_eq!(ExpectedWrappedSupply::<Test>::get(), 1500);
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::DataHavenNativeTransfer(crate::Event::TransferRefundFailed {
+                message_id,
+                account: ALICE,
+                amount: 1000,
+                reason: RefundReason::DeliveryFailed,
+            })
+        );
+
+        // The transfer stays tracked until it is refunded
+        assert_eq!(
+            PendingTransfers::<Test>::get(message_id).unwrap().status,
+            TransferStatus::RefundFailed(RefundReason::DeliveryFailed)
+        );
+        assert_eq!(pending_transfer_id(ALICE), message_id);
+        assert_noop!(
+            DataHavenNativeTransfer::<Test>::retry_refund(
+                RuntimeOrigin::signed(CHARLIE),
+                message_id
+            ),
+            DispatchError::Token(sp_runtime::TokenError::FundsUnavailable)
+        );
+
+        // Only failed refunds are retried
+        assert_noop!(
+            DataHavenNativeTransfer::<Test>::retry_refund(
+                RuntimeOrigin::signed(CHARLIE),
+                pending_transfer_id(BOB)
+            ),
+            Error::<Test>::RefundNotFailed
+        );
+        assert_noop!(
+            DataHavenNativeTransfer::<Test>::retry_refund(
+                RuntimeOrigin::signed(CHARLIE),
+                H256::repeat_byte(0x02)
+            ),
+            Error::<Test>::UnknownTransfer
+        );
+
+        <Balances as Mutate<_>>::set_balance(&ETHEREUM_SOVEREIGN, 1500);
+        assert_ok!(DataHavenNativeTransfer::<Test>::retry_refund(
+            RuntimeOrigin::signed(CHARLIE),
+            message_id
+        ));
+        assert!(PendingTransfers::<Test>::get(message_id).is_none());
+        assert!(DataHavenNativeTransfer::<Test>::pending_transfers(&ALICE, None, 10).is_empty());
+        assert_eq!(Balances::balance(&ALICE), INITIAL_BALANCE - 10);
+        assert_eq!(ExpectedWrappedSupply::<Test>::get(), 500);
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::DataHavenNativeTransfer(crate::Event::TransferRefunded {
+                message_id,
+                account: ALICE,
+                amount: 1000,
+                reason: RefundReason::DeliveryFailed,
+            })
+        );
+    });
+}
+
+#[test]
+fn too_many_pending_transfers_fails() {
+    new_test_ext().execute_with(|| {
+        let max_transfers = <Test as crate::Config>::MaxTransfersPerBlock::get();
+        for _ in 0..max_transfers {
+            assert_ok!(transfer(ALICE, 10));
+        }
+
+        assert_noop!(transfer(BOB, 10), Error::<Test>::TooManyPendingTransfers);
+
+        // Transfers of the next block expire at a different block
+        System::set_block_number(2);
+        assert_ok!(transfer(BOB, 10));
+    });
+}
+
+#[test]
+fn pending_transfers_are_listed_per_account() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(transfer(ALICE, 1000));
+        assert_ok!(transfer(ALICE, 2000));
+        assert_ok!(transfer(BOB, 500));
+
+        let mut amounts: Vec<u128> =
+            DataHavenNativeTransfer::<Test>::pending_transfers(&ALICE, None, 10)
+                .into_iter()
+                .map(|(_, transfer)| transfer.amount)
+                .collect();
+        amounts.sort();
+        assert_eq!(amounts, vec![1000, 2000]);
+        assert!(DataHavenNativeTransfer::<Test>::pending_transfers(&CHARLIE, None, 10).is_empty());
+    });
+}
+
+#[test]
+fn pending_transfers_are_listed_by_page() {
+    new_test_ext().execute_with(|| {
+        for amount in 1..=5 {
+            assert_ok!(transfer(ALICE, amount * 100));
+        }
+
+        let first_page = DataHavenNativeTransfer::<Test>::pending_transfers(&ALICE, None, 2);
+        assert_eq!(first_page.len(), 2);
+        let second_page =
+            DataHavenNativeTransfer::<Test>::pending_transfers(&ALICE, Some(first_page[1].0), 2);
+        assert_eq!(second_page.len(), 2);
+        let last_page =
+            DataHavenNativeTransfer::<Test>::pending_transfers(&ALICE, Some(second_page[1].0), 2);
+        assert_eq!(last_page.len(), 1);
+
+        let mut amounts: Vec<u128> = first_page
+            .into_iter()
+            .chain(second_page)
+            .chain(last_page)
+            .map(|(_, transfer)| transfer.amount)
+            .collect();
+        amounts.sort();
+        assert_eq!(amounts, vec![100, 200, 300, 400, 500]);
+
+        // Pages are capped
+        assert_eq!(
+            DataHavenNativeTransfer::<Test>::pending_transfers(&ALICE, None, u32::MAX).len(),
+            5.min(MAX_PENDING_TRANSFERS_PAGE as usize)
+        );
+    });
+}
+
+// ===========================
+// Batching Tests
+// ===========================
+
+fn enable_batching(period: u64) {
+    assert_ok!(DataHavenNativeTransfer::<Test>::set_batching_period(
+        RuntimeOrigin::root(),
+        Some(period)
+    ));
+}
+
+fn batched_transfer_ids() -> Vec<H256> {
+    CurrentBatch::<Test>::get()
+        .iter()
+        .map(|transfer| transfer.id)
+        .collect()
+}
+
+fn sent_batch() -> (H256, Vec<H256>) {
+    let mut batches: Vec<_> = SentBatches::<Test>::iter().collect();
+    assert_eq!(batches.len(), 1);
+    let (message_id, transfer_ids) = batches.remove(0);
+    (message_id, transfer_ids.into_inner())
+}
+
+#[test]
+fn set_batching_period_works() {
+    new_test_ext().execute_with(|| {
+        enable_batching(5);
+        assert_eq!(BatchingPeriod::<Test>::get(), Some(5));
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::DataHavenNativeTransfer(crate::Event::BatchingPeriodSet {
+                period: Some(5),
+            })
+        );
+
+        assert_noop!(
+            DataHavenNativeTransfer::<Test>::set_batching_period(
+                RuntimeOrigin::signed(ALICE),
+                None
+            ),
+            DispatchError::BadOrigin
+        );
+    });
+}
+
+#[test]
+fn batched_transfers_are_sent_after_the_period() {
+    new_test_ext().execute_with(|| {
+        enable_batching(5);
+        assert_ok!(transfer(ALICE, 1000));
+        assert_ok!(transfer(BOB, 500));
+
+        // The tokens are locked but nothing is sent yet
+        let transfer_ids = batched_transfer_ids();
+        assert_eq!(transfer_ids.len(), 2);
+        assert_eq!(Balances::balance(&ETHEREUM_SOVEREIGN), 1500);
+        assert_eq!(Balances::balance(&FEE_RECIPIENT), 20);
+        assert!(PendingTransfers::<Test>::iter().next().is_none());
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::DataHavenNativeTransfer(crate::Event::TransferBatched {
+                transfer_id: transfer_ids[1],
+                from: BOB,
+                to: ethereum_address(),
+                amount: 500,
+                fee: 10,
+            })
+        );
+
+        DataHavenNativeTransfer::<Test>::on_initialize(5);
+        assert_eq!(CurrentBatch::<Test>::get().len(), 2);
+
+        DataHavenNativeTransfer::<Test>::on_initialize(6);
+        assert!(CurrentBatch::<Test>::get().is_empty());
+        let (message_id, sent_ids) = sent_batch();
+        assert_eq!(sent_ids, transfer_ids);
+        assert_eq!(
+            PendingTransfers::<Test>::get(transfer_ids[0])
+                .unwrap()
+                .amount,
+            1000
+        );
+        assert_eq!(
+            PendingTransfers::<Test>::get(transfer_ids[1])
+                .unwrap()
+                .sender,
+            BOB
+        );
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::DataHavenNativeTransfer(crate::Event::BatchSent {
+                message_id,
+                transfers: 2,
+                fee: 20,
+            })
+        );
+
+        // Each transfer is confirmed on its own
+        DataHavenNativeTransfer::<Test>::on_message_accepted(message_id, 3);
+        assert_eq!(PendingTransferIds::<Test>::get(3), Some(message_id));
+        DataHavenNativeTransfer::<Test>::on_message_delivered(3, true);
+        assert!(PendingTransfers::<Test>::iter().next().is_none());
+        assert!(SentBatches::<Test>::get(message_id).is_none());
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::DataHavenNativeTransfer(crate::Event::TransferConfirmed {
+                message_id: transfer_ids[1],
+                nonce: 3,
+            })
+        );
+        assert_eq!(ExpectedWrappedSupply::<Test>::get(), 1500);
+    });
+}
+
+#[test]
+fn full_batch_is_sent_right_away() {
+    new_test_ext().execute_with(|| {
+        enable_batching(100);
+        assert_ok!(transfer(ALICE, 100));
+        assert_ok!(transfer(BOB, 200));
+        assert_ok!(transfer(CHARLIE, 300));
+
+        assert!(CurrentBatch::<Test>::get().is_empty());
+        let (message_id, transfer_ids) = sent_batch();
+        assert_eq!(transfer_ids.len(), 3);
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::DataHavenNativeTransfer(crate::Event::BatchSent {
+                message_id,
+                transfers: 3,
+                fee: 30,
+            })
+        );
+    });
+}
+
+#[test]
+fn batch_is_held_while_paused() {
+    new_test_ext().execute_with(|| {
+        enable_batching(1);
+        assert_ok!(transfer(ALICE, 1000));
+        assert_ok!(DataHavenNativeTransfer::<Test>::pause(RuntimeOrigin::root()));
+
+        DataHavenNativeTransfer::<Test>::on_initialize(2);
+        assert_eq!(CurrentBatch::<Test>::get().len(), 1);
+
+        // Disabling batching sends the open batch once unpaused
+        assert_ok!(DataHavenNativeTransfer::<Test>::unpause(
+            RuntimeOrigin::root()
+        ));
+        assert_ok!(DataHavenNativeTransfer::<Test>::set_batching_period(
+            RuntimeOrigin::root(),
+            None
+        ));
+        DataHavenNativeTransfer::<Test>::on_initialize(3);
+        assert!(CurrentBatch::<Test>::get().is_empty());
+        assert_eq!(sent_batch().1.len(), 1);
+    });
+}
+
+#[test]
+fn batched_transfer_ids_are_sequential() {
+    new_test_ext().execute_with(|| {
+        enable_batching(1);
+        assert_ok!(transfer(ALICE, 1000));
+        assert_ok!(transfer(ALICE, 1000));
+        let first_ids = batched_transfer_ids();
+        assert_eq!(
+            first_ids,
+            vec![H256::from_low_u64_be(0), H256::from_low_u64_be(1)]
+        );
+
+        DataHavenNativeTransfer::<Test>::on_initialize(2);
+        assert!(CurrentBatch::<Test>::get().is_empty());
+
+        // The same transfer in the next batch gets a new id
+        assert_ok!(transfer(ALICE, 1000));
+        assert_eq!(batched_transfer_ids(), vec![H256::from_low_u64_be(2)]);
+        assert_eq!(NextBatchedTransferIndex::<Test>::get(), 3);
+    });
+}
+
+#[test]
+fn failing_batch_is_reported_once() {
+    new_test_ext().execute_with(|| {
+        enable_batching(1);
+        assert_ok!(transfer(ALICE, 1000));
+        IsTokenRegistered::set(&false);
+
+        let send_failures = || {
+            System::events()
+                .iter()
+                .filter(|record| {
+                    matches!(
+                        record.event,
+                        RuntimeEvent::DataHavenNativeTransfer(crate::Event::BatchSendFailed { .. })
+                    )
+                })
+                .count()
+        };
+        DataHavenNativeTransfer::<Test>::on_initialize(2);
+        DataHavenNativeTransfer::<Test>::on_initialize(3);
+        DataHavenNativeTransfer::<Test>::on_initialize(4);
+        assert_eq!(CurrentBatch::<Test>::get().len(), 1);
+        assert_eq!(send_failures(), 1);
+        assert!(CurrentBatchSendFailed::<Test>::get());
+
+        // Once sent, a failure of the next batch is reported again
+        IsTokenRegistered::set(&true);
+        DataHavenNativeTransfer::<Test>::on_initialize(5);
+        assert!(CurrentBatch::<Test>::get().is_empty());
+        assert!(!CurrentBatchSendFailed::<Test>::get());
+
+        assert_ok!(transfer(BOB, 500));
+        IsTokenRegistered::set(&false);
+        DataHavenNativeTransfer::<Test>::on_initialize(7);
+        assert_eq!(send_failures(), 2);
+
+        // Re-register for other tests
+        IsTokenRegistered::set(&true);
+    });
+}
+
+#[test]
+fn failed_batch_delivery_refunds_every_transfer() {
+    new_test_ext().execute_with(|| {
+        // Keeps the sovereign account alive once every transfer is refunded
+        assert_ok!(<Balances as Mutate<_>>::mint_into(&ETHEREUM_SOVEREIGN, 1));
+        enable_batching(5);
+        assert_ok!(transfer(ALICE, 1000));
+        assert_ok!(transfer(BOB, 500));
+        assert_ok!(DataHavenNativeTransfer::<Test>::send_batch(
+            RuntimeOrigin::root()
+        ));
+        let (message_id, transfer_ids) = sent_batch();
+
+        DataHavenNativeTransfer::<Test>::on_message_accepted(message_id, 1);
+        DataHavenNativeTransfer::<Test>::on_message_delivered(1, false);
+
+        assert!(PendingTransfers::<Test>::iter().next().is_none());
+        assert!(TransferTimeouts::<Test>::get(1 + TransferTimeout::get()).is_empty());
+        // The fees are not refunded
+        assert_eq!(Balances::balance(&ALICE), INITIAL_BALANCE - 10);
+        assert_eq!(Balances::balance(&BOB), INITIAL_BALANCE - 10);
+        assert_eq!(ExpectedWrappedSupply::<Test>::get(), 0);
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::DataHavenNativeTransfer(crate::Event::TransferRefunded {
+                message_id: transfer_ids[1],
+                account: BOB,
+                amount: 500,
+                reason: RefundReason::DeliveryFailed,
+            })
+        );
+    });
+}
+
+#[test]
+fn dropped_batch_is_refunded() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(<Balances as Mutate<_>>::mint_into(&ETHEREUM_SOVEREIGN, 1));
+        enable_batching(5);
+        assert_ok!(transfer(ALICE, 1000));
+        assert_ok!(transfer(BOB, 500));
+        assert_ok!(DataHavenNativeTransfer::<Test>::send_batch(
+            RuntimeOrigin::root()
+        ));
+        let (message_id, transfer_ids) = sent_batch();
+
+        // A transfer sent after the batch is accepted before it
+        assert_ok!(DataHavenNativeTransfer::<Test>::set_batching_period(
+            RuntimeOrigin::root(),
+            None
+        ));
+        assert_ok!(transfer(CHARLIE, 300));
+        DataHavenNativeTransfer::<Test>::on_message_accepted(pending_transfer_id(CHARLIE), 1);
+
+        DataHavenNativeTransfer::<Test>::on_initialize(1 + TransferTimeout::get());
+
+        for transfer_id in transfer_ids {
+            assert!(PendingTransfers::<Test>::get(transfer_id).is_none());
+        }
+        assert!(SentBatches::<Test>::get(message_id).is_none());
+        assert_eq!(Balances::balance(&ALICE), INITIAL_BALANCE - 10);
+        assert_eq!(Balances::balance(&BOB), INITIAL_BALANCE - 10);
+        assert_eq!(ExpectedWrappedSupply::<Test>::get(), 300);
+    });
+}
+
+#[test]
+fn cancel_batch_refunds_the_transfers() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(<Balances as Mutate<_>>::mint_into(&ETHEREUM_SOVEREIGN, 1));
+        enable_batching(5);
+        assert_ok!(transfer(ALICE, 1000));
+        assert_ok!(transfer(BOB, 500));
+
+        assert_noop!(
+            DataHavenNativeTransfer::<Test>::cancel_batch(RuntimeOrigin::signed(ALICE)),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(DataHavenNativeTransfer::<Test>::cancel_batch(
+            RuntimeOrigin::root()
+        ));
+
+        assert!(CurrentBatch::<Test>::get().is_empty());
+        assert_eq!(Balances::balance(&ALICE), INITIAL_BALANCE - 10);
+        assert_eq!(Balances::balance(&BOB), INITIAL_BALANCE - 10);
+        assert_eq!(ExpectedWrappedSupply::<Test>::get(), 0);
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::DataHavenNativeTransfer(crate::Event::BatchCancelled { transfers: 2 })
+        );
+
+        // Nothing is left to send or cancel
+        assert_noop!(
+            DataHavenNativeTransfer::<Test>::cancel_batch(RuntimeOrigin::root()),
+            Error::<Test>::EmptyBatch
+        );
+        assert_noop!(
+            DataHavenNativeTransfer::<Test>::send_batch(RuntimeOrigin::root()),
+            Error::<Test>::EmptyBatch
+        );
+        DataHavenNativeTransfer::<Test>::on_initialize(10);
+        assert!(SentBatches::<Test>::iter().next().is_none());
+    });
+}
+
+// ===========================
+// Held Tokens & Follow-up Call Tests
+// ===========================
+
+fn balance_transfer_call(dest: u64, value: u128) -> Vec<u8> {
+    RuntimeCall::Balances(pallet_balances::Call::transfer_allow_death { dest, value }).encode()
+}
+
+#[test]
+fn hold_tokens_moves_tokens_to_holding_account() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(DataHavenNativeTransfer::<Test>::lock_tokens(&ALICE, 5000));
+
+        assert_ok!(DataHavenNativeTransfer::<Test>::hold_tokens(
+            b"invalid", 1000
+        ));
+
+        assert_eq!(Balances::balance(&HOLDING_ACCOUNT), 1000);
+        assert_eq!(Balances::balance(&ETHEREUM_SOVEREIGN), 4000);
+        assert_eq!(ExpectedWrappedSupply::<Test>::get(), 4000);
+        let held = HeldTransfers::<Test>::get(0).unwrap();
+        assert_eq!(held.claimer.into_inner(), b"invalid".to_vec());
+        assert_eq!(held.amount, 1000);
+        assert_eq!(NextHeldTransferId::<Test>::get(), 1);
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::DataHavenNativeTransfer(crate::Event::TokensHeld {
+                id: 0,
+                amount: 1000
+            })
+        );
+    });
+}
+
+#[test]
+fn hold_tokens_when_paused_fails() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(DataHavenNativeTransfer::<Test>::lock_tokens(&ALICE, 5000));
+        assert_ok!(DataHavenNativeTransfer::<Test>::pause(RuntimeOrigin::root()));
+
+        assert_noop!(
+            DataHavenNativeTransfer::<Test>::hold_tokens(b"invalid", 1000),
+            Error::<Test>::TransfersDisabled
+        );
+    });
+}
+
+#[test]
+fn release_held_tokens_works() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(DataHavenNativeTransfer::<Test>::lock_tokens(&ALICE, 5000));
+        assert_ok!(DataHavenNativeTransfer::<Test>::hold_tokens(
+            b"invalid", 1000
+        ));
+
+        assert_ok!(DataHavenNativeTransfer::<Test>::release_held_tokens(
+            RuntimeOrigin::root(),
+            0,
+            BOB
+        ));
+
+        assert_eq!(Balances::balance(&BOB), INITIAL_BALANCE + 1000);
+        assert_eq!(Balances::balance(&HOLDING_ACCOUNT), 0);
+        assert!(HeldTransfers::<Test>::get(0).is_none());
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::DataHavenNativeTransfer(crate::Event::HeldTokensReleased {
+                id: 0,
+                account: BOB,
+                amount: 1000,
+            })
+        );
+
+        // Released only once
+        assert_noop!(
+            DataHavenNativeTransfer::<Test>::release_held_tokens(RuntimeOrigin::root(), 0, BOB),
+            Error::<Test>::UnknownHeldTransfer
+        );
+    });
+}
+
+#[test]
+fn release_held_tokens_unauthorized_fails() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(DataHavenNativeTransfer::<Test>::lock_tokens(&ALICE, 5000));
+        assert_ok!(DataHavenNativeTransfer::<Test>::hold_tokens(
+            b"invalid", 1000
+        ));
+
+        assert_noop!(
+            DataHavenNativeTransfer::<Test>::release_held_tokens(
+                RuntimeOrigin::signed(ALICE),
+                0,
+                ALICE
+            ),
+            DispatchError::BadOrigin
+        );
+    });
+}
+
+#[test]
+fn follow_up_call_is_dispatched_for_the_sender() {
+    new_test_ext().execute_with(|| {
+        DataHavenNativeTransfer::<Test>::dispatch_follow_up_call(
+            &ALICE,
+            &ALICE,
+            &balance_transfer_call(CHARLIE, 100),
+        );
+
+        assert_eq!(Balances::balance(&ALICE), INITIAL_BALANCE - 100);
+        assert_eq!(Balances::balance(&CHARLIE), INITIAL_BALANCE + 100);
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::DataHavenNativeTransfer(crate::Event::FollowUpCallDispatched {
+                account: ALICE,
+                result: Ok(()),
+            })
+        );
+    });
+}
+
+#[test]
+fn failed_follow_up_call_is_reported() {
+    new_test_ext().execute_with(|| {
+        DataHavenNativeTransfer::<Test>::dispatch_follow_up_call(
+            &ALICE,
+            &ALICE,
+            &balance_transfer_call(CHARLIE, INITIAL_BALANCE * 2),
+        );
+
+        assert_eq!(Balances::balance(&ALICE), INITIAL_BALANCE);
+        assert!(matches!(
+            last_event(),
+            RuntimeEvent::DataHavenNativeTransfer(crate::Event::FollowUpCallDispatched {
+                account: ALICE,
+                result: Err(_),
+            })
+        ));
+    });
+}
+
+#[test]
+fn follow_up_call_is_skipped_when_not_allowed() {
+    new_test_ext().execute_with(|| {
+        let skipped = |reason| {
+            RuntimeEvent::DataHavenNativeTransfer(crate::Event::FollowUpCallSkipped {
+                account: ALICE,
+                reason,
+            })
+        };
+
+        // Only the Ethereum sender can attach a call to its own account
+        DataHavenNativeTransfer::<Test>::dispatch_follow_up_call(
+            &BOB,
+            &ALICE,
+            &balance_transfer_call(BOB, 100),
+        );
+        assert_eq!(last_event(), skipped(FollowUpCallError::Unauthorized));
+
+        DataHavenNativeTransfer::<Test>::dispatch_follow_up_call(&ALICE, &ALICE, &[0xff]);
+        assert_eq!(last_event(), skipped(FollowUpCallError::Undecodable));
+
+        let remark = RuntimeCall::System(frame_system::Call::remark { remark: vec![] }).encode();
+        DataHavenNativeTransfer::<Test>::dispatch_follow_up_call(&ALICE, &ALICE, &remark);
+        assert_eq!(last_event(), skipped(FollowUpCallError::Filtered));
+
+        MaxFollowUpCallWeight::set(&Weight::zero());
+        DataHavenNativeTransfer::<Test>::dispatch_follow_up_call(
+            &ALICE,
+            &ALICE,
+            &balance_transfer_call(CHARLIE, 100),
+        );
+        assert_eq!(last_event(), skipped(FollowUpCallError::Overweight));
+
+        assert_eq!(Balances::balance(&ALICE), INITIAL_BALANCE);
+    });
+}
+
+#[test]
+fn follow_up_call_weight_is_only_charged_when_dispatched() {
+    new_test_ext().execute_with(|| {
+        let call = balance_transfer_call(CHARLIE, 100);
+        let call_weight = RuntimeCall::Balances(pallet_balances::Call::transfer_allow_death {
+            dest: CHARLIE,
+            value: 100,
+        })
+        .get_dispatch_info()
+        .call_weight;
+
+        assert_eq!(
+            DataHavenNativeTransfer::<Test>::follow_up_call_weight(&ALICE, &ALICE, &call),
+            call_weight
+        );
+        assert_eq!(
+            DataHavenNativeTransfer::<Test>::follow_up_call_weight(&BOB, &ALICE, &call),
+            Weight::zero()
+        );
+
+        // Never more than the maximum
+        MaxFollowUpCallWeight::set(&Weight::zero());
+        assert_eq!(
+            DataHavenNativeTransfer::<Test>::follow_up_call_weight(&ALICE, &ALICE, &call),
+            Weight::zero()
+        );
+    });
+}
+
+// ===========================
+// Payment Tests
+// ===========================
+
+type PayFromAlice = PayToEthereum<Test, ConstU64<ALICE>>;
+
+#[test]
+fn payment_succeeds_once_delivered() {
+    new_test_ext().execute_with(|| {
+        let payment = PayFromAlice::pay(&ethereum_address(), (), 1000).unwrap();
+
+        // The payer also pays the suggested fee
+        assert_eq!(Balances::balance(&ALICE), INITIAL_BALANCE - 1010);
+        assert_eq!(
+            PendingTransfers::<Test>::get(payment).unwrap().recipient,
+            ethereum_address()
+        );
+        assert_eq!(
+            PaymentOutcomes::<Test>::get(payment),
+            Some(PaymentOutcome::Pending)
+        );
+        assert_eq!(
+            PayFromAlice::check_status(payment),
+            PaymentStatus::InProgress
+        );
+
+        DataHavenNativeTransfer::<Test>::on_message_accepted(payment, 1);
+        DataHavenNativeTransfer::<Test>::on_message_delivered(1, true);
+        assert_eq!(PayFromAlice::check_status(payment), PaymentStatus::Success);
+
+        // The outcome is pruned once reported
+        assert!(PaymentOutcomes::<Test>::get(payment).is_none());
+        assert_eq!(PayFromAlice::check_status(payment), PaymentStatus::Unknown);
+    });
+}
+
+#[test]
+fn payment_fails_once_refunded() {
+    new_test_ext().execute_with(|| {
+        let payment = PayFromAlice::pay(&ethereum_address(), (), 1000).unwrap();
+
+        DataHavenNativeTransfer::<Test>::on_message_accepted(payment, 1);
+        DataHavenNativeTransfer::<Test>::on_message_delivered(1, false);
+
+        assert_eq!(Balances::balance(&ALICE), INITIAL_BALANCE - 10);
+        assert_eq!(PayFromAlice::check_status(payment), PaymentStatus::Failure);
+        assert!(PaymentOutcomes::<Test>::get(payment).is_none());
+    });
+}
+
+#[test]
+fn payment_is_in_progress_after_timeout() {
+    new_test_ext().execute_with(|| {
+        let payment = PayFromAlice::pay(&ethereum_address(), (), 1000).unwrap();
+
+        // The message may still be delivered, so the payment neither failed nor is pruned
+        DataHavenNativeTransfer::<Test>::on_initialize(1 + TransferTimeout::get());
+        assert_eq!(
+            PayFromAlice::check_status(payment),
+            PaymentStatus::InProgress
+        );
+        assert_eq!(
+            PaymentOutcomes::<Test>::get(payment),
+            Some(PaymentOutcome::Pending)
+        );
+
+        DataHavenNativeTransfer::<Test>::on_message_accepted(payment, 1);
+        DataHavenNativeTransfer::<Test>::on_message_delivered(1, true);
+        assert_eq!(PayFromAlice::check_status(payment), PaymentStatus::Success);
+    });
+}
+
+#[test]
+fn batched_payment_fails_when_batch_is_cancelled() {
+    new_test_ext().execute_with(|| {
+        enable_batching(5);
+        let payment = PayFromAlice::pay(&ethereum_address(), (), 1000).unwrap();
+        assert_eq!(batched_transfer_ids(), vec![payment]);
+        assert_eq!(
+            PayFromAlice::check_status(payment),
+            PaymentStatus::InProgress
+        );
+
+        assert_ok!(DataHavenNativeTransfer::<Test>::cancel_batch(
+            RuntimeOrigin::root()
+        ));
+        assert_eq!(PayFromAlice::check_status(payment), PaymentStatus::Failure);
+    });
+}
+
+#[test]
+fn payment_fails_when_paused() {
+    new_test_ext().execute_with(|| {
+        Paused::<Test>::put(true);
+
+        assert_noop!(
+            PayFromAlice::pay(&ethereum_address(), (), 1000),
+            Error::<Test>::TransfersDisabled
+        );
+    });
+}
+
+#[test]
+fn transfers_are_not_tracked_as_payments() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(transfer(ALICE, 1000));
+        let message_id = pending_transfer_id(ALICE);
+
+        DataHavenNativeTransfer::<Test>::on_message_accepted(message_id, 1);
+        DataHavenNativeTransfer::<Test>::on_message_delivered(1, true);
+
+        assert!(PaymentOutcomes::<Test>::get(message_id).is_none());
+        assert_eq!(
+            PayFromAlice::check_status(message_id),
+            PaymentStatus::Unknown
+        );
+    });
+}
+
 // ===========================
 // Balance Preservation Tests
 // ===========================
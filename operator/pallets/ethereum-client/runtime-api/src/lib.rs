@@ -0,0 +1,16 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: 2023 Snowfork <hello@snowfork.com>
+
+//! Ethereum Client Runtime API
+//!
+//! * `light_client_health`: How far the light client is behind the beacon chain
+
+#![cfg_attr(not(feature = "std"), no_std)]
+pub use snowbridge_pallet_ethereum_client::{LightClientHealth, LightClientStatus};
+
+sp_api::decl_runtime_apis! {
+    pub trait EthereumClientApi {
+        /// Lag of the latest finalized header behind the beacon chain, and the resulting status.
+        fn light_client_health() -> LightClientHealth;
+    }
+}
@@ -27,6 +27,8 @@ const_assert!(SYNC_COMMITTEE_BITS_SIZE == SYNC_COMMITTEE_SIZE / 8);
 /// Defined in <https://github.com/ethereum/consensus-specs/tree/f1dff5f6768608d890fc0b347e548297fc3e1f1c/presets/mainnet>
 /// There are 32 slots in an epoch. An epoch is 6.4 minutes long.
 pub const SLOTS_PER_EPOCH: usize = 32;
+/// A slot is 12 seconds long.
+pub const SECONDS_PER_SLOT: u64 = 12;
 /// 256 epochs in a sync committee period. Frequency of sync committee (subset of Ethereum
 /// validators) change is every ~27 hours.
 pub const EPOCHS_PER_SYNC_COMMITTEE_PERIOD: usize = 256;
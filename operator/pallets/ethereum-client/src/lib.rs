@@ -11,10 +11,24 @@
 //! * [`Call::force_checkpoint`]: Set the initial trusted consensus checkpoint.
 //! * [`Call::set_operating_mode`]: Set the operating mode of the pallet. Can be used to disable
 //!   processing of consensus updates.
+//! * [`Call::set_degraded_mode_enabled`]: Set whether dependent pallets switch to degraded mode
+//!   while the light client is falling behind.
 //!
 //! ## Consensus Updates
 //!
 //! * [`Call::submit`]: Submit a finalized beacon header with an optional sync committee update
+//!
+//! # Health Monitoring
+//!
+//! Every block, the slot of the latest finalized header is compared with the current beacon slot,
+//! derived from the block timestamp and `BeaconGenesisTime`. The resulting [`LightClientStatus`]
+//! is reported through [`Event::HealthStatusChanged`] when it changes, and the full
+//! [`LightClientHealth`] through [`Pallet::light_client_health`].
+//!
+//! Once the latest finalized header is more than one sync committee period behind, updates can no
+//! longer be verified and the client is stalled until [`Call::force_checkpoint`] is called. When
+//! enabled, dependent pallets run in degraded mode, see [`DegradedModeQuery`], as soon as the
+//! client is degraded, before it stalls.
 #![cfg_attr(not(feature = "std"), no_std)]
 
 pub mod config;
@@ -38,21 +52,24 @@ mod tests_electra;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
+use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
 use frame_support::{
     dispatch::{DispatchResult, PostDispatchInfo},
     pallet_prelude::OptionQuery,
-    traits::Get,
+    traits::{Get, UnixTime},
     transactional,
 };
 use frame_system::ensure_signed;
+use scale_info::TypeInfo;
 use snowbridge_beacon_primitives::{
     fast_aggregate_verify,
     merkle_proof::{generalized_index_length, subtree_index},
     verify_merkle_branch, verify_receipt_proof, BeaconHeader, BlsError, CompactBeaconState,
     ForkData, ForkVersion, ForkVersions, PublicKeyPrepared, SigningData,
 };
-use snowbridge_core::{BasicOperatingMode, RingBufferMap};
+use snowbridge_core::{operating_mode::DegradedModeQuery, BasicOperatingMode, RingBufferMap};
 use sp_core::H256;
+use sp_runtime::RuntimeDebug;
 use sp_std::prelude::*;
 pub use weights::WeightInfo;
 
@@ -67,6 +84,64 @@ pub use config::SLOTS_PER_HISTORICAL_ROOT;
 
 pub const LOG_TARGET: &str = "ethereum-client";
 
+/// Health of the light client, depending on how far its latest finalized header is behind the
+/// beacon chain.
+#[derive(
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    Clone,
+    Copy,
+    Default,
+    PartialEq,
+    Eq,
+    RuntimeDebug,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub enum LightClientStatus {
+    /// No checkpoint was imported yet.
+    #[default]
+    NotBootstrapped,
+    /// The latest finalized header is at most `LaggingThreshold` slots behind.
+    Healthy,
+    /// The latest finalized header is more than `LaggingThreshold` slots behind.
+    Lagging,
+    /// The latest finalized header is more than `DegradedThreshold` slots behind.
+    Degraded,
+    /// The latest finalized header is more than one sync committee period behind, updates can no
+    /// longer be verified.
+    Stalled,
+}
+
+impl LightClientStatus {
+    /// Whether dependent pallets should run in degraded mode.
+    pub fn is_degraded(&self) -> bool {
+        matches!(self, Self::Degraded | Self::Stalled)
+    }
+}
+
+/// How far the light client is behind the beacon chain.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct LightClientHealth {
+    /// Status derived from the lag
+    pub status: LightClientStatus,
+    /// Slot of the latest finalized header
+    pub latest_finalized_slot: u64,
+    /// Current beacon slot, derived from the block timestamp
+    pub current_slot: u64,
+    /// Slots between the latest finalized header and the current slot
+    pub slot_lag: u64,
+    /// Last period in which the next sync committee was updated
+    pub latest_sync_committee_update_period: u64,
+    /// Sync committee period of the current slot
+    pub current_period: u64,
+    /// Sync committee periods between the latest finalized header and the current slot
+    pub period_lag: u64,
+    /// Whether dependent pallets run in degraded mode
+    pub degraded_mode: bool,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
@@ -96,6 +171,16 @@ pub mod pallet {
         /// Minimum gap between finalized headers for an update to be free.
         #[pallet::constant]
         type FreeHeadersInterval: Get<u32>;
+        /// Time source, from which the current beacon slot is derived.
+        type UnixTime: UnixTime;
+        /// Time of the beacon chain genesis, in seconds since the Unix epoch.
+        type BeaconGenesisTime: Get<u64>;
+        /// Number of slots the latest finalized header may be behind before the client is
+        /// lagging.
+        type LaggingThreshold: Get<u64>;
+        /// Number of slots the latest finalized header may be behind before the client is
+        /// degraded.
+        type DegradedThreshold: Get<u64>;
         type WeightInfo: WeightInfo;
     }
 
@@ -113,6 +198,16 @@ pub mod pallet {
         OperatingModeChanged {
             mode: BasicOperatingMode,
         },
+        /// The lag of the light client crossed a threshold.
+        HealthStatusChanged {
+            status: LightClientStatus,
+            slot_lag: u64,
+            period_lag: u64,
+        },
+        /// Set whether dependent pallets switch to degraded mode.
+        DegradedModeEnabledSet {
+            enabled: bool,
+        },
     }
 
     #[pallet::error]
@@ -196,6 +291,23 @@ pub mod pallet {
     #[pallet::getter(fn operating_mode)]
     pub type OperatingMode<T: Config> = StorageValue<_, BasicOperatingMode, ValueQuery>;
 
+    /// Health status of the light client, as of the start of the block.
+    #[pallet::storage]
+    pub type HealthStatus<T: Config> = StorageValue<_, LightClientStatus, ValueQuery>;
+
+    /// Whether dependent pallets run in degraded mode while the light client is degraded or
+    /// stalled.
+    #[pallet::storage]
+    pub type DegradedModeEnabled<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+            Self::update_health_status();
+            T::DbWeight::get().reads_writes(6, 1)
+        }
+    }
+
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         #[pallet::call_index(0)]
@@ -240,6 +352,17 @@ pub mod pallet {
             Self::deposit_event(Event::OperatingModeChanged { mode });
             Ok(())
         }
+
+        /// Set whether dependent pallets switch to degraded mode while the light client is
+        /// degraded or stalled. May only be called by root.
+        #[pallet::call_index(4)]
+        #[pallet::weight((T::DbWeight::get().reads_writes(1, 1), DispatchClass::Operational))]
+        pub fn set_degraded_mode_enabled(origin: OriginFor<T>, enabled: bool) -> DispatchResult {
+            ensure_root(origin)?;
+            DegradedModeEnabled::<T>::set(enabled);
+            Self::deposit_event(Event::DegradedModeEnabledSet { enabled });
+            Ok(())
+        }
     }
 
     impl<T: Config> Pallet<T> {
@@ -784,5 +907,78 @@ pub mod pallet {
         pub fn execution_header_gindex() -> usize {
             config::altair::EXECUTION_HEADER_INDEX
         }
+
+        /// Current beacon slot, derived from the block timestamp.
+        pub fn current_slot() -> u64 {
+            T::UnixTime::now()
+                .as_secs()
+                .saturating_sub(T::BeaconGenesisTime::get())
+                / config::SECONDS_PER_SLOT
+        }
+
+        /// How far the light client is behind the beacon chain.
+        pub fn light_client_health() -> LightClientHealth {
+            let current_slot = Self::current_slot();
+            let current_period = compute_period(current_slot);
+            let latest_finalized_slot =
+                FinalizedBeaconState::<T>::get(LatestFinalizedBlockRoot::<T>::get())
+                    .map(|state| state.slot);
+
+            let (slot_lag, period_lag) = latest_finalized_slot
+                .map(|slot| {
+                    (
+                        current_slot.saturating_sub(slot),
+                        current_period.saturating_sub(compute_period(slot)),
+                    )
+                })
+                .unwrap_or_default();
+
+            let status = match latest_finalized_slot {
+                None => LightClientStatus::NotBootstrapped,
+                Some(_) if period_lag > 1 => LightClientStatus::Stalled,
+                Some(_) if slot_lag > T::DegradedThreshold::get() => LightClientStatus::Degraded,
+                Some(_) if slot_lag > T::LaggingThreshold::get() => LightClientStatus::Lagging,
+                Some(_) => LightClientStatus::Healthy,
+            };
+
+            LightClientHealth {
+                status,
+                latest_finalized_slot: latest_finalized_slot.unwrap_or_default(),
+                current_slot,
+                slot_lag,
+                latest_sync_committee_update_period: LatestSyncCommitteeUpdatePeriod::<T>::get(),
+                current_period,
+                period_lag,
+                degraded_mode: Self::is_degraded(),
+            }
+        }
+
+        /// Stores the status of the light client, reporting it if it changed.
+        pub(crate) fn update_health_status() {
+            let health = Self::light_client_health();
+            if health.status == HealthStatus::<T>::get() {
+                return;
+            }
+
+            log::warn!(
+                target: LOG_TARGET,
+                "💫 Light client is now {:?}, {} slots and {} periods behind.",
+                health.status,
+                health.slot_lag,
+                health.period_lag
+            );
+            HealthStatus::<T>::set(health.status);
+            Self::deposit_event(Event::HealthStatusChanged {
+                status: health.status,
+                slot_lag: health.slot_lag,
+                period_lag: health.period_lag,
+            });
+        }
+    }
+}
+
+impl<T: Config> DegradedModeQuery for Pallet<T> {
+    fn is_degraded() -> bool {
+        DegradedModeEnabled::<T>::get() && HealthStatus::<T>::get().is_degraded()
     }
 }
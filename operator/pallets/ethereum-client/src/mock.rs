@@ -10,7 +10,7 @@ use sp_std::default::Default;
 use std::{fs::File, path::PathBuf};
 
 type Block = frame_system::mocking::MockBlock<Test>;
-use frame_support::traits::ConstU32;
+use frame_support::traits::{ConstU32, ConstU64};
 use sp_runtime::BuildStorage;
 
 fn load_fixture<T>(basename: String) -> Result<T, serde_json::Error>
@@ -142,11 +142,17 @@ parameter_types! {
 }
 
 pub const FREE_SLOTS_INTERVAL: u32 = config::SLOTS_PER_EPOCH as u32;
+pub const LAGGING_THRESHOLD: u64 = config::SLOTS_PER_EPOCH as u64;
+pub const DEGRADED_THRESHOLD: u64 = 4 * config::SLOTS_PER_EPOCH as u64;
 
 impl ethereum_beacon_client::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type ForkVersions = ChainForkVersions;
     type FreeHeadersInterval = ConstU32<FREE_SLOTS_INTERVAL>;
+    type UnixTime = Timestamp;
+    type BeaconGenesisTime = ConstU64<0>;
+    type LaggingThreshold = ConstU64<LAGGING_THRESHOLD>;
+    type DegradedThreshold = ConstU64<DEGRADED_THRESHOLD>;
     type WeightInfo = ();
 }
 
@@ -2,7 +2,10 @@
 // SPDX-FileCopyrightText: 2023 Snowfork <hello@snowfork.com>
 pub use crate::mock::*;
 use crate::{
-    config::{EPOCHS_PER_SYNC_COMMITTEE_PERIOD, SLOTS_PER_EPOCH, SLOTS_PER_HISTORICAL_ROOT},
+    config::{
+        EPOCHS_PER_SYNC_COMMITTEE_PERIOD, SECONDS_PER_SLOT, SLOTS_PER_EPOCH,
+        SLOTS_PER_HISTORICAL_ROOT,
+    },
     functions::compute_period,
     mock::{
         get_message_verification_payload, load_checkpoint_update_fixture,
@@ -10,8 +13,8 @@ use crate::{
         load_next_sync_committee_update_fixture, load_sync_committee_update_fixture,
     },
     sync_committee_sum, verify_merkle_branch, BeaconHeader, CompactBeaconState, Error,
-    FinalizedBeaconState, LatestFinalizedBlockRoot, LatestSyncCommitteeUpdatePeriod,
-    NextSyncCommittee, SyncCommitteePrepared,
+    FinalizedBeaconState, HealthStatus, LatestFinalizedBlockRoot, LatestSyncCommitteeUpdatePeriod,
+    LightClientStatus, NextSyncCommittee, SyncCommitteePrepared,
 };
 use frame_support::{assert_err, assert_noop, assert_ok, pallet_prelude::Pays};
 use hex_literal::hex;
@@ -20,6 +23,7 @@ use snowbridge_beacon_primitives::{
     types::deneb,
     Fork, ForkVersions, NextSyncCommitteeUpdate, VersionedExecutionPayloadHeader,
 };
+use snowbridge_core::operating_mode::DegradedModeQuery;
 use snowbridge_inbound_queue_primitives::{VerificationError, Verifier};
 use sp_core::H256;
 use sp_runtime::DispatchError;
@@ -1010,3 +1014,84 @@ fn verify_execution_proof_not_finalized() {
         );
     });
 }
+
+fn set_current_slot(slot: u64) {
+    Timestamp::set_timestamp(slot * SECONDS_PER_SLOT * 1000);
+}
+
+#[test]
+fn light_client_health_not_bootstrapped() {
+    new_tester().execute_with(|| {
+        set_current_slot(100);
+
+        let health = EthereumBeaconClient::light_client_health();
+        assert_eq!(health.status, LightClientStatus::NotBootstrapped);
+        assert_eq!(health.current_slot, 100);
+        assert_eq!(health.slot_lag, 0);
+    });
+}
+
+#[test]
+fn light_client_health_follows_lag() {
+    let checkpoint = Box::new(load_checkpoint_update_fixture());
+    let slot = checkpoint.header.slot;
+
+    new_tester().execute_with(|| {
+        System::set_block_number(1);
+        assert_ok!(EthereumBeaconClient::process_checkpoint_update(&checkpoint));
+
+        set_current_slot(slot);
+        EthereumBeaconClient::update_health_status();
+        assert_eq!(HealthStatus::<Test>::get(), LightClientStatus::Healthy);
+
+        set_current_slot(slot + LAGGING_THRESHOLD + 1);
+        EthereumBeaconClient::update_health_status();
+        assert_eq!(HealthStatus::<Test>::get(), LightClientStatus::Lagging);
+
+        set_current_slot(slot + DEGRADED_THRESHOLD + 1);
+        EthereumBeaconClient::update_health_status();
+        assert_eq!(HealthStatus::<Test>::get(), LightClientStatus::Degraded);
+        System::assert_last_event(
+            crate::Event::HealthStatusChanged {
+                status: LightClientStatus::Degraded,
+                slot_lag: DEGRADED_THRESHOLD + 1,
+                period_lag: compute_period(slot + DEGRADED_THRESHOLD + 1) - compute_period(slot),
+            }
+            .into(),
+        );
+
+        let period_slots = (SLOTS_PER_EPOCH * EPOCHS_PER_SYNC_COMMITTEE_PERIOD) as u64;
+        set_current_slot(slot + 2 * period_slots);
+        let health = EthereumBeaconClient::light_client_health();
+        assert_eq!(health.status, LightClientStatus::Stalled);
+        assert_eq!(health.latest_finalized_slot, slot);
+        assert_eq!(health.period_lag, 2);
+    });
+}
+
+#[test]
+fn degraded_mode_requires_switch() {
+    let checkpoint = Box::new(load_checkpoint_update_fixture());
+    let slot = checkpoint.header.slot;
+
+    new_tester().execute_with(|| {
+        assert_ok!(EthereumBeaconClient::process_checkpoint_update(&checkpoint));
+        set_current_slot(slot + DEGRADED_THRESHOLD + 1);
+        EthereumBeaconClient::update_health_status();
+        assert!(!<EthereumBeaconClient as DegradedModeQuery>::is_degraded());
+
+        assert_noop!(
+            EthereumBeaconClient::set_degraded_mode_enabled(RuntimeOrigin::signed(1), true),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(EthereumBeaconClient::set_degraded_mode_enabled(
+            RuntimeOrigin::root(),
+            true
+        ));
+        assert!(<EthereumBeaconClient as DegradedModeQuery>::is_degraded());
+
+        set_current_slot(slot);
+        EthereumBeaconClient::update_health_status();
+        assert!(!<EthereumBeaconClient as DegradedModeQuery>::is_degraded());
+    });
+}
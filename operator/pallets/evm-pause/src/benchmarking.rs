@@ -0,0 +1,90 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Benchmarking setup for pallet-evm-pause
+
+use super::*;
+use frame_benchmarking::v2::*;
+
+const ADDRESS: H160 = H160::repeat_byte(0x01);
+const SELECTOR: Selector = [0xde, 0xad, 0xbe, 0xef];
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn pause_address() -> Result<(), BenchmarkError> {
+        let origin =
+            T::PauseOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, ADDRESS);
+
+        // Verify
+        assert!(PausedAddresses::<T>::contains_key(ADDRESS));
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn unpause_address() -> Result<(), BenchmarkError> {
+        // Setup
+        PausedAddresses::<T>::insert(ADDRESS, ());
+        let origin =
+            T::UnpauseOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, ADDRESS);
+
+        // Verify
+        assert!(!PausedAddresses::<T>::contains_key(ADDRESS));
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn pause_selector() -> Result<(), BenchmarkError> {
+        let origin =
+            T::PauseOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, ADDRESS, SELECTOR);
+
+        // Verify
+        assert!(PausedSelectors::<T>::contains_key(ADDRESS, SELECTOR));
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn unpause_selector() -> Result<(), BenchmarkError> {
+        // Setup
+        PausedSelectors::<T>::insert(ADDRESS, SELECTOR, ());
+        let origin =
+            T::UnpauseOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, ADDRESS, SELECTOR);
+
+        // Verify
+        assert!(!PausedSelectors::<T>::contains_key(ADDRESS, SELECTOR));
+
+        Ok(())
+    }
+
+    impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,334 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Enforcement of the pause list in the EVM.
+
+use crate::{Config, Error, Pallet};
+use core::marker::PhantomData;
+use fp_evm::{
+    CallInfo, CreateInfo, IsPrecompileResult, PrecompileHandle, PrecompileResult, PrecompileSet,
+};
+use frame_support::{traits::Get, weights::Weight};
+use pallet_evm::{Runner, RunnerError};
+use precompile_utils::{
+    precompile_set::IsActivePrecompile,
+    prelude::{revert, EvmResult, PrecompileHandleExt},
+};
+use sp_core::{H160, H256, U256};
+use sp_runtime::DispatchError;
+use sp_std::vec::Vec;
+
+/// Size of a read of `PausedAddresses`: Blake2_128(16) + H160(20)
+const PAUSED_ADDRESS_READ_SIZE: usize = 36;
+
+/// Size of a read of `PausedSelectors`: Blake2_128(16) + H160(20) + Blake2_128(16) + Selector(4)
+const PAUSED_SELECTOR_READ_SIZE: usize = 56;
+
+/// Precompile set reverting the calls to the paused precompiles.
+pub struct PausablePrecompiles<Runtime, P> {
+    inner: P,
+    _marker: PhantomData<Runtime>,
+}
+
+impl<Runtime, P> PausablePrecompiles<Runtime, P> {
+    pub fn new(inner: P) -> Self {
+        Self {
+            inner,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<Runtime, P> PausablePrecompiles<Runtime, P>
+where
+    Runtime: Config + pallet_evm::Config,
+{
+    fn ensure_not_paused(handle: &mut impl PrecompileHandle) -> EvmResult {
+        handle.record_db_read::<Runtime>(PAUSED_ADDRESS_READ_SIZE)?;
+        handle.record_db_read::<Runtime>(PAUSED_SELECTOR_READ_SIZE)?;
+
+        if Pallet::<Runtime>::is_call_paused(handle.code_address(), handle.input()) {
+            return Err(revert("precompile is paused"));
+        }
+
+        Ok(())
+    }
+}
+
+impl<Runtime, P> PrecompileSet for PausablePrecompiles<Runtime, P>
+where
+    Runtime: Config + pallet_evm::Config,
+    P: PrecompileSet,
+{
+    fn execute(&self, handle: &mut impl PrecompileHandle) -> Option<PrecompileResult> {
+        let is_precompile = matches!(
+            self.inner
+                .is_precompile(handle.code_address(), handle.remaining_gas()),
+            IsPrecompileResult::Answer {
+                is_precompile: true,
+                ..
+            }
+        );
+        if is_precompile {
+            if let Err(failure) = Self::ensure_not_paused(handle) {
+                return Some(Err(failure));
+            }
+        } else if Pallet::<Runtime>::is_call_paused(handle.code_address(), handle.input()) {
+            // Every call frame, including the ones of constructors, goes through the precompile
+            // set before running the code of a contract. The check is not charged: a frame may
+            // have no gas left, e.g. a plain transfer or a call with the 2300 gas stipend.
+            return Some(Err(revert("contract is paused")));
+        }
+
+        self.inner.execute(handle)
+    }
+
+    fn is_precompile(&self, address: H160, remaining_gas: u64) -> IsPrecompileResult {
+        self.inner.is_precompile(address, remaining_gas)
+    }
+}
+
+impl<Runtime, P> IsActivePrecompile for PausablePrecompiles<Runtime, P>
+where
+    Runtime: Config,
+    P: IsActivePrecompile,
+{
+    /// Precompiles paused for all their functions are inactive.
+    fn is_active_precompile(&self, address: H160, remaining_gas: u64) -> IsPrecompileResult {
+        match self.inner.is_active_precompile(address, remaining_gas) {
+            IsPrecompileResult::Answer {
+                is_precompile: true,
+                extra_cost,
+            } => IsPrecompileResult::Answer {
+                is_precompile: !Pallet::<Runtime>::is_address_paused(address),
+                extra_cost,
+            },
+            result => result,
+        }
+    }
+}
+
+/// EVM runner rejecting the transactions that call a paused precompile or contract before they
+/// are executed. The calls made during the execution are checked by [`PausablePrecompiles`].
+pub struct PausableRunner<Runtime, R>(PhantomData<(Runtime, R)>);
+
+impl<Runtime, R> PausableRunner<Runtime, R>
+where
+    Runtime: Config + pallet_evm::Config,
+{
+    fn ensure_not_paused(target: H160, input: &[u8]) -> Result<(), RunnerError<DispatchError>> {
+        if Pallet::<Runtime>::is_call_paused(target, input) {
+            return Err(RunnerError {
+                error: Error::<Runtime>::CallPaused.into(),
+                weight: <Runtime as frame_system::Config>::DbWeight::get().reads(2),
+            });
+        }
+
+        Ok(())
+    }
+}
+
+fn into_dispatch_error<E: Into<DispatchError>>(e: RunnerError<E>) -> RunnerError<DispatchError> {
+    RunnerError {
+        error: e.error.into(),
+        weight: e.weight,
+    }
+}
+
+impl<Runtime, R> Runner<Runtime> for PausableRunner<Runtime, R>
+where
+    Runtime: Config + pallet_evm::Config,
+    R: Runner<Runtime>,
+{
+    type Error = DispatchError;
+
+    fn validate(
+        source: H160,
+        target: Option<H160>,
+        input: Vec<u8>,
+        value: U256,
+        gas_limit: u64,
+        max_fee_per_gas: Option<U256>,
+        max_priority_fee_per_gas: Option<U256>,
+        nonce: Option<U256>,
+        access_list: Vec<(H160, Vec<H256>)>,
+        is_transactional: bool,
+        weight_limit: Option<Weight>,
+        proof_size_base_cost: Option<u64>,
+        evm_config: &evm::Config,
+    ) -> Result<(), RunnerError<Self::Error>> {
+        if let Some(target) = target {
+            Self::ensure_not_paused(target, &input)?;
+        }
+
+        R::validate(
+            source,
+            target,
+            input,
+            value,
+            gas_limit,
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+            nonce,
+            access_list,
+            is_transactional,
+            weight_limit,
+            proof_size_base_cost,
+            evm_config,
+        )
+        .map_err(into_dispatch_error)
+    }
+
+    fn call(
+        source: H160,
+        target: H160,
+        input: Vec<u8>,
+        value: U256,
+        gas_limit: u64,
+        max_fee_per_gas: Option<U256>,
+        max_priority_fee_per_gas: Option<U256>,
+        nonce: Option<U256>,
+        access_list: Vec<(H160, Vec<H256>)>,
+        is_transactional: bool,
+        validate: bool,
+        weight_limit: Option<Weight>,
+        proof_size_base_cost: Option<u64>,
+        config: &evm::Config,
+    ) -> Result<CallInfo, RunnerError<Self::Error>> {
+        Self::ensure_not_paused(target, &input)?;
+
+        R::call(
+            source,
+            target,
+            input,
+            value,
+            gas_limit,
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+            nonce,
+            access_list,
+            is_transactional,
+            validate,
+            weight_limit,
+            proof_size_base_cost,
+            config,
+        )
+        .map_err(into_dispatch_error)
+    }
+
+    fn create(
+        source: H160,
+        init: Vec<u8>,
+        value: U256,
+        gas_limit: u64,
+        max_fee_per_gas: Option<U256>,
+        max_priority_fee_per_gas: Option<U256>,
+        nonce: Option<U256>,
+        access_list: Vec<(H160, Vec<H256>)>,
+        is_transactional: bool,
+        validate: bool,
+        weight_limit: Option<Weight>,
+        proof_size_base_cost: Option<u64>,
+        config: &evm::Config,
+    ) -> Result<CreateInfo, RunnerError<Self::Error>> {
+        R::create(
+            source,
+            init,
+            value,
+            gas_limit,
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+            nonce,
+            access_list,
+            is_transactional,
+            validate,
+            weight_limit,
+            proof_size_base_cost,
+            config,
+        )
+        .map_err(into_dispatch_error)
+    }
+
+    fn create2(
+        source: H160,
+        init: Vec<u8>,
+        salt: H256,
+        value: U256,
+        gas_limit: u64,
+        max_fee_per_gas: Option<U256>,
+        max_priority_fee_per_gas: Option<U256>,
+        nonce: Option<U256>,
+        access_list: Vec<(H160, Vec<H256>)>,
+        is_transactional: bool,
+        validate: bool,
+        weight_limit: Option<Weight>,
+        proof_size_base_cost: Option<u64>,
+        config: &evm::Config,
+    ) -> Result<CreateInfo, RunnerError<Self::Error>> {
+        R::create2(
+            source,
+            init,
+            salt,
+            value,
+            gas_limit,
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+            nonce,
+            access_list,
+            is_transactional,
+            validate,
+            weight_limit,
+            proof_size_base_cost,
+            config,
+        )
+        .map_err(into_dispatch_error)
+    }
+
+    fn create_force_address(
+        source: H160,
+        init: Vec<u8>,
+        value: U256,
+        gas_limit: u64,
+        max_fee_per_gas: Option<U256>,
+        max_priority_fee_per_gas: Option<U256>,
+        nonce: Option<U256>,
+        access_list: Vec<(H160, Vec<H256>)>,
+        is_transactional: bool,
+        validate: bool,
+        weight_limit: Option<Weight>,
+        proof_size_base_cost: Option<u64>,
+        config: &evm::Config,
+        contract_address: H160,
+    ) -> Result<CreateInfo, RunnerError<Self::Error>> {
+        R::create_force_address(
+            source,
+            init,
+            value,
+            gas_limit,
+            max_fee_per_gas,
+            max_priority_fee_per_gas,
+            nonce,
+            access_list,
+            is_transactional,
+            validate,
+            weight_limit,
+            proof_size_base_cost,
+            config,
+            contract_address,
+        )
+        .map_err(into_dispatch_error)
+    }
+}
@@ -0,0 +1,207 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! # EVM Pause Pallet
+//!
+//! Keeps a governance-controlled pause list of EVM addresses, so a misbehaving precompile or
+//! contract can be stopped without pausing `Ethereum::transact` and `EVM::call` as a whole.
+//!
+//! An address can be paused entirely, or only for some of its function selectors. The list is
+//! enforced by:
+//! - [`PausablePrecompiles`], wrapping the precompile set of the runtime. Every call frame goes
+//!   through it, so calls to a paused precompile or contract revert, whether they are made by a
+//!   transaction, a contract or a constructor run by `CREATE` or `CREATE2`. Paused precompiles are
+//!   reported as inactive through `IsActivePrecompile`.
+//! - [`PausableRunner`], wrapping the EVM runner of the runtime. Transactions calling a paused
+//!   address fail before being executed.
+//!
+//! Deploying a contract at a paused address is not prevented, but every call to it reverts.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::pallet_prelude::*;
+use sp_core::H160;
+
+pub use pallet::*;
+
+mod evm;
+pub use evm::{PausablePrecompiles, PausableRunner};
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+/// The first four bytes of the calldata of a call, identifying the called function.
+pub type Selector = [u8; 4];
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use frame_system::pallet_prelude::*;
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        /// The overarching event type
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// Origin allowed to pause addresses and selectors
+        type PauseOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Origin allowed to unpause addresses and selectors
+        type UnpauseOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// Weight information
+        type WeightInfo: WeightInfo;
+    }
+
+    /// Precompiles and contracts paused for all their functions.
+    #[pallet::storage]
+    pub type PausedAddresses<T: Config> = StorageMap<_, Blake2_128Concat, H160, (), OptionQuery>;
+
+    /// Functions of precompiles and contracts paused by selector.
+    #[pallet::storage]
+    pub type PausedSelectors<T: Config> =
+        StorageDoubleMap<_, Blake2_128Concat, H160, Blake2_128Concat, Selector, (), OptionQuery>;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// All the functions of an address were paused
+        AddressPaused { address: H160 },
+        /// An address was unpaused
+        AddressUnpaused { address: H160 },
+        /// A function of an address was paused
+        SelectorPaused { address: H160, selector: Selector },
+        /// A function of an address was unpaused
+        SelectorUnpaused { address: H160, selector: Selector },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// The address or selector is already paused
+        IsPaused,
+        /// The address or selector is not paused
+        IsUnpaused,
+        /// The call targets a paused precompile or contract
+        CallPaused,
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Pause all the functions of `address`.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::pause_address())]
+        pub fn pause_address(origin: OriginFor<T>, address: H160) -> DispatchResult {
+            T::PauseOrigin::ensure_origin(origin)?;
+
+            ensure!(
+                !PausedAddresses::<T>::contains_key(address),
+                Error::<T>::IsPaused
+            );
+            PausedAddresses::<T>::insert(address, ());
+
+            Self::deposit_event(Event::AddressPaused { address });
+
+            Ok(())
+        }
+
+        /// Unpause `address`. The selectors paused individually stay paused.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::unpause_address())]
+        pub fn unpause_address(origin: OriginFor<T>, address: H160) -> DispatchResult {
+            T::UnpauseOrigin::ensure_origin(origin)?;
+
+            ensure!(
+                PausedAddresses::<T>::take(address).is_some(),
+                Error::<T>::IsUnpaused
+            );
+
+            Self::deposit_event(Event::AddressUnpaused { address });
+
+            Ok(())
+        }
+
+        /// Pause the function of `address` identified by `selector`.
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::pause_selector())]
+        pub fn pause_selector(
+            origin: OriginFor<T>,
+            address: H160,
+            selector: Selector,
+        ) -> DispatchResult {
+            T::PauseOrigin::ensure_origin(origin)?;
+
+            ensure!(
+                !PausedSelectors::<T>::contains_key(address, selector),
+                Error::<T>::IsPaused
+            );
+            PausedSelectors::<T>::insert(address, selector, ());
+
+            Self::deposit_event(Event::SelectorPaused { address, selector });
+
+            Ok(())
+        }
+
+        /// Unpause the function of `address` identified by `selector`.
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::unpause_selector())]
+        pub fn unpause_selector(
+            origin: OriginFor<T>,
+            address: H160,
+            selector: Selector,
+        ) -> DispatchResult {
+            T::UnpauseOrigin::ensure_origin(origin)?;
+
+            ensure!(
+                PausedSelectors::<T>::take(address, selector).is_some(),
+                Error::<T>::IsUnpaused
+            );
+
+            Self::deposit_event(Event::SelectorUnpaused { address, selector });
+
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Whether all the functions of `address` are paused
+        pub fn is_address_paused(address: H160) -> bool {
+            PausedAddresses::<T>::contains_key(address)
+        }
+
+        /// Whether a call to `address` with the calldata `input` is paused
+        pub fn is_call_paused(address: H160, input: &[u8]) -> bool {
+            if Self::is_address_paused(address) {
+                return true;
+            }
+
+            input
+                .get(..4)
+                .and_then(|selector| Selector::try_from(selector).ok())
+                .is_some_and(|selector| PausedSelectors::<T>::contains_key(address, selector))
+        }
+    }
+}
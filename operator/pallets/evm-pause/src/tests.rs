@@ -0,0 +1,187 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+use {
+    crate::{mock::*, Error, Event, Pallet as EvmPausePallet, PausedAddresses, PausedSelectors},
+    frame_support::{assert_noop, assert_ok},
+    sp_core::H160,
+    sp_runtime::DispatchError,
+};
+
+const PRECOMPILE: H160 = H160::repeat_byte(0x08);
+const SELECTOR: [u8; 4] = [0xde, 0xad, 0xbe, 0xef];
+
+fn calldata(selector: [u8; 4]) -> Vec<u8> {
+    let mut input = selector.to_vec();
+    input.extend_from_slice(&[0u8; 32]);
+    input
+}
+
+#[test]
+fn pause_address_pauses_every_call() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(EvmPause::pause_address(RuntimeOrigin::root(), PRECOMPILE));
+
+        assert!(PausedAddresses::<Test>::contains_key(PRECOMPILE));
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::EvmPause(Event::AddressPaused {
+                address: PRECOMPILE
+            })
+        );
+        assert!(EvmPausePallet::<Test>::is_address_paused(PRECOMPILE));
+        assert!(EvmPausePallet::<Test>::is_call_paused(
+            PRECOMPILE,
+            &calldata(SELECTOR)
+        ));
+        assert!(EvmPausePallet::<Test>::is_call_paused(PRECOMPILE, &[]));
+        assert!(!EvmPausePallet::<Test>::is_call_paused(
+            H160::repeat_byte(0x01),
+            &calldata(SELECTOR)
+        ));
+    });
+}
+
+#[test]
+fn pause_selector_pauses_only_that_function() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(EvmPause::pause_selector(
+            RuntimeOrigin::root(),
+            PRECOMPILE,
+            SELECTOR
+        ));
+
+        assert!(PausedSelectors::<Test>::contains_key(PRECOMPILE, SELECTOR));
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::EvmPause(Event::SelectorPaused {
+                address: PRECOMPILE,
+                selector: SELECTOR,
+            })
+        );
+        assert!(!EvmPausePallet::<Test>::is_address_paused(PRECOMPILE));
+        assert!(EvmPausePallet::<Test>::is_call_paused(
+            PRECOMPILE,
+            &calldata(SELECTOR)
+        ));
+        assert!(!EvmPausePallet::<Test>::is_call_paused(
+            PRECOMPILE,
+            &calldata([0x01, 0x02, 0x03, 0x04])
+        ));
+        // Calldata too short to hold a selector
+        assert!(!EvmPausePallet::<Test>::is_call_paused(
+            PRECOMPILE,
+            &SELECTOR[..3]
+        ));
+    });
+}
+
+#[test]
+fn unpause_restores_calls() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(EvmPause::pause_address(RuntimeOrigin::root(), PRECOMPILE));
+        assert_ok!(EvmPause::pause_selector(
+            RuntimeOrigin::root(),
+            PRECOMPILE,
+            SELECTOR
+        ));
+
+        assert_ok!(EvmPause::unpause_address(RuntimeOrigin::root(), PRECOMPILE));
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::EvmPause(Event::AddressUnpaused {
+                address: PRECOMPILE
+            })
+        );
+        // The selector paused on its own stays paused
+        assert!(EvmPausePallet::<Test>::is_call_paused(
+            PRECOMPILE,
+            &calldata(SELECTOR)
+        ));
+
+        assert_ok!(EvmPause::unpause_selector(
+            RuntimeOrigin::root(),
+            PRECOMPILE,
+            SELECTOR
+        ));
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::EvmPause(Event::SelectorUnpaused {
+                address: PRECOMPILE,
+                selector: SELECTOR,
+            })
+        );
+        assert!(!EvmPausePallet::<Test>::is_call_paused(
+            PRECOMPILE,
+            &calldata(SELECTOR)
+        ));
+    });
+}
+
+#[test]
+fn pausing_twice_fails() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(EvmPause::pause_address(RuntimeOrigin::root(), PRECOMPILE));
+        assert_noop!(
+            EvmPause::pause_address(RuntimeOrigin::root(), PRECOMPILE),
+            Error::<Test>::IsPaused
+        );
+
+        assert_ok!(EvmPause::pause_selector(
+            RuntimeOrigin::root(),
+            PRECOMPILE,
+            SELECTOR
+        ));
+        assert_noop!(
+            EvmPause::pause_selector(RuntimeOrigin::root(), PRECOMPILE, SELECTOR),
+            Error::<Test>::IsPaused
+        );
+    });
+}
+
+#[test]
+fn unpausing_unpaused_fails() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            EvmPause::unpause_address(RuntimeOrigin::root(), PRECOMPILE),
+            Error::<Test>::IsUnpaused
+        );
+        assert_noop!(
+            EvmPause::unpause_selector(RuntimeOrigin::root(), PRECOMPILE, SELECTOR),
+            Error::<Test>::IsUnpaused
+        );
+    });
+}
+
+#[test]
+fn only_governance_can_pause() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            EvmPause::pause_address(RuntimeOrigin::signed(ALICE), PRECOMPILE),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            EvmPause::pause_selector(RuntimeOrigin::signed(ALICE), PRECOMPILE, SELECTOR),
+            DispatchError::BadOrigin
+        );
+
+        assert_ok!(EvmPause::pause_address(RuntimeOrigin::root(), PRECOMPILE));
+        assert_noop!(
+            EvmPause::unpause_address(RuntimeOrigin::signed(ALICE), PRECOMPILE),
+            DispatchError::BadOrigin
+        );
+    });
+}
@@ -0,0 +1,149 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Autogenerated weights for `pallet_evm_pause`
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 36.0.0
+//! DATE: 2025-02-10, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `datahaven-benchmarks`, CPU: `Apple M1 Pro`
+//! WASM-EXECUTION: `Compiled`, CHAIN: `Some("testnet-dev")`, DB CACHE: `1024`
+
+// Executed Command:
+// ./target/release/datahaven-node
+// benchmark
+// pallet
+// --chain=testnet-dev
+// --steps=50
+// --repeat=20
+// --pallet=pallet_evm_pause
+// --extrinsic=*
+// --wasm-execution=compiled
+// --heap-pages=4096
+// --output=pallets/evm-pause/src/weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_evm_pause`.
+pub trait WeightInfo {
+    fn pause_address() -> Weight;
+    fn unpause_address() -> Weight;
+    fn pause_selector() -> Weight;
+    fn unpause_selector() -> Weight;
+}
+
+/// Weights for `pallet_evm_pause` using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+    /// Storage: `EvmPause::PausedAddresses` (r:1 w:1)
+    /// Proof: `EvmPause::PausedAddresses` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+    fn pause_address() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `4`
+        //  Estimated: `3501`
+        // Minimum execution time: 9_000_000 picoseconds.
+        Weight::from_parts(10_000_000, 3501)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Storage: `EvmPause::PausedAddresses` (r:1 w:1)
+    /// Proof: `EvmPause::PausedAddresses` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+    fn unpause_address() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `76`
+        //  Estimated: `3501`
+        // Minimum execution time: 11_000_000 picoseconds.
+        Weight::from_parts(12_000_000, 3501)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Storage: `EvmPause::PausedSelectors` (r:1 w:1)
+    /// Proof: `EvmPause::PausedSelectors` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+    fn pause_selector() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `4`
+        //  Estimated: `3521`
+        // Minimum execution time: 9_000_000 picoseconds.
+        Weight::from_parts(10_000_000, 3521)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+    /// Storage: `EvmPause::PausedSelectors` (r:1 w:1)
+    /// Proof: `EvmPause::PausedSelectors` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+    fn unpause_selector() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `96`
+        //  Estimated: `3521`
+        // Minimum execution time: 11_000_000 picoseconds.
+        Weight::from_parts(12_000_000, 3521)
+            .saturating_add(T::DbWeight::get().reads(1_u64))
+            .saturating_add(T::DbWeight::get().writes(1_u64))
+    }
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+    /// Storage: `EvmPause::PausedAddresses` (r:1 w:1)
+    /// Proof: `EvmPause::PausedAddresses` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+    fn pause_address() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `4`
+        //  Estimated: `3501`
+        // Minimum execution time: 9_000_000 picoseconds.
+        Weight::from_parts(10_000_000, 3501)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    /// Storage: `EvmPause::PausedAddresses` (r:1 w:1)
+    /// Proof: `EvmPause::PausedAddresses` (`max_values`: None, `max_size`: Some(36), added: 2511, mode: `MaxEncodedLen`)
+    fn unpause_address() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `76`
+        //  Estimated: `3501`
+        // Minimum execution time: 11_000_000 picoseconds.
+        Weight::from_parts(12_000_000, 3501)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    /// Storage: `EvmPause::PausedSelectors` (r:1 w:1)
+    /// Proof: `EvmPause::PausedSelectors` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+    fn pause_selector() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `4`
+        //  Estimated: `3521`
+        // Minimum execution time: 9_000_000 picoseconds.
+        Weight::from_parts(10_000_000, 3521)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+    /// Storage: `EvmPause::PausedSelectors` (r:1 w:1)
+    /// Proof: `EvmPause::PausedSelectors` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+    fn unpause_selector() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `96`
+        //  Estimated: `3521`
+        // Minimum execution time: 11_000_000 picoseconds.
+        Weight::from_parts(12_000_000, 3521)
+            .saturating_add(RocksDbWeight::get().reads(1_u64))
+            .saturating_add(RocksDbWeight::get().writes(1_u64))
+    }
+}
@@ -0,0 +1,40 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Runtime API for the external validator slashes pallet.
+//!
+//! Lets operators find the slashes that are queued, waiting for a delivery receipt, or failing to
+//! reach EigenLayer.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet_external_validator_slashes::{SlashDelivery, SlashDeliveryStatus};
+use {parity_scale_codec::Codec, sp_std::vec::Vec};
+
+sp_api::decl_runtime_apis! {
+    #[api_version(1)]
+    pub trait ExternalValidatorSlashesApi<SlashId, BlockNumber>
+    where
+        SlashId: Codec,
+        BlockNumber: Codec,
+    {
+        /// Delivery state of every slash that is not confirmed by a delivery receipt yet.
+        fn pending_slash_deliveries() -> Vec<(SlashId, SlashDelivery<BlockNumber>)>;
+
+        /// Delivery state of the slash `slash_id`, kept for the bonding period once confirmed.
+        fn slash_delivery(slash_id: SlashId) -> Option<SlashDelivery<BlockNumber>>;
+    }
+}
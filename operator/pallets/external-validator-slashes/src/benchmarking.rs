@@ -21,11 +21,12 @@ use super::*;
 #[allow(unused)]
 use crate::Pallet as ExternalValidatorSlashes;
 use {
-    crate::SlashingModeOption,
+    crate::{ReporterBountyMode, SlashingModeOption},
     frame_benchmarking::{v2::*, BenchmarkError},
+    frame_support::traits::fungible::{Inspect, Mutate},
     frame_system::RawOrigin,
     pallet_session as session,
-    sp_runtime::traits::TrailingZeroInput,
+    sp_runtime::traits::{Bounded, TrailingZeroInput},
 };
 
 const MAX_SLASHES: u32 = 1000;
@@ -93,10 +94,15 @@ mod benchmarks {
         let dummy = || T::AccountId::decode(&mut TrailingZeroInput::zeroes()).unwrap();
 
         for _ in 0..(s + 1) {
-            queue.push_back(Slash::<T::AccountId, T::SlashId>::default_from(dummy()));
+            let mut slash = Slash::<T::AccountId, T::SlashId>::default_from(dummy());
+            slash.reporters = vec![dummy()];
+            queue.push_back(slash);
         }
 
         UnreportedSlashesQueue::<T>::set(queue);
+        // Worst case: every sent slash accrues a bounty for its reporter
+        ReporterBounty::<T>::put(ReporterBountyMode::Fixed(T::Currency::minimum_balance()));
+        ReporterBountyEraCap::<T>::put(BalanceOf::<T>::max_value());
 
         let processed;
 
@@ -119,6 +125,143 @@ mod benchmarks {
         Ok(())
     }
 
+    #[benchmark]
+    fn set_reporter_bounty() -> Result<(), BenchmarkError> {
+        let amount = T::Currency::minimum_balance();
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, ReporterBountyMode::Fixed(amount), amount);
+
+        assert_eq!(ReporterBountyEraCap::<T>::get(), amount);
+        Ok(())
+    }
+
+    #[benchmark]
+    fn claim_reporter_bounty() -> Result<(), BenchmarkError> {
+        let reporter: T::AccountId = whitelisted_caller();
+        let amount = T::Currency::minimum_balance().saturating_mul(1000u32.into());
+        T::Currency::set_balance(
+            &T::ReporterBountySource::get(),
+            amount.saturating_mul(2u32.into()),
+        );
+        PendingReporterBounties::<T>::insert(&reporter, amount);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(reporter.clone()));
+
+        assert_eq!(T::Currency::balance(&reporter), amount);
+        Ok(())
+    }
+
+    #[benchmark]
+    fn schedule_slashing_mode() -> Result<(), BenchmarkError> {
+        let era = T::EraIndexProvider::active_era().index.saturating_add(1);
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, SlashingModeOption::LogOnly, era);
+
+        assert_eq!(
+            ScheduledSlashingModes::<T>::get(era),
+            Some(SlashingModeOption::LogOnly)
+        );
+        Ok(())
+    }
+
+    #[benchmark]
+    fn promote_logged_slash() -> Result<(), BenchmarkError> {
+        let era = T::EraIndexProvider::active_era().index;
+        let dummy = || T::AccountId::decode(&mut TrailingZeroInput::zeroes()).unwrap();
+        let existing_slashes: Vec<_> = (0..MAX_SLASHES)
+            .map(|_| Slash::<T::AccountId, T::SlashId>::default_from(dummy()))
+            .collect();
+        LoggedSlashes::<T>::insert(era, existing_slashes.clone());
+        let promoted_era = era
+            .saturating_add(T::SlashDeferDuration::get())
+            .saturating_add(One::one());
+        Slashes::<T>::insert(promoted_era, existing_slashes);
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, era, 0);
+
+        assert_eq!(
+            Slashes::<T>::get(promoted_era).len(),
+            MAX_SLASHES as usize + 1
+        );
+        Ok(())
+    }
+
+    #[benchmark]
+    fn retry_slash_delivery() -> Result<(), BenchmarkError> {
+        let dummy = || T::AccountId::decode(&mut TrailingZeroInput::zeroes()).unwrap();
+        let slash = Slash::<T::AccountId, T::SlashId>::default_from(dummy());
+        let slash_id = slash.slash_id;
+        SlashDeliveries::<T>::insert(
+            slash_id,
+            SlashDelivery {
+                status: SlashDeliveryStatus::Failed { retry_at: None },
+                attempts: T::MaxSlashDeliveryAttempts::get(),
+                first_sent_at: None,
+            },
+        );
+        FailedSlashes::<T>::insert(slash_id, slash);
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, slash_id);
+
+        assert_eq!(UnreportedSlashesQueue::<T>::get().len(), 1);
+        Ok(())
+    }
+
+    #[benchmark]
+    fn on_message_accepted() -> Result<(), BenchmarkError> {
+        let dummy = || T::AccountId::decode(&mut TrailingZeroInput::zeroes()).unwrap();
+        let message_id = H256::repeat_byte(0x01);
+        let slashes = vec![(Slash::<T::AccountId, T::SlashId>::default_from(dummy()), 1)];
+        SentSlashMessages::<T>::insert(message_id, vec![slashes.clone(), slashes]);
+
+        #[block]
+        {
+            <Pallet<T> as MessageDeliveryHandler>::on_message_accepted(message_id, 1);
+        }
+
+        assert!(AcceptedSlashMessages::<T>::get(1).is_some());
+        Ok(())
+    }
+
+    #[benchmark]
+    fn on_message_delivered(s: Linear<1, 200>) -> Result<(), BenchmarkError> {
+        let dummy = || T::AccountId::decode(&mut TrailingZeroInput::zeroes()).unwrap();
+        let message_id = H256::repeat_byte(0x01);
+        let mut slashes = Vec::new();
+        let mut slash_id = T::SlashId::one();
+        for _ in 0..s {
+            let mut slash = Slash::<T::AccountId, T::SlashId>::default_from(dummy());
+            slash.slash_id = slash_id;
+            slash_id = slash_id.saturating_add(One::one());
+            SlashDeliveries::<T>::insert(
+                slash.slash_id,
+                SlashDelivery {
+                    status: SlashDeliveryStatus::Sent { message_id },
+                    attempts: 1,
+                    first_sent_at: None,
+                },
+            );
+            slashes.push((slash, 1));
+        }
+        AcceptedSlashMessages::<T>::insert(1, (message_id, slashes));
+
+        // Worst case: the slashes failed on Ethereum and are scheduled for a retry
+        #[block]
+        {
+            <Pallet<T> as MessageDeliveryHandler>::on_message_delivered(1, false);
+        }
+
+        let retry_at =
+            frame_system::Pallet::<T>::block_number().saturating_add(T::SlashRetryDelay::get());
+        assert_eq!(SlashRetries::<T>::get(retry_at).len(), s as usize);
+        Ok(())
+    }
+
     impl_benchmark_test_suite!(
         ExternalValidatorSlashes,
         crate::mock::new_test_ext(),
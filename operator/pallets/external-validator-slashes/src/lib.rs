@@ -25,19 +25,62 @@
 //!
 //! The pallet also implements the OnOffence trait that reacts to offences being injected by other pallets
 //! Invulnerables are not slashed and no slashing information is stored for them
+//!
+//! ## Reporter bounties
+//!
+//! Once a slash is sent to Ethereum, its reporters accrue a bounty set by governance through
+//! `set_reporter_bounty`, either a fixed amount or a fraction of the slash, up to a cap per era.
+//! Reporters claim their pending bounties with `claim_reporter_bounty`, paid from
+//! `ReporterBountySource`.
+//!
+//! ## Slashing mode
+//!
+//! Offences are handled according to the slashing mode of the era they happened in, recorded in
+//! `SlashingModes` at every era start and kept for the bonding period. Mode changes are scheduled
+//! for a future era with `schedule_slashing_mode`, while `set_slashing_mode` applies from the
+//! active era right away.
+//!
+//! Slashes of offences committed while in `LogOnly` mode are kept in `LoggedSlashes`, so
+//! governance can promote them to real slashes with `promote_logged_slash`.
+//!
+//! ## Delivery to Ethereum
+//!
+//! The delivery state of every queued slash is tracked in `SlashDeliveries`. A slash is `Queued`
+//! until it is sent, then `Sent` until the delivery receipt of its message is relayed back by the
+//! outbound queue, see [`MessageDeliveryHandler`], which makes it `Confirmed`.
+//!
+//! A slash that could not be sent, or whose execution failed on Ethereum, is `Failed` and queued
+//! again after `SlashRetryDelay` blocks, doubled at every new failure. A slash still `Sent`
+//! `SlashDeliveryTimeout` blocks after being sent fails the same way. After
+//! `MaxSlashDeliveryAttempts` attempts it is set aside in `FailedSlashes` until governance queues
+//! it again with `retry_slash_delivery`, or until the era of its offence leaves the bonding
+//! period, when it is dropped.
+//!
+//! A successful receipt arriving after the timeout still confirms the slash, and cancels its
+//! retry unless it was already sent again.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 extern crate alloc;
 use pallet_external_validators::apply;
-use snowbridge_outbound_queue_primitives::SendError;
+use snowbridge_outbound_queue_primitives::{v2::MessageDeliveryHandler, SendError};
 use {
     alloc::{collections::vec_deque::VecDeque, vec, vec::Vec},
-    frame_support::{pallet_prelude::*, traits::DefensiveSaturating},
+    frame_support::{
+        pallet_prelude::*,
+        traits::{
+            fungible::{self, Mutate},
+            tokens::Preservation,
+            DefensiveSaturating,
+        },
+    },
     frame_system::pallet_prelude::*,
     log::log,
     pallet_external_validators::{
         derive_storage_traits,
-        traits::{EraIndexProvider, ExternalIndexProvider, InvulnerablesProvider, OnEraStart},
+        traits::{
+            EraIndexProvider, ExternalIndexProvider, InvulnerablesProvider, OnEraStart,
+            OnSlashCancelled,
+        },
     },
     parity_scale_codec::{Decode, DecodeWithMemTracking, Encode, FullCodec},
     sp_core::H256,
@@ -53,6 +96,15 @@ use {
 
 pub use pallet::*;
 
+pub type BalanceOf<T> =
+    <<T as Config>::Currency as fungible::Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+
+/// A sent slash, with its number of delivery attempts once sent.
+pub type SentSlashOf<T> = (
+    Slash<<T as frame_system::Config>::AccountId, <T as Config>::SlashId>,
+    u32,
+);
+
 #[cfg(test)]
 mod mock;
 
@@ -61,6 +113,7 @@ mod tests;
 
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
+pub mod migration;
 pub mod weights;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
@@ -101,6 +154,56 @@ pub mod pallet {
         SlashInjected { slash_id: T::SlashId, era: u32 },
         /// Number of slashes processed
         SlashAddedToQueue { number: u32, era: u32 },
+        /// The reporter bounty parameters were updated.
+        ReporterBountySet {
+            mode: ReporterBountyMode<BalanceOf<T>>,
+            era_cap: BalanceOf<T>,
+        },
+        /// A reporter earned a bounty for a slash that was sent to Ethereum.
+        ReporterBountyAccrued {
+            reporter: T::AccountId,
+            slash_id: T::SlashId,
+            amount: BalanceOf<T>,
+        },
+        /// A reporter claimed its pending bounties.
+        ReporterBountyClaimed {
+            reporter: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        /// A slashing mode change was scheduled.
+        SlashingModeScheduled {
+            mode: SlashingModeOption,
+            era: EraIndex,
+        },
+        /// The slashing mode changed, starting from `era`.
+        SlashingModeChanged {
+            mode: SlashingModeOption,
+            era: EraIndex,
+        },
+        /// The slash of an offence committed in `LogOnly` mode was recorded without being applied.
+        SlashLogged {
+            slash_id: T::SlashId,
+            slash_era: EraIndex,
+        },
+        /// A logged slash was promoted, to be applied at `era`.
+        LoggedSlashPromoted { slash_id: T::SlashId, era: EraIndex },
+        /// A logged slash was dropped instead of promoted, the validator being already slashed at
+        /// least as much in the offence era.
+        LoggedSlashSuperseded { slash_id: T::SlashId },
+        /// The delivery receipt of a slashes message confirmed its execution on Ethereum.
+        SlashesConfirmed { message_id: H256, nonce: u64 },
+        /// A slash could not be delivered. It is queued again at `retry_at`, if any attempt is
+        /// left.
+        SlashDeliveryFailed {
+            slash_id: T::SlashId,
+            attempts: u32,
+            retry_at: Option<BlockNumberFor<T>>,
+        },
+        /// A slash that ran out of delivery attempts was queued again.
+        SlashDeliveryRetried { slash_id: T::SlashId },
+        /// A slash that ran out of delivery attempts was dropped, the era of its offence having
+        /// left the bonding period.
+        FailedSlashDropped { slash_id: T::SlashId },
     }
 
     #[pallet::config]
@@ -157,6 +260,29 @@ pub mod pallet {
         #[pallet::constant]
         type QueuedSlashesProcessedPerBlock: Get<u32>;
 
+        /// Currency the reporter bounties are paid in.
+        type Currency: fungible::Mutate<Self::AccountId>;
+
+        /// Account the reporter bounties are paid from.
+        type ReporterBountySource: Get<Self::AccountId>;
+
+        /// Hook called for every slash cancelled through `cancel_deferred_slash`.
+        type OnSlashCancelled: OnSlashCancelled<Self::AccountId>;
+
+        /// Blocks to wait before sending a failed slash again, doubled at every new failure.
+        #[pallet::constant]
+        type SlashRetryDelay: Get<BlockNumberFor<Self>>;
+
+        /// Number of delivery attempts of a slash before it is set aside for governance.
+        #[pallet::constant]
+        type MaxSlashDeliveryAttempts: Get<u32>;
+
+        /// Blocks to wait for the delivery receipt of a sent slash before it fails, to be sent
+        /// again. Must exceed the usual delivery time, as a late receipt cannot cancel a slash
+        /// already sent again.
+        #[pallet::constant]
+        type SlashDeliveryTimeout: Get<BlockNumberFor<Self>>;
+
         /// The weight information of this pallet.
         type WeightInfo: WeightInfo;
     }
@@ -183,6 +309,14 @@ pub mod pallet {
         EthereumDeliverFail,
         /// Invalid params for root_test_send_msg_to_eth
         RootTestInvalidParams,
+        /// The caller has no pending reporter bounty
+        NoReporterBounty,
+        /// Slashing mode changes can only be scheduled for a future era
+        NotFutureEra,
+        /// No logged slash was found at the given index
+        InvalidLoggedSlashIndex,
+        /// The slash did not run out of delivery attempts
+        SlashNotFailed,
     }
 
     #[apply(derive_storage_traits)]
@@ -196,7 +330,49 @@ pub mod pallet {
         Disabled,
     }
 
+    /// Bounty paid to the reporters of an offence once its slash is sent to Ethereum.
+    #[apply(derive_storage_traits)]
+    #[derive(MaxEncodedLen, DecodeWithMemTracking, Default)]
+    pub enum ReporterBountyMode<Balance> {
+        #[default]
+        Disabled,
+        /// Fixed amount per slash.
+        Fixed(Balance),
+        /// `fraction` of the slashed value. As stakes live on EigenLayer, the slashed value is
+        /// the slash percentage applied to `reference_stake`.
+        FractionOfSlash {
+            fraction: Perbill,
+            reference_stake: Balance,
+        },
+    }
+
+    /// Delivery state of a slash sent to Ethereum.
+    #[apply(derive_storage_traits)]
+    #[derive(MaxEncodedLen)]
+    pub enum SlashDeliveryStatus<BlockNumber> {
+        /// Waiting in the queue to be sent.
+        Queued,
+        /// Sent in the message `message_id`, waiting for its delivery receipt.
+        Sent { message_id: H256 },
+        /// Executed on Ethereum, as proven by the delivery receipt of `message_id`.
+        Confirmed { message_id: H256 },
+        /// Sending or executing the slash failed. It is queued again at `retry_at`, if any
+        /// attempt is left.
+        Failed { retry_at: Option<BlockNumber> },
+    }
+
+    #[apply(derive_storage_traits)]
+    #[derive(MaxEncodedLen)]
+    pub struct SlashDelivery<BlockNumber> {
+        pub status: SlashDeliveryStatus<BlockNumber>,
+        /// Number of times the slash was sent, successfully or not.
+        pub attempts: u32,
+        /// Block the slash was first sent at.
+        pub first_sent_at: Option<BlockNumber>,
+    }
+
     #[pallet::pallet]
+    #[pallet::storage_version(migration::STORAGE_VERSION)]
     pub struct Pallet<T>(PhantomData<T>);
 
     /// All slashing events on validators, mapped by era to the highest slash proportion
@@ -237,6 +413,98 @@ pub mod pallet {
     #[pallet::storage]
     pub type SlashingMode<T: Config> = StorageValue<_, SlashingModeOption, ValueQuery>;
 
+    /// Slashing mode of each still-bonded era.
+    #[pallet::storage]
+    pub type SlashingModes<T: Config> =
+        StorageMap<_, Twox64Concat, EraIndex, SlashingModeOption, OptionQuery>;
+
+    /// Slashing mode changes taking effect at the start of an era.
+    #[pallet::storage]
+    pub type ScheduledSlashingModes<T: Config> =
+        StorageMap<_, Twox64Concat, EraIndex, SlashingModeOption, OptionQuery>;
+
+    /// Slashes of offences committed in `LogOnly` mode, by offence era. Kept for the bonding
+    /// period so they can be promoted.
+    #[pallet::storage]
+    #[pallet::unbounded]
+    pub type LoggedSlashes<T: Config> =
+        StorageMap<_, Twox64Concat, EraIndex, Vec<Slash<T::AccountId, T::SlashId>>, ValueQuery>;
+
+    /// Delivery state of the slashes added to the queue.
+    #[pallet::storage]
+    pub type SlashDeliveries<T: Config> =
+        StorageMap<_, Twox64Concat, T::SlashId, SlashDelivery<BlockNumberFor<T>>, OptionQuery>;
+
+    /// Slashes to queue again at a block, after a failed delivery.
+    #[pallet::storage]
+    #[pallet::unbounded]
+    pub type SlashRetries<T: Config> = StorageMap<
+        _,
+        Twox64Concat,
+        BlockNumberFor<T>,
+        Vec<Slash<T::AccountId, T::SlashId>>,
+        ValueQuery,
+    >;
+
+    /// Slashes that ran out of delivery attempts.
+    #[pallet::storage]
+    #[pallet::unbounded]
+    pub type FailedSlashes<T: Config> =
+        StorageMap<_, Twox64Concat, T::SlashId, Slash<T::AccountId, T::SlashId>, OptionQuery>;
+
+    /// Slashes set aside in `FailedSlashes`, by offence era. Those still there when the era
+    /// leaves the bonding period are dropped.
+    #[pallet::storage]
+    #[pallet::unbounded]
+    pub type FailedSlashesByEra<T: Config> =
+        StorageMap<_, Twox64Concat, EraIndex, Vec<T::SlashId>, ValueQuery>;
+
+    /// Slashes sent `SlashDeliveryTimeout` blocks before a block, with their number of delivery
+    /// attempts then. Those still waiting for their delivery receipt at that block fail.
+    #[pallet::storage]
+    #[pallet::unbounded]
+    pub type SlashDeliveryTimeouts<T: Config> =
+        StorageMap<_, Twox64Concat, BlockNumberFor<T>, Vec<SentSlashOf<T>>, ValueQuery>;
+
+    /// Slashes of the messages sent with a given id, waiting to be accepted by the outbound queue.
+    /// Messages sharing an id are accepted in the order they were sent.
+    #[pallet::storage]
+    #[pallet::unbounded]
+    pub type SentSlashMessages<T: Config> =
+        StorageMap<_, Identity, H256, Vec<Vec<SentSlashOf<T>>>, ValueQuery>;
+
+    /// Slashes of the messages accepted by the outbound queue, by message nonce, waiting for
+    /// their delivery receipt.
+    #[pallet::storage]
+    #[pallet::unbounded]
+    pub type AcceptedSlashMessages<T: Config> =
+        StorageMap<_, Twox64Concat, u64, (H256, Vec<SentSlashOf<T>>), OptionQuery>;
+
+    /// Slashes confirmed in an era. Their delivery state is kept for the bonding period.
+    #[pallet::storage]
+    #[pallet::unbounded]
+    pub type ConfirmedSlashes<T: Config> =
+        StorageMap<_, Twox64Concat, EraIndex, Vec<T::SlashId>, ValueQuery>;
+
+    /// How reporter bounties are computed.
+    #[pallet::storage]
+    pub type ReporterBounty<T: Config> =
+        StorageValue<_, ReporterBountyMode<BalanceOf<T>>, ValueQuery>;
+
+    /// Maximum amount of reporter bounties accrued per era. Bounties above it are not paid.
+    #[pallet::storage]
+    pub type ReporterBountyEraCap<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+    /// Reporter bounties accrued in each era, kept for the bonding period.
+    #[pallet::storage]
+    pub type ReporterBountiesInEra<T: Config> =
+        StorageMap<_, Twox64Concat, EraIndex, BalanceOf<T>, ValueQuery>;
+
+    /// Bounties owed to each reporter, paid from `ReporterBountySource` when claimed.
+    #[pallet::storage]
+    pub type PendingReporterBounties<T: Config> =
+        StorageMap<_, Twox64Concat, T::AccountId, BalanceOf<T>, ValueQuery>;
+
     #[pallet::genesis_config]
     #[derive(frame_support::DefaultNoBound)]
     pub struct GenesisConfig<T: Config> {
@@ -290,8 +558,29 @@ pub mod pallet {
             );
 
             // Remove elements starting from the highest index to avoid shifting issues.
+            let mut cancelled = Vec::with_capacity(slash_indices.len());
             for index in slash_indices.into_iter().rev() {
-                era_slashes.remove(index as usize);
+                cancelled.push(era_slashes.remove(index as usize));
+            }
+
+            // The penalty of a validator in the offence era is now that of its slashes left
+            for slash in cancelled {
+                let highest_left = era_slashes
+                    .iter()
+                    .filter(|s| {
+                        s.validator == slash.validator && s.offence_era == slash.offence_era
+                    })
+                    .map(|s| s.percentage)
+                    .max();
+                match highest_left {
+                    Some(percentage) => ValidatorSlashInEra::<T>::insert(
+                        slash.offence_era,
+                        &slash.validator,
+                        percentage,
+                    ),
+                    None => ValidatorSlashInEra::<T>::remove(slash.offence_era, &slash.validator),
+                }
+                T::OnSlashCancelled::on_slash_cancelled(slash.offence_era, &slash.validator);
             }
             // insert back slashes
             Slashes::<T>::insert(era, &era_slashes);
@@ -349,12 +638,152 @@ pub mod pallet {
             Ok(())
         }
 
+        /// Change the slashing mode right away. It applies to the offences of the active era.
         #[pallet::call_index(3)]
         #[pallet::weight(T::WeightInfo::set_slashing_mode())]
         pub fn set_slashing_mode(origin: OriginFor<T>, mode: SlashingModeOption) -> DispatchResult {
             ensure_root(origin)?;
 
-            SlashingMode::<T>::put(mode);
+            let active_era = T::EraIndexProvider::active_era().index;
+            SlashingMode::<T>::put(&mode);
+            SlashingModes::<T>::insert(active_era, &mode);
+
+            Self::deposit_event(Event::<T>::SlashingModeChanged {
+                mode,
+                era: active_era,
+            });
+
+            Ok(())
+        }
+
+        /// Set how much reporters earn per slash sent to Ethereum, and the cap per era.
+        #[pallet::call_index(4)]
+        #[pallet::weight(T::WeightInfo::set_reporter_bounty())]
+        pub fn set_reporter_bounty(
+            origin: OriginFor<T>,
+            mode: ReporterBountyMode<BalanceOf<T>>,
+            era_cap: BalanceOf<T>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            ReporterBounty::<T>::put(&mode);
+            ReporterBountyEraCap::<T>::put(era_cap);
+
+            Self::deposit_event(Event::<T>::ReporterBountySet { mode, era_cap });
+
+            Ok(())
+        }
+
+        /// Claim all the reporter bounties owed to the caller.
+        #[pallet::call_index(5)]
+        #[pallet::weight(T::WeightInfo::claim_reporter_bounty())]
+        pub fn claim_reporter_bounty(origin: OriginFor<T>) -> DispatchResult {
+            let reporter = ensure_signed(origin)?;
+
+            let amount = PendingReporterBounties::<T>::take(&reporter);
+            ensure!(!amount.is_zero(), Error::<T>::NoReporterBounty);
+
+            T::Currency::transfer(
+                &T::ReporterBountySource::get(),
+                &reporter,
+                amount,
+                Preservation::Preserve,
+            )?;
+
+            Self::deposit_event(Event::<T>::ReporterBountyClaimed { reporter, amount });
+
+            Ok(())
+        }
+
+        /// Change the slashing mode at the start of `era`, replacing any change already
+        /// scheduled for it.
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::WeightInfo::schedule_slashing_mode())]
+        pub fn schedule_slashing_mode(
+            origin: OriginFor<T>,
+            mode: SlashingModeOption,
+            era: EraIndex,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            let active_era = T::EraIndexProvider::active_era().index;
+            ensure!(era > active_era, Error::<T>::NotFutureEra);
+
+            ScheduledSlashingModes::<T>::insert(era, &mode);
+
+            Self::deposit_event(Event::<T>::SlashingModeScheduled { mode, era });
+
+            Ok(())
+        }
+
+        /// Apply a slash that was only logged because of the `LogOnly` mode.
+        ///
+        /// The slash is computed as an offence of its era, so it is only enforced if larger than
+        /// the slash of the validator in that era, and is dropped otherwise. It goes through the
+        /// defer period from the offence era, or is applied at the next era if it is already over.
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::promote_logged_slash())]
+        pub fn promote_logged_slash(
+            origin: OriginFor<T>,
+            slash_era: EraIndex,
+            index: u32,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            let mut logged_slashes = LoggedSlashes::<T>::get(slash_era);
+            ensure!(
+                (index as usize) < logged_slashes.len(),
+                Error::<T>::InvalidLoggedSlashIndex
+            );
+            let logged = logged_slashes.remove(index as usize);
+            LoggedSlashes::<T>::insert(slash_era, logged_slashes);
+            let slash_id = logged.slash_id;
+
+            let Some(mut slash) = compute_slash::<T>(
+                logged.percentage,
+                slash_id,
+                logged.offence_era,
+                logged.validator,
+                T::SlashDeferDuration::get(),
+            ) else {
+                Self::deposit_event(Event::<T>::LoggedSlashSuperseded { slash_id });
+                return Ok(());
+            };
+            slash.reporters = logged.reporters;
+
+            let active_era = T::EraIndexProvider::active_era().index;
+            let era_to_consider = slash_era
+                .saturating_add(T::SlashDeferDuration::get())
+                .saturating_add(One::one())
+                .max(active_era.saturating_add(One::one()));
+
+            Slashes::<T>::mutate(era_to_consider, |era_slashes| era_slashes.push(slash));
+
+            Self::deposit_event(Event::<T>::LoggedSlashPromoted {
+                slash_id,
+                era: era_to_consider,
+            });
+
+            Ok(())
+        }
+
+        /// Queue again a slash that ran out of delivery attempts, with a fresh set of attempts.
+        #[pallet::call_index(8)]
+        #[pallet::weight(T::WeightInfo::retry_slash_delivery())]
+        pub fn retry_slash_delivery(origin: OriginFor<T>, slash_id: T::SlashId) -> DispatchResult {
+            ensure_root(origin)?;
+
+            let slash = FailedSlashes::<T>::take(slash_id).ok_or(Error::<T>::SlashNotFailed)?;
+
+            SlashDeliveries::<T>::mutate(slash_id, |maybe_delivery| {
+                if let Some(delivery) = maybe_delivery {
+                    delivery.status = SlashDeliveryStatus::Queued;
+                    delivery.attempts = 0;
+                }
+            });
+            UnreportedSlashesQueue::<T>::mutate(|queue| queue.push_back(slash));
+
+            Self::deposit_event(Event::<T>::SlashDeliveryRetried { slash_id });
 
             Ok(())
         }
@@ -362,13 +791,31 @@ pub mod pallet {
 
     #[pallet::hooks]
     impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
-        fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+        fn on_initialize(n: BlockNumberFor<T>) -> Weight {
+            let timed_out = Self::fail_timed_out_slashes(n);
+            let retried = Self::queue_slash_retries(n);
             let processed = Self::process_slashes_queue(T::QueuedSlashesProcessedPerBlock::get());
 
+            // A timed out slash is checked, then may be scheduled for a retry or set aside
+            let timed_out = u64::from(timed_out);
+            let weight = T::DbWeight::get()
+                .reads_writes(2, 3)
+                .saturating_add(
+                    T::DbWeight::get()
+                        .reads_writes(timed_out.saturating_mul(3), timed_out.saturating_mul(3)),
+                )
+                .saturating_add(T::DbWeight::get().writes(retried.into()));
+
             if let Some(p) = processed {
-                T::WeightInfo::process_slashes_queue(p)
+                // The delivery notifications of the message are paid for upfront, and its
+                // slashes are scheduled for the timeout
+                weight
+                    .saturating_add(T::DbWeight::get().reads_writes(1, 1))
+                    .saturating_add(T::WeightInfo::process_slashes_queue(p))
+                    .saturating_add(T::WeightInfo::on_message_accepted())
+                    .saturating_add(T::WeightInfo::on_message_delivered(p))
             } else {
-                T::WeightInfo::process_slashes_queue(0)
+                weight.saturating_add(T::WeightInfo::process_slashes_queue(0))
             }
         }
     }
@@ -402,13 +849,6 @@ where
             consumed_weight += T::DbWeight::get().reads_writes(reads, writes);
         };
 
-        let slashing_mode = SlashingMode::<T>::get();
-        add_db_reads_writes(1, 0);
-
-        if slashing_mode == SlashingModeOption::Disabled {
-            return consumed_weight;
-        }
-
         let active_era = { T::EraIndexProvider::active_era().index };
         let active_era_start_session_index = T::EraIndexProvider::era_to_session_start(active_era)
             .unwrap_or_else(|| {
@@ -441,6 +881,14 @@ where
             }
         };
 
+        // Offences are handled according to the mode of the era they happened in
+        let slashing_mode = Self::slashing_mode_at(slash_era);
+        add_db_reads_writes(2, 0);
+
+        if slashing_mode == SlashingModeOption::Disabled {
+            return consumed_weight;
+        }
+
         let slash_defer_duration = T::SlashDeferDuration::get();
         add_db_reads_writes(1, 0);
 
@@ -464,7 +912,23 @@ where
                 slash_era,
             });
 
+            // Logged slashes are not slashes in era: a later enforced offence must still slash
             if slashing_mode == SlashingModeOption::LogOnly {
+                let slash = Slash {
+                    validator: stash.clone(),
+                    reporters: details.reporters.clone(),
+                    slash_id: next_slash_id,
+                    percentage: *slash_fraction,
+                    offence_era: slash_era,
+                    confirmed: slash_defer_duration.is_zero(),
+                };
+                Self::deposit_event(Event::<T>::SlashLogged {
+                    slash_id: next_slash_id,
+                    slash_era,
+                });
+                LoggedSlashes::<T>::mutate(slash_era, move |logged| logged.push(slash));
+                add_db_reads_writes(1, 1);
+                next_slash_id = next_slash_id.saturating_add(One::one());
                 continue;
             }
 
@@ -554,6 +1018,19 @@ where
                         );
                     }
                     Slashes::<T>::remove(pruned_era);
+                    ReporterBountiesInEra::<T>::remove(pruned_era);
+                    SlashingModes::<T>::remove(pruned_era);
+                    LoggedSlashes::<T>::remove(pruned_era);
+                    for slash_id in ConfirmedSlashes::<T>::take(pruned_era) {
+                        SlashDeliveries::<T>::remove(slash_id);
+                    }
+                    // Slashes queued again by governance in the meantime are kept
+                    for slash_id in FailedSlashesByEra::<T>::take(pruned_era) {
+                        if FailedSlashes::<T>::take(slash_id).is_some() {
+                            SlashDeliveries::<T>::remove(slash_id);
+                            Self::deposit_event(Event::<T>::FailedSlashDropped { slash_id });
+                        }
+                    }
                 }
 
                 if let Some(&(_, first_session, _)) = bonded.first() {
@@ -562,16 +1039,49 @@ where
             }
         });
 
+        Self::apply_scheduled_slashing_mode(era_index);
         Self::add_era_slashes_to_queue(era_index);
     }
 }
 
 impl<T: Config> Pallet<T> {
+    /// Slashing mode of `era`, or the current one if it was not recorded.
+    pub fn slashing_mode_at(era: EraIndex) -> SlashingModeOption {
+        SlashingModes::<T>::get(era).unwrap_or_else(SlashingMode::<T>::get)
+    }
+
+    /// Switch to the mode scheduled for `era`, if any, and record the mode of `era`.
+    fn apply_scheduled_slashing_mode(era: EraIndex) {
+        let mode = match ScheduledSlashingModes::<T>::take(era) {
+            Some(mode) => {
+                SlashingMode::<T>::put(&mode);
+                Self::deposit_event(Event::<T>::SlashingModeChanged {
+                    mode: mode.clone(),
+                    era,
+                });
+                mode
+            }
+            None => SlashingMode::<T>::get(),
+        };
+
+        SlashingModes::<T>::insert(era, mode);
+    }
+
     fn add_era_slashes_to_queue(active_era: EraIndex) {
         let mut slashes: VecDeque<_> = Slashes::<T>::get(active_era).into();
 
         let len = slashes.len();
 
+        for slash in slashes.iter() {
+            SlashDeliveries::<T>::insert(
+                slash.slash_id,
+                SlashDelivery {
+                    status: SlashDeliveryStatus::Queued,
+                    attempts: 0,
+                    first_sent_at: None,
+                },
+            );
+        }
         UnreportedSlashesQueue::<T>::mutate(|queue| queue.append(&mut slashes));
 
         if len > 0 {
@@ -585,6 +1095,7 @@ impl<T: Config> Pallet<T> {
     /// Returns number of slashes that were sent to ethereum.
     fn process_slashes_queue(amount: u32) -> Option<u32> {
         let mut slashes_to_send: Vec<SlashData<T::AccountId>> = vec![];
+        let mut sent_slashes = vec![];
         let era_index = T::EraIndexProvider::active_era().index;
 
         UnreportedSlashesQueue::<T>::mutate(|queue| {
@@ -595,9 +1106,10 @@ impl<T: Config> Pallet<T> {
                 };
 
                 slashes_to_send.push(SlashData {
-                    validator: slash.validator,
+                    validator: slash.validator.clone(),
                     wad_to_slash: u128::from_str_radix("10000000000000000", 10).unwrap(), // TODO: need to compute how much we slash (for now it is 1e16)
                 });
+                sent_slashes.push(slash);
             }
         });
 
@@ -607,7 +1119,41 @@ impl<T: Config> Pallet<T> {
 
         let slashes_count = slashes_to_send.len() as u32;
 
-        let outbound = match T::SendMessage::build(&slashes_to_send, era_index) {
+        let Some(message_id) = Self::send_slashes(&slashes_to_send, era_index) else {
+            for slash in sent_slashes {
+                Self::record_attempt(
+                    slash.slash_id,
+                    SlashDeliveryStatus::Failed { retry_at: None },
+                );
+                Self::schedule_retry(slash);
+            }
+            return Some(slashes_count);
+        };
+
+        Self::deposit_event(Event::<T>::SlashesMessageSent { message_id });
+
+        let mut sent = Vec::with_capacity(sent_slashes.len());
+        for slash in sent_slashes {
+            let (first_sent, attempts) =
+                Self::record_attempt(slash.slash_id, SlashDeliveryStatus::Sent { message_id });
+            // Bounties are earned once, even if the slash has to be sent again
+            if first_sent {
+                Self::accrue_reporter_bounty(era_index, &slash);
+            }
+            sent.push((slash, attempts));
+        }
+        SentSlashMessages::<T>::append(message_id, sent.clone());
+
+        let timeout_at = frame_system::Pallet::<T>::block_number()
+            .saturating_add(T::SlashDeliveryTimeout::get());
+        SlashDeliveryTimeouts::<T>::mutate(timeout_at, |timeouts| timeouts.extend(sent));
+
+        Some(slashes_count)
+    }
+
+    /// Builds, validates and delivers a slashes message, returning its id.
+    fn send_slashes(slashes: &Vec<SlashData<T::AccountId>>, era_index: EraIndex) -> Option<H256> {
+        let outbound = match T::SendMessage::build(slashes, era_index) {
             Some(send_msg) => send_msg,
             None => {
                 log::error!(target: "ext_validators_slashes", "Failed to build outbound message");
@@ -626,7 +1172,7 @@ impl<T: Config> Pallet<T> {
             })
             .ok()?;
 
-        let message_id = T::SendMessage::deliver(ticket)
+        T::SendMessage::deliver(ticket)
             .map_err(|e| {
                 log::error!(
                     target: "ext_validators_slashes",
@@ -634,11 +1180,248 @@ impl<T: Config> Pallet<T> {
                     e
                 );
             })
-            .ok()?;
+            .ok()
+    }
 
-        Self::deposit_event(Event::<T>::SlashesMessageSent { message_id });
+    /// Counts a delivery attempt of a slash, returning whether it is the first time it is sent
+    /// and its number of attempts.
+    fn record_attempt(
+        slash_id: T::SlashId,
+        status: SlashDeliveryStatus<BlockNumberFor<T>>,
+    ) -> (bool, u32) {
+        let now = frame_system::Pallet::<T>::block_number();
+        let sent = matches!(status, SlashDeliveryStatus::Sent { .. });
+
+        let mut delivery = SlashDeliveries::<T>::get(slash_id).unwrap_or(SlashDelivery {
+            status: SlashDeliveryStatus::Queued,
+            attempts: 0,
+            first_sent_at: None,
+        });
+        let first_sent = sent && delivery.first_sent_at.is_none();
+        if first_sent {
+            delivery.first_sent_at = Some(now);
+        }
+        delivery.status = status;
+        delivery.attempts = delivery.attempts.saturating_add(1);
+        let attempts = delivery.attempts;
+        SlashDeliveries::<T>::insert(slash_id, delivery);
 
-        Some(slashes_count)
+        (first_sent, attempts)
+    }
+
+    /// Queues a failed slash again after a delay growing with its attempts, or sets it aside
+    /// once it ran out of attempts.
+    fn schedule_retry(slash: Slash<T::AccountId, T::SlashId>) {
+        let slash_id = slash.slash_id;
+        let attempts = SlashDeliveries::<T>::get(slash_id)
+            .map(|delivery| delivery.attempts)
+            .unwrap_or_default();
+
+        let retry_at = if attempts < T::MaxSlashDeliveryAttempts::get() {
+            let backoff = 2u32.saturating_pow(attempts.saturating_sub(1));
+            let retry_at = frame_system::Pallet::<T>::block_number()
+                .saturating_add(T::SlashRetryDelay::get().saturating_mul(backoff.into()));
+            SlashRetries::<T>::append(retry_at, slash);
+            Some(retry_at)
+        } else {
+            // Kept at least for the bonding period, even if the offence era left it already
+            let bonded_era = T::EraIndexProvider::active_era()
+                .index
+                .saturating_sub(T::BondingDuration::get());
+            FailedSlashesByEra::<T>::append(slash.offence_era.max(bonded_era), slash_id);
+            FailedSlashes::<T>::insert(slash_id, slash);
+            None
+        };
+
+        SlashDeliveries::<T>::mutate(slash_id, |maybe_delivery| {
+            if let Some(delivery) = maybe_delivery {
+                delivery.status = SlashDeliveryStatus::Failed { retry_at };
+            }
+        });
+
+        Self::deposit_event(Event::<T>::SlashDeliveryFailed {
+            slash_id,
+            attempts,
+            retry_at,
+        });
+    }
+
+    /// Fails the slashes sent `SlashDeliveryTimeout` blocks before `n` that are still waiting
+    /// for their delivery receipt, returning how many slashes were checked.
+    fn fail_timed_out_slashes(n: BlockNumberFor<T>) -> u32 {
+        let sent = SlashDeliveryTimeouts::<T>::take(n);
+        let count = sent.len() as u32;
+
+        for (slash, attempts) in sent {
+            if Self::is_waiting_for_receipt(&slash, attempts) {
+                Self::schedule_retry(slash);
+            }
+        }
+
+        count
+    }
+
+    /// Whether the slash sent at its attempt `attempts` is still waiting for its delivery
+    /// receipt: not confirmed nor failed since, and not sent again.
+    fn is_waiting_for_receipt(slash: &Slash<T::AccountId, T::SlashId>, attempts: u32) -> bool {
+        SlashDeliveries::<T>::get(slash.slash_id).is_some_and(|delivery| {
+            delivery.attempts == attempts
+                && matches!(delivery.status, SlashDeliveryStatus::Sent { .. })
+        })
+    }
+
+    /// Cancels the pending retry of a slash confirmed by a late receipt, returning whether it was
+    /// not sent again yet.
+    fn cancel_retry(
+        slash: &Slash<T::AccountId, T::SlashId>,
+        status: &SlashDeliveryStatus<BlockNumberFor<T>>,
+    ) -> bool {
+        let slash_id = slash.slash_id;
+        match status {
+            SlashDeliveryStatus::Failed {
+                retry_at: Some(retry_at),
+            } => SlashRetries::<T>::mutate(retry_at, |slashes| {
+                slashes.retain(|s| s.slash_id != slash_id)
+            }),
+            SlashDeliveryStatus::Failed { retry_at: None } => {
+                FailedSlashes::<T>::remove(slash_id);
+            }
+            SlashDeliveryStatus::Queued => UnreportedSlashesQueue::<T>::mutate(|queue| {
+                queue.retain(|s| s.slash_id != slash_id)
+            }),
+            SlashDeliveryStatus::Sent { .. } | SlashDeliveryStatus::Confirmed { .. } => {
+                return false
+            }
+        }
+        true
+    }
+
+    /// Moves the slashes to retry at block `n` to the front of the queue, returning how many
+    /// there were.
+    fn queue_slash_retries(n: BlockNumberFor<T>) -> u32 {
+        let slashes = SlashRetries::<T>::take(n);
+        if slashes.is_empty() {
+            return 0;
+        }
+
+        let count = slashes.len() as u32;
+        UnreportedSlashesQueue::<T>::mutate(|queue| {
+            for slash in slashes.into_iter().rev() {
+                SlashDeliveries::<T>::mutate(slash.slash_id, |maybe_delivery| {
+                    if let Some(delivery) = maybe_delivery {
+                        delivery.status = SlashDeliveryStatus::Queued;
+                    }
+                });
+                queue.push_front(slash);
+            }
+        });
+
+        count
+    }
+
+    /// Delivery state of the slashes that are not confirmed yet.
+    pub fn pending_slash_deliveries() -> Vec<(T::SlashId, SlashDelivery<BlockNumberFor<T>>)> {
+        SlashDeliveries::<T>::iter()
+            .filter(|(_, delivery)| {
+                !matches!(delivery.status, SlashDeliveryStatus::Confirmed { .. })
+            })
+            .collect()
+    }
+
+    /// Splits the bounty of a sent slash equally among its reporters, within the era cap.
+    fn accrue_reporter_bounty(era_index: EraIndex, slash: &Slash<T::AccountId, T::SlashId>) {
+        if slash.reporters.is_empty() {
+            return;
+        }
+
+        let bounty = match ReporterBounty::<T>::get() {
+            ReporterBountyMode::Disabled => return,
+            ReporterBountyMode::Fixed(amount) => amount,
+            ReporterBountyMode::FractionOfSlash {
+                fraction,
+                reference_stake,
+            } => fraction.mul_floor(slash.percentage.mul_floor(reference_stake)),
+        };
+
+        let accrued_in_era = ReporterBountiesInEra::<T>::get(era_index);
+        let bounty = bounty.min(ReporterBountyEraCap::<T>::get().saturating_sub(accrued_in_era));
+
+        let reporters_count = BalanceOf::<T>::from(slash.reporters.len() as u32);
+        let per_reporter = bounty / reporters_count;
+        if per_reporter.is_zero() {
+            return;
+        }
+
+        for reporter in slash.reporters.iter() {
+            PendingReporterBounties::<T>::mutate(reporter, |pending| {
+                *pending = pending.saturating_add(per_reporter)
+            });
+            Self::deposit_event(Event::<T>::ReporterBountyAccrued {
+                reporter: reporter.clone(),
+                slash_id: slash.slash_id,
+                amount: per_reporter,
+            });
+        }
+
+        ReporterBountiesInEra::<T>::insert(
+            era_index,
+            accrued_in_era.saturating_add(per_reporter.saturating_mul(reporters_count)),
+        );
+    }
+}
+
+impl<T: Config> MessageDeliveryHandler for Pallet<T> {
+    fn on_message_accepted(id: H256, nonce: u64) {
+        SentSlashMessages::<T>::mutate_exists(id, |maybe_messages| {
+            let Some(messages) = maybe_messages else {
+                return;
+            };
+            if !messages.is_empty() {
+                AcceptedSlashMessages::<T>::insert(nonce, (id, messages.remove(0)));
+            }
+            if messages.is_empty() {
+                *maybe_messages = None;
+            }
+        });
+    }
+
+    fn on_message_delivered(nonce: u64, success: bool) {
+        let Some((message_id, slashes)) = AcceptedSlashMessages::<T>::take(nonce) else {
+            return;
+        };
+
+        if success {
+            let active_era = T::EraIndexProvider::active_era().index;
+            let slash_ids: Vec<T::SlashId> =
+                slashes.iter().map(|(slash, _)| slash.slash_id).collect();
+            for (slash, attempts) in slashes.iter() {
+                SlashDeliveries::<T>::mutate(slash.slash_id, |maybe_delivery| {
+                    if let Some(delivery) = maybe_delivery {
+                        // Timed out in the meantime
+                        let timed_out = delivery.attempts != *attempts
+                            || !matches!(delivery.status, SlashDeliveryStatus::Sent { .. });
+                        if timed_out && !Self::cancel_retry(slash, &delivery.status) {
+                            log::warn!(
+                                target: "ext_validators_slashes",
+                                "Slash {:?} confirmed after being sent again",
+                                slash.slash_id
+                            );
+                        }
+                        delivery.status = SlashDeliveryStatus::Confirmed { message_id };
+                    }
+                });
+            }
+            ConfirmedSlashes::<T>::mutate(active_era, |confirmed| confirmed.extend(slash_ids));
+
+            Self::deposit_event(Event::<T>::SlashesConfirmed { message_id, nonce });
+        } else {
+            for (slash, attempts) in slashes {
+                // Unless it already failed on timeout
+                if Self::is_waiting_for_receipt(&slash, attempts) {
+                    Self::schedule_retry(slash);
+                }
+            }
+        }
     }
 }
 
@@ -653,6 +1436,8 @@ pub struct Slash<AccountId, SlashId> {
     /// The amount of payout.
     pub slash_id: SlashId,
     pub percentage: Perbill,
+    /// The era the offence was committed in.
+    pub offence_era: EraIndex,
     // Whether the slash is confirmed or still needs to go through deferred period
     pub confirmed: bool,
 }
@@ -665,6 +1450,7 @@ impl<AccountId, SlashId: One> Slash<AccountId, SlashId> {
             reporters: vec![],
             slash_id: One::one(),
             percentage: Perbill::from_percent(50),
+            offence_era: Zero::zero(),
             confirmed: false,
         }
     }
@@ -706,6 +1492,7 @@ pub(crate) fn compute_slash<T: Config>(
         percentage: slash_fraction,
         slash_id,
         reporters: Vec::new(),
+        offence_era: slash_era,
         confirmed,
     })
 }
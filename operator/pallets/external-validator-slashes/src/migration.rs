@@ -0,0 +1,132 @@
+// Copyright (C) Moondance Labs Ltd.
+// This file is part of Tanssi.
+
+// Tanssi is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Tanssi is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Tanssi.  If not, see <http://www.gnu.org/licenses/>
+
+//! Storage migrations of the external validator slashes pallet.
+
+use super::*;
+use frame_support::{migrations::VersionedMigration, traits::UncheckedOnRuntimeUpgrade};
+
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+
+const LOG_TARGET: &str = "ext_validators_slashes::migration";
+
+/// The in-code storage version.
+pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
+
+pub mod v1 {
+    use super::*;
+
+    /// A slash before the era of its offence was recorded.
+    #[derive(Encode, Decode)]
+    pub struct OldSlash<AccountId, SlashId> {
+        pub validator: AccountId,
+        pub reporters: Vec<AccountId>,
+        pub slash_id: SlashId,
+        pub percentage: Perbill,
+        pub confirmed: bool,
+    }
+
+    impl<AccountId, SlashId> OldSlash<AccountId, SlashId> {
+        fn migrate(self, offence_era: EraIndex) -> Slash<AccountId, SlashId> {
+            Slash {
+                validator: self.validator,
+                reporters: self.reporters,
+                slash_id: self.slash_id,
+                percentage: self.percentage,
+                offence_era,
+                confirmed: self.confirmed,
+            }
+        }
+    }
+
+    /// Records the era of the offence on the stored slashes.
+    ///
+    /// Slashes are applied `SlashDeferDuration + 1` eras after their offence, which gives the
+    /// offence era of the deferred slashes. Queued slashes can no longer be cancelled, so theirs
+    /// is derived from the active era.
+    pub struct UncheckedMigrateToV1<T>(PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for UncheckedMigrateToV1<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let defer_eras = T::SlashDeferDuration::get().saturating_add(One::one());
+
+            let mut translated = 0u64;
+            Slashes::<T>::translate::<Vec<OldSlash<T::AccountId, T::SlashId>>, _>(
+                |era, slashes| {
+                    translated.saturating_inc();
+                    let offence_era = era.saturating_sub(defer_eras);
+                    Some(
+                        slashes
+                            .into_iter()
+                            .map(|s| s.migrate(offence_era))
+                            .collect(),
+                    )
+                },
+            );
+
+            let offence_era = T::EraIndexProvider::active_era()
+                .index
+                .saturating_sub(defer_eras);
+            let _ = UnreportedSlashesQueue::<T>::translate::<
+                VecDeque<OldSlash<T::AccountId, T::SlashId>>,
+                _,
+            >(|queue| {
+                queue.map(|queue| queue.into_iter().map(|s| s.migrate(offence_era)).collect())
+            });
+
+            log::info!(
+                target: LOG_TARGET,
+                "Recorded the offence era of slashes in {} eras",
+                translated
+            );
+
+            T::DbWeight::get()
+                .reads_writes(translated.saturating_add(2), translated.saturating_add(1))
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+            let slashes = Slashes::<T>::iter_keys().count() as u32;
+            let queued = UnreportedSlashesQueue::<T>::decode_len().unwrap_or_default() as u32;
+            Ok((slashes, queued).encode())
+        }
+
+        #[cfg(feature = "try-runtime")]
+        fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+            let (slashes, queued) = <(u32, u32)>::decode(&mut &state[..])
+                .map_err(|_| TryRuntimeError::Other("Invalid pre-upgrade state"))?;
+            frame_support::ensure!(
+                Slashes::<T>::iter().count() as u32 == slashes,
+                "Slashes were lost"
+            );
+            frame_support::ensure!(
+                UnreportedSlashesQueue::<T>::get().len() as u32 == queued,
+                "Queued slashes were lost"
+            );
+            Ok(())
+        }
+    }
+
+    /// [`UncheckedMigrateToV1`] run only from storage version 0.
+    pub type MigrateToV1<T> = VersionedMigration<
+        0,
+        1,
+        UncheckedMigrateToV1<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
@@ -20,12 +20,13 @@ use pallet_external_validators::traits::EraIndex;
 use pallet_external_validators::traits::EraIndexProvider;
 use pallet_external_validators::traits::ExternalIndexProvider;
 use pallet_external_validators::traits::InvulnerablesProvider;
+use pallet_external_validators::traits::OnSlashCancelled;
 use {
     crate as external_validator_slashes,
     core::cell::RefCell,
     frame_support::{
         parameter_types,
-        traits::{ConstU16, ConstU32, ConstU64, Get},
+        traits::{ConstU128, ConstU16, ConstU32, ConstU64, Get},
         weights::constants::RocksDbWeight,
     },
     frame_system as system,
@@ -50,6 +51,7 @@ frame_support::construct_runtime!(
         Historical: pallet_session::historical,
         ExternalValidatorSlashes: external_validator_slashes,
         Timestamp: pallet_timestamp,
+        Balances: pallet_balances,
     }
 );
 
@@ -77,7 +79,7 @@ impl system::Config for Test {
     type BlockHashCount = ConstU64<250>;
     type Version = ();
     type PalletInfo = PalletInfo;
-    type AccountData = ();
+    type AccountData = pallet_balances::AccountData<u128>;
     type OnNewAccount = ();
     type OnKilledAccount = ();
     type SystemWeightInfo = ();
@@ -93,6 +95,23 @@ impl system::Config for Test {
     type ExtensionsWeightInfo = ();
 }
 
+impl pallet_balances::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = ();
+    type Balance = u128;
+    type DustRemoval = ();
+    type ExistentialDeposit = ConstU128<1>;
+    type AccountStore = System;
+    type ReserveIdentifier = [u8; 8];
+    type RuntimeHoldReason = ();
+    type RuntimeFreezeReason = ();
+    type FreezeIdentifier = ();
+    type MaxLocks = ();
+    type MaxReserves = ();
+    type MaxFreezes = ConstU32<0>;
+    type DoneSlashHandler = ();
+}
+
 parameter_types! {
     pub static Validators: Option<Vec<u64>> = Some(vec![
         1,
@@ -132,6 +151,8 @@ thread_local! {
     pub static ERA_INDEX: RefCell<EraIndex> = const { RefCell::new(0) };
     pub static DEFER_PERIOD: RefCell<EraIndex> = const { RefCell::new(2) };
     pub static SENT_ETHEREUM_MESSAGE_NONCE: RefCell<u64> = const { RefCell::new(0) };
+    pub static CANCELLED_SLASHES: RefCell<Vec<(EraIndex, u64)>> = const { RefCell::new(vec![]) };
+    pub static DELIVERY_FAILS: RefCell<bool> = const { RefCell::new(false) };
 
 }
 
@@ -214,6 +235,19 @@ impl DeferPeriodGetter {
     }
 }
 
+pub struct MockOnSlashCancelled;
+impl OnSlashCancelled<AccountId> for MockOnSlashCancelled {
+    fn on_slash_cancelled(slash_era: EraIndex, validator: &AccountId) {
+        CANCELLED_SLASHES.with(|r| r.borrow_mut().push((slash_era, *validator)));
+    }
+}
+
+impl MockOnSlashCancelled {
+    pub fn cancelled() -> Vec<(EraIndex, u64)> {
+        CANCELLED_SLASHES.with(|r| r.borrow().clone())
+    }
+}
+
 pub struct MockOkOutboundQueue;
 impl crate::SendMessage<AccountId> for MockOkOutboundQueue {
     type Ticket = ();
@@ -225,10 +259,19 @@ impl crate::SendMessage<AccountId> for MockOkOutboundQueue {
         Ok(())
     }
     fn deliver(_: Self::Ticket) -> Result<H256, SendError> {
+        if DELIVERY_FAILS.with(|r| *r.borrow()) {
+            return Err(SendError::Halted);
+        }
         Ok(H256::zero())
     }
 }
 
+impl MockOkOutboundQueue {
+    pub fn with_delivery_failing(fails: bool) {
+        DELIVERY_FAILS.with(|r| *r.borrow_mut() = fails);
+    }
+}
+
 impl SendMessageFeeProvider for MockOkOutboundQueue {
     type Balance = u128;
 
@@ -246,8 +289,13 @@ impl ExternalIndexProvider for TimestampProvider {
 
 parameter_types! {
     pub const BondingDuration: u32 = 5u32;
+    pub const ReporterBountySource: AccountId = BOUNTY_SOURCE;
+    pub const SlashRetryDelay: u64 = 2;
+    pub const SlashDeliveryTimeout: u64 = 20;
 }
 
+pub const BOUNTY_SOURCE: AccountId = 1000;
+
 impl external_validator_slashes::Config for Test {
     type RuntimeEvent = RuntimeEvent;
     type ValidatorId = <Self as frame_system::Config>::AccountId;
@@ -259,6 +307,12 @@ impl external_validator_slashes::Config for Test {
     type InvulnerablesProvider = MockInvulnerableProvider;
     type ExternalIndexProvider = TimestampProvider;
     type QueuedSlashesProcessedPerBlock = ConstU32<20>;
+    type Currency = Balances;
+    type ReporterBountySource = ReporterBountySource;
+    type OnSlashCancelled = MockOnSlashCancelled;
+    type SlashRetryDelay = SlashRetryDelay;
+    type MaxSlashDeliveryAttempts = ConstU32<3>;
+    type SlashDeliveryTimeout = SlashDeliveryTimeout;
     type WeightInfo = ();
     type SendMessage = MockOkOutboundQueue;
 }
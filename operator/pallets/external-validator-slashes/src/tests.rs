@@ -18,12 +18,13 @@ use {
     super::*,
     crate::{
         mock::{
-            new_test_ext, run_block, DeferPeriodGetter, ExternalValidatorSlashes,
-            MockEraIndexProvider, RuntimeEvent, RuntimeOrigin, System, Test,
+            new_test_ext, run_block, run_to_block, Balances, DeferPeriodGetter,
+            ExternalValidatorSlashes, MockEraIndexProvider, MockOkOutboundQueue,
+            MockOnSlashCancelled, RuntimeEvent, RuntimeOrigin, System, Test, BOUNTY_SOURCE,
         },
         Slash,
     },
-    frame_support::{assert_noop, assert_ok},
+    frame_support::{assert_noop, assert_ok, traits::fungible::Inspect},
 };
 
 #[test]
@@ -41,6 +42,7 @@ fn root_can_inject_manual_offence() {
             vec![Slash {
                 validator: 1,
                 percentage: Perbill::from_percent(75),
+                offence_era: 0,
                 confirmed: false,
                 reporters: vec![],
                 slash_id: 0
@@ -100,6 +102,46 @@ fn root_can_cancel_deferred_slash() {
         ));
 
         assert_eq!(Slashes::<Test>::get(get_slashing_era(0)), vec![]);
+        // Offence era is notified, not the era the slash was deferred to
+        assert_eq!(MockOnSlashCancelled::cancelled(), vec![(0, 1)]);
+    });
+}
+
+#[test]
+fn cancelling_a_slash_keeps_the_penalty_of_the_slashes_left() {
+    new_test_ext().execute_with(|| {
+        start_era(1, 0, 1);
+        for percentage in [50, 75] {
+            assert_ok!(ExternalValidatorSlashes::force_inject_slash(
+                RuntimeOrigin::root(),
+                0,
+                1u64,
+                Perbill::from_percent(percentage)
+            ));
+        }
+        assert_eq!(
+            ValidatorSlashInEra::<Test>::get(0, 1),
+            Some(Perbill::from_percent(75))
+        );
+
+        assert_ok!(ExternalValidatorSlashes::cancel_deferred_slash(
+            RuntimeOrigin::root(),
+            3,
+            vec![1]
+        ));
+        assert_eq!(
+            ValidatorSlashInEra::<Test>::get(0, 1),
+            Some(Perbill::from_percent(50))
+        );
+
+        assert_ok!(ExternalValidatorSlashes::cancel_deferred_slash(
+            RuntimeOrigin::root(),
+            3,
+            vec![0]
+        ));
+        assert_eq!(ValidatorSlashInEra::<Test>::get(0, 1), None);
+        assert_eq!(Slashes::<Test>::get(get_slashing_era(0)), vec![]);
+        assert_eq!(MockOnSlashCancelled::cancelled(), vec![(0, 1), (0, 1)]);
     });
 }
 
@@ -204,6 +246,7 @@ fn test_after_bonding_period_we_can_remove_slashes() {
             vec![Slash {
                 validator: 1,
                 percentage: Perbill::from_percent(75),
+                offence_era: 0,
                 confirmed: false,
                 reporters: vec![],
                 slash_id: 0
@@ -240,6 +283,7 @@ fn test_on_offence_injects_offences() {
             vec![Slash {
                 validator: 3,
                 percentage: Perbill::from_percent(75),
+                offence_era: 0,
                 confirmed: false,
                 reporters: vec![],
                 slash_id: 0
@@ -276,6 +320,7 @@ fn test_on_offence_does_not_work_if_slashing_disabled() {
             RuntimeOrigin::root(),
             SlashingModeOption::Disabled,
         ));
+        // The mode applies to offences of the active era
         let weight = Pallet::<Test>::on_offence(
             &[OffenceDetails {
                 // 1 and 2 are invulnerables
@@ -283,11 +328,11 @@ fn test_on_offence_does_not_work_if_slashing_disabled() {
                 reporters: vec![],
             }],
             &[Perbill::from_percent(75)],
-            0,
+            1,
         );
 
         // on_offence didn't do anything
-        assert_eq!(Slashes::<Test>::get(get_slashing_era(0)), vec![]);
+        assert_eq!(Slashes::<Test>::get(get_slashing_era(1)), vec![]);
 
         // Weight is not zero
         assert_ne!(weight, Weight::default());
@@ -310,6 +355,7 @@ fn defer_period_of_zero_confirms_immediately_slashes() {
             vec![Slash {
                 validator: 1,
                 percentage: Perbill::from_percent(75),
+                offence_era: 0,
                 confirmed: true,
                 reporters: vec![],
                 slash_id: 0
@@ -357,6 +403,7 @@ fn test_on_offence_defer_period_0() {
             vec![Slash {
                 validator: 3,
                 percentage: Perbill::from_percent(75),
+                offence_era: 1,
                 confirmed: true,
                 reporters: vec![],
                 slash_id: 0
@@ -389,6 +436,7 @@ fn test_slashes_command_matches_event() {
             vec![Slash {
                 validator: 3,
                 percentage: Perbill::from_percent(75),
+                offence_era: 1,
                 confirmed: true,
                 reporters: vec![],
                 slash_id: 0
@@ -512,6 +560,698 @@ fn test_on_offence_defer_period_0_messages_get_queued_across_eras() {
     });
 }
 
+#[test]
+fn reporters_can_claim_bounty_once_slash_is_sent() {
+    new_test_ext().execute_with(|| {
+        crate::mock::DeferPeriodGetter::with_defer_period(0);
+        Balances::set_balance(&BOUNTY_SOURCE, 1_000);
+        assert_ok!(ExternalValidatorSlashes::set_reporter_bounty(
+            RuntimeOrigin::root(),
+            ReporterBountyMode::Fixed(100),
+            1_000,
+        ));
+        start_era(0, 0, 0);
+        start_era(1, 1, 1);
+        Pallet::<Test>::on_offence(
+            &[OffenceDetails {
+                offender: (3, ()),
+                reporters: vec![10, 11],
+            }],
+            &[Perbill::from_percent(75)],
+            1,
+        );
+
+        // Nothing is owed until the slash is sent
+        assert_eq!(PendingReporterBounties::<Test>::get(10), 0);
+        start_era(2, 2, 2);
+        run_block();
+
+        assert_eq!(PendingReporterBounties::<Test>::get(10), 50);
+        assert_eq!(PendingReporterBounties::<Test>::get(11), 50);
+        assert_eq!(ReporterBountiesInEra::<Test>::get(2), 100);
+        System::assert_has_event(RuntimeEvent::ExternalValidatorSlashes(
+            crate::Event::ReporterBountyAccrued {
+                reporter: 11,
+                slash_id: 0,
+                amount: 50,
+            },
+        ));
+
+        assert_ok!(ExternalValidatorSlashes::claim_reporter_bounty(
+            RuntimeOrigin::signed(10)
+        ));
+        assert_eq!(Balances::balance(&10), 50);
+        assert_eq!(Balances::balance(&BOUNTY_SOURCE), 950);
+        System::assert_last_event(RuntimeEvent::ExternalValidatorSlashes(
+            crate::Event::ReporterBountyClaimed {
+                reporter: 10,
+                amount: 50,
+            },
+        ));
+        assert_noop!(
+            ExternalValidatorSlashes::claim_reporter_bounty(RuntimeOrigin::signed(10)),
+            Error::<Test>::NoReporterBounty
+        );
+    });
+}
+
+#[test]
+fn reporter_bounty_is_a_fraction_of_the_slash_capped_per_era() {
+    new_test_ext().execute_with(|| {
+        crate::mock::DeferPeriodGetter::with_defer_period(0);
+        // 10% of 75% of 10_000 = 75 per slash, at most 100 per era
+        assert_ok!(ExternalValidatorSlashes::set_reporter_bounty(
+            RuntimeOrigin::root(),
+            ReporterBountyMode::FractionOfSlash {
+                fraction: Perbill::from_percent(10),
+                reference_stake: 10_000,
+            },
+            100,
+        ));
+        start_era(0, 0, 0);
+        start_era(1, 1, 1);
+        for (offender, reporter) in [(3, 10), (4, 11), (5, 12)] {
+            Pallet::<Test>::on_offence(
+                &[OffenceDetails {
+                    offender: (offender, ()),
+                    reporters: vec![reporter],
+                }],
+                &[Perbill::from_percent(75)],
+                1,
+            );
+        }
+        // Slashes without reporters earn nothing
+        Pallet::<Test>::on_offence(
+            &[OffenceDetails {
+                offender: (6, ()),
+                reporters: vec![],
+            }],
+            &[Perbill::from_percent(75)],
+            1,
+        );
+        start_era(2, 2, 2);
+        run_block();
+
+        assert_eq!(PendingReporterBounties::<Test>::get(10), 75);
+        assert_eq!(PendingReporterBounties::<Test>::get(11), 25);
+        assert_eq!(PendingReporterBounties::<Test>::get(12), 0);
+        assert_eq!(ReporterBountiesInEra::<Test>::get(2), 100);
+    });
+}
+
+#[test]
+fn only_root_can_set_reporter_bounty() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ExternalValidatorSlashes::set_reporter_bounty(
+                RuntimeOrigin::signed(1),
+                ReporterBountyMode::Fixed(100),
+                1_000,
+            ),
+            DispatchError::BadOrigin
+        );
+    });
+}
+
+#[test]
+fn slashing_mode_change_is_applied_at_scheduled_era() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        start_era(0, 0, 0);
+
+        assert_noop!(
+            ExternalValidatorSlashes::schedule_slashing_mode(
+                RuntimeOrigin::signed(1),
+                SlashingModeOption::Disabled,
+                2,
+            ),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            ExternalValidatorSlashes::schedule_slashing_mode(
+                RuntimeOrigin::root(),
+                SlashingModeOption::Disabled,
+                0,
+            ),
+            Error::<Test>::NotFutureEra
+        );
+        assert_ok!(ExternalValidatorSlashes::schedule_slashing_mode(
+            RuntimeOrigin::root(),
+            SlashingModeOption::Disabled,
+            2,
+        ));
+
+        start_era(1, 1, 1);
+        assert_eq!(SlashingMode::<Test>::get(), SlashingModeOption::Enabled);
+        assert_eq!(
+            SlashingModes::<Test>::get(1),
+            Some(SlashingModeOption::Enabled)
+        );
+
+        start_era(2, 2, 2);
+        assert_eq!(SlashingMode::<Test>::get(), SlashingModeOption::Disabled);
+        assert_eq!(
+            SlashingModes::<Test>::get(2),
+            Some(SlashingModeOption::Disabled)
+        );
+        assert!(ScheduledSlashingModes::<Test>::get(2).is_none());
+        System::assert_last_event(RuntimeEvent::ExternalValidatorSlashes(
+            crate::Event::SlashingModeChanged {
+                mode: SlashingModeOption::Disabled,
+                era: 2,
+            },
+        ));
+    });
+}
+
+#[test]
+fn offences_are_handled_with_mode_of_offence_era() {
+    new_test_ext().execute_with(|| {
+        start_era(0, 0, 0);
+        assert_ok!(ExternalValidatorSlashes::schedule_slashing_mode(
+            RuntimeOrigin::root(),
+            SlashingModeOption::Disabled,
+            1,
+        ));
+        start_era(1, 1, 1);
+
+        // Offence of era 1, while slashing is disabled
+        Pallet::<Test>::on_offence(
+            &[OffenceDetails {
+                offender: (3, ()),
+                reporters: vec![],
+            }],
+            &[Perbill::from_percent(75)],
+            1,
+        );
+        assert_eq!(Slashes::<Test>::get(get_slashing_era(1)), vec![]);
+
+        // Offence of era 0, reported after slashing was disabled
+        Pallet::<Test>::on_offence(
+            &[OffenceDetails {
+                offender: (3, ()),
+                reporters: vec![],
+            }],
+            &[Perbill::from_percent(75)],
+            0,
+        );
+        assert_eq!(
+            Slashes::<Test>::get(get_slashing_era(0)),
+            vec![Slash {
+                validator: 3,
+                percentage: Perbill::from_percent(75),
+                offence_era: 0,
+                confirmed: false,
+                reporters: vec![],
+                slash_id: 0
+            }]
+        );
+    });
+}
+
+#[test]
+fn log_only_slashes_can_be_promoted() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        start_era(0, 0, 0);
+        start_era(1, 1, 1);
+        assert_ok!(ExternalValidatorSlashes::set_slashing_mode(
+            RuntimeOrigin::root(),
+            SlashingModeOption::LogOnly,
+        ));
+
+        Pallet::<Test>::on_offence(
+            &[OffenceDetails {
+                offender: (3, ()),
+                reporters: vec![],
+            }],
+            &[Perbill::from_percent(75)],
+            1,
+        );
+
+        let slash = Slash {
+            validator: 3,
+            percentage: Perbill::from_percent(75),
+            offence_era: 1,
+            confirmed: false,
+            reporters: vec![],
+            slash_id: 0,
+        };
+        assert_eq!(Slashes::<Test>::get(get_slashing_era(1)), vec![]);
+        assert_eq!(LoggedSlashes::<Test>::get(1), vec![slash.clone()]);
+        System::assert_last_event(RuntimeEvent::ExternalValidatorSlashes(
+            crate::Event::SlashLogged {
+                slash_id: 0,
+                slash_era: 1,
+            },
+        ));
+
+        assert_noop!(
+            ExternalValidatorSlashes::promote_logged_slash(RuntimeOrigin::signed(1), 1, 0),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(ExternalValidatorSlashes::promote_logged_slash(
+            RuntimeOrigin::root(),
+            1,
+            0
+        ));
+
+        assert_eq!(Slashes::<Test>::get(get_slashing_era(1)), vec![slash]);
+        assert!(LoggedSlashes::<Test>::get(1).is_empty());
+        System::assert_last_event(RuntimeEvent::ExternalValidatorSlashes(
+            crate::Event::LoggedSlashPromoted {
+                slash_id: 0,
+                era: get_slashing_era(1),
+            },
+        ));
+
+        assert_noop!(
+            ExternalValidatorSlashes::promote_logged_slash(RuntimeOrigin::root(), 1, 0),
+            Error::<Test>::InvalidLoggedSlashIndex
+        );
+    });
+}
+
+#[test]
+fn promoted_slash_is_seen_by_the_rewards_penalty() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        start_era(0, 0, 0);
+        start_era(1, 1, 1);
+        assert_ok!(ExternalValidatorSlashes::set_slashing_mode(
+            RuntimeOrigin::root(),
+            SlashingModeOption::LogOnly,
+        ));
+        Pallet::<Test>::on_offence(
+            &[OffenceDetails {
+                offender: (3, ()),
+                reporters: vec![1, 2],
+            }],
+            &[Perbill::from_percent(75)],
+            1,
+        );
+        assert_eq!(ValidatorSlashInEra::<Test>::get(1, 3), None);
+
+        assert_ok!(ExternalValidatorSlashes::promote_logged_slash(
+            RuntimeOrigin::root(),
+            1,
+            0
+        ));
+
+        assert_eq!(
+            Slashes::<Test>::get(get_slashing_era(1)),
+            vec![Slash {
+                validator: 3,
+                percentage: Perbill::from_percent(75),
+                offence_era: 1,
+                confirmed: false,
+                reporters: vec![1, 2],
+                slash_id: 0,
+            }]
+        );
+        assert_eq!(
+            ValidatorSlashInEra::<Test>::get(1, 3),
+            Some(Perbill::from_percent(75))
+        );
+    });
+}
+
+#[test]
+fn logged_slash_is_not_promoted_over_a_larger_slash() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        start_era(0, 0, 0);
+        start_era(1, 1, 1);
+        Pallet::<Test>::on_offence(
+            &[OffenceDetails {
+                offender: (3, ()),
+                reporters: vec![],
+            }],
+            &[Perbill::from_percent(75)],
+            1,
+        );
+        let enforced = Slashes::<Test>::get(get_slashing_era(1));
+        assert_eq!(enforced.len(), 1);
+
+        assert_ok!(ExternalValidatorSlashes::set_slashing_mode(
+            RuntimeOrigin::root(),
+            SlashingModeOption::LogOnly,
+        ));
+        Pallet::<Test>::on_offence(
+            &[OffenceDetails {
+                offender: (3, ()),
+                reporters: vec![],
+            }],
+            &[Perbill::from_percent(50)],
+            1,
+        );
+        assert_eq!(LoggedSlashes::<Test>::get(1).len(), 1);
+
+        assert_ok!(ExternalValidatorSlashes::promote_logged_slash(
+            RuntimeOrigin::root(),
+            1,
+            0
+        ));
+
+        assert_eq!(Slashes::<Test>::get(get_slashing_era(1)), enforced);
+        assert!(LoggedSlashes::<Test>::get(1).is_empty());
+        assert_eq!(
+            ValidatorSlashInEra::<Test>::get(1, 3),
+            Some(Perbill::from_percent(75))
+        );
+        System::assert_last_event(RuntimeEvent::ExternalValidatorSlashes(
+            crate::Event::LoggedSlashSuperseded { slash_id: 1 },
+        ));
+    });
+}
+
+#[test]
+fn enforced_offence_is_slashed_after_logged_one() {
+    new_test_ext().execute_with(|| {
+        System::set_block_number(1);
+        start_era(0, 0, 0);
+        start_era(1, 1, 1);
+        assert_ok!(ExternalValidatorSlashes::set_slashing_mode(
+            RuntimeOrigin::root(),
+            SlashingModeOption::LogOnly,
+        ));
+        Pallet::<Test>::on_offence(
+            &[OffenceDetails {
+                offender: (3, ()),
+                reporters: vec![],
+            }],
+            &[Perbill::from_percent(75)],
+            1,
+        );
+        assert_eq!(LoggedSlashes::<Test>::get(1).len(), 1);
+        assert_eq!(ValidatorSlashInEra::<Test>::get(1, 3), None);
+
+        assert_ok!(ExternalValidatorSlashes::set_slashing_mode(
+            RuntimeOrigin::root(),
+            SlashingModeOption::Enabled,
+        ));
+        Pallet::<Test>::on_offence(
+            &[OffenceDetails {
+                offender: (3, ()),
+                reporters: vec![],
+            }],
+            &[Perbill::from_percent(50)],
+            1,
+        );
+
+        assert_eq!(
+            Slashes::<Test>::get(get_slashing_era(1)),
+            vec![Slash {
+                validator: 3,
+                percentage: Perbill::from_percent(50),
+                offence_era: 1,
+                confirmed: false,
+                reporters: vec![],
+                slash_id: 1,
+            }]
+        );
+        assert_eq!(
+            ValidatorSlashInEra::<Test>::get(1, 3),
+            Some(Perbill::from_percent(50))
+        );
+    });
+}
+
+#[test]
+fn logged_slashes_and_modes_are_pruned_after_bonding_period() {
+    new_test_ext().execute_with(|| {
+        start_era(0, 0, 0);
+        assert_ok!(ExternalValidatorSlashes::set_slashing_mode(
+            RuntimeOrigin::root(),
+            SlashingModeOption::LogOnly,
+        ));
+        Pallet::<Test>::on_offence(
+            &[OffenceDetails {
+                offender: (3, ()),
+                reporters: vec![],
+            }],
+            &[Perbill::from_percent(75)],
+            0,
+        );
+        assert_eq!(LoggedSlashes::<Test>::get(0).len(), 1);
+
+        start_era(8, 8, 8);
+
+        assert!(LoggedSlashes::<Test>::get(0).is_empty());
+        assert!(SlashingModes::<Test>::get(0).is_none());
+    });
+}
+
+fn queue_slash(reporters: Vec<u64>) {
+    crate::mock::DeferPeriodGetter::with_defer_period(0);
+    start_era(0, 0, 0);
+    start_era(1, 1, 1);
+    Pallet::<Test>::on_offence(
+        &[OffenceDetails {
+            offender: (3, ()),
+            reporters,
+        }],
+        &[Perbill::from_percent(75)],
+        0,
+    );
+    start_era(2, 2, 2);
+}
+
+fn delivery(
+    status: SlashDeliveryStatus<u64>,
+    attempts: u32,
+    first_sent_at: Option<u64>,
+) -> Option<SlashDelivery<u64>> {
+    Some(SlashDelivery {
+        status,
+        attempts,
+        first_sent_at,
+    })
+}
+
+#[test]
+fn slash_delivery_is_confirmed_by_receipt() {
+    new_test_ext().execute_with(|| {
+        queue_slash(vec![]);
+        assert_eq!(
+            SlashDeliveries::<Test>::get(0),
+            delivery(SlashDeliveryStatus::Queued, 0, None)
+        );
+
+        run_block();
+        let message_id = H256::zero();
+        assert_eq!(
+            SlashDeliveries::<Test>::get(0),
+            delivery(SlashDeliveryStatus::Sent { message_id }, 1, Some(1))
+        );
+
+        Pallet::<Test>::on_message_accepted(message_id, 5);
+        assert!(SentSlashMessages::<Test>::get(message_id).is_empty());
+
+        Pallet::<Test>::on_message_delivered(5, true);
+        assert!(AcceptedSlashMessages::<Test>::get(5).is_none());
+        assert_eq!(
+            SlashDeliveries::<Test>::get(0),
+            delivery(SlashDeliveryStatus::Confirmed { message_id }, 1, Some(1))
+        );
+        assert_eq!(ConfirmedSlashes::<Test>::get(2), vec![0]);
+        System::assert_last_event(RuntimeEvent::ExternalValidatorSlashes(
+            crate::Event::SlashesConfirmed {
+                message_id,
+                nonce: 5,
+            },
+        ));
+
+        // The delivery state is kept for the bonding period
+        start_era(8, 8, 8);
+        assert!(SlashDeliveries::<Test>::get(0).is_none());
+        assert!(ConfirmedSlashes::<Test>::get(2).is_empty());
+    });
+}
+
+#[test]
+fn failed_slash_delivery_is_retried_with_backoff() {
+    new_test_ext().execute_with(|| {
+        queue_slash(vec![]);
+        MockOkOutboundQueue::with_delivery_failing(true);
+
+        run_block();
+        assert!(UnreportedSlashesQueue::<Test>::get().is_empty());
+        assert_eq!(SlashRetries::<Test>::get(3).len(), 1);
+        assert_eq!(
+            SlashDeliveries::<Test>::get(0),
+            delivery(SlashDeliveryStatus::Failed { retry_at: Some(3) }, 1, None)
+        );
+        System::assert_last_event(RuntimeEvent::ExternalValidatorSlashes(
+            crate::Event::SlashDeliveryFailed {
+                slash_id: 0,
+                attempts: 1,
+                retry_at: Some(3),
+            },
+        ));
+
+        // The delay doubles at every new failure
+        run_to_block(3);
+        assert_eq!(
+            SlashDeliveries::<Test>::get(0),
+            delivery(SlashDeliveryStatus::Failed { retry_at: Some(7) }, 2, None)
+        );
+
+        // Out of attempts, the slash is set aside
+        run_to_block(7);
+        assert_eq!(
+            SlashDeliveries::<Test>::get(0),
+            delivery(SlashDeliveryStatus::Failed { retry_at: None }, 3, None)
+        );
+        assert!(FailedSlashes::<Test>::get(0).is_some());
+
+        MockOkOutboundQueue::with_delivery_failing(false);
+        assert_noop!(
+            ExternalValidatorSlashes::retry_slash_delivery(RuntimeOrigin::signed(1), 0),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(ExternalValidatorSlashes::retry_slash_delivery(
+            RuntimeOrigin::root(),
+            0
+        ));
+        assert_noop!(
+            ExternalValidatorSlashes::retry_slash_delivery(RuntimeOrigin::root(), 0),
+            Error::<Test>::SlashNotFailed
+        );
+        assert_eq!(UnreportedSlashesQueue::<Test>::get().len(), 1);
+
+        run_block();
+        assert_eq!(
+            SlashDeliveries::<Test>::get(0),
+            delivery(
+                SlashDeliveryStatus::Sent {
+                    message_id: H256::zero()
+                },
+                1,
+                Some(8)
+            )
+        );
+    });
+}
+
+#[test]
+fn failed_slash_is_dropped_after_bonding_period() {
+    new_test_ext().execute_with(|| {
+        queue_slash(vec![]);
+        MockOkOutboundQueue::with_delivery_failing(true);
+        run_to_block(7);
+        assert!(FailedSlashes::<Test>::get(0).is_some());
+        assert_eq!(FailedSlashesByEra::<Test>::get(0), vec![0]);
+
+        start_era(6, 6, 6);
+
+        assert!(FailedSlashes::<Test>::get(0).is_none());
+        assert!(SlashDeliveries::<Test>::get(0).is_none());
+        assert!(FailedSlashesByEra::<Test>::get(0).is_empty());
+        System::assert_last_event(RuntimeEvent::ExternalValidatorSlashes(
+            crate::Event::FailedSlashDropped { slash_id: 0 },
+        ));
+    });
+}
+
+#[test]
+fn slash_without_receipt_is_sent_again_after_timeout() {
+    new_test_ext().execute_with(|| {
+        queue_slash(vec![]);
+        let message_id = H256::zero();
+
+        run_block();
+        Pallet::<Test>::on_message_accepted(message_id, 1);
+
+        // No receipt within the timeout
+        run_to_block(21);
+        assert_eq!(
+            SlashDeliveries::<Test>::get(0),
+            delivery(
+                SlashDeliveryStatus::Failed { retry_at: Some(23) },
+                1,
+                Some(1)
+            )
+        );
+        assert!(SlashDeliveryTimeouts::<Test>::get(21).is_empty());
+
+        run_to_block(23);
+        assert_eq!(
+            SlashDeliveries::<Test>::get(0),
+            delivery(SlashDeliveryStatus::Sent { message_id }, 2, Some(1))
+        );
+        assert_eq!(SlashDeliveryTimeouts::<Test>::get(43).len(), 1);
+
+        // The failure of the first message does not fail the slash sent again
+        Pallet::<Test>::on_message_delivered(1, false);
+        assert_eq!(
+            SlashDeliveries::<Test>::get(0),
+            delivery(SlashDeliveryStatus::Sent { message_id }, 2, Some(1))
+        );
+    });
+}
+
+#[test]
+fn late_receipt_cancels_the_retry() {
+    new_test_ext().execute_with(|| {
+        queue_slash(vec![]);
+        let message_id = H256::zero();
+
+        run_block();
+        Pallet::<Test>::on_message_accepted(message_id, 1);
+        run_to_block(21);
+        assert_eq!(SlashRetries::<Test>::get(23).len(), 1);
+
+        Pallet::<Test>::on_message_delivered(1, true);
+        assert!(SlashRetries::<Test>::get(23).is_empty());
+        assert_eq!(
+            SlashDeliveries::<Test>::get(0),
+            delivery(SlashDeliveryStatus::Confirmed { message_id }, 1, Some(1))
+        );
+
+        run_to_block(23);
+        assert!(UnreportedSlashesQueue::<Test>::get().is_empty());
+        assert_eq!(
+            SlashDeliveries::<Test>::get(0),
+            delivery(SlashDeliveryStatus::Confirmed { message_id }, 1, Some(1))
+        );
+    });
+}
+
+#[test]
+fn slash_failed_on_ethereum_is_sent_again() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(ExternalValidatorSlashes::set_reporter_bounty(
+            RuntimeOrigin::root(),
+            ReporterBountyMode::Fixed(100),
+            1_000,
+        ));
+        queue_slash(vec![10]);
+        let message_id = H256::zero();
+
+        run_block();
+        Pallet::<Test>::on_message_accepted(message_id, 1);
+        Pallet::<Test>::on_message_delivered(1, false);
+        assert_eq!(
+            SlashDeliveries::<Test>::get(0),
+            delivery(
+                SlashDeliveryStatus::Failed { retry_at: Some(3) },
+                1,
+                Some(1)
+            )
+        );
+
+        run_to_block(3);
+        assert_eq!(
+            SlashDeliveries::<Test>::get(0),
+            delivery(SlashDeliveryStatus::Sent { message_id }, 2, Some(1))
+        );
+
+        // The bounty was only earned once
+        assert_eq!(PendingReporterBounties::<Test>::get(10), 100);
+    });
+}
+
 fn start_era(era_index: EraIndex, session_index: SessionIndex, external_idx: u64) {
     Pallet::<Test>::on_era_start(era_index, session_index, external_idx);
     crate::mock::MockEraIndexProvider::with_era(era_index);
@@ -58,6 +58,13 @@ pub trait WeightInfo {
 	fn root_test_send_msg_to_eth() -> Weight;
 	fn process_slashes_queue(s: u32, ) -> Weight;
 	fn set_slashing_mode() -> Weight;
+	fn set_reporter_bounty() -> Weight;
+	fn claim_reporter_bounty() -> Weight;
+	fn schedule_slashing_mode() -> Weight;
+	fn promote_logged_slash() -> Weight;
+	fn retry_slash_delivery() -> Weight;
+	fn on_message_accepted() -> Weight;
+	fn on_message_delivered(s: u32, ) -> Weight;
 }
 
 /// Weights for pallet_external_validator_slashes using the Substrate node and recommended hardware.
@@ -133,15 +140,82 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(Weight::from_parts(2_894_084, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
+			// Estimated reporter bounty accrual, one reporter per slash
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(s.into())))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(s.into())))
+			.saturating_add(Weight::from_parts(0, 2519).saturating_mul(s.into()))
 			.saturating_add(Weight::from_parts(0, 42).saturating_mul(s.into()))
 	}
 
 	fn set_slashing_mode() -> Weight {
 		Weight::from_parts(7_402_000, 3601)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+
+	fn set_reporter_bounty() -> Weight {
+		Weight::from_parts(7_402_000, 0)
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: `ExternalValidatorSlashes::PendingReporterBounties` (r:1 w:1)
+	/// Storage: `System::Account` (r:2 w:2)
+	fn claim_reporter_bounty() -> Weight {
+		Weight::from_parts(60_000_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+
+	/// Storage: `ExternalValidators::ActiveEra` (r:1 w:0)
+	/// Storage: `ExternalValidatorSlashes::ScheduledSlashingModes` (r:0 w:1)
+	fn schedule_slashing_mode() -> Weight {
+		Weight::from_parts(8_000_000, 1501)
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 
+	/// Storage: `ExternalValidatorSlashes::LoggedSlashes` (r:1 w:1)
+	/// Storage: `ExternalValidators::ActiveEra` (r:1 w:0)
+	/// Storage: `ExternalValidatorSlashes::Slashes` (r:1 w:1)
+	/// Storage: `ExternalValidatorSlashes::ValidatorSlashInEra` (r:1 w:1)
+	fn promote_logged_slash() -> Weight {
+		Weight::from_parts(20_000_000, 3601)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+
+	/// Storage: `ExternalValidatorSlashes::FailedSlashes` (r:1 w:1)
+	/// Storage: `ExternalValidatorSlashes::SlashDeliveries` (r:1 w:1)
+	/// Storage: `ExternalValidatorSlashes::UnreportedSlashesQueue` (r:1 w:1)
+	fn retry_slash_delivery() -> Weight {
+		Weight::from_parts(15_000_000, 3601)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+
+	/// Storage: `ExternalValidatorSlashes::SentSlashMessages` (r:1 w:1)
+	/// Storage: `ExternalValidatorSlashes::AcceptedSlashMessages` (r:0 w:1)
+	fn on_message_accepted() -> Weight {
+		Weight::from_parts(12_000_000, 3601)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: `ExternalValidatorSlashes::AcceptedSlashMessages` (r:1 w:1)
+	/// Storage: `ExternalValidatorSlashes::SlashDeliveries` (r:200 w:200)
+	/// Storage: `ExternalValidatorSlashes::SlashRetries` (r:1 w:1)
+	/// The range of component `s` is `[1, 200]`.
+	fn on_message_delivered(s: u32, ) -> Weight {
+		Weight::from_parts(15_000_000, 3601)
+			.saturating_add(Weight::from_parts(5_000_000, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(s.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(s.into())))
+			.saturating_add(Weight::from_parts(0, 2500).saturating_mul(s.into()))
+	}
+
 }
 
 // For backwards compatibility and tests
@@ -218,12 +292,79 @@ impl WeightInfo for () {
 			.saturating_add(Weight::from_parts(2_894_084, 0).saturating_mul(s.into()))
 			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			// Estimated reporter bounty accrual, one reporter per slash
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(s.into())))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(s.into())))
+			.saturating_add(Weight::from_parts(0, 2519).saturating_mul(s.into()))
 			.saturating_add(Weight::from_parts(0, 42).saturating_mul(s.into()))
 	}
 
 	fn set_slashing_mode() -> Weight {
 		Weight::from_parts(7_402_000, 3601)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+
+	fn set_reporter_bounty() -> Weight {
+		Weight::from_parts(7_402_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: `ExternalValidatorSlashes::PendingReporterBounties` (r:1 w:1)
+	/// Storage: `System::Account` (r:2 w:2)
+	fn claim_reporter_bounty() -> Weight {
+		Weight::from_parts(60_000_000, 6196)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+
+	/// Storage: `ExternalValidators::ActiveEra` (r:1 w:0)
+	/// Storage: `ExternalValidatorSlashes::ScheduledSlashingModes` (r:0 w:1)
+	fn schedule_slashing_mode() -> Weight {
+		Weight::from_parts(8_000_000, 1501)
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+
+	/// Storage: `ExternalValidatorSlashes::LoggedSlashes` (r:1 w:1)
+	/// Storage: `ExternalValidators::ActiveEra` (r:1 w:0)
+	/// Storage: `ExternalValidatorSlashes::Slashes` (r:1 w:1)
+	/// Storage: `ExternalValidatorSlashes::ValidatorSlashInEra` (r:1 w:1)
+	fn promote_logged_slash() -> Weight {
+		Weight::from_parts(20_000_000, 3601)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+
+	/// Storage: `ExternalValidatorSlashes::FailedSlashes` (r:1 w:1)
+	/// Storage: `ExternalValidatorSlashes::SlashDeliveries` (r:1 w:1)
+	/// Storage: `ExternalValidatorSlashes::UnreportedSlashesQueue` (r:1 w:1)
+	fn retry_slash_delivery() -> Weight {
+		Weight::from_parts(15_000_000, 3601)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+
+	/// Storage: `ExternalValidatorSlashes::SentSlashMessages` (r:1 w:1)
+	/// Storage: `ExternalValidatorSlashes::AcceptedSlashMessages` (r:0 w:1)
+	fn on_message_accepted() -> Weight {
+		Weight::from_parts(12_000_000, 3601)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+
+	/// Storage: `ExternalValidatorSlashes::AcceptedSlashMessages` (r:1 w:1)
+	/// Storage: `ExternalValidatorSlashes::SlashDeliveries` (r:200 w:200)
+	/// Storage: `ExternalValidatorSlashes::SlashRetries` (r:1 w:1)
+	/// The range of component `s` is `[1, 200]`.
+	fn on_message_delivered(s: u32, ) -> Weight {
+		Weight::from_parts(15_000_000, 3601)
+			.saturating_add(Weight::from_parts(5_000_000, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(s.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(s.into())))
+			.saturating_add(Weight::from_parts(0, 2500).saturating_mul(s.into()))
+	}
 }
@@ -0,0 +1,92 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! RPC interface for the external validators rewards pallet.
+//!
+//! Serves the `externalValidatorsRewards_*` namespace on top of
+//! [`ExternalValidatorsRewardsRuntimeApi`].
+
+pub use pallet_external_validators_rewards_runtime_api::ExternalValidatorsRewardsApi as ExternalValidatorsRewardsRuntimeApi;
+use {
+    jsonrpsee::{
+        core::RpcResult,
+        proc_macros::rpc,
+        types::error::{ErrorObject, ErrorObjectOwned},
+    },
+    pallet_external_validators_rewards_runtime_api::{EraIndex, EraRewardsPreview},
+    sp_api::{ApiError, ProvideRuntimeApi},
+    sp_blockchain::HeaderBackend,
+    sp_runtime::traits::Block as BlockT,
+    std::{marker::PhantomData, sync::Arc},
+};
+
+/// Error code returned when the runtime API call fails.
+const RUNTIME_ERROR: i32 = 1;
+
+#[rpc(client, server)]
+pub trait ExternalValidatorsRewardsApi<BlockHash> {
+    /// Points and expected payout of every operator for `era`, if it is still retained.
+    /// For the active era this is a projection computed as if the era ended at `at`.
+    #[method(name = "externalValidatorsRewards_eraRewardsPreview")]
+    fn era_rewards_preview(
+        &self,
+        era: EraIndex,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<EraRewardsPreview>>;
+}
+
+/// Implementation of [`ExternalValidatorsRewardsApiServer`] backed by the runtime API.
+pub struct ExternalValidatorsRewards<C, Block> {
+    client: Arc<C>,
+    _marker: PhantomData<Block>,
+}
+
+impl<C, Block> ExternalValidatorsRewards<C, Block> {
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: PhantomData,
+        }
+    }
+}
+
+fn runtime_error_into_rpc_err(err: ApiError) -> ErrorObjectOwned {
+    ErrorObject::owned(
+        RUNTIME_ERROR,
+        "Unable to query external validators rewards",
+        Some(err.to_string()),
+    )
+}
+
+impl<C, Block> ExternalValidatorsRewardsApiServer<Block::Hash>
+    for ExternalValidatorsRewards<C, Block>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: ExternalValidatorsRewardsRuntimeApi<Block>,
+{
+    fn era_rewards_preview(
+        &self,
+        era: EraIndex,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Option<EraRewardsPreview>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.client
+            .runtime_api()
+            .era_rewards_preview(at, era)
+            .map_err(runtime_error_into_rpc_err)
+    }
+}
@@ -0,0 +1,36 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Runtime API for the external validators rewards pallet.
+//!
+//! Lets operators check the points and payouts of an era before the rewards message is bridged
+//! to EigenLayer.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet_external_validators_rewards::{
+    types::{EraRewardsPreview, OperatorRewardPreview},
+    EraIndex,
+};
+
+sp_api::decl_runtime_apis! {
+    #[api_version(1)]
+    pub trait ExternalValidatorsRewardsApi {
+        /// Points and expected payout of every operator for `era`, if it is still retained.
+        /// For the active era this is a projection computed as if the era ended now.
+        fn era_rewards_preview(era: EraIndex) -> Option<EraRewardsPreview>;
+    }
+}
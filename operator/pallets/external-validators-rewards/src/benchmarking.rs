@@ -21,9 +21,10 @@ use super::*;
 #[allow(unused)]
 use crate::Pallet as ExternalValidatorsRewards;
 use {
-    crate::{types::BenchmarkHelper, OnEraEnd},
+    crate::{liveness::FinalityGadget, types::BenchmarkHelper, OnEraEnd},
     frame_benchmarking::{account, v2::*, BenchmarkError},
     frame_support::traits::Currency,
+    frame_system::RawOrigin,
     sp_std::prelude::*,
 };
 
@@ -72,6 +73,41 @@ mod benchmarks {
         Ok(())
     }
 
+    #[benchmark]
+    fn set_reward_penalty_mode() -> Result<(), BenchmarkError> {
+        #[extrinsic_call]
+        _(RawOrigin::Root, RewardPenaltyModeOption::SlashFraction);
+
+        assert_eq!(
+            RewardPenaltyMode::<T>::get(),
+            RewardPenaltyModeOption::SlashFraction
+        );
+        Ok(())
+    }
+
+    #[benchmark]
+    fn note_finality_proofs(s: Linear<0, 100>) -> Result<(), BenchmarkError> {
+        let signers: Vec<T::AccountId> = (0..s).map(|i| account("signer", i, SEED)).collect();
+        // Signers already noted earlier in the session
+        ExternalValidatorsRewards::<T>::note_finality_signers(
+            FinalityGadget::Grandpa,
+            1,
+            signers.clone(),
+        );
+
+        #[block]
+        {
+            ExternalValidatorsRewards::<T>::note_finality_signers(
+                FinalityGadget::Grandpa,
+                2,
+                signers,
+            );
+        }
+
+        assert_eq!(FinalityProofsInSession::<T>::get(), 2);
+        Ok(())
+    }
+
     impl_benchmark_test_suite!(
         ExternalValidatorsRewards,
         crate::mock::new_test_ext(),
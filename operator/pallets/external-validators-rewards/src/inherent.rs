@@ -0,0 +1,56 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Inherent carrying the finality proofs seen by the block author.
+
+use sp_inherents::InherentIdentifier;
+
+/// Identifier of the finality proofs inherent.
+pub const INHERENT_IDENTIFIER: InherentIdentifier = *b"finalsig";
+
+/// Maximum number of finality proofs noted per block (one per gadget).
+pub const MAX_FINALITY_PROOFS_PER_BLOCK: usize = 2;
+
+/// Provides the finality proofs collected by the node since it last authored a block.
+#[cfg(feature = "std")]
+pub struct InherentDataProvider<Proof>(pub sp_std::vec::Vec<Proof>);
+
+#[cfg(feature = "std")]
+#[async_trait::async_trait]
+impl<Proof> sp_inherents::InherentDataProvider for InherentDataProvider<Proof>
+where
+    Proof: parity_scale_codec::Encode + Send + Sync,
+{
+    async fn provide_inherent_data(
+        &self,
+        inherent_data: &mut sp_inherents::InherentData,
+    ) -> Result<(), sp_inherents::Error> {
+        if self.0.is_empty() {
+            return Ok(());
+        }
+
+        inherent_data.put_data(INHERENT_IDENTIFIER, &self.0)
+    }
+
+    async fn try_handle_error(
+        &self,
+        _identifier: &InherentIdentifier,
+        _error: &[u8],
+    ) -> Option<Result<(), sp_inherents::Error>> {
+        // The inherent is optional and never fails.
+        None
+    }
+}
@@ -28,16 +28,26 @@ mod tests;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
+pub mod inherent;
+pub mod liveness;
 pub mod types;
 pub mod weights;
 
 pub use pallet::*;
 
 use {
-    crate::types::{EraRewardsUtils, HandleInflation, SendMessage},
+    crate::{
+        liveness::{FinalityProofVerifier, LivenessSource},
+        types::{
+            split_inflation, EraRewardsPreview, EraRewardsUtils, HandleInflation,
+            OperatorRewardPreview, SendMessage,
+        },
+    },
     frame_support::traits::{Get, ValidatorSet},
-    pallet_external_validators::traits::{ExternalIndexProvider, OnEraEnd, OnEraStart},
-    parity_scale_codec::{Decode, Encode},
+    pallet_external_validators::traits::{
+        ExternalIndexProvider, OnEraEnd, OnEraStart, OnSlashCancelled,
+    },
+    parity_scale_codec::{Decode, DecodeWithMemTracking, Encode},
     sp_core::{H160, H256},
     sp_runtime::{
         traits::{Hash, Zero},
@@ -49,13 +59,18 @@ use {
 
 /// Trait for checking if a validator has been slashed in a given era
 pub trait SlashingCheck<AccountId> {
-    fn is_slashed(era_index: u32, validator: &AccountId) -> bool;
+    /// Highest fraction the validator was slashed by in the era, if any.
+    fn slash_fraction(era_index: u32, validator: &AccountId) -> Option<Perbill>;
+
+    fn is_slashed(era_index: u32, validator: &AccountId) -> bool {
+        Self::slash_fraction(era_index, validator).is_some()
+    }
 }
 
-/// Implementation that always returns false (no slashes)
+/// Implementation that always returns None (no slashes)
 impl<AccountId> SlashingCheck<AccountId> for () {
-    fn is_slashed(_era_index: u32, _validator: &AccountId) -> bool {
-        false
+    fn slash_fraction(_era_index: u32, _validator: &AccountId) -> Option<Perbill> {
+        None
     }
 }
 
@@ -66,8 +81,16 @@ pub mod pallet {
 
     pub use crate::weights::WeightInfo;
     use {
-        super::*, frame_support::pallet_prelude::*,
-        pallet_external_validators::traits::EraIndexProvider, sp_runtime::Saturating,
+        super::*,
+        crate::{
+            inherent::{INHERENT_IDENTIFIER, MAX_FINALITY_PROOFS_PER_BLOCK},
+            liveness::FinalityGadget,
+        },
+        frame_support::pallet_prelude::*,
+        frame_system::pallet_prelude::*,
+        pallet_external_validators::traits::EraIndexProvider,
+        sp_inherents::{InherentData, InherentIdentifier, ProvideInherent},
+        sp_runtime::Saturating,
         sp_std::collections::btree_map::BTreeMap,
     };
 
@@ -76,6 +99,9 @@ pub mod pallet {
 
     pub type RewardPoints = u32;
     pub type EraIndex = u32;
+    pub type FinalityProofOf<T> = <<T as Config>::FinalityProofVerifier as FinalityProofVerifier<
+        <T as frame_system::Config>::AccountId,
+    >>::Proof;
 
     #[pallet::config]
     pub trait Config: frame_system::Config {
@@ -124,11 +150,33 @@ pub mod pallet {
         /// The remainder (100% - block - liveness) is the unconditional base reward.
         type BlockAuthoringWeight: Get<Perbill>;
 
-        /// Weight of liveness (block authorship) in the rewards formula.
+        /// Weight of liveness in the rewards formula.
         /// Combined with BlockAuthoringWeight, the sum should not exceed 100%.
         /// The remainder (100% - block - liveness) is the unconditional base reward.
+        /// How the liveness score is computed is set by the `*LivenessWeight` items below.
         type LivenessWeight: Get<Perbill>;
 
+        /// Heartbeat liveness signal, typically [`crate::liveness::ImOnlineHeartbeats`].
+        type HeartbeatLiveness: LivenessSource<Self::AccountId>;
+
+        /// Share of the liveness score given by `HeartbeatLiveness`.
+        type HeartbeatLivenessWeight: Get<Perbill>;
+
+        /// Finality participation liveness signal, typically [`crate::liveness::FinalitySignatures`].
+        type FinalityLiveness: LivenessSource<Self::AccountId>;
+
+        /// Share of the liveness score given by `FinalityLiveness`.
+        /// Block authorship gets the remainder (100% - heartbeat - finality). If the sum exceeds
+        /// 100%, both are proportionally scaled down and block authorship gets nothing.
+        type FinalityLivenessWeight: Get<Perbill>;
+
+        /// Verifies the finality proofs noted through the inherent.
+        type FinalityProofVerifier: FinalityProofVerifier<Self::AccountId>;
+
+        /// Maximum number of finality proof signatures verified per block.
+        #[pallet::constant]
+        type MaxFinalitySignaturesPerBlock: Get<u32>;
+
         /// Soft cap on block authoring rewards as a percentage above fair share.
         /// E.g., 50% means validators can earn credit for up to 150% of their fair share.
         /// With 60% BlockAuthoringWeight, this gives over-performers up to 30% bonus reward.
@@ -185,6 +233,44 @@ pub mod pallet {
             total_points: u128,
             inflation_amount: u128,
         },
+        /// The reward penalty mode for slashed validators was updated.
+        RewardPenaltyModeSet { mode: RewardPenaltyModeOption },
+        /// Points of a slashed validator were withheld.
+        RewardPointsWithheld {
+            era_index: EraIndex,
+            validator: T::AccountId,
+            points: RewardPoints,
+        },
+        /// Points withheld from a validator were given back after its slash was cancelled.
+        WithheldRewardPointsRestored {
+            era_index: EraIndex,
+            validator: T::AccountId,
+            points: RewardPoints,
+        },
+    }
+
+    /// How the session points of a slashed validator are penalized.
+    #[derive(
+        Clone,
+        Copy,
+        Encode,
+        Decode,
+        DecodeWithMemTracking,
+        PartialEq,
+        Eq,
+        RuntimeDebug,
+        TypeInfo,
+        MaxEncodedLen,
+        Default,
+    )]
+    pub enum RewardPenaltyModeOption {
+        /// Slashed validators keep all their points.
+        #[default]
+        Ignore,
+        /// Slashed validators lose all their points.
+        ZeroPoints,
+        /// Points are cut by the slash fraction.
+        SlashFraction,
     }
 
     /// Keep tracks of distributed points per validator and total.
@@ -260,6 +346,147 @@ pub mod pallet {
     pub type BlocksProducedInEra<T: Config> =
         StorageMap<_, Twox64Concat, EraIndex, u32, ValueQuery>;
 
+    /// How slashed validators are penalized when awarding session points.
+    #[pallet::storage]
+    pub type RewardPenaltyMode<T: Config> = StorageValue<_, RewardPenaltyModeOption, ValueQuery>;
+
+    /// Points withheld from slashed validators in each era, restored if the slash is cancelled
+    /// before the era ends.
+    #[pallet::storage]
+    pub type WithheldPointsForEra<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        EraIndex,
+        Twox64Concat,
+        T::AccountId,
+        RewardPoints,
+        ValueQuery,
+    >;
+
+    /// Validators reported as unresponsive by `pallet_im_online` in a session.
+    /// Cleared when the session points are awarded.
+    #[pallet::storage]
+    pub type UnresponsiveInSession<T: Config> = StorageDoubleMap<
+        _,
+        Twox64Concat,
+        SessionIndex,
+        Twox64Concat,
+        T::AccountId,
+        (),
+        OptionQuery,
+    >;
+
+    /// Number of finality proofs noted in the current session.
+    /// Cleared at the end of each session.
+    #[pallet::storage]
+    pub type FinalityProofsInSession<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    /// Number of finality proofs noted in the current session signed by each validator.
+    /// Cleared at the end of each session.
+    #[pallet::storage]
+    #[pallet::unbounded]
+    pub type FinalitySignaturesInSession<T: Config> =
+        StorageMap<_, Twox64Concat, T::AccountId, u32, ValueQuery>;
+
+    /// Block number of the last finality proof noted for each gadget. Only newer proofs are
+    /// noted, so the same justification is never counted twice.
+    #[pallet::storage]
+    pub type LastFinalityProofBlock<T: Config> =
+        StorageMap<_, Twox64Concat, FinalityGadget, u32, ValueQuery>;
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Set how slashed validators are penalized from now on.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::set_reward_penalty_mode())]
+        pub fn set_reward_penalty_mode(
+            origin: OriginFor<T>,
+            mode: RewardPenaltyModeOption,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            RewardPenaltyMode::<T>::put(mode);
+            Self::deposit_event(Event::RewardPenaltyModeSet { mode });
+
+            Ok(())
+        }
+
+        /// Note the signers of GRANDPA/BEEFY finality proofs as liveness evidence for the
+        /// current session. Proofs that fail verification, exceed the
+        /// `MaxFinalitySignaturesPerBlock` budget or are not newer than the last noted proof of
+        /// their gadget are ignored.
+        #[pallet::call_index(1)]
+        #[pallet::weight((
+            Pallet::<T>::note_finality_proofs_weight(proofs),
+            DispatchClass::Mandatory
+        ))]
+        pub fn note_finality_proofs(
+            origin: OriginFor<T>,
+            proofs: Vec<FinalityProofOf<T>>,
+        ) -> DispatchResult {
+            ensure_none(origin)?;
+
+            for proof in Self::finality_proofs_within_budget(&proofs) {
+                Self::note_finality_proof(proof);
+            }
+
+            Ok(())
+        }
+    }
+
+    #[pallet::inherent]
+    impl<T: Config> ProvideInherent for Pallet<T> {
+        type Call = Call<T>;
+        type Error = sp_inherents::MakeFatalError<()>;
+        const INHERENT_IDENTIFIER: InherentIdentifier = INHERENT_IDENTIFIER;
+
+        fn create_inherent(data: &InherentData) -> Option<Self::Call> {
+            let proofs: Vec<FinalityProofOf<T>> = data
+                .get_data(&INHERENT_IDENTIFIER)
+                .inspect_err(|e| {
+                    log::error!(
+                        target: "ext_validators_rewards",
+                        "Failed to decode finality proofs inherent data: {:?}",
+                        e
+                    )
+                })
+                .ok()
+                .flatten()?;
+
+            // Only put the proofs the block can note, so it passes `check_inherent`
+            let proofs: Vec<FinalityProofOf<T>> = proofs
+                .into_iter()
+                .filter(Self::is_noteworthy_finality_proof)
+                .collect();
+            let proofs: Vec<FinalityProofOf<T>> = Self::finality_proofs_within_budget(&proofs)
+                .cloned()
+                .collect();
+            if proofs.is_empty() {
+                return None;
+            }
+
+            Some(Call::note_finality_proofs { proofs })
+        }
+
+        fn check_inherent(call: &Self::Call, _data: &InherentData) -> Result<(), Self::Error> {
+            let Call::note_finality_proofs { proofs } = call else {
+                return Ok(());
+            };
+
+            // Every proof paid for must be verified and noted
+            let within_budget = Self::finality_proofs_within_budget(proofs).count() == proofs.len();
+            if within_budget && proofs.iter().all(Self::is_noteworthy_finality_proof) {
+                Ok(())
+            } else {
+                Err(().into())
+            }
+        }
+
+        fn is_inherent(call: &Self::Call) -> bool {
+            matches!(call, Call::note_finality_proofs { .. })
+        }
+    }
+
     impl<T: Config> Pallet<T> {
         /// Reward validators. Does not check if the validators are valid, caller needs to make sure of that.
         pub fn reward_by_ids(points: impl IntoIterator<Item = (T::AccountId, RewardPoints)>) {
@@ -336,8 +563,28 @@ pub mod pallet {
         ///
         /// The scaled inflation amount based on network performance
         pub fn calculate_scaled_inflation(era_index: EraIndex, base_inflation: u128) -> u128 {
-            use sp_runtime::Perbill;
+            let inflation_percent = Self::inflation_percent(era_index);
+
+            // Apply percentage to base inflation
+            let scaled_inflation =
+                Perbill::from_percent(inflation_percent).mul_floor(base_inflation);
+
+            log::debug!(
+                target: "ext_validators_rewards",
+                "Era {} inflation scaling: {} blocks / {} expected → {}% inflation ({} tokens)",
+                era_index,
+                BlocksProducedInEra::<T>::get(era_index),
+                T::ExpectedBlocksPerEra::get(),
+                inflation_percent,
+                scaled_inflation
+            );
+
+            scaled_inflation
+        }
 
+        /// Percentage of the base inflation minted for `era_index`, between MinInflationPercent
+        /// and MaxInflationPercent depending on the blocks produced in the era.
+        pub fn inflation_percent(era_index: EraIndex) -> u32 {
             let blocks_produced = BlocksProducedInEra::<T>::get(era_index);
             let expected_blocks = T::ExpectedBlocksPerEra::get();
             let min_percent = T::MinInflationPercent::get();
@@ -353,26 +600,254 @@ pub mod pallet {
 
             // Scale from min to max based on performance
             // inflation_percent = min + (performance_ratio × (max - min))
-            let inflation_percent = min_percent.saturating_add(
+            min_percent.saturating_add(
                 performance_ratio.mul_floor(max_percent.saturating_sub(min_percent)),
-            );
+            )
+        }
 
-            // Apply percentage to base inflation
-            let scaled_inflation =
+        /// Preview the rewards submission for `era_index`: each operator's points and expected
+        /// amount, the inflation scaling and the rounding remainder.
+        ///
+        /// Returns `None` if the era has not started yet or its points were already pruned.
+        /// For the active era, the preview is computed as if the era ended now. The base inflation
+        /// is always the current `EraInflationProvider` value. The amounts are split with
+        /// [`split_inflation`], as in the submission, and no operator is listed if the split
+        /// overflows, the submission being skipped then.
+        pub fn era_rewards_preview(era_index: EraIndex) -> Option<EraRewardsPreview> {
+            let active_era = T::EraIndexProvider::active_era().index;
+            if era_index > active_era
+                || era_index.saturating_add(T::HistoryDepth::get()) <= active_era
+            {
+                return None;
+            }
+
+            let base_inflation = T::EraInflationProvider::get();
+            let inflation_percent = Self::inflation_percent(era_index);
+            let inflation_amount =
                 Perbill::from_percent(inflation_percent).mul_floor(base_inflation);
 
+            let mut preview = EraRewardsPreview {
+                era_index,
+                in_progress: era_index == active_era,
+                blocks_produced: BlocksProducedInEra::<T>::get(era_index),
+                expected_blocks: T::ExpectedBlocksPerEra::get(),
+                inflation_percent,
+                base_inflation,
+                inflation_amount,
+                total_points: 0,
+                operators: Vec::new(),
+                remainder: 0,
+            };
+
+            // Nothing is minted nor sent for an era without points.
+            let Some(utils) = RewardPointsForEra::<T>::get(era_index).generate_era_rewards_utils(
+                era_index,
+                inflation_amount,
+                0,
+            ) else {
+                return Some(preview);
+            };
+
+            // Nothing is sent if the inflation can't be split.
+            let Ok((amounts, remainder)) = split_inflation(
+                &utils.individual_points,
+                utils.total_points,
+                inflation_amount,
+            ) else {
+                return Some(preview);
+            };
+            preview.operators = utils
+                .individual_points
+                .into_iter()
+                .zip(amounts)
+                .map(|((operator, points), amount)| OperatorRewardPreview {
+                    operator,
+                    points,
+                    amount,
+                })
+                .collect();
+            preview.operators.sort_by_key(|o| o.operator);
+            preview.total_points = utils.total_points;
+            preview.remainder = remainder;
+
+            Some(preview)
+        }
+
+        /// Returns the points left to a validator after the `RewardPenaltyMode` penalty for its
+        /// slash in `era_index`, if any. Withheld points are recorded so they can be restored.
+        fn apply_reward_penalty(
+            era_index: EraIndex,
+            validator: &T::AccountId,
+            points: RewardPoints,
+        ) -> RewardPoints {
+            let Some(slash_fraction) = T::SlashingCheck::slash_fraction(era_index, validator)
+            else {
+                return points;
+            };
+
+            let withheld = match RewardPenaltyMode::<T>::get() {
+                RewardPenaltyModeOption::Ignore => return points,
+                RewardPenaltyModeOption::ZeroPoints => points,
+                RewardPenaltyModeOption::SlashFraction => slash_fraction.mul_floor(points),
+            };
+            if withheld.is_zero() {
+                return points;
+            }
+
             log::debug!(
                 target: "ext_validators_rewards",
-                "Era {} inflation scaling: {} blocks / {} expected = {}% performance → {}% inflation ({} tokens)",
+                "Validator {:?} has slash in era {}, withholding {} of {} points",
+                validator,
                 era_index,
-                blocks_produced,
-                expected_blocks,
-                performance_ratio.deconstruct() * 100 / 1_000_000_000,
-                inflation_percent,
-                scaled_inflation
+                withheld,
+                points
             );
+            WithheldPointsForEra::<T>::mutate(era_index, validator, |w| {
+                w.saturating_accrue(withheld)
+            });
+            Self::deposit_event(Event::RewardPointsWithheld {
+                era_index,
+                validator: validator.clone(),
+                points: withheld,
+            });
 
-            scaled_inflation
+            points.saturating_sub(withheld)
+        }
+
+        /// Gives back the points withheld from `validator` in `era_index` once it has no slash
+        /// left in the era. Does nothing once the era rewards message was built.
+        pub fn restore_withheld_points(era_index: EraIndex, validator: &T::AccountId) {
+            if era_index != T::EraIndexProvider::active_era().index {
+                return;
+            }
+            // The penalty of the slashes left in the era is kept
+            if T::SlashingCheck::is_slashed(era_index, validator) {
+                return;
+            }
+
+            let points = WithheldPointsForEra::<T>::take(era_index, validator);
+            if points.is_zero() {
+                return;
+            }
+
+            Self::reward_by_ids([(validator.clone(), points)]);
+            Self::deposit_event(Event::WithheldRewardPointsRestored {
+                era_index,
+                validator: validator.clone(),
+                points,
+            });
+        }
+
+        /// Proofs verified by `note_finality_proofs`: the first `MAX_FINALITY_PROOFS_PER_BLOCK`
+        /// ones fitting in the `MaxFinalitySignaturesPerBlock` budget.
+        fn finality_proofs_within_budget(
+            proofs: &[FinalityProofOf<T>],
+        ) -> impl Iterator<Item = &FinalityProofOf<T>> {
+            let mut budget = T::MaxFinalitySignaturesPerBlock::get();
+            proofs
+                .iter()
+                .take(MAX_FINALITY_PROOFS_PER_BLOCK)
+                .filter(move |proof| {
+                    let signatures = T::FinalityProofVerifier::signature_count(proof);
+                    if signatures > budget {
+                        return false;
+                    }
+                    budget.saturating_reduce(signatures);
+                    true
+                })
+        }
+
+        /// Weight of `note_finality_proofs`: bookkeeping plus signature verification.
+        pub(crate) fn note_finality_proofs_weight(proofs: &[FinalityProofOf<T>]) -> Weight {
+            let (signatures, verification) = Self::finality_proofs_within_budget(proofs).fold(
+                (0u32, Weight::zero()),
+                |(signatures, verification), proof| {
+                    (
+                        signatures.saturating_add(T::FinalityProofVerifier::signature_count(proof)),
+                        verification
+                            .saturating_add(T::FinalityProofVerifier::verification_weight(proof)),
+                    )
+                },
+            );
+
+            T::WeightInfo::note_finality_proofs(signatures).saturating_add(verification)
+        }
+
+        /// Whether a finality proof is newer than the last noted one of the same gadget, and valid.
+        fn is_noteworthy_finality_proof(proof: &FinalityProofOf<T>) -> bool {
+            let (gadget, block_number) = T::FinalityProofVerifier::target(proof);
+            block_number > LastFinalityProofBlock::<T>::get(gadget)
+                && T::FinalityProofVerifier::signers(proof).is_some()
+        }
+
+        /// Verify a finality proof and note its signers. Proofs not newer than the last noted one
+        /// of the same gadget are ignored.
+        fn note_finality_proof(proof: &FinalityProofOf<T>) {
+            let (gadget, block_number) = T::FinalityProofVerifier::target(proof);
+            if block_number <= LastFinalityProofBlock::<T>::get(gadget) {
+                return;
+            }
+
+            let Some(signers) = T::FinalityProofVerifier::signers(proof) else {
+                log::debug!(
+                    target: "ext_validators_rewards",
+                    "Ignoring invalid {:?} finality proof for block {}",
+                    gadget,
+                    block_number
+                );
+                return;
+            };
+
+            Self::note_finality_signers(gadget, block_number, signers);
+        }
+
+        /// Count a signature for each of `signers` in the current session.
+        pub(crate) fn note_finality_signers(
+            gadget: FinalityGadget,
+            block_number: u32,
+            mut signers: Vec<T::AccountId>,
+        ) {
+            signers.sort();
+            signers.dedup();
+
+            LastFinalityProofBlock::<T>::insert(gadget, block_number);
+            FinalityProofsInSession::<T>::mutate(|count| count.saturating_inc());
+            for signer in signers {
+                FinalitySignaturesInSession::<T>::mutate(signer, |count| count.saturating_inc());
+            }
+        }
+
+        /// Shares of the liveness score given to (block authorship, heartbeats, finality).
+        /// Scaled down like the reward weights if the configured shares exceed 100%.
+        fn liveness_shares() -> (Perbill, Perbill, Perbill) {
+            let raw_heartbeat = T::HeartbeatLivenessWeight::get();
+            let raw_finality = T::FinalityLivenessWeight::get();
+            let sum = raw_heartbeat.saturating_add(raw_finality);
+
+            if sum > Perbill::one() {
+                let scale = Perbill::from_rational(Perbill::one().deconstruct(), sum.deconstruct());
+                (
+                    Perbill::zero(),
+                    scale.saturating_mul(raw_heartbeat),
+                    scale.saturating_mul(raw_finality),
+                )
+            } else {
+                (
+                    Perbill::one()
+                        .saturating_sub(raw_heartbeat)
+                        .saturating_sub(raw_finality),
+                    raw_heartbeat,
+                    raw_finality,
+                )
+            }
+        }
+
+        /// Clears the liveness evidence gathered during `session_index`.
+        fn clear_session_tracking(session_index: SessionIndex) {
+            let _ = BlocksAuthoredInSession::<T>::clear(u32::MAX, None);
+            let _ = UnresponsiveInSession::<T>::clear_prefix(session_index, u32::MAX, None);
+            let _ = FinalitySignaturesInSession::<T>::clear(u32::MAX, None);
+            FinalityProofsInSession::<T>::kill();
         }
 
         /// Awards performance-based points at session end using a configurable weighted formula.
@@ -395,9 +870,14 @@ pub mod pallet {
         ///
         /// # Liveness Scoring
         ///
-        /// Uses block authorship as proof of liveness. A validator is considered online if
-        /// they authored at least one block in the current session. This is simpler and more
-        /// reliable than ImOnline heartbeats, which have timing issues with session rotation.
+        /// The liveness score is a weighted sum of three signals, each 1.0 if the validator is
+        /// live and 0.0 otherwise:
+        /// - **HeartbeatLivenessWeight**: `HeartbeatLiveness` source (e.g. ImOnline heartbeats)
+        /// - **FinalityLivenessWeight**: `FinalityLiveness` source (e.g. GRANDPA/BEEFY signatures)
+        /// - **Block authorship**: Remainder, live if the validator authored at least one block
+        ///   in the session
+        ///
+        /// With both weights at zero, liveness is block authorship only.
         ///
         /// # Weight Validation
         ///
@@ -432,7 +912,7 @@ pub mod pallet {
                     session_index
                 );
                 // Clear session tracking storage even if no rewards
-                let _ = BlocksAuthoredInSession::<T>::clear(u32::MAX, None);
+                Self::clear_session_tracking(session_index);
                 return;
             }
 
@@ -499,7 +979,10 @@ pub mod pallet {
                 base_weight.deconstruct() * 100 / Perbill::ACCURACY
             );
 
+            let (authorship_share, heartbeat_share, finality_share) = Self::liveness_shares();
+
             let mut rewards = Vec::new();
+            let active_era = T::EraIndexProvider::active_era().index;
 
             // Calculate points for each validator
             for validator in validators.iter() {
@@ -508,39 +991,28 @@ pub mod pallet {
                     continue;
                 }
 
-                // NOTE: Slashing check is disabled for now but hook is retained for future use.
-                // Slashed validators will still be slashed financially via the slashing pallet;
-                // they just won't lose their era rewards. This allows governance to cancel
-                // erroneous slashes without also losing the validator's rewards.
-                //
-                // To re-enable, uncomment the following block:
-                // let active_era = T::EraIndexProvider::active_era();
-                // if T::SlashingCheck::is_slashed(active_era.index, validator) {
-                //     log::warn!(
-                //         target: "ext_validators_rewards",
-                //         "Validator {:?} has slash in era {}, nullifying rewards",
-                //         validator,
-                //         active_era.index
-                //     );
-                //     continue;
-                // }
-
                 let blocks_authored = BlocksAuthoredInSession::<T>::get(validator);
 
                 // Block production with soft cap allowing over-performance
                 // credited_blocks = min(blocks_authored, max_credited_blocks)
                 let credited_blocks = blocks_authored.min(max_credited_blocks);
 
-                // Liveness score: Use block authorship as proof of liveness.
+                // Liveness score: weighted sum of the liveness signals.
                 // A validator who authored at least one block is definitively online.
-                // This is simpler and more reliable than trying to cache ImOnline state
-                // which has timing issues with session rotation.
-                let is_online = blocks_authored > 0;
-                let liveness_score = if is_online {
-                    Perbill::one()
-                } else {
-                    Perbill::zero()
-                };
+                let mut liveness_score = Perbill::zero();
+                if blocks_authored > 0 {
+                    liveness_score.saturating_accrue(authorship_share);
+                }
+                if !heartbeat_share.is_zero()
+                    && T::HeartbeatLiveness::is_live(session_index, validator)
+                {
+                    liveness_score.saturating_accrue(heartbeat_share);
+                }
+                if !finality_share.is_zero()
+                    && T::FinalityLiveness::is_live(session_index, validator)
+                {
+                    liveness_score.saturating_accrue(finality_share);
+                }
 
                 // Calculate points using direct computation to avoid Perbill capping.
                 // Perbill::from_rational caps at 100% when numerator > denominator,
@@ -587,17 +1059,18 @@ pub mod pallet {
                 if points > 0 {
                     log::debug!(
                         target: "ext_validators_rewards",
-                        "Validator {:?}: blocks={}/{} (credited={}), online={}, block_pts={}, liveness_base_pts={}, total={}",
+                        "Validator {:?}: blocks={}/{} (credited={}), liveness={}%, block_pts={}, liveness_base_pts={}, total={}",
                         validator,
                         blocks_authored,
                         fair_share,
                         credited_blocks,
-                        if is_online { "yes" } else { "no" },
+                        liveness_score.deconstruct() * 100 / Perbill::ACCURACY,
                         block_contribution,
                         liveness_base_contribution,
                         points
                     );
 
+                    let points = Self::apply_reward_penalty(active_era, validator, points);
                     rewards.push((validator.clone(), points));
                 }
             }
@@ -607,7 +1080,7 @@ pub mod pallet {
             }
 
             // Clear session tracking storage
-            let _ = BlocksAuthoredInSession::<T>::clear(u32::MAX, None);
+            Self::clear_session_tracking(session_index);
         }
     }
 
@@ -619,6 +1092,13 @@ pub mod pallet {
 
             RewardPointsForEra::<T>::remove(era_index_to_delete);
             BlocksProducedInEra::<T>::remove(era_index_to_delete);
+            let _ = WithheldPointsForEra::<T>::clear_prefix(era_index_to_delete, u32::MAX, None);
+        }
+    }
+
+    impl<T: Config> OnSlashCancelled<T::AccountId> for Pallet<T> {
+        fn on_slash_cancelled(slash_era: EraIndex, validator: &T::AccountId) {
+            Self::restore_withheld_points(slash_era, validator);
         }
     }
 
@@ -686,7 +1166,7 @@ pub mod pallet {
 ///
 /// This implements the 60/30/10 performance formula for solochain validators:
 /// - 60% weight: Block production (credited blocks vs fair share)
-/// - 30% weight: Liveness (1.0 if authored at least one block, 0.0 otherwise)
+/// - 30% weight: Liveness (block authorship, plus heartbeats and finality signatures if configured)
 /// - 10% weight: Base guarantee (always awarded)
 ///
 /// Wraps an inner SessionManager (typically `NoteHistoricalRoot<ExternalValidators>`) and calls
@@ -0,0 +1,167 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Liveness signals combined into the liveness score of the session performance points.
+//!
+//! Block authorship is always part of the score. On top of it, a runtime can plug in:
+//! - [`ImOnlineHeartbeats`]: validators that `pallet_im_online` did not report as unresponsive.
+//!   Requires wrapping the im-online `ReportUnresponsiveness` with [`RecordUnresponsiveness`],
+//!   since im-online clears its heartbeats before the session manager is called.
+//! - [`FinalitySignatures`]: validators that signed the GRANDPA/BEEFY justifications noted
+//!   during the session through the `note_finality_proofs` inherent.
+
+use {
+    crate::pallet::{
+        Config, FinalityProofsInSession, FinalitySignaturesInSession, UnresponsiveInSession,
+    },
+    frame_support::pallet_prelude::*,
+    pallet_im_online::UnresponsivenessOffence,
+    sp_staking::{
+        offence::{OffenceError, ReportOffence},
+        SessionIndex,
+    },
+    sp_std::{marker::PhantomData, vec::Vec},
+};
+
+/// A source of liveness evidence for validators.
+pub trait LivenessSource<AccountId> {
+    /// Whether `validator` is considered live in `session_index`, the session that is ending.
+    fn is_live(session_index: SessionIndex, validator: &AccountId) -> bool;
+}
+
+/// No evidence, nobody is live. Pair it with a zero weight.
+impl<AccountId> LivenessSource<AccountId> for () {
+    fn is_live(_session_index: SessionIndex, _validator: &AccountId) -> bool {
+        false
+    }
+}
+
+/// Finality gadget a proof comes from.
+#[derive(
+    Clone,
+    Copy,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    PartialEq,
+    Eq,
+    RuntimeDebug,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub enum FinalityGadget {
+    Grandpa,
+    Beefy,
+}
+
+/// Verifies finality proofs and tells which validators signed them.
+pub trait FinalityProofVerifier<AccountId> {
+    /// Proof provided by the block author through the inherent.
+    type Proof: Parameter;
+
+    /// Gadget and block number finalized by the proof.
+    fn target(proof: &Self::Proof) -> (FinalityGadget, u32);
+
+    /// Number of signatures to verify, counted against `MaxFinalitySignaturesPerBlock`.
+    fn signature_count(proof: &Self::Proof) -> u32;
+
+    /// Weight of [`Self::signers`] for the proof.
+    fn verification_weight(proof: &Self::Proof) -> Weight;
+
+    /// Validators with a valid signature in the proof, or `None` if the proof can't be verified.
+    fn signers(proof: &Self::Proof) -> Option<Vec<AccountId>>;
+}
+
+/// Rejects every proof.
+impl<AccountId> FinalityProofVerifier<AccountId> for () {
+    type Proof = ();
+
+    fn target(_proof: &Self::Proof) -> (FinalityGadget, u32) {
+        (FinalityGadget::Grandpa, 0)
+    }
+
+    fn signature_count(_proof: &Self::Proof) -> u32 {
+        0
+    }
+
+    fn verification_weight(_proof: &Self::Proof) -> Weight {
+        Weight::zero()
+    }
+
+    fn signers(_proof: &Self::Proof) -> Option<Vec<AccountId>> {
+        None
+    }
+}
+
+/// Live if `pallet_im_online` did not report the validator as unresponsive for the session,
+/// i.e. it sent a heartbeat or authored a block.
+pub struct ImOnlineHeartbeats<T>(PhantomData<T>);
+
+impl<T: Config> LivenessSource<T::AccountId> for ImOnlineHeartbeats<T> {
+    fn is_live(session_index: SessionIndex, validator: &T::AccountId) -> bool {
+        !UnresponsiveInSession::<T>::contains_key(session_index, validator)
+    }
+}
+
+/// Wraps the `ReportUnresponsiveness` of `pallet_im_online` to remember the reported validators
+/// until the session points are awarded, then forwards the offence to `Inner`.
+pub struct RecordUnresponsiveness<T, Inner>(PhantomData<(T, Inner)>);
+
+impl<T, Inner, Reporter, FullIdentification>
+    ReportOffence<
+        Reporter,
+        (T::AccountId, FullIdentification),
+        UnresponsivenessOffence<(T::AccountId, FullIdentification)>,
+    > for RecordUnresponsiveness<T, Inner>
+where
+    T: Config,
+    FullIdentification: Clone,
+    Inner: ReportOffence<
+        Reporter,
+        (T::AccountId, FullIdentification),
+        UnresponsivenessOffence<(T::AccountId, FullIdentification)>,
+    >,
+{
+    fn report_offence(
+        reporters: Vec<Reporter>,
+        offence: UnresponsivenessOffence<(T::AccountId, FullIdentification)>,
+    ) -> Result<(), OffenceError> {
+        for (validator, _) in offence.offenders.iter() {
+            UnresponsiveInSession::<T>::insert(offence.session_index, validator, ());
+        }
+
+        Inner::report_offence(reporters, offence)
+    }
+
+    fn is_known_offence(
+        offenders: &[(T::AccountId, FullIdentification)],
+        time_slot: &SessionIndex,
+    ) -> bool {
+        Inner::is_known_offence(offenders, time_slot)
+    }
+}
+
+/// Live if the validator signed at least one of the finality proofs noted in the session.
+/// Everybody is live if no proof was noted, so a session without justifications (e.g. while
+/// finality is stalled or no author provides them) does not penalize anyone.
+pub struct FinalitySignatures<T>(PhantomData<T>);
+
+impl<T: Config> LivenessSource<T::AccountId> for FinalitySignatures<T> {
+    fn is_live(_session_index: SessionIndex, validator: &T::AccountId) -> bool {
+        FinalityProofsInSession::<T>::get() == 0
+            || FinalitySignaturesInSession::<T>::get(validator) > 0
+    }
+}
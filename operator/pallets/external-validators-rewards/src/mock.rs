@@ -16,18 +16,23 @@
 
 use {
     crate as pallet_external_validators_rewards,
-    crate::types::HandleInflation,
+    crate::{
+        liveness::{FinalityGadget, FinalityProofVerifier},
+        types::HandleInflation,
+    },
     frame_support::{
         parameter_types,
         traits::{fungible::Mutate, ConstU32, ConstU64},
     },
     pallet_balances::AccountData,
     pallet_external_validators::traits::ExternalIndexProvider,
+    parity_scale_codec::{Decode, DecodeWithMemTracking, Encode},
+    scale_info::TypeInfo,
     snowbridge_outbound_queue_primitives::{SendError, SendMessageFeeProvider},
     sp_core::{H160, H256},
     sp_runtime::{
         traits::{BlakeTwo256, IdentityLookup, Keccak256},
-        BuildStorage, DispatchError,
+        BuildStorage, DispatchError, RuntimeDebug,
     },
 };
 
@@ -168,6 +173,9 @@ parameter_types! {
     // Reward split parameters: 60% block authoring, 30% liveness, 10% base
     pub const BlockAuthoringWeight: sp_runtime::Perbill = sp_runtime::Perbill::from_percent(60);
     pub const LivenessWeight: sp_runtime::Perbill = sp_runtime::Perbill::from_percent(30);
+    // Liveness signals other than block authorship are disabled unless a test sets them
+    pub HeartbeatLivenessWeight: sp_runtime::Perbill = Mock::mock().heartbeat_liveness_weight;
+    pub FinalityLivenessWeight: sp_runtime::Perbill = Mock::mock().finality_liveness_weight;
     // Soft cap: validators can earn up to 150% of fair share (50% bonus)
     pub const FairShareCap: sp_runtime::Perbill = sp_runtime::Perbill::from_percent(50);
     // Base points per block: 320 points added to the pool per block
@@ -195,10 +203,42 @@ impl frame_support::traits::ValidatorSet<H160> for MockValidatorSet {
 /// Validators in the slashed_validators list (for the given era) are considered slashed.
 pub struct MockSlashingCheck;
 impl crate::SlashingCheck<H160> for MockSlashingCheck {
-    fn is_slashed(era_index: u32, validator: &H160) -> bool {
+    fn slash_fraction(era_index: u32, validator: &H160) -> Option<sp_runtime::Perbill> {
         Mock::mock()
             .slashed_validators
-            .contains(&(era_index, *validator))
+            .into_iter()
+            .find(|(era, v, _)| *era == era_index && v == validator)
+            .map(|(_, _, fraction)| fraction)
+    }
+}
+
+/// Finality proof whose signers are given as is, valid unless `valid` is false.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, DecodeWithMemTracking, RuntimeDebug, TypeInfo)]
+pub struct MockFinalityProof {
+    pub gadget: FinalityGadget,
+    pub block_number: u32,
+    pub signers: Vec<H160>,
+    pub valid: bool,
+}
+
+pub struct MockFinalityProofVerifier;
+impl FinalityProofVerifier<H160> for MockFinalityProofVerifier {
+    type Proof = MockFinalityProof;
+
+    fn target(proof: &Self::Proof) -> (FinalityGadget, u32) {
+        (proof.gadget, proof.block_number)
+    }
+
+    fn signature_count(proof: &Self::Proof) -> u32 {
+        proof.signers.len() as u32
+    }
+
+    fn verification_weight(_proof: &Self::Proof) -> frame_support::weights::Weight {
+        frame_support::weights::Weight::zero()
+    }
+
+    fn signers(proof: &Self::Proof) -> Option<Vec<H160>> {
+        proof.valid.then(|| proof.signers.clone())
     }
 }
 
@@ -214,6 +254,12 @@ impl pallet_external_validators_rewards::Config for Test {
     type BasePointsPerBlock = BasePointsPerBlock;
     type BlockAuthoringWeight = BlockAuthoringWeight;
     type LivenessWeight = LivenessWeight;
+    type HeartbeatLiveness = crate::liveness::ImOnlineHeartbeats<Test>;
+    type HeartbeatLivenessWeight = HeartbeatLivenessWeight;
+    type FinalityLiveness = crate::liveness::FinalitySignatures<Test>;
+    type FinalityLivenessWeight = FinalityLivenessWeight;
+    type FinalityProofVerifier = MockFinalityProofVerifier;
+    type MaxFinalitySignaturesPerBlock = ConstU32<8>;
     type FairShareCap = FairShareCap;
     type ExpectedBlocksPerEra = ExpectedBlocksPerEra;
     type MinInflationPercent = MinInflationPercent;
@@ -284,8 +330,12 @@ pub mod mock_data {
         pub era_inflation: Option<u128>,
         /// Set of validators that are considered offline (for liveness testing)
         pub offline_validators: sp_std::vec::Vec<sp_core::H160>,
-        /// Set of (era_index, validator_id) pairs that are slashed
-        pub slashed_validators: sp_std::vec::Vec<(u32, sp_core::H160)>,
+        /// Set of (era_index, validator_id, slash_fraction) that are slashed
+        pub slashed_validators: sp_std::vec::Vec<(u32, sp_core::H160, sp_runtime::Perbill)>,
+        /// Share of the liveness score given to ImOnline heartbeats
+        pub heartbeat_liveness_weight: sp_runtime::Perbill,
+        /// Share of the liveness score given to finality signatures
+        pub finality_liveness_weight: sp_runtime::Perbill,
     }
 
     #[pallet::config]
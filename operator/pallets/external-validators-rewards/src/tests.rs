@@ -15,10 +15,22 @@
 // along with Tanssi.  If not, see <http://www.gnu.org/licenses/>
 
 use {
-    crate::{self as pallet_external_validators_rewards, mock::*},
+    crate::{
+        self as pallet_external_validators_rewards,
+        inherent::INHERENT_IDENTIFIER,
+        liveness::{FinalityGadget, RecordUnresponsiveness},
+        mock::*,
+        types::OperatorRewardPreview,
+        RewardPenaltyModeOption,
+    },
+    frame_support::inherent::{InherentData, ProvideInherent},
     frame_support::traits::fungible::Mutate,
-    pallet_external_validators::traits::{ActiveEraInfo, OnEraEnd, OnEraStart},
+    frame_support::{assert_noop, assert_ok},
+    pallet_external_validators::traits::{ActiveEraInfo, OnEraEnd, OnEraStart, OnSlashCancelled},
+    pallet_im_online::UnresponsivenessOffence,
     sp_core::H160,
+    sp_runtime::{DispatchError, Perbill},
+    sp_staking::offence::ReportOffence,
     sp_std::collections::btree_map::BTreeMap,
 };
 
@@ -2745,6 +2757,310 @@ fn test_session_performance_offline_validator_zero_blocks() {
     })
 }
 
+// =============================================================================
+// LIVENESS SOURCES TESTS
+// =============================================================================
+
+/// Each of validators 1, 2 and 3 authors 6 blocks.
+fn author_six_blocks_each() -> Vec<H160> {
+    let validators = vec![
+        H160::from_low_u64_be(1),
+        H160::from_low_u64_be(2),
+        H160::from_low_u64_be(3),
+    ];
+    for _ in 0..6 {
+        for v in validators.iter() {
+            ExternalValidatorsRewards::note_block_author(*v);
+        }
+    }
+    validators
+}
+
+fn finality_proof(gadget: FinalityGadget, block_number: u32, signers: &[u64]) -> MockFinalityProof {
+    MockFinalityProof {
+        gadget,
+        block_number,
+        signers: signers.iter().map(|i| H160::from_low_u64_be(*i)).collect(),
+        valid: true,
+    }
+}
+
+#[test]
+fn test_session_performance_missed_heartbeat_reduces_liveness() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1);
+
+        Mock::mutate(|mock| {
+            mock.active_era = Some(ActiveEraInfo {
+                index: 1,
+                start: None,
+            });
+            mock.heartbeat_liveness_weight = Perbill::from_percent(50);
+        });
+
+        let validators = author_six_blocks_each();
+
+        // ImOnline reports validator 2 as unresponsive at the end of session 1
+        assert_ok!(RecordUnresponsiveness::<Test, ()>::report_offence(
+            Vec::<H160>::new(),
+            UnresponsivenessOffence {
+                session_index: 1,
+                validator_set_count: 3,
+                offenders: vec![(H160::from_low_u64_be(2), ())],
+            },
+        ));
+
+        end_session(1, validators, vec![]);
+
+        // With 18 blocks total, each validator gets block_contribution = 60% × 6 × 320 = 1152
+        // Validators 1 and 3 are fully live:
+        // liveness_base_contribution = (30% + 10%) × 18 × 320 / 3 = 768, total = 1920
+        // Validator 2 only has the authorship half of the liveness score:
+        // liveness_base_contribution = (30% × 50% + 10%) × 18 × 320 / 3 = 480, total = 1632
+        let era_rewards = pallet_external_validators_rewards::RewardPointsForEra::<Test>::get(1);
+        assert_eq!(
+            era_rewards.individual.get(&H160::from_low_u64_be(1)),
+            Some(&1920)
+        );
+        assert_eq!(
+            era_rewards.individual.get(&H160::from_low_u64_be(2)),
+            Some(&1632)
+        );
+        assert_eq!(
+            era_rewards.individual.get(&H160::from_low_u64_be(3)),
+            Some(&1920)
+        );
+
+        // The report is only kept until the session points are awarded
+        assert_eq!(
+            pallet_external_validators_rewards::UnresponsiveInSession::<Test>::iter().count(),
+            0
+        );
+    })
+}
+
+#[test]
+fn test_session_performance_finality_signatures_as_liveness() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1);
+
+        Mock::mutate(|mock| {
+            mock.active_era = Some(ActiveEraInfo {
+                index: 1,
+                start: None,
+            });
+            mock.finality_liveness_weight = Perbill::from_percent(100);
+        });
+
+        let validators = author_six_blocks_each();
+
+        let invalid_beefy = MockFinalityProof {
+            valid: false,
+            ..finality_proof(FinalityGadget::Beefy, 3, &[1, 3])
+        };
+        assert_ok!(ExternalValidatorsRewards::note_finality_proofs(
+            RuntimeOrigin::none(),
+            vec![
+                finality_proof(FinalityGadget::Grandpa, 5, &[1, 2]),
+                invalid_beefy
+            ],
+        ));
+        assert_eq!(
+            pallet_external_validators_rewards::FinalityProofsInSession::<Test>::get(),
+            1
+        );
+
+        end_session(1, validators, vec![]);
+
+        // Liveness only comes from finality signatures, validator 3 signed nothing valid
+        // Validators 1 and 2: 1152 + (30% + 10%) × 18 × 320 / 3 = 1920
+        // Validator 3: 1152 + 10% × 18 × 320 / 3 = 1344
+        let era_rewards = pallet_external_validators_rewards::RewardPointsForEra::<Test>::get(1);
+        assert_eq!(
+            era_rewards.individual.get(&H160::from_low_u64_be(1)),
+            Some(&1920)
+        );
+        assert_eq!(
+            era_rewards.individual.get(&H160::from_low_u64_be(2)),
+            Some(&1920)
+        );
+        assert_eq!(
+            era_rewards.individual.get(&H160::from_low_u64_be(3)),
+            Some(&1344)
+        );
+
+        // Session tracking is cleared, the last noted block is kept
+        assert_eq!(
+            pallet_external_validators_rewards::FinalityProofsInSession::<Test>::get(),
+            0
+        );
+        assert_eq!(
+            pallet_external_validators_rewards::FinalitySignaturesInSession::<Test>::iter().count(),
+            0
+        );
+        assert_eq!(
+            pallet_external_validators_rewards::LastFinalityProofBlock::<Test>::get(
+                FinalityGadget::Grandpa
+            ),
+            5
+        );
+    })
+}
+
+#[test]
+fn test_session_performance_no_finality_proofs_is_neutral() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1);
+
+        Mock::mutate(|mock| {
+            mock.active_era = Some(ActiveEraInfo {
+                index: 1,
+                start: None,
+            });
+            mock.finality_liveness_weight = Perbill::from_percent(100);
+        });
+
+        let validators = author_six_blocks_each();
+        end_session(1, validators, vec![]);
+
+        // Without any proof noted in the session, everybody is considered live
+        let era_rewards = pallet_external_validators_rewards::RewardPointsForEra::<Test>::get(1);
+        assert_eq!(era_rewards.total, 1920 * 3);
+    })
+}
+
+#[test]
+fn note_finality_proofs_ignores_old_proofs_and_respects_budget() {
+    new_test_ext().execute_with(|| {
+        run_to_block(1);
+
+        assert_ok!(ExternalValidatorsRewards::note_finality_proofs(
+            RuntimeOrigin::none(),
+            vec![finality_proof(FinalityGadget::Grandpa, 5, &[1, 1, 2])],
+        ));
+        // Same and older GRANDPA proofs are not counted again, BEEFY is tracked separately
+        assert_ok!(ExternalValidatorsRewards::note_finality_proofs(
+            RuntimeOrigin::none(),
+            vec![
+                finality_proof(FinalityGadget::Grandpa, 5, &[1]),
+                finality_proof(FinalityGadget::Beefy, 4, &[1]),
+            ],
+        ));
+        assert_ok!(ExternalValidatorsRewards::note_finality_proofs(
+            RuntimeOrigin::none(),
+            vec![finality_proof(FinalityGadget::Grandpa, 4, &[1])],
+        ));
+        // MaxFinalitySignaturesPerBlock is 8, the oversized proof is skipped
+        assert_ok!(ExternalValidatorsRewards::note_finality_proofs(
+            RuntimeOrigin::none(),
+            vec![
+                finality_proof(FinalityGadget::Grandpa, 6, &[1, 2, 3, 4, 5, 6, 7, 8, 9]),
+                finality_proof(FinalityGadget::Beefy, 7, &[2]),
+            ],
+        ));
+
+        assert_eq!(
+            pallet_external_validators_rewards::FinalityProofsInSession::<Test>::get(),
+            3
+        );
+        assert_eq!(
+            pallet_external_validators_rewards::FinalitySignaturesInSession::<Test>::get(
+                H160::from_low_u64_be(1)
+            ),
+            2
+        );
+        assert_eq!(
+            pallet_external_validators_rewards::FinalitySignaturesInSession::<Test>::get(
+                H160::from_low_u64_be(2)
+            ),
+            2
+        );
+        assert_eq!(
+            pallet_external_validators_rewards::LastFinalityProofBlock::<Test>::get(
+                FinalityGadget::Grandpa
+            ),
+            5
+        );
+    })
+}
+
+#[test]
+fn note_finality_proofs_is_an_inherent() {
+    new_test_ext().execute_with(|| {
+        let proofs = vec![finality_proof(FinalityGadget::Beefy, 1, &[1])];
+
+        assert_noop!(
+            ExternalValidatorsRewards::note_finality_proofs(
+                RuntimeOrigin::signed(H160::from_low_u64_be(1)),
+                proofs.clone(),
+            ),
+            DispatchError::BadOrigin
+        );
+
+        let mut data = InherentData::new();
+        assert_eq!(ExternalValidatorsRewards::create_inherent(&data), None);
+        data.put_data(INHERENT_IDENTIFIER, &proofs).unwrap();
+        let call = ExternalValidatorsRewards::create_inherent(&data).unwrap();
+        assert_eq!(
+            call,
+            pallet_external_validators_rewards::Call::note_finality_proofs { proofs }
+        );
+        assert!(ExternalValidatorsRewards::is_inherent(&call));
+    })
+}
+
+#[test]
+fn finality_proofs_inherent_only_carries_noteworthy_proofs() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(ExternalValidatorsRewards::note_finality_proofs(
+            RuntimeOrigin::none(),
+            vec![finality_proof(FinalityGadget::Grandpa, 5, &[1])],
+        ));
+
+        let stale = finality_proof(FinalityGadget::Grandpa, 5, &[1, 2]);
+        let invalid = MockFinalityProof {
+            valid: false,
+            ..finality_proof(FinalityGadget::Beefy, 3, &[1])
+        };
+        let valid = finality_proof(FinalityGadget::Grandpa, 6, &[1, 2]);
+
+        let mut data = InherentData::new();
+        data.put_data(
+            INHERENT_IDENTIFIER,
+            &vec![stale.clone(), invalid.clone(), valid.clone()],
+        )
+        .unwrap();
+        let call = ExternalValidatorsRewards::create_inherent(&data).unwrap();
+        assert_eq!(
+            call,
+            pallet_external_validators_rewards::Call::note_finality_proofs {
+                proofs: vec![valid.clone()]
+            }
+        );
+        assert!(ExternalValidatorsRewards::check_inherent(&call, &data).is_ok());
+
+        // Nothing is left to note
+        let mut data = InherentData::new();
+        data.put_data(INHERENT_IDENTIFIER, &vec![stale.clone(), invalid.clone()])
+            .unwrap();
+        assert_eq!(ExternalValidatorsRewards::create_inherent(&data), None);
+
+        // Blocks noting stale, invalid or oversized proofs are rejected
+        for proofs in [
+            vec![stale],
+            vec![valid.clone(), invalid],
+            vec![finality_proof(
+                FinalityGadget::Beefy,
+                7,
+                &[1, 2, 3, 4, 5, 6, 7, 8, 9],
+            )],
+        ] {
+            let call = pallet_external_validators_rewards::Call::note_finality_proofs { proofs };
+            assert!(ExternalValidatorsRewards::check_inherent(&call, &data).is_err());
+        }
+    })
+}
+
 // =============================================================================
 // WEIGHT OVERFLOW HANDLING TESTS
 // =============================================================================
@@ -2796,15 +3112,12 @@ fn test_session_performance_weight_overflow_handled() {
 }
 
 // =============================================================================
-// SLASHING TESTS (Note: Slashing logic is currently disabled in lib.rs)
+// SLASHING TESTS (Note: RewardPenaltyMode defaults to Ignore)
 // =============================================================================
 
 #[test]
 fn test_slashing_check_mock_works() {
     // This test verifies that the MockSlashingCheck correctly identifies slashed validators.
-    // Note: The actual slashing logic in award_session_performance_points is currently
-    // commented out (disabled), so slashed validators still receive rewards.
-    // This test validates the mock infrastructure is ready for when slashing is re-enabled.
     new_test_ext().execute_with(|| {
         Mock::mutate(|mock| {
             mock.active_era = Some(ActiveEraInfo {
@@ -2812,7 +3125,8 @@ fn test_slashing_check_mock_works() {
                 start: None,
             });
             // Mark validator 2 as slashed in era 1
-            mock.slashed_validators = vec![(1, H160::from_low_u64_be(2))];
+            mock.slashed_validators =
+                vec![(1, H160::from_low_u64_be(2), Perbill::from_percent(50))];
         });
 
         // Verify MockSlashingCheck works correctly
@@ -2834,9 +3148,7 @@ fn test_slashing_check_mock_works() {
 
 #[test]
 fn test_session_performance_slashed_validator_still_gets_points_when_disabled() {
-    // This test documents the CURRENT behavior where slashing is disabled.
-    // Slashed validators still receive points because the slashing check
-    // in award_session_performance_points is commented out.
+    // With the default Ignore penalty mode, slashed validators still receive points.
     new_test_ext().execute_with(|| {
         run_to_block(1);
 
@@ -2846,7 +3158,8 @@ fn test_session_performance_slashed_validator_still_gets_points_when_disabled()
                 start: None,
             });
             // Mark validator 2 as slashed
-            mock.slashed_validators = vec![(1, H160::from_low_u64_be(2))];
+            mock.slashed_validators =
+                vec![(1, H160::from_low_u64_be(2), Perbill::from_percent(50))];
         });
 
         let validators = vec![H160::from_low_u64_be(1), H160::from_low_u64_be(2)];
@@ -2884,6 +3197,200 @@ fn test_session_performance_slashed_validator_still_gets_points_when_disabled()
     })
 }
 
+fn slashed_session_points(mode: RewardPenaltyModeOption) -> (u32, u32) {
+    run_to_block(1);
+
+    Mock::mutate(|mock| {
+        mock.active_era = Some(ActiveEraInfo {
+            index: 1,
+            start: None,
+        });
+        mock.slashed_validators = vec![(1, H160::from_low_u64_be(2), Perbill::from_percent(25))];
+    });
+    assert_ok!(ExternalValidatorsRewards::set_reward_penalty_mode(
+        RuntimeOrigin::root(),
+        mode
+    ));
+
+    let validators = vec![H160::from_low_u64_be(1), H160::from_low_u64_be(2)];
+    for _ in 0..5 {
+        ExternalValidatorsRewards::note_block_author(H160::from_low_u64_be(1));
+        ExternalValidatorsRewards::note_block_author(H160::from_low_u64_be(2));
+    }
+    end_session(1, validators, vec![]);
+
+    // Without penalty each validator earns 1600 points
+    let era_rewards = pallet_external_validators_rewards::RewardPointsForEra::<Test>::get(1);
+    (
+        *era_rewards
+            .individual
+            .get(&H160::from_low_u64_be(2))
+            .unwrap_or(&0),
+        era_rewards.total,
+    )
+}
+
+#[test]
+fn test_reward_penalty_zero_points_withholds_all_points() {
+    new_test_ext().execute_with(|| {
+        let (points, total) = slashed_session_points(RewardPenaltyModeOption::ZeroPoints);
+
+        assert_eq!(points, 0);
+        assert_eq!(total, 1600);
+        assert_eq!(
+            pallet_external_validators_rewards::WithheldPointsForEra::<Test>::get(
+                1,
+                H160::from_low_u64_be(2)
+            ),
+            1600
+        );
+        System::assert_has_event(RuntimeEvent::ExternalValidatorsRewards(
+            crate::Event::RewardPointsWithheld {
+                era_index: 1,
+                validator: H160::from_low_u64_be(2),
+                points: 1600,
+            },
+        ));
+    })
+}
+
+#[test]
+fn test_reward_penalty_slash_fraction_cuts_points() {
+    new_test_ext().execute_with(|| {
+        let (points, total) = slashed_session_points(RewardPenaltyModeOption::SlashFraction);
+
+        // 25% of 1600 is withheld
+        assert_eq!(points, 1200);
+        assert_eq!(total, 2800);
+        assert_eq!(
+            pallet_external_validators_rewards::WithheldPointsForEra::<Test>::get(
+                1,
+                H160::from_low_u64_be(2)
+            ),
+            400
+        );
+    })
+}
+
+#[test]
+fn test_cancelled_slash_restores_withheld_points_in_active_era() {
+    new_test_ext().execute_with(|| {
+        slashed_session_points(RewardPenaltyModeOption::ZeroPoints);
+
+        // The cancelled slash was the only one of the validator in the era
+        Mock::mutate(|mock| mock.slashed_validators.clear());
+        ExternalValidatorsRewards::on_slash_cancelled(1, &H160::from_low_u64_be(2));
+
+        let era_rewards = pallet_external_validators_rewards::RewardPointsForEra::<Test>::get(1);
+        assert_eq!(era_rewards.individual[&H160::from_low_u64_be(2)], 1600);
+        assert_eq!(era_rewards.total, 3200);
+        assert_eq!(
+            pallet_external_validators_rewards::WithheldPointsForEra::<Test>::get(
+                1,
+                H160::from_low_u64_be(2)
+            ),
+            0
+        );
+        System::assert_last_event(RuntimeEvent::ExternalValidatorsRewards(
+            crate::Event::WithheldRewardPointsRestored {
+                era_index: 1,
+                validator: H160::from_low_u64_be(2),
+                points: 1600,
+            },
+        ));
+
+        // The validator is no longer penalized for the rest of the era
+        for _ in 0..5 {
+            ExternalValidatorsRewards::note_block_author(H160::from_low_u64_be(1));
+            ExternalValidatorsRewards::note_block_author(H160::from_low_u64_be(2));
+        }
+        end_session(
+            2,
+            vec![H160::from_low_u64_be(1), H160::from_low_u64_be(2)],
+            vec![],
+        );
+        let era_rewards = pallet_external_validators_rewards::RewardPointsForEra::<Test>::get(1);
+        assert_eq!(era_rewards.individual[&H160::from_low_u64_be(2)], 3200);
+    })
+}
+
+#[test]
+fn test_cancelled_slash_keeps_penalty_of_slashes_left_in_era() {
+    new_test_ext().execute_with(|| {
+        slashed_session_points(RewardPenaltyModeOption::SlashFraction);
+
+        // Another slash of the validator in the era is left
+        Mock::mutate(|mock| {
+            mock.slashed_validators = vec![(1, H160::from_low_u64_be(2), Perbill::from_percent(10))]
+        });
+        ExternalValidatorsRewards::on_slash_cancelled(1, &H160::from_low_u64_be(2));
+
+        let era_rewards = pallet_external_validators_rewards::RewardPointsForEra::<Test>::get(1);
+        assert_eq!(era_rewards.individual[&H160::from_low_u64_be(2)], 1200);
+        assert_eq!(
+            pallet_external_validators_rewards::WithheldPointsForEra::<Test>::get(
+                1,
+                H160::from_low_u64_be(2)
+            ),
+            400
+        );
+
+        // The next sessions are penalized by the slash left
+        for _ in 0..5 {
+            ExternalValidatorsRewards::note_block_author(H160::from_low_u64_be(1));
+            ExternalValidatorsRewards::note_block_author(H160::from_low_u64_be(2));
+        }
+        end_session(
+            2,
+            vec![H160::from_low_u64_be(1), H160::from_low_u64_be(2)],
+            vec![],
+        );
+        let era_rewards = pallet_external_validators_rewards::RewardPointsForEra::<Test>::get(1);
+        assert_eq!(
+            era_rewards.individual[&H160::from_low_u64_be(2)],
+            1200 + 1440
+        );
+    })
+}
+
+#[test]
+fn test_cancelled_slash_does_not_restore_points_of_ended_era() {
+    new_test_ext().execute_with(|| {
+        slashed_session_points(RewardPenaltyModeOption::ZeroPoints);
+
+        Mock::mutate(|mock| {
+            mock.active_era = Some(ActiveEraInfo {
+                index: 2,
+                start: None,
+            })
+        });
+        ExternalValidatorsRewards::on_slash_cancelled(1, &H160::from_low_u64_be(2));
+
+        let era_rewards = pallet_external_validators_rewards::RewardPointsForEra::<Test>::get(1);
+        assert_eq!(era_rewards.total, 1600);
+        assert_eq!(
+            pallet_external_validators_rewards::WithheldPointsForEra::<Test>::get(
+                1,
+                H160::from_low_u64_be(2)
+            ),
+            1600
+        );
+    })
+}
+
+#[test]
+fn test_only_root_can_set_reward_penalty_mode() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            ExternalValidatorsRewards::set_reward_penalty_mode(
+                RuntimeOrigin::signed(H160::from_low_u64_be(1)),
+                RewardPenaltyModeOption::ZeroPoints
+            ),
+            DispatchError::BadOrigin
+        );
+    })
+}
+
 // =============================================================================
 // EDGE CASE TESTS
 // =============================================================================
@@ -3718,3 +4225,117 @@ fn test_era_end_uses_correct_era_blocks_not_session() {
         );
     })
 }
+
+#[test]
+fn era_rewards_preview_projects_active_era_and_matches_closed_era() {
+    new_test_ext().execute_with(|| {
+        Mock::mutate(|mock| {
+            mock.active_era = Some(ActiveEraInfo {
+                index: 1,
+                start: None,
+            });
+            mock.era_inflation = Some(1_000);
+        });
+        ExternalValidatorsRewards::reward_by_ids([
+            (H160::from_low_u64_be(5), 4),
+            (H160::from_low_u64_be(1), 1),
+            (H160::from_low_u64_be(3), 2),
+        ]);
+        // Half the expected blocks: 20% + 50% × (100% - 20%) = 60% inflation
+        pallet_external_validators_rewards::BlocksProducedInEra::<Test>::insert(1, 300);
+
+        let preview = ExternalValidatorsRewards::era_rewards_preview(1).unwrap();
+        assert!(preview.in_progress);
+        assert_eq!(preview.blocks_produced, 300);
+        assert_eq!(preview.expected_blocks, 600);
+        assert_eq!(preview.inflation_percent, 60);
+        assert_eq!(preview.base_inflation, 1_000);
+        assert_eq!(
+            preview.inflation_amount,
+            ExternalValidatorsRewards::calculate_scaled_inflation(1, 1_000)
+        );
+        assert_eq!(preview.inflation_amount, 600);
+        assert_eq!(preview.total_points, 7);
+        assert_eq!(
+            preview.operators,
+            vec![
+                OperatorRewardPreview {
+                    operator: H160::from_low_u64_be(1),
+                    points: 1,
+                    amount: 85,
+                },
+                OperatorRewardPreview {
+                    operator: H160::from_low_u64_be(3),
+                    points: 2,
+                    amount: 171,
+                },
+                OperatorRewardPreview {
+                    operator: H160::from_low_u64_be(5),
+                    points: 4,
+                    amount: 342,
+                },
+            ]
+        );
+        assert_eq!(preview.remainder, 2);
+
+        // Once the era is over the preview is final
+        Mock::mutate(|mock| {
+            mock.active_era = Some(ActiveEraInfo {
+                index: 2,
+                start: None,
+            })
+        });
+        let closed = ExternalValidatorsRewards::era_rewards_preview(1).unwrap();
+        assert!(!closed.in_progress);
+        assert_eq!(closed.operators, preview.operators);
+        assert_eq!(closed.remainder, preview.remainder);
+    })
+}
+
+#[test]
+fn era_rewards_preview_lists_no_operator_when_the_split_overflows() {
+    new_test_ext().execute_with(|| {
+        Mock::mutate(|mock| {
+            mock.active_era = Some(ActiveEraInfo {
+                index: 1,
+                start: None,
+            });
+            mock.era_inflation = Some(u128::MAX);
+        });
+        ExternalValidatorsRewards::reward_by_ids([
+            (H160::from_low_u64_be(1), 1),
+            (H160::from_low_u64_be(2), 2),
+        ]);
+        pallet_external_validators_rewards::BlocksProducedInEra::<Test>::insert(1, 600);
+
+        // The submission refuses to send these rewards, so the preview has none
+        let preview = ExternalValidatorsRewards::era_rewards_preview(1).unwrap();
+        assert_eq!(preview.inflation_amount, u128::MAX);
+        assert!(preview.operators.is_empty());
+        assert_eq!(preview.total_points, 0);
+    })
+}
+
+#[test]
+fn era_rewards_preview_only_covers_retained_eras() {
+    new_test_ext().execute_with(|| {
+        Mock::mutate(|mock| {
+            mock.active_era = Some(ActiveEraInfo {
+                index: 11,
+                start: None,
+            })
+        });
+
+        // Future eras have no preview
+        assert_eq!(ExternalValidatorsRewards::era_rewards_preview(12), None);
+        // HistoryDepth is 10, era 1 points were pruned when era 11 started
+        assert_eq!(ExternalValidatorsRewards::era_rewards_preview(1), None);
+
+        // A retained era without points distributes nothing
+        let preview = ExternalValidatorsRewards::era_rewards_preview(2).unwrap();
+        assert!(!preview.in_progress);
+        assert_eq!(preview.total_points, 0);
+        assert!(preview.operators.is_empty());
+        assert_eq!(preview.remainder, 0);
+    })
+}
@@ -14,8 +14,11 @@
 // You should have received a copy of the GNU General Public License
 // along with Tanssi.  If not, see <http://www.gnu.org/licenses/>
 
+use parity_scale_codec::{Decode, Encode};
+use scale_info::TypeInfo;
 use snowbridge_outbound_queue_primitives::SendError;
 use sp_core::{H160, H256};
+use sp_runtime::RuntimeDebug;
 use sp_std::vec::Vec;
 
 /// Data needed for EigenLayer rewards submission via Snowbridge.
@@ -28,6 +31,97 @@ pub struct EraRewardsUtils {
     pub inflation_amount: u128,
 }
 
+/// Expected payout of a single operator for an era.
+#[derive(
+    Clone,
+    Encode,
+    Decode,
+    PartialEq,
+    Eq,
+    RuntimeDebug,
+    TypeInfo,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+pub struct OperatorRewardPreview {
+    /// Operator address, as sent to EigenLayer.
+    pub operator: H160,
+    /// Reward points earned by the operator in the era.
+    pub points: u32,
+    /// Tokens the operator receives: `points * inflation_amount / total_points`, rounded down.
+    pub amount: u128,
+}
+
+/// Preview of the rewards submission for an era, as it would be sent to EigenLayer.
+#[derive(
+    Clone,
+    Encode,
+    Decode,
+    PartialEq,
+    Eq,
+    RuntimeDebug,
+    TypeInfo,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct EraRewardsPreview {
+    pub era_index: u32,
+    /// Whether the era is still active, in which case the preview is a projection computed as if
+    /// the era ended at the queried block.
+    pub in_progress: bool,
+    /// Blocks produced so far in the era.
+    pub blocks_produced: u32,
+    /// Blocks expected to be produced in a full era.
+    pub expected_blocks: u32,
+    /// Percentage of the base inflation minted for the era, scaled by block production.
+    pub inflation_percent: u32,
+    /// Base era inflation, before scaling.
+    pub base_inflation: u128,
+    /// Scaled inflation to be distributed among operators.
+    pub inflation_amount: u128,
+    /// Sum of all operators' points.
+    pub total_points: u128,
+    /// Per operator points and expected amounts, sorted by operator address.
+    pub operators: Vec<OperatorRewardPreview>,
+    /// Dust left undistributed by rounding down each operator's amount.
+    pub remainder: u128,
+}
+
+/// Error splitting the inflation of an era between operators.
+#[derive(Clone, Copy, PartialEq, Eq, RuntimeDebug)]
+pub enum RewardsSplitError {
+    /// `points * inflation` overflowed.
+    MultiplicationOverflow,
+    /// The total of the points is zero.
+    DivisionByZero,
+}
+
+/// Split `inflation` between operators proportionally to their `points`, rounding down.
+///
+/// Returns the amount of each operator, in the order of `points`, and the remainder left by the
+/// rounding. Used both to submit the rewards and to preview them, so both always agree.
+pub fn split_inflation(
+    points: &[(H160, u32)],
+    total_points: u128,
+    inflation: u128,
+) -> Result<(Vec<u128>, u128), RewardsSplitError> {
+    let mut amounts = Vec::with_capacity(points.len());
+    let mut distributed = 0u128;
+
+    for &(_, points) in points {
+        let amount = (points as u128)
+            .checked_mul(inflation)
+            .ok_or(RewardsSplitError::MultiplicationOverflow)?
+            .checked_div(total_points)
+            .ok_or(RewardsSplitError::DivisionByZero)?;
+        distributed = distributed.saturating_add(amount);
+        amounts.push(amount);
+    }
+
+    Ok((amounts, inflation.saturating_sub(distributed)))
+}
+
 pub trait SendMessage {
     type Message;
     type Ticket;
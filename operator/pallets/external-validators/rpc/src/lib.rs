@@ -0,0 +1,154 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! RPC interface for the external validators pallet.
+//!
+//! Serves the `externalValidators_*` namespace on top of [`ExternalValidatorsRuntimeApi`].
+
+pub use pallet_external_validators_runtime_api::ExternalValidatorsApi as ExternalValidatorsRuntimeApi;
+use {
+    jsonrpsee::{
+        core::RpcResult,
+        proc_macros::rpc,
+        types::error::{ErrorObject, ErrorObjectOwned},
+    },
+    pallet_external_validators_runtime_api::{EraIndex, EraSummary, ExternalIndexInfo},
+    parity_scale_codec::Codec,
+    serde::Serialize,
+    sp_api::{ApiError, ProvideRuntimeApi},
+    sp_blockchain::HeaderBackend,
+    sp_runtime::traits::Block as BlockT,
+    sp_staking::SessionIndex,
+    std::{marker::PhantomData, sync::Arc},
+};
+
+/// Error code returned when the runtime API call fails.
+const RUNTIME_ERROR: i32 = 1;
+
+#[rpc(client, server)]
+pub trait ExternalValidatorsApi<BlockHash, ValidatorId> {
+    /// Active and planned era together with their session boundaries.
+    #[method(name = "externalValidators_eraSummary")]
+    fn era_summary(&self, at: Option<BlockHash>) -> RpcResult<EraSummary>;
+
+    /// Session index at which `era` started, if it is still kept in history.
+    #[method(name = "externalValidators_eraStartSessionIndex")]
+    fn era_start_session_index(
+        &self,
+        era: EraIndex,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<SessionIndex>>;
+
+    /// Fixed validators set by governance.
+    #[method(name = "externalValidators_whitelistedValidators")]
+    fn whitelisted_validators(&self, at: Option<BlockHash>) -> RpcResult<Vec<ValidatorId>>;
+
+    /// Latest validators received from Ethereum.
+    #[method(name = "externalValidators_externalValidators")]
+    fn external_validators(&self, at: Option<BlockHash>) -> RpcResult<Vec<ValidatorId>>;
+
+    /// Validator set that will be applied when the next era is planned.
+    #[method(name = "externalValidators_nextEraValidators")]
+    fn next_era_validators(&self, at: Option<BlockHash>) -> RpcResult<Vec<ValidatorId>>;
+
+    /// Latest received, pending and applied external indexes.
+    #[method(name = "externalValidators_externalIndex")]
+    fn external_index(&self, at: Option<BlockHash>) -> RpcResult<ExternalIndexInfo>;
+}
+
+/// Implementation of [`ExternalValidatorsApiServer`] backed by the runtime API.
+pub struct ExternalValidators<C, Block> {
+    client: Arc<C>,
+    _marker: PhantomData<Block>,
+}
+
+impl<C, Block> ExternalValidators<C, Block> {
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: PhantomData,
+        }
+    }
+}
+
+fn runtime_error_into_rpc_err(err: ApiError) -> ErrorObjectOwned {
+    ErrorObject::owned(
+        RUNTIME_ERROR,
+        "Unable to query external validators state",
+        Some(err.to_string()),
+    )
+}
+
+impl<C, Block, ValidatorId> ExternalValidatorsApiServer<Block::Hash, ValidatorId>
+    for ExternalValidators<C, Block>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: ExternalValidatorsRuntimeApi<Block, ValidatorId>,
+    ValidatorId: Codec + Serialize + Send + Sync + 'static,
+{
+    fn era_summary(&self, at: Option<Block::Hash>) -> RpcResult<EraSummary> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.client
+            .runtime_api()
+            .era_summary(at)
+            .map_err(runtime_error_into_rpc_err)
+    }
+
+    fn era_start_session_index(
+        &self,
+        era: EraIndex,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Option<SessionIndex>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.client
+            .runtime_api()
+            .era_start_session_index(at, era)
+            .map_err(runtime_error_into_rpc_err)
+    }
+
+    fn whitelisted_validators(&self, at: Option<Block::Hash>) -> RpcResult<Vec<ValidatorId>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.client
+            .runtime_api()
+            .whitelisted_validators(at)
+            .map_err(runtime_error_into_rpc_err)
+    }
+
+    fn external_validators(&self, at: Option<Block::Hash>) -> RpcResult<Vec<ValidatorId>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.client
+            .runtime_api()
+            .external_validators(at)
+            .map_err(runtime_error_into_rpc_err)
+    }
+
+    fn next_era_validators(&self, at: Option<Block::Hash>) -> RpcResult<Vec<ValidatorId>> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.client
+            .runtime_api()
+            .next_era_validators(at)
+            .map_err(runtime_error_into_rpc_err)
+    }
+
+    fn external_index(&self, at: Option<Block::Hash>) -> RpcResult<ExternalIndexInfo> {
+        let at = at.unwrap_or_else(|| self.client.info().best_hash);
+        self.client
+            .runtime_api()
+            .external_index(at)
+            .map_err(runtime_error_into_rpc_err)
+    }
+}
@@ -0,0 +1,51 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Runtime API for the external validators pallet.
+//!
+//! Exposes the era state kept by `pallet-external-validators` so that off-chain tooling does not
+//! need to decode raw storage.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet_external_validators::{traits::EraIndex, EraSummary, ExternalIndexInfo};
+use {parity_scale_codec::Codec, sp_staking::SessionIndex, sp_std::vec::Vec};
+
+sp_api::decl_runtime_apis! {
+    #[api_version(1)]
+    pub trait ExternalValidatorsApi<ValidatorId>
+    where
+        ValidatorId: Codec,
+    {
+        /// Active and planned era together with their session boundaries.
+        fn era_summary() -> EraSummary;
+
+        /// Session index at which `era` started, if it is still kept in history.
+        fn era_start_session_index(era: EraIndex) -> Option<SessionIndex>;
+
+        /// Fixed validators set by governance.
+        fn whitelisted_validators() -> Vec<ValidatorId>;
+
+        /// Latest validators received from Ethereum, regardless of `SkipExternalValidators`.
+        fn external_validators() -> Vec<ValidatorId>;
+
+        /// Validator set that will be applied when the next era is planned.
+        fn next_era_validators() -> Vec<ValidatorId>;
+
+        /// Latest received, pending and applied external indexes.
+        fn external_index() -> ExternalIndexInfo;
+    }
+}
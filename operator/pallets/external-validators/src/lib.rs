@@ -472,6 +472,10 @@ pub mod pallet {
             <WhitelistedValidators<T>>::get().into()
         }
 
+        pub fn external_validators() -> Vec<T::ValidatorId> {
+            <ExternalValidators<T>>::get().into()
+        }
+
         pub fn active_era() -> Option<ActiveEraInfo> {
             <ActiveEra<T>>::get()
         }
@@ -484,6 +488,33 @@ pub mod pallet {
             <ErasStartSessionIndex<T>>::get(era)
         }
 
+        /// Returns the active and planned era together with their session boundaries.
+        pub fn era_summary() -> EraSummary {
+            let active_era = Self::active_era();
+            let active_era_index = active_era.as_ref().map(|info| info.index);
+
+            EraSummary {
+                active_era: active_era_index,
+                active_era_start: active_era.and_then(|info| info.start),
+                current_era: Self::current_era(),
+                active_era_start_session: active_era_index.and_then(Self::eras_start_session_index),
+                // Only known once the next era has been planned.
+                next_era_start_session: active_era_index
+                    .and_then(|index| Self::eras_start_session_index(index.saturating_add(1))),
+                sessions_per_era: T::SessionsPerEra::get(),
+                forcing: ForceEra::<T>::get(),
+            }
+        }
+
+        /// Returns the latest received, pending and applied external indexes.
+        pub fn external_index_info() -> ExternalIndexInfo {
+            ExternalIndexInfo {
+                latest: ExternalIndex::<T>::get(),
+                pending: PendingExternalIndex::<T>::get(),
+                applied: CurrentExternalIndex::<T>::get(),
+            }
+        }
+
         /// Returns validators for the next session. Whitelisted validators first, then external validators.
         /// The returned list is deduplicated, but the order is respected.
         /// If `SkipExternalValidators` is true, this function will ignore external validators.
@@ -763,7 +794,18 @@ impl<T: Config> InvulnerablesProvider<T::ValidatorId> for Pallet<T> {
 
 /// Mode of era-forcing.
 #[derive(
-    Copy, Clone, PartialEq, Eq, Default, Encode, Decode, RuntimeDebug, TypeInfo, MaxEncodedLen,
+    Copy,
+    Clone,
+    PartialEq,
+    Eq,
+    Default,
+    Encode,
+    Decode,
+    RuntimeDebug,
+    TypeInfo,
+    MaxEncodedLen,
+    serde::Serialize,
+    serde::Deserialize,
 )]
 pub enum Forcing {
     /// Not forcing anything - just let whatever happen.
@@ -776,3 +818,56 @@ pub enum Forcing {
     /// Force a new era at the end of all sessions indefinitely.
     ForceAlways,
 }
+
+/// Snapshot of the era state, as returned by the runtime API.
+#[derive(
+    Clone,
+    PartialEq,
+    Eq,
+    Encode,
+    Decode,
+    RuntimeDebug,
+    TypeInfo,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+#[serde(rename_all = "camelCase")]
+pub struct EraSummary {
+    /// Index of the active era, `None` before the first era starts.
+    pub active_era: Option<EraIndex>,
+    /// Start of the active era in milliseconds since `$UNIX_EPOCH`.
+    pub active_era_start: Option<u64>,
+    /// Latest planned era. Either the active era or the next one if it has been queued.
+    pub current_era: Option<EraIndex>,
+    /// Session index at which the active era started.
+    pub active_era_start_session: Option<SessionIndex>,
+    /// Session index at which the next era will start, if it has already been planned.
+    pub next_era_start_session: Option<SessionIndex>,
+    /// Number of sessions per era.
+    pub sessions_per_era: SessionIndex,
+    /// Current era forcing mode.
+    pub forcing: Forcing,
+}
+
+/// External indexes tracked by the pallet, as returned by the runtime API.
+#[derive(
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Default,
+    Encode,
+    Decode,
+    RuntimeDebug,
+    TypeInfo,
+    serde::Serialize,
+    serde::Deserialize,
+)]
+pub struct ExternalIndexInfo {
+    /// Latest external index received with a validator set.
+    pub latest: u64,
+    /// External index that will be applied at the start of the next era.
+    pub pending: u64,
+    /// External index attached to the validators of the active era.
+    pub applied: u64,
+}
@@ -21,7 +21,7 @@ use {
             Mock, RootAccount, RuntimeEvent, RuntimeOrigin, Session, System, Test,
         },
         traits::{ExternalIndexProvider, ValidatorProvider},
-        Error,
+        Error, ExternalIndexInfo, Forcing,
     },
     frame_support::{assert_noop, assert_ok},
     sp_runtime::traits::BadOrigin,
@@ -553,3 +553,58 @@ fn era_boundary_race_resubmit_without_advance() {
         );
     });
 }
+
+#[test]
+fn era_summary_tracks_era_and_session_boundaries() {
+    new_test_ext().execute_with(|| {
+        // Advance to era 1 (session 6 starts era 1)
+        run_to_session(6);
+
+        let summary = ExternalValidators::era_summary();
+        assert_eq!(summary.active_era, Some(1));
+        assert_eq!(summary.current_era, Some(1));
+        assert_eq!(summary.active_era_start_session, Some(6));
+        assert_eq!(summary.next_era_start_session, None);
+        assert_eq!(summary.sessions_per_era, 6);
+        assert_eq!(summary.forcing, Forcing::NotForcing);
+
+        // Era 2 gets planned one session before it starts
+        run_to_session(11);
+
+        let summary = ExternalValidators::era_summary();
+        assert_eq!(summary.active_era, Some(1));
+        assert_eq!(summary.current_era, Some(2));
+        assert_eq!(summary.next_era_start_session, Some(12));
+    });
+}
+
+#[test]
+fn external_index_info_tracks_pending_and_applied_index() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(ExternalValidators::set_external_validators_inner(
+            vec![50, 51],
+            1
+        ));
+
+        assert_eq!(
+            ExternalValidators::external_index_info(),
+            ExternalIndexInfo {
+                latest: 1,
+                pending: 0,
+                applied: 0,
+            }
+        );
+
+        // Era 1 applies the external index received during era 0
+        run_to_session(6);
+
+        assert_eq!(
+            ExternalValidators::external_index_info(),
+            ExternalIndexInfo {
+                latest: 1,
+                pending: 0,
+                applied: 1,
+            }
+        );
+    });
+}
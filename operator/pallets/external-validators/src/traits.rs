@@ -74,6 +74,18 @@ impl OnEraEnd for Tuple {
     }
 }
 
+/// Notified when a deferred slash of `validator` for an offence in `slash_era` is cancelled.
+pub trait OnSlashCancelled<ValidatorId> {
+    fn on_slash_cancelled(_slash_era: EraIndex, _validator: &ValidatorId) {}
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(5)]
+impl<ValidatorId> OnSlashCancelled<ValidatorId> for Tuple {
+    fn on_slash_cancelled(slash_era: EraIndex, validator: &ValidatorId) {
+        for_tuples!( #( Tuple::on_slash_cancelled(slash_era, validator); )* );
+    }
+}
+
 // A trait to retrieve the external index provider identifying some set of data
 // In starlight, used to retrieve the external index associated to validators
 #[allow(dead_code)]
@@ -0,0 +1,22 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: 2023 Snowfork <hello@snowfork.com>
+
+//! Ethereum Inbound Queue V2 Runtime API
+//!
+//! * `dead_letters`: List the messages that failed processing
+
+#![cfg_attr(not(feature = "std"), no_std)]
+use codec::Codec;
+pub use snowbridge_pallet_inbound_queue_v2::DeadLetter;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+    pub trait InboundQueueV2Api<AccountId, BlockNumber>
+    where
+        AccountId: Codec,
+        BlockNumber: Codec,
+    {
+        /// Messages that failed processing, by nonce, until they are retried or refunded.
+        fn dead_letters() -> Vec<(u64, DeadLetter<AccountId, BlockNumber>)>;
+    }
+}
@@ -6,8 +6,32 @@ use crate::Pallet as InboundQueue;
 use frame_benchmarking::v2::*;
 use frame_support::assert_ok;
 use frame_system::RawOrigin;
+use snowbridge_inbound_queue_primitives::v2::Payload;
 use snowbridge_pallet_inbound_queue_v2_fixtures::register_token::make_register_token_message;
 
+fn insert_dead_letter<T: Config>(nonce: u64) {
+    let message = Message {
+        gateway: T::GatewayAddress::get(),
+        nonce,
+        origin: H160::repeat_byte(0x01),
+        assets: Vec::new(),
+        xcm: Payload::Raw(Vec::new()),
+        claimer: None,
+        value: 1_000_000_000,
+        execution_fee: 0,
+        relayer_fee: 0,
+    };
+    DeadLetters::<T>::insert(
+        nonce,
+        DeadLetter {
+            message,
+            relayer: whitelisted_caller(),
+            reason: DispatchError::Other("benchmark"),
+            failed_at: frame_system::Pallet::<T>::block_number(),
+        },
+    );
+}
+
 #[benchmarks]
 mod benchmarks {
     use super::*;
@@ -34,5 +58,27 @@ mod benchmarks {
         Ok(())
     }
 
+    #[benchmark]
+    fn retry_dead_letter() -> Result<(), BenchmarkError> {
+        insert_dead_letter::<T>(1);
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, 1);
+
+        assert!(DeadLetters::<T>::get(1).is_none());
+        Ok(())
+    }
+
+    #[benchmark]
+    fn refund_dead_letter() -> Result<(), BenchmarkError> {
+        insert_dead_letter::<T>(1);
+
+        #[extrinsic_call]
+        _(RawOrigin::Root, 1);
+
+        assert!(DeadLetters::<T>::get(1).is_none());
+        Ok(())
+    }
+
     impl_benchmark_test_suite!(InboundQueue, crate::mock::new_tester(), crate::mock::Test);
 }
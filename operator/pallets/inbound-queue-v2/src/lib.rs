@@ -16,11 +16,22 @@
 //!
 //! * [`Call::set_operating_mode`]: Set the operating mode of the pallet. Can be used to disable
 //!   processing of inbound messages.
+//! * [`Call::retry_dead_letter`]: Process again a message that failed processing.
+//! * [`Call::refund_dead_letter`]: Give back the assets of a message that failed processing.
 //!
 //! ## Message Submission
 //!
 //! * [`Call::submit`]: Submit a message for verification and dispatch to the final destination
 //!   parachain.
+//!
+//! # Dead Letters
+//!
+//! A verified message that fails processing still consumes its nonce. It is kept in
+//! [`DeadLetters`] with the reason of the failure, until governance retries or refunds it.
+//!
+//! While `DegradedMode` reports the bridge as degraded, because the Ethereum light client is
+//! falling behind, messages are rejected with [`Error::BridgeDegraded`] without consuming their
+//! nonce, so relayers submit them again once the light client is healthy.
 #![cfg_attr(not(feature = "std"), no_std)]
 
 extern crate alloc;
@@ -37,17 +48,20 @@ mod mock;
 mod test;
 
 pub use crate::weights::WeightInfo;
-use frame_system::ensure_signed;
+use codec::{Decode, Encode};
+use frame_system::{ensure_signed, pallet_prelude::BlockNumberFor};
+use scale_info::TypeInfo;
 use snowbridge_core::{
+    operating_mode::DegradedModeQuery,
     sparse_bitmap::{SparseBitmap, SparseBitmapImpl},
     BasicOperatingMode,
 };
 use snowbridge_inbound_queue_primitives::{
-    v2::{ConvertMessage, ConvertMessageError, Message, MessageProcessor},
+    v2::{ConvertMessage, ConvertMessageError, Message, MessageProcessor, RefundMessage},
     EventProof, RewardLedger, VerificationError, Verifier,
 };
 use sp_core::H160;
-use sp_runtime::traits::Zero;
+use sp_runtime::{traits::Zero, DispatchError, RuntimeDebug};
 use sp_std::prelude::*;
 use xcm::prelude::*;
 
@@ -62,11 +76,26 @@ pub type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
 
 pub type Nonce<T> = SparseBitmapImpl<crate::NonceBitmap<T>>;
 
+/// A verified message that failed processing.
+#[derive(Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct DeadLetter<AccountId, BlockNumber> {
+    /// The message, as submitted by the relayer
+    pub message: Message,
+    /// The relayer that submitted the message
+    pub relayer: AccountId,
+    /// Why processing the message failed
+    pub reason: DispatchError,
+    /// Block in which the message failed
+    pub failed_at: BlockNumber,
+}
+
+pub type DeadLetterOf<T> = DeadLetter<<T as frame_system::Config>::AccountId, BlockNumberFor<T>>;
+
 #[frame_support::pallet]
 pub mod pallet {
     use super::*;
 
-    use frame_support::pallet_prelude::*;
+    use frame_support::{pallet_prelude::*, storage::with_storage_layer};
     use frame_system::pallet_prelude::*;
 
     #[pallet::pallet]
@@ -96,6 +125,15 @@ pub mod pallet {
         type DefaultRewardKind: Get<Self::RewardKind>;
         /// Relayer reward payment.
         type RewardPayment: RewardLedger<Self::AccountId, Self::RewardKind, u128>;
+        /// Gives back the assets of dead letters.
+        type DeadLetterRefund: RefundMessage;
+        /// Maximum number of dead letters kept. Messages failing while the store is full fail the
+        /// submission, as if there was no store.
+        #[pallet::constant]
+        type MaxDeadLetters: Get<u32>;
+        /// Whether the bridge runs in degraded mode, rejecting messages instead of processing
+        /// them.
+        type DegradedMode: DegradedModeQuery;
         type WeightInfo: WeightInfo;
     }
 
@@ -111,6 +149,25 @@ pub mod pallet {
         },
         /// Set OperatingMode
         OperatingModeChanged { mode: BasicOperatingMode },
+        /// A message failed processing and was kept as a dead letter
+        MessageFailed {
+            /// The message nonce
+            nonce: u64,
+            /// Why processing the message failed
+            reason: DispatchError,
+        },
+        /// A dead letter was processed successfully
+        DeadLetterRetried {
+            /// The message nonce
+            nonce: u64,
+            /// ID of the message which was forwarded to the final destination
+            message_id: [u8; 32],
+        },
+        /// The assets of a dead letter were given back
+        DeadLetterRefunded {
+            /// The message nonce
+            nonce: u64,
+        },
     }
 
     #[pallet::error]
@@ -151,6 +208,10 @@ pub mod pallet {
         CannotReanchor,
         /// Message verification error
         Verification(VerificationError),
+        /// There is no dead letter with this nonce
+        UnknownDeadLetter,
+        /// The bridge runs in degraded mode
+        BridgeDegraded,
     }
 
     impl<T: Config> From<SendError> for Error<T> {
@@ -182,11 +243,19 @@ pub mod pallet {
     #[pallet::storage]
     pub type OperatingMode<T: Config> = StorageValue<_, BasicOperatingMode, ValueQuery>;
 
+    /// Verified messages that failed processing, by nonce. Their nonce is consumed.
+    #[pallet::storage]
+    #[pallet::unbounded]
+    pub type DeadLetters<T: Config> =
+        CountedStorageMap<_, Twox64Concat, u64, DeadLetterOf<T>, OptionQuery>;
+
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         /// Submit an inbound message originating from the Gateway contract on Ethereum
         #[pallet::call_index(0)]
-        #[pallet::weight(T::WeightInfo::submit())]
+        #[pallet::weight(
+            T::WeightInfo::submit().saturating_add(Pallet::<T>::processing_weight(event))
+        )]
         pub fn submit(origin: OriginFor<T>, event: Box<EventProof>) -> DispatchResult {
             let who = ensure_signed(origin)?;
             ensure!(!OperatingMode::<T>::get().is_halted(), Error::<T>::Halted);
@@ -214,6 +283,35 @@ pub mod pallet {
             Self::deposit_event(Event::OperatingModeChanged { mode });
             Ok(())
         }
+
+        /// Process a dead letter again, once the cause of the failure is fixed. May only be
+        /// called by root.
+        #[pallet::call_index(2)]
+        #[pallet::weight(T::WeightInfo::retry_dead_letter())]
+        pub fn retry_dead_letter(origin: OriginFor<T>, nonce: u64) -> DispatchResult {
+            ensure_root(origin)?;
+            let dead_letter = DeadLetters::<T>::take(nonce).ok_or(Error::<T>::UnknownDeadLetter)?;
+
+            let message_id =
+                T::MessageProcessor::process_message(dead_letter.relayer, dead_letter.message)?;
+
+            Self::deposit_event(Event::DeadLetterRetried { nonce, message_id });
+            Ok(())
+        }
+
+        /// Give back the assets of a dead letter to its sender on Ethereum. May only be called
+        /// by root.
+        #[pallet::call_index(3)]
+        #[pallet::weight(T::WeightInfo::refund_dead_letter())]
+        pub fn refund_dead_letter(origin: OriginFor<T>, nonce: u64) -> DispatchResult {
+            ensure_root(origin)?;
+            let dead_letter = DeadLetters::<T>::take(nonce).ok_or(Error::<T>::UnknownDeadLetter)?;
+
+            T::DeadLetterRefund::refund(&dead_letter.message)?;
+
+            Self::deposit_event(Event::DeadLetterRefunded { nonce });
+            Ok(())
+        }
     }
 
     impl<T: Config> Pallet<T> {
@@ -229,8 +327,20 @@ pub mod pallet {
             // Verify the message has not been processed
             ensure!(!Nonce::<T>::get(nonce.into()), Error::<T>::InvalidNonce);
 
-            // Process message
-            let message_id = T::MessageProcessor::process_message(relayer.clone(), message)?;
+            // Leave the nonce unused while degraded, for the message to be submitted again
+            ensure!(!T::DegradedMode::is_degraded(), Error::<T>::BridgeDegraded);
+
+            // Process message, keeping it as a dead letter if it fails
+            let processed = with_storage_layer(|| {
+                T::MessageProcessor::process_message(relayer.clone(), message.clone())
+            });
+            let message_id = match processed {
+                Ok(message_id) => Some(message_id),
+                Err(reason) => {
+                    Self::store_dead_letter(relayer.clone(), message, reason)?;
+                    None
+                }
+            };
 
             // Pay relayer reward if needed
             if !relayer_fee.is_zero() {
@@ -245,9 +355,50 @@ pub mod pallet {
             Nonce::<T>::set(nonce.into());
 
             // Emit event with the message_id
-            Self::deposit_event(Event::MessageReceived { nonce, message_id });
+            if let Some(message_id) = message_id {
+                Self::deposit_event(Event::MessageReceived { nonce, message_id });
+            }
+
+            Ok(())
+        }
+
+        fn store_dead_letter(
+            relayer: T::AccountId,
+            message: Message,
+            reason: DispatchError,
+        ) -> DispatchResult {
+            if DeadLetters::<T>::count() >= T::MaxDeadLetters::get() {
+                return Err(reason);
+            }
+
+            let nonce = message.nonce;
+            tracing::warn!(target: LOG_TARGET, ?nonce, ?reason, "Inbound message failed processing");
+
+            DeadLetters::<T>::insert(
+                nonce,
+                DeadLetter {
+                    message,
+                    relayer,
+                    reason,
+                    failed_at: frame_system::Pallet::<T>::block_number(),
+                },
+            );
+            Self::deposit_event(Event::MessageFailed { nonce, reason });
 
             Ok(())
         }
+
+        /// Weight of processing the message of `event` not covered by the `submit` benchmark,
+        /// e.g. the call following an inbound native transfer
+        pub fn processing_weight(event: &EventProof) -> Weight {
+            Message::try_from(&event.event_log)
+                .map(|message| T::MessageProcessor::processing_weight(&message))
+                .unwrap_or_default()
+        }
+
+        /// Verified messages that failed processing, by nonce
+        pub fn dead_letters() -> Vec<(u64, DeadLetterOf<T>)> {
+            DeadLetters::<T>::iter().collect()
+        }
     }
 }
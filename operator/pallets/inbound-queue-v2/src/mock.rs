@@ -126,6 +126,14 @@ pub enum BridgeReward {
 
 parameter_types! {
     pub static RegisteredRewardsCount: u128 = 0;
+    pub static IsBridgeDegraded: bool = false;
+}
+
+pub struct MockDegradedMode;
+impl DegradedModeQuery for MockDegradedMode {
+    fn is_degraded() -> bool {
+        IsBridgeDegraded::get()
+    }
 }
 
 impl RewardLedger<<mock::Test as frame_system::Config>::AccountId, BridgeReward, u128> for () {
@@ -138,6 +146,22 @@ impl RewardLedger<<mock::Test as frame_system::Config>::AccountId, BridgeReward,
     }
 }
 
+parameter_types! {
+    pub static RefundedNonces: Vec<u64> = vec![];
+}
+
+pub struct MockRefund;
+
+impl RefundMessage for MockRefund {
+    fn refund(message: &Message) -> Result<(), DispatchError> {
+        if message.value == 0 {
+            return Err(DispatchError::Other("Nothing to refund"));
+        }
+        RefundedNonces::mutate(|nonces| nonces.push(message.nonce));
+        Ok(())
+    }
+}
+
 pub struct DummyPrefix;
 
 impl MessageProcessor<AccountId> for DummyPrefix {
@@ -193,6 +217,9 @@ impl inbound_queue_v2::Config for Test {
     type WeightInfo = ();
     type RewardKind = BridgeReward;
     type DefaultRewardKind = SnowbridgeReward;
+    type DeadLetterRefund = MockRefund;
+    type MaxDeadLetters = ConstU32<2>;
+    type DegradedMode = MockDegradedMode;
 }
 
 pub fn setup() {
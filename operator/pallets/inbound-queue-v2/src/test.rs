@@ -6,10 +6,37 @@ use crate::{mock::*, Error};
 use codec::Encode;
 use frame_support::{assert_noop, assert_ok};
 use snowbridge_inbound_queue_primitives::{v2::Payload, EventProof, Proof};
-use snowbridge_test_utils::mock_xcm::{set_charge_fees_override, set_sender_override};
+use snowbridge_test_utils::mock_xcm::{
+    clear_charge_fees_override, set_charge_fees_override, set_sender_override,
+};
 use sp_keyring::sr25519::Keyring;
 use sp_runtime::DispatchError;
 
+fn assert_dead_letter(nonce: u64, reason: DispatchError) {
+    let dead_letter = DeadLetters::<Test>::get(nonce).expect("dead letter should be kept");
+    assert_eq!(dead_letter.reason, reason);
+    assert_eq!(dead_letter.message.nonce, nonce);
+    assert!(Nonce::<Test>::get(nonce.into()), "nonce should be consumed");
+    assert_eq!(
+        frame_system::Pallet::<Test>::events().last().unwrap().event,
+        RuntimeEvent::InboundQueue(Event::MessageFailed { nonce, reason })
+    );
+}
+
+fn message_with_nonce(nonce: u64) -> Message {
+    Message {
+        nonce,
+        assets: vec![],
+        xcm: Payload::Raw(vec![]),
+        claimer: None,
+        execution_fee: 1_000_000_000,
+        relayer_fee: 0,
+        gateway: GatewayAddress::get(),
+        origin: H160::repeat_byte(0x01),
+        value: 3_000_000_000,
+    }
+}
+
 #[test]
 fn test_submit_happy_path() {
     new_tester().execute_with(|| {
@@ -223,10 +250,11 @@ fn test_xcm_send_failure() {
             },
         };
 
-        assert_noop!(
-            crate::test::InboundQueue::submit(origin.clone(), Box::new(event.clone())),
-            Error::<Test>::SendFailure
-        );
+        assert_ok!(crate::test::InboundQueue::submit(
+            origin.clone(),
+            Box::new(event.clone())
+        ));
+        assert_dead_letter(1, Error::<Test>::SendFailure.into());
     });
 }
 
@@ -253,10 +281,11 @@ fn test_xcm_send_validate_failure() {
             },
         };
 
-        assert_noop!(
-            crate::test::InboundQueue::submit(origin.clone(), Box::new(event.clone())),
-            Error::<Test>::Unreachable
-        );
+        assert_ok!(crate::test::InboundQueue::submit(
+            origin.clone(),
+            Box::new(event.clone())
+        ));
+        assert_dead_letter(1, Error::<Test>::Unreachable.into());
     });
 }
 
@@ -278,10 +307,11 @@ fn test_xcm_charge_fees_failure() {
             },
         };
 
-        assert_noop!(
-            crate::test::InboundQueue::submit(origin.clone(), Box::new(event.clone())),
-            Error::<Test>::FeesNotMet
-        );
+        assert_ok!(crate::test::InboundQueue::submit(
+            origin.clone(),
+            Box::new(event.clone())
+        ));
+        assert_dead_letter(1, Error::<Test>::FeesNotMet.into());
     });
 }
 
@@ -361,3 +391,143 @@ fn zero_reward_does_not_register_reward() {
         );
     });
 }
+
+#[test]
+fn failed_message_cannot_be_submitted_again() {
+    new_tester().execute_with(|| {
+        set_charge_fees_override(|_, _| Err(XcmError::FeesNotMet));
+        let relayer: AccountId = Keyring::Bob.into();
+        let origin = RuntimeOrigin::signed(relayer);
+        let event = EventProof {
+            event_log: mock_event_log(),
+            proof: Proof {
+                receipt_proof: Default::default(),
+                execution_proof: mock_execution_proof(),
+            },
+        };
+
+        assert_ok!(InboundQueue::submit(
+            origin.clone(),
+            Box::new(event.clone())
+        ));
+        assert_eq!(RegisteredRewardsCount::get(), 1);
+
+        clear_charge_fees_override();
+        assert_noop!(
+            InboundQueue::submit(origin, Box::new(event)),
+            Error::<Test>::InvalidNonce
+        );
+    });
+}
+
+#[test]
+fn message_fails_submission_when_dead_letters_are_full() {
+    new_tester().execute_with(|| {
+        set_charge_fees_override(|_, _| Err(XcmError::FeesNotMet));
+        let relayer: AccountId = Keyring::Bob.into();
+
+        assert_ok!(InboundQueue::process_message(
+            relayer.clone(),
+            message_with_nonce(1)
+        ));
+        assert_ok!(InboundQueue::process_message(
+            relayer.clone(),
+            message_with_nonce(2)
+        ));
+        assert_eq!(DeadLetters::<Test>::count(), 2);
+
+        assert_noop!(
+            InboundQueue::process_message(relayer, message_with_nonce(3)),
+            Error::<Test>::FeesNotMet
+        );
+    });
+}
+
+#[test]
+fn message_is_rejected_when_bridge_degraded() {
+    new_tester().execute_with(|| {
+        IsBridgeDegraded::set(true);
+        let relayer: AccountId = Keyring::Bob.into();
+        assert_noop!(
+            InboundQueue::process_message(relayer.clone(), message_with_nonce(1)),
+            Error::<Test>::BridgeDegraded
+        );
+        assert!(!Nonce::<Test>::get(1));
+        assert!(DeadLetters::<Test>::get(1).is_none());
+
+        // The message goes through once submitted again
+        IsBridgeDegraded::set(false);
+        assert_ok!(InboundQueue::process_message(
+            relayer,
+            message_with_nonce(1)
+        ));
+        assert!(Nonce::<Test>::get(1));
+        assert!(DeadLetters::<Test>::get(1).is_none());
+    });
+}
+
+#[test]
+fn dead_letter_can_be_retried() {
+    new_tester().execute_with(|| {
+        set_charge_fees_override(|_, _| Err(XcmError::FeesNotMet));
+        let relayer: AccountId = Keyring::Bob.into();
+        assert_ok!(InboundQueue::process_message(
+            relayer,
+            message_with_nonce(1)
+        ));
+
+        // Still failing
+        assert_noop!(
+            InboundQueue::retry_dead_letter(RuntimeOrigin::root(), 1),
+            Error::<Test>::FeesNotMet
+        );
+
+        clear_charge_fees_override();
+        assert_noop!(
+            InboundQueue::retry_dead_letter(RuntimeOrigin::signed(Keyring::Bob.into()), 1),
+            DispatchError::BadOrigin
+        );
+        assert_ok!(InboundQueue::retry_dead_letter(RuntimeOrigin::root(), 1));
+
+        assert!(DeadLetters::<Test>::get(1).is_none());
+        assert_eq!(DeadLetters::<Test>::count(), 0);
+        assert!(matches!(
+            frame_system::Pallet::<Test>::events().last().unwrap().event,
+            RuntimeEvent::InboundQueue(Event::DeadLetterRetried { nonce: 1, .. })
+        ));
+        assert_noop!(
+            InboundQueue::retry_dead_letter(RuntimeOrigin::root(), 1),
+            Error::<Test>::UnknownDeadLetter
+        );
+    });
+}
+
+#[test]
+fn dead_letter_can_be_refunded() {
+    new_tester().execute_with(|| {
+        set_charge_fees_override(|_, _| Err(XcmError::FeesNotMet));
+        let relayer: AccountId = Keyring::Bob.into();
+        assert_ok!(InboundQueue::process_message(
+            relayer.clone(),
+            message_with_nonce(1)
+        ));
+        let mut nothing_to_refund = message_with_nonce(2);
+        nothing_to_refund.value = 0;
+        assert_ok!(InboundQueue::process_message(relayer, nothing_to_refund));
+
+        assert_ok!(InboundQueue::refund_dead_letter(RuntimeOrigin::root(), 1));
+        assert_eq!(RefundedNonces::get(), vec![1]);
+        assert!(DeadLetters::<Test>::get(1).is_none());
+        assert_eq!(
+            frame_system::Pallet::<Test>::events().last().unwrap().event,
+            RuntimeEvent::InboundQueue(Event::DeadLetterRefunded { nonce: 1 })
+        );
+
+        // Kept when the refund fails
+        assert_noop!(
+            InboundQueue::refund_dead_letter(RuntimeOrigin::root(), 2),
+            DispatchError::Other("Nothing to refund")
+        );
+        assert_eq!(InboundQueue::dead_letters().len(), 1);
+    });
+}
@@ -18,6 +18,8 @@ use sp_std::marker::PhantomData;
 /// Weight functions needed for ethereum_beacon_client.
 pub trait WeightInfo {
     fn submit() -> Weight;
+    fn retry_dead_letter() -> Weight;
+    fn refund_dead_letter() -> Weight;
 }
 
 // For backwards compatibility and tests
@@ -32,5 +34,25 @@ impl WeightInfo for () {
             .saturating_add(RocksDbWeight::get().reads(7))
             .saturating_add(RocksDbWeight::get().writes(2))
     }
+    fn retry_dead_letter() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `412`
+        //  Estimated: `3877`
+        // Minimum execution time: 31_000_000 picoseconds.
+        Weight::from_parts(32_000_000, 0)
+            .saturating_add(Weight::from_parts(0, 3877))
+            .saturating_add(RocksDbWeight::get().reads(3))
+            .saturating_add(RocksDbWeight::get().writes(2))
+    }
+    fn refund_dead_letter() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `412`
+        //  Estimated: `3877`
+        // Minimum execution time: 38_000_000 picoseconds.
+        Weight::from_parts(39_000_000, 0)
+            .saturating_add(Weight::from_parts(0, 3877))
+            .saturating_add(RocksDbWeight::get().reads(4))
+            .saturating_add(RocksDbWeight::get().writes(5))
+    }
 }
 
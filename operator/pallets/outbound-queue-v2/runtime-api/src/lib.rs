@@ -4,15 +4,23 @@
 //! Ethereum Outbound Queue V2 Runtime API
 //!
 //! * `prove_message`: Generate a merkle proof for a committed message
+//! * `dry_run`: Estimate the gas and fees of a message before sending it
 
 #![cfg_attr(not(feature = "std"), no_std)]
 use frame_support::traits::tokens::Balance as BalanceT;
 use snowbridge_merkle_tree::MerkleProof;
+use snowbridge_outbound_queue_primitives::{
+    v2::{GasEstimate, Message},
+    SendError,
+};
 
 sp_api::decl_runtime_apis! {
     pub trait OutboundQueueV2Api<Balance> where Balance: BalanceT
     {
         /// Generate a merkle proof for a committed message identified by `leaf_index`.
         fn prove_message(leaf_index: u64) -> Option<MerkleProof>;
+
+        /// Estimate the Ethereum gas and the fees of a message, as metered when it is committed.
+        fn dry_run(message: Message) -> Result<GasEstimate<Balance>, SendError>;
     }
 }
@@ -2,9 +2,14 @@
 // SPDX-FileCopyrightText: 2023 Snowfork <hello@snowfork.com>
 //! Helpers for implementing runtime api
 
-use crate::{Config, MessageLeaves};
+use crate::{Config, MessageLeaves, Pallet};
 use frame_support::storage::StorageStreamIter;
 use snowbridge_merkle_tree::{merkle_proof, MerkleProof};
+use snowbridge_outbound_queue_primitives::{
+    v2::{GasEstimate, GasMeter, Message, SendMessage},
+    SendError,
+};
+use sp_std::prelude::*;
 
 pub fn prove_message<T>(leaf_index: u64) -> Option<MerkleProof>
 where
@@ -17,3 +22,26 @@ where
         merkle_proof::<<T as Config>::Hashing, _>(MessageLeaves::<T>::stream_iter(), leaf_index);
     Some(proof)
 }
+
+/// Estimates the gas and fees of a message without queuing it, pricing the gas at `gas_price` wei.
+pub fn dry_run<T>(message: Message, gas_price: u128) -> Result<GasEstimate<T::Balance>, SendError>
+where
+    T: Config,
+{
+    let ticket = <Pallet<T> as SendMessage>::validate(&message)?;
+    let commands: Vec<u64> = ticket
+        .commands
+        .iter()
+        .map(T::GasMeter::maximum_dispatch_gas_used_at_most)
+        .collect();
+    let gas = commands
+        .iter()
+        .fold(0u64, |total, gas| total.saturating_add(*gas));
+
+    Ok(GasEstimate {
+        commands,
+        gas,
+        remote_fee: (gas as u128).saturating_mul(gas_price),
+        local_fee: Pallet::<T>::calculate_local_fee(),
+    })
+}
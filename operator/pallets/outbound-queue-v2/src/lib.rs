@@ -26,10 +26,12 @@
 //! 	d. Increment nonce and update the `Nonce` storage
 //! 6. At the end of the block, a merkle root is constructed from all the leaves in `MessageLeaves`.
 //!    At the beginning of the next block, both `Messages` and `MessageLeaves` are dropped so that
-//!    state at each block only holds the messages processed in that block.
+//!    state at each block only holds the messages processed in that block. With offchain indexing
+//!    enabled, the leaves and messages are kept in the offchain storage (see [`offchain`]).
 //! 7. This merkle root is inserted into the parachain header as a digest item
 //! 8. Offchain relayers are able to relay the message to Ethereum after:
-//! 	a. Generating a merkle proof for the committed message using the `prove_message` runtime API
+//! 	a. Generating a merkle proof for the committed message using the `prove_message` runtime API,
+//! 	   or for any historical nonce from the offchain storage of the node
 //! 	b. Reading the actual message content from the `Messages` vector in storage
 //! 9. On the Ethereum side, the message root is ultimately the thing being verified by the Beefy
 //!    light client.
@@ -39,6 +41,7 @@
 //! 	   same as the inbound queue verification flow
 //! 	b. Fetch the pending order by nonce of the message, pay reward with fee attached in the order
 //!    	c. Remove the order from `PendingOrders` map storage by nonce
+//! 	d. Notify [`Config::DeliveryHandler`] of the dispatch result on Ethereum
 //!
 //!
 //! # Extrinsics
@@ -50,6 +53,7 @@
 //! * `prove_message`: Generate a merkle proof for a committed message
 #![cfg_attr(not(feature = "std"), no_std)]
 pub mod api;
+pub mod offchain;
 pub mod process_message_impl;
 pub mod send_message_impl;
 pub mod types;
@@ -84,7 +88,8 @@ use snowbridge_merkle_tree::merkle_root;
 use snowbridge_outbound_queue_primitives::{
     v2::{
         abi::{CommandWrapper, OutboundMessageWrapper},
-        DeliveryReceipt, GasMeter, Message, OutboundCommandWrapper, OutboundMessage,
+        DeliveryReceipt, GasMeter, Message, MessageDeliveryHandler, OutboundCommandWrapper,
+        OutboundMessage,
     },
     EventProof, VerificationError, Verifier,
 };
@@ -137,6 +142,10 @@ pub mod pallet {
         /// Hook that is called whenever there is a new commitment.
         type OnNewCommitment: OnNewCommitment;
 
+        /// Hook that is called when a message is assigned a nonce and when its delivery receipt
+        /// is received.
+        type DeliveryHandler: MessageDeliveryHandler;
+
         /// Convert a weight value into a deductible fee based.
         type WeightToFee: WeightToFee<Balance = Self::Balance>;
 
@@ -190,6 +199,8 @@ pub mod pallet {
         OperatingModeChanged { mode: BasicOperatingMode },
         /// Delivery Proof received
         MessageDeliveryProofReceived { nonce: u64 },
+        /// The delivered message failed to dispatch on Ethereum
+        MessageDispatchFailed { nonce: u64 },
     }
 
     #[pallet::error]
@@ -302,6 +313,15 @@ pub mod pallet {
 
             T::OnNewCommitment::on_new_commitment(root);
 
+            offchain::index_commitment(
+                frame_system::Pallet::<T>::block_number(),
+                &offchain::CommittedMessages {
+                    root,
+                    leaves: MessageLeaves::<T>::get(),
+                    messages: Messages::<T>::get(),
+                },
+            );
+
             Self::deposit_event(Event::MessagesCommitted { root, count });
         }
 
@@ -382,6 +402,8 @@ pub mod pallet {
 
             Nonce::<T>::set(nonce.checked_add(1).ok_or(Unsupported)?);
 
+            T::DeliveryHandler::on_message_accepted(id, nonce);
+
             Self::deposit_event(Event::MessageAccepted { id, nonce });
 
             Ok(true)
@@ -412,7 +434,12 @@ pub mod pallet {
 
             <PendingOrders<T>>::remove(nonce);
 
+            T::DeliveryHandler::on_message_delivered(nonce, receipt.success);
+
             Self::deposit_event(Event::MessageDeliveryProofReceived { nonce });
+            if !receipt.success {
+                Self::deposit_event(Event::MessageDispatchFailed { nonce });
+            }
 
             Ok(())
         }
@@ -146,6 +146,7 @@ impl crate::Config for Test {
     type RewardKind = BridgeReward;
     type DefaultRewardKind = DefaultMyRewardKind;
     type OnNewCommitment = ();
+    type DeliveryHandler = ();
 }
 
 fn setup() {
@@ -0,0 +1,60 @@
+// SPDX-License-Identifier: Apache-2.0
+// SPDX-FileCopyrightText: 2023 Snowfork <hello@snowfork.com>
+//! Committed messages kept in the offchain storage through offchain indexing
+//!
+//! The leaves and messages of a block are dropped from the state at the beginning of the next
+//! block. When the node runs with offchain indexing enabled, each commitment is also written to
+//! the persistent offchain storage under the number of its block, and each nonce under the number
+//! of the block committing it, so the node can prove any historical message.
+
+use codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use snowbridge_outbound_queue_primitives::v2::OutboundMessage;
+use sp_core::H256;
+use sp_runtime::RuntimeDebug;
+use sp_std::prelude::*;
+
+/// Prefix of the offchain storage keys written by the pallet.
+pub const OFFCHAIN_PREFIX: &[u8] = b"snowbridge-outbound-queue-v2";
+
+/// Messages committed in a block, in the order of their leaves.
+#[derive(Encode, Decode, PartialEq, Clone, RuntimeDebug, TypeInfo)]
+pub struct CommittedMessages {
+    /// Merkle root of the leaves, as put in the header digest
+    pub root: H256,
+    /// Hashes of the ABI-encoded messages
+    pub leaves: Vec<H256>,
+    /// The committed messages
+    pub messages: Vec<OutboundMessage>,
+}
+
+impl CommittedMessages {
+    /// Index of the leaf of the message with the given nonce.
+    pub fn leaf_index(&self, nonce: u64) -> Option<u64> {
+        self.messages
+            .iter()
+            .position(|message| message.nonce == nonce)
+            .map(|index| index as u64)
+    }
+}
+
+/// Key of the messages committed in a block.
+pub fn commitment_key<BlockNumber: Encode>(block_number: BlockNumber) -> Vec<u8> {
+    (OFFCHAIN_PREFIX, b"commitment", block_number).encode()
+}
+
+/// Key of the number of the block committing the message with the given nonce.
+pub fn nonce_key(nonce: u64) -> Vec<u8> {
+    (OFFCHAIN_PREFIX, b"nonce", nonce).encode()
+}
+
+/// Writes the messages committed in the current block to the offchain storage.
+pub(crate) fn index_commitment<BlockNumber: Encode>(
+    block_number: BlockNumber,
+    commitment: &CommittedMessages,
+) {
+    for message in &commitment.messages {
+        sp_io::offchain_index::set(&nonce_key(message.nonce), &block_number.encode());
+    }
+    sp_io::offchain_index::set(&commitment_key(block_number), &commitment.encode());
+}
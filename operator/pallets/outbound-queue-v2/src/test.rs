@@ -18,6 +18,7 @@ use snowbridge_outbound_queue_primitives::{
     SendError,
 };
 use sp_core::{hexdisplay::HexDisplay, H256};
+use sp_runtime::{traits::Keccak256, AccountId32};
 
 #[test]
 fn submit_messages_and_commit() {
@@ -40,6 +41,52 @@ fn submit_messages_and_commit() {
     });
 }
 
+#[test]
+fn commit_indexes_messages_offchain() {
+    let mut ext = new_tester();
+    let block_number = ext.execute_with(|| {
+        for para_id in 1000..1004 {
+            let message = mock_message(para_id);
+            let ticket = OutboundQueue::validate(&message).unwrap();
+            assert_ok!(OutboundQueue::deliver(ticket));
+        }
+
+        ServiceWeight::set(Some(Weight::MAX));
+        run_to_end_of_next_block();
+        System::block_number()
+    });
+    ext.persist_offchain_overlay();
+
+    let offchain_db = ext.offchain_db();
+    let committed_at = offchain_db
+        .get(&offchain::nonce_key(2))
+        .map(|block_number| u64::decode(&mut &block_number[..]).unwrap());
+    assert_eq!(committed_at, Some(block_number));
+
+    let commitment = offchain_db
+        .get(&offchain::commitment_key(block_number))
+        .map(|commitment| offchain::CommittedMessages::decode(&mut &commitment[..]).unwrap())
+        .unwrap();
+    assert_eq!(commitment.messages.len(), 4);
+    assert_eq!(
+        commitment.root,
+        snowbridge_merkle_tree::merkle_root::<Keccak256, _>(commitment.leaves.iter().cloned())
+    );
+
+    let leaf_index = commitment.leaf_index(2).unwrap();
+    let proof = snowbridge_merkle_tree::merkle_proof::<Keccak256, _>(
+        commitment.leaves.into_iter(),
+        leaf_index,
+    );
+    assert!(snowbridge_merkle_tree::verify_proof::<Keccak256, _, _>(
+        &commitment.root,
+        proof.proof,
+        proof.number_of_leaves,
+        proof.leaf_index,
+        proof.leaf,
+    ));
+}
+
 #[test]
 fn submit_message_fail_too_large() {
     new_tester().execute_with(|| {
@@ -51,6 +98,25 @@ fn submit_message_fail_too_large() {
     });
 }
 
+#[test]
+fn dry_run_estimates_gas_and_fees() {
+    new_tester().execute_with(|| {
+        let message = mock_message(1000);
+        let estimate = api::dry_run::<Test>(message, 10).unwrap();
+
+        assert_eq!(estimate.commands, vec![100_000]);
+        assert_eq!(estimate.gas, 100_000);
+        assert_eq!(estimate.remote_fee, 1_000_000);
+        assert_eq!(estimate.local_fee, OutboundQueue::calculate_local_fee());
+
+        let message = mock_invalid_governance_message::<Test>();
+        assert_err!(
+            api::dry_run::<Test>(message, 10),
+            SendError::MessageTooLarge
+        );
+    });
+}
+
 #[test]
 fn commit_exits_early_if_no_processed_messages() {
     new_tester().execute_with(|| {
@@ -204,6 +270,38 @@ fn governance_message_not_processed_in_same_block_when_queue_congested_with_low_
     });
 }
 
+#[test]
+fn failed_dispatch_is_reported_with_the_delivery_proof() {
+    new_tester().execute_with(|| {
+        let relayer: AccountId32 = [1; 32].into();
+        for (nonce, success) in [(0, true), (1, false)] {
+            PendingOrders::<Test>::insert(
+                nonce,
+                PendingOrder {
+                    nonce,
+                    block_number: 1,
+                    fee: 0,
+                },
+            );
+            assert_ok!(OutboundQueue::process_delivery_receipt(
+                relayer.clone(),
+                DeliveryReceipt {
+                    gateway: GatewayAddress::get(),
+                    nonce,
+                    topic: H256::zero(),
+                    success,
+                    reward_address: relayer.clone(),
+                }
+            ));
+            System::assert_has_event(Event::MessageDeliveryProofReceived { nonce }.into());
+        }
+
+        let failed = RuntimeEvent::OutboundQueue(Event::MessageDispatchFailed { nonce: 0 });
+        assert!(!System::events().iter().any(|record| record.event == failed));
+        System::assert_last_event(Event::MessageDispatchFailed { nonce: 1 }.into());
+    });
+}
+
 #[test]
 fn encode_digest_item_with_correct_index() {
     new_tester().execute_with(|| {
@@ -0,0 +1,35 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Runtime API for the relayer rewards pallet.
+//!
+//! Lets relayers check the rewards they can claim before claiming them.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use pallet_relayer_rewards::RewardKind;
+use {parity_scale_codec::Codec, sp_std::vec::Vec};
+
+sp_api::decl_runtime_apis! {
+    #[api_version(1)]
+    pub trait RelayerRewardsApi<AccountId>
+    where
+        AccountId: Codec,
+    {
+        /// Unclaimed rewards of `relayer`, by kind.
+        fn rewards(relayer: AccountId) -> Vec<(RewardKind, u128)>;
+    }
+}
@@ -0,0 +1,70 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Benchmarking setup for pallet-relayer-rewards
+
+use super::*;
+use frame_benchmarking::v2::*;
+use frame_support::traits::fungible::Mutate;
+use frame_system::RawOrigin;
+
+const REWARD: u128 = 1_000_000_000_000;
+
+#[benchmarks(
+    where
+        T: Config,
+        BalanceOf<T>: From<u128>,
+)]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn claim_rewards() -> Result<(), BenchmarkError> {
+        // Setup
+        let _ = T::Currency::mint_into(&T::RewardSource::get(), (REWARD * 1_000).into());
+        let relayer: T::AccountId = whitelisted_caller();
+        Pallet::<T>::register_reward(&relayer, RewardKind::Native, REWARD);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(relayer.clone()));
+
+        // Verify
+        assert_eq!(RelayerRewards::<T>::get(&relayer, RewardKind::Native), 0);
+
+        Ok(())
+    }
+
+    #[benchmark]
+    fn claim_rewards_on_ethereum() -> Result<(), BenchmarkError> {
+        // Setup
+        let relayer: T::AccountId = whitelisted_caller();
+        Pallet::<T>::register_reward(&relayer, RewardKind::Ether, REWARD);
+
+        #[extrinsic_call]
+        _(RawOrigin::Signed(relayer.clone()), H160::repeat_byte(0x02));
+
+        // Verify
+        assert_eq!(RelayerRewards::<T>::get(&relayer, RewardKind::Ether), 0);
+
+        Ok(())
+    }
+
+    impl_benchmark_test_suite!(
+        Pallet,
+        crate::mock::new_test_ext(),
+        crate::mock::Test
+    );
+}
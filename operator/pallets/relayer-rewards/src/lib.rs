@@ -0,0 +1,250 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! # Relayer Rewards Pallet
+//!
+//! Keeps the rewards earned by the relayers of the Snowbridge inbound and outbound queues, which
+//! register them through [`RewardLedger`].
+//!
+//! Rewards are accrued per relayer and [`RewardKind`], depending on the currency they are paid in:
+//! - `Native` rewards, such as the fees of the messages sent to Ethereum, are claimed with
+//!   `claim_rewards` and paid in native tokens from `RewardSource`.
+//! - `Ether` rewards, such as the relayer fees of the messages coming from Ethereum, are claimed
+//!   with `claim_rewards_on_ethereum` and paid in Ether on Ethereum, through the outbound queue.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use frame_support::{
+    pallet_prelude::*,
+    traits::{
+        fungible::{Inspect, Mutate},
+        tokens::Preservation,
+    },
+};
+use snowbridge_inbound_queue_primitives::RewardLedger;
+use snowbridge_outbound_queue_primitives::v2::{Command, Message as OutboundMessage, SendMessage};
+use sp_core::{H160, H256};
+use sp_runtime::{traits::Saturating, BoundedVec};
+use sp_std::{vec, vec::Vec};
+
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+type BalanceOf<T> =
+    <<T as Config>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+
+/// Currency a relayer reward is paid in.
+#[derive(
+    Clone,
+    Copy,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    PartialEq,
+    Eq,
+    RuntimeDebug,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub enum RewardKind {
+    /// Paid in native tokens on DataHaven.
+    Native,
+    /// Paid in Ether on Ethereum.
+    Ether,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+    use super::*;
+    use frame_system::pallet_prelude::*;
+    use frame_system::unique;
+
+    #[pallet::pallet]
+    pub struct Pallet<T>(_);
+
+    #[pallet::config]
+    pub trait Config: frame_system::Config {
+        /// The overarching event type
+        type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// The currency the `Native` rewards are paid in
+        type Currency: Mutate<Self::AccountId>;
+
+        /// Account the `Native` rewards are paid from
+        type RewardSource: Get<Self::AccountId>;
+
+        /// The Snowbridge outbound queue, used to pay the `Ether` rewards on Ethereum
+        type OutboundQueue: SendMessage;
+
+        /// Origin of the messages paying the `Ether` rewards, whose agent holds the Ether
+        #[pallet::constant]
+        type EtherPayoutOrigin: Get<H256>;
+
+        /// Weight information
+        type WeightInfo: WeightInfo;
+    }
+
+    /// Unclaimed rewards of each relayer, by kind.
+    #[pallet::storage]
+    pub type RelayerRewards<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Twox64Concat,
+        RewardKind,
+        u128,
+        ValueQuery,
+    >;
+
+    #[pallet::event]
+    #[pallet::generate_deposit(pub(super) fn deposit_event)]
+    pub enum Event<T: Config> {
+        /// A relayer earned a reward
+        RewardRegistered {
+            relayer: T::AccountId,
+            kind: RewardKind,
+            amount: u128,
+        },
+        /// A relayer claimed its native rewards
+        RewardsClaimed {
+            relayer: T::AccountId,
+            amount: BalanceOf<T>,
+        },
+        /// The Ether rewards of a relayer were sent to `recipient` on Ethereum
+        RewardsPaidOnEthereum {
+            relayer: T::AccountId,
+            recipient: H160,
+            amount: u128,
+            message_id: H256,
+        },
+    }
+
+    #[pallet::error]
+    pub enum Error<T> {
+        /// The relayer has no reward of this kind to claim
+        NoRewards,
+        /// The reward does not fit in the balance type
+        Overflow,
+        /// Failed to send the payout message to Ethereum
+        SendMessageFailed,
+    }
+
+    #[pallet::call]
+    impl<T: Config> Pallet<T> {
+        /// Pay the native rewards of the caller.
+        #[pallet::call_index(0)]
+        #[pallet::weight(T::WeightInfo::claim_rewards())]
+        pub fn claim_rewards(origin: OriginFor<T>) -> DispatchResult {
+            let relayer = ensure_signed(origin)?;
+
+            let reward = RelayerRewards::<T>::take(&relayer, RewardKind::Native);
+            ensure!(reward > 0, Error::<T>::NoRewards);
+            let amount: BalanceOf<T> = reward.try_into().map_err(|_| Error::<T>::Overflow)?;
+
+            T::Currency::transfer(
+                &T::RewardSource::get(),
+                &relayer,
+                amount,
+                Preservation::Preserve,
+            )?;
+
+            Self::deposit_event(Event::RewardsClaimed { relayer, amount });
+
+            Ok(())
+        }
+
+        /// Send the Ether rewards of the caller to `recipient` on Ethereum.
+        #[pallet::call_index(1)]
+        #[pallet::weight(T::WeightInfo::claim_rewards_on_ethereum())]
+        pub fn claim_rewards_on_ethereum(origin: OriginFor<T>, recipient: H160) -> DispatchResult {
+            let relayer = ensure_signed(origin)?;
+
+            let amount = RelayerRewards::<T>::take(&relayer, RewardKind::Ether);
+            ensure!(amount > 0, Error::<T>::NoRewards);
+
+            let message = Self::build_payout_message(recipient, amount)?;
+            let ticket =
+                T::OutboundQueue::validate(&message).map_err(|_| Error::<T>::SendMessageFailed)?;
+            let message_id =
+                T::OutboundQueue::deliver(ticket).map_err(|_| Error::<T>::SendMessageFailed)?;
+
+            Self::deposit_event(Event::RewardsPaidOnEthereum {
+                relayer,
+                recipient,
+                amount,
+                message_id,
+            });
+
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Build the message unlocking `amount` of Ether to `recipient`
+        fn build_payout_message(
+            recipient: H160,
+            amount: u128,
+        ) -> Result<OutboundMessage, Error<T>> {
+            // The zero token address stands for Ether
+            let command = Command::UnlockNativeToken {
+                token: H160::zero(),
+                recipient,
+                amount,
+            };
+
+            let commands =
+                BoundedVec::try_from(vec![command]).map_err(|_| Error::<T>::SendMessageFailed)?;
+
+            Ok(OutboundMessage {
+                origin: T::EtherPayoutOrigin::get(),
+                id: unique(commands.encode()).into(),
+                fee: 0,
+                commands,
+            })
+        }
+
+        /// Unclaimed rewards of `relayer`, by kind
+        pub fn rewards(relayer: &T::AccountId) -> Vec<(RewardKind, u128)> {
+            RelayerRewards::<T>::iter_prefix(relayer).collect()
+        }
+    }
+}
+
+impl<T: Config> RewardLedger<T::AccountId, RewardKind, u128> for Pallet<T> {
+    fn register_reward(relayer: &T::AccountId, kind: RewardKind, amount: u128) {
+        if amount == 0 {
+            return;
+        }
+
+        RelayerRewards::<T>::mutate(relayer, kind, |reward| reward.saturating_accrue(amount));
+
+        Self::deposit_event(Event::RewardRegistered {
+            relayer: relayer.clone(),
+            kind,
+            amount,
+        });
+    }
+}
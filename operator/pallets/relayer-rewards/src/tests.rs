@@ -0,0 +1,200 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+use {
+    crate::{
+        mock::*, Error, Pallet as RelayerRewardsPallet, RelayerRewards as Rewards, RewardKind,
+    },
+    frame_support::{
+        assert_noop, assert_ok,
+        traits::{fungible::Inspect, Get},
+    },
+    snowbridge_inbound_queue_primitives::RewardLedger,
+    snowbridge_outbound_queue_primitives::v2::Command,
+    sp_core::H160,
+    sp_runtime::DispatchError,
+};
+
+fn register(relayer: u64, kind: RewardKind, amount: u128) {
+    <RelayerRewardsPallet<Test> as RewardLedger<u64, RewardKind, u128>>::register_reward(
+        &relayer, kind, amount,
+    );
+}
+
+#[test]
+fn register_reward_accrues_per_kind() {
+    new_test_ext().execute_with(|| {
+        register(ALICE, RewardKind::Native, 100);
+        register(ALICE, RewardKind::Native, 50);
+        register(ALICE, RewardKind::Ether, 7);
+
+        assert_eq!(Rewards::<Test>::get(ALICE, RewardKind::Native), 150);
+        assert_eq!(Rewards::<Test>::get(ALICE, RewardKind::Ether), 7);
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::RelayerRewards(crate::Event::RewardRegistered {
+                relayer: ALICE,
+                kind: RewardKind::Ether,
+                amount: 7,
+            })
+        );
+
+        let mut rewards = RelayerRewardsPallet::<Test>::rewards(&ALICE);
+        rewards.sort_by_key(|(_, amount)| *amount);
+        assert_eq!(
+            rewards,
+            vec![(RewardKind::Ether, 7), (RewardKind::Native, 150)]
+        );
+        assert!(RelayerRewardsPallet::<Test>::rewards(&BOB).is_empty());
+    });
+}
+
+#[test]
+fn zero_reward_is_ignored() {
+    new_test_ext().execute_with(|| {
+        register(ALICE, RewardKind::Native, 0);
+
+        assert!(!Rewards::<Test>::contains_key(ALICE, RewardKind::Native));
+        assert!(System::events().is_empty());
+    });
+}
+
+#[test]
+fn claim_rewards_pays_native_rewards() {
+    new_test_ext().execute_with(|| {
+        register(BOB, RewardKind::Native, 1000);
+        register(BOB, RewardKind::Ether, 5);
+
+        assert_ok!(RelayerRewardsPallet::<Test>::claim_rewards(
+            RuntimeOrigin::signed(BOB)
+        ));
+
+        assert_eq!(Balances::balance(&BOB), 1000);
+        assert_eq!(Balances::balance(&REWARD_SOURCE), INITIAL_BALANCE - 1000);
+        assert!(!Rewards::<Test>::contains_key(BOB, RewardKind::Native));
+        // Ether rewards are left untouched
+        assert_eq!(Rewards::<Test>::get(BOB, RewardKind::Ether), 5);
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::RelayerRewards(crate::Event::RewardsClaimed {
+                relayer: BOB,
+                amount: 1000,
+            })
+        );
+    });
+}
+
+#[test]
+fn claim_without_rewards_fails() {
+    new_test_ext().execute_with(|| {
+        register(ALICE, RewardKind::Ether, 5);
+
+        assert_noop!(
+            RelayerRewardsPallet::<Test>::claim_rewards(RuntimeOrigin::signed(ALICE)),
+            Error::<Test>::NoRewards
+        );
+        assert_noop!(
+            RelayerRewardsPallet::<Test>::claim_rewards_on_ethereum(
+                RuntimeOrigin::signed(BOB),
+                H160::repeat_byte(0x02)
+            ),
+            Error::<Test>::NoRewards
+        );
+    });
+}
+
+#[test]
+fn claim_fails_when_reward_source_is_short() {
+    new_test_ext().execute_with(|| {
+        register(ALICE, RewardKind::Native, INITIAL_BALANCE);
+
+        // The reward source is kept alive
+        assert!(RelayerRewardsPallet::<Test>::claim_rewards(RuntimeOrigin::signed(ALICE)).is_err());
+        assert_eq!(
+            Rewards::<Test>::get(ALICE, RewardKind::Native),
+            INITIAL_BALANCE
+        );
+    });
+}
+
+#[test]
+fn claim_rewards_on_ethereum_sends_payout_message() {
+    new_test_ext().execute_with(|| {
+        let recipient = H160::repeat_byte(0x02);
+        register(ALICE, RewardKind::Ether, 5);
+
+        assert_ok!(RelayerRewardsPallet::<Test>::claim_rewards_on_ethereum(
+            RuntimeOrigin::signed(ALICE),
+            recipient
+        ));
+
+        let sent = MockOutboundQueue::sent_messages();
+        assert_eq!(sent.len(), 1);
+        assert_eq!(sent[0].origin, EtherPayoutOrigin::get());
+        assert_eq!(
+            sent[0].commands.to_vec(),
+            vec![Command::UnlockNativeToken {
+                token: H160::zero(),
+                recipient,
+                amount: 5,
+            }]
+        );
+        assert!(!Rewards::<Test>::contains_key(ALICE, RewardKind::Ether));
+        assert_eq!(
+            last_event(),
+            RuntimeEvent::RelayerRewards(crate::Event::RewardsPaidOnEthereum {
+                relayer: ALICE,
+                recipient,
+                amount: 5,
+                message_id: sent[0].id,
+            })
+        );
+    });
+}
+
+#[test]
+fn failed_payout_keeps_rewards() {
+    new_test_ext().execute_with(|| {
+        register(ALICE, RewardKind::Ether, 5);
+        MockOutboundQueue::with_delivery_failing(true);
+
+        assert_noop!(
+            RelayerRewardsPallet::<Test>::claim_rewards_on_ethereum(
+                RuntimeOrigin::signed(ALICE),
+                H160::repeat_byte(0x02)
+            ),
+            Error::<Test>::SendMessageFailed
+        );
+        assert_eq!(Rewards::<Test>::get(ALICE, RewardKind::Ether), 5);
+    });
+}
+
+#[test]
+fn claims_require_signed_origin() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            RelayerRewardsPallet::<Test>::claim_rewards(RuntimeOrigin::root()),
+            DispatchError::BadOrigin
+        );
+        assert_noop!(
+            RelayerRewardsPallet::<Test>::claim_rewards_on_ethereum(
+                RuntimeOrigin::none(),
+                H160::repeat_byte(0x02)
+            ),
+            DispatchError::BadOrigin
+        );
+    });
+}
@@ -0,0 +1,119 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Autogenerated weights for `pallet_relayer_rewards`
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 36.0.0
+//! DATE: 2025-02-10, STEPS: `50`, REPEAT: `20`, LOW RANGE: `[]`, HIGH RANGE: `[]`
+//! WORST CASE MAP SIZE: `1000000`
+//! HOSTNAME: `datahaven-benchmarks`, CPU: `Apple M1 Pro`
+//! WASM-EXECUTION: `Compiled`, CHAIN: `Some("testnet-dev")`, DB CACHE: `1024`
+
+// Executed Command:
+// ./target/release/datahaven-node
+// benchmark
+// pallet
+// --chain=testnet-dev
+// --steps=50
+// --repeat=20
+// --pallet=pallet_relayer_rewards
+// --extrinsic=*
+// --wasm-execution=compiled
+// --heap-pages=4096
+// --output=pallets/relayer-rewards/src/weights.rs
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_relayer_rewards`.
+pub trait WeightInfo {
+    fn claim_rewards() -> Weight;
+    fn claim_rewards_on_ethereum() -> Weight;
+}
+
+/// Weights for `pallet_relayer_rewards` using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+    /// Storage: `RelayerRewards::RelayerRewards` (r:1 w:1)
+    /// Proof: `RelayerRewards::RelayerRewards` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:2 w:2)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+    fn claim_rewards() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `309`
+        //  Estimated: `6196`
+        // Minimum execution time: 48_000_000 picoseconds.
+        Weight::from_parts(49_000_000, 6196)
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().writes(3_u64))
+    }
+    /// Storage: `RelayerRewards::RelayerRewards` (r:1 w:1)
+    /// Proof: `RelayerRewards::RelayerRewards` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+    /// Storage: `EthereumOutboundQueueV2::MessageLeaves` (r:1 w:1)
+    /// Proof: `EthereumOutboundQueueV2::MessageLeaves` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+    /// Storage: `MessageQueue::BookStateFor` (r:1 w:1)
+    /// Proof: `MessageQueue::BookStateFor` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `MessageQueue::Pages` (r:0 w:1)
+    /// Proof: `MessageQueue::Pages` (`max_values`: None, `max_size`: Some(65585), added: 68060, mode: `MaxEncodedLen`)
+    fn claim_rewards_on_ethereum() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `243`
+        //  Estimated: `3538`
+        // Minimum execution time: 41_000_000 picoseconds.
+        Weight::from_parts(42_000_000, 3538)
+            .saturating_add(T::DbWeight::get().reads(3_u64))
+            .saturating_add(T::DbWeight::get().writes(4_u64))
+    }
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+    /// Storage: `RelayerRewards::RelayerRewards` (r:1 w:1)
+    /// Proof: `RelayerRewards::RelayerRewards` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+    /// Storage: `System::Account` (r:2 w:2)
+    /// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+    fn claim_rewards() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `309`
+        //  Estimated: `6196`
+        // Minimum execution time: 48_000_000 picoseconds.
+        Weight::from_parts(49_000_000, 6196)
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().writes(3_u64))
+    }
+    /// Storage: `RelayerRewards::RelayerRewards` (r:1 w:1)
+    /// Proof: `RelayerRewards::RelayerRewards` (`max_values`: None, `max_size`: Some(73), added: 2548, mode: `MaxEncodedLen`)
+    /// Storage: `EthereumOutboundQueueV2::MessageLeaves` (r:1 w:1)
+    /// Proof: `EthereumOutboundQueueV2::MessageLeaves` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+    /// Storage: `MessageQueue::BookStateFor` (r:1 w:1)
+    /// Proof: `MessageQueue::BookStateFor` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+    /// Storage: `MessageQueue::Pages` (r:0 w:1)
+    /// Proof: `MessageQueue::Pages` (`max_values`: None, `max_size`: Some(65585), added: 68060, mode: `MaxEncodedLen`)
+    fn claim_rewards_on_ethereum() -> Weight {
+        // Proof Size summary in bytes:
+        //  Measured:  `243`
+        //  Estimated: `3538`
+        // Minimum execution time: 41_000_000 picoseconds.
+        Weight::from_parts(42_000_000, 3538)
+            .saturating_add(RocksDbWeight::get().reads(3_u64))
+            .saturating_add(RocksDbWeight::get().writes(4_u64))
+    }
+}
@@ -13,6 +13,21 @@ DataHavenNativeTransfer constant DATAHAVEN_NATIVE_TRANSFER_CONTRACT =
 /// @notice Interface for transferring DataHaven native tokens to/from Ethereum via Snowbridge
 /// @custom:address 0x0000000000000000000000000000000000000819
 interface DataHavenNativeTransfer {
+    /// @notice A transfer to Ethereum waiting for its delivery receipt
+    struct PendingTransfer {
+        /// Outbound message id, or transfer id for batched transfers
+        bytes32 id;
+        address recipient;
+        uint256 amount;
+        /// Whether the outbound queue assigned a nonce to the message
+        bool hasNonce;
+        uint64 nonce;
+        /// Block at which the transfer is checked for a refund if its message is not committed
+        uint256 expiresAt;
+        /// Whether the refund of the transfer failed and waits to be retried
+        bool refundFailed;
+    }
+
     /// @notice Emitted when tokens are locked for transfer to Ethereum
     /// @param account The account that locked tokens
     /// @param amount The amount of tokens locked
@@ -24,6 +39,12 @@ interface DataHavenNativeTransfer {
     /// @param amount The amount of tokens transferred
     event TokensTransferredToEthereum(address indexed from, address indexed to, uint256 amount);
 
+    /// @notice Emitted when a transfer permit is used
+    /// @param from The account that signed the permit
+    /// @param spender The account that submitted the permit
+    /// @param nonce The nonce of the permit
+    event TransferPermitUsed(address indexed from, address indexed spender, uint256 nonce);
+
     /// @notice Transfer DataHaven native tokens to Ethereum
     /// @dev Locks tokens in the sovereign account and sends message through Snowbridge
     /// @param recipient Ethereum address to receive the tokens
@@ -32,6 +53,40 @@ interface DataHavenNativeTransfer {
     /// @custom:selector 0a3727e3
     function transferToEthereum(address recipient, uint256 amount, uint256 fee) external;
 
+    /// @notice Transfer DataHaven native tokens to Ethereum on behalf of `from`
+    /// @dev The permit is an EIP-712 signature of
+    /// TransferToEthereum(address from,address recipient,uint256 amount,uint256 fee,uint256 nonce,uint256 deadline)
+    /// @param from Account the tokens are transferred from, signer of the permit
+    /// @param recipient Ethereum address to receive the tokens
+    /// @param amount Amount of tokens to transfer (in smallest unit)
+    /// @param fee Fee to incentivize relayers (in smallest unit)
+    /// @param deadline Timestamp, in seconds, after which the permit expires
+    /// @param v V of the signature
+    /// @param r R of the signature
+    /// @param s S of the signature
+    function transferToEthereumWithPermit(
+        address from,
+        address recipient,
+        uint256 amount,
+        uint256 fee,
+        uint256 deadline,
+        uint8 v,
+        bytes32 r,
+        bytes32 s
+    ) external;
+
+    /// @notice Transfer DataHaven native tokens to several Ethereum recipients
+    /// @dev Each recipient gets its own transfer, paying `fee`. Reverts if any transfer fails.
+    /// At most 8 recipients.
+    /// @param recipients Ethereum addresses to receive the tokens
+    /// @param amounts Amount of tokens to transfer to each recipient (in smallest unit)
+    /// @param fee Fee of each transfer (in smallest unit)
+    function batchTransferToEthereum(
+        address[] memory recipients,
+        uint256[] memory amounts,
+        uint256 fee
+    ) external;
+
     /// @notice Check if the pallet is currently paused
     /// @return paused True if paused, false otherwise
     /// @custom:selector b187bd26
@@ -46,4 +101,28 @@ interface DataHavenNativeTransfer {
     /// @return account The sovereign account address (as H160)
     /// @custom:selector 71f9ae03
     function ethereumSovereignAccount() external view returns (address account);
+
+    /// @notice Get the fee suggested for a number of transfers to Ethereum
+    /// @param transfers Number of transfers, e.g. the recipients of a batch transfer
+    /// @return fee Suggested fee, to be split evenly between the transfers
+    function estimateFee(uint32 transfers) external view returns (uint256 fee);
+
+    /// @notice Get the transfers to Ethereum of an account waiting for their delivery receipt
+    /// @param account Account the tokens were transferred from
+    /// @return transfers The pending transfers of the account
+    function pendingTransfers(address account)
+        external
+        view
+        returns (PendingTransfer[] memory transfers);
+
+    /// @notice Get the nonce of the next transfer permit of an account
+    /// @param owner Signer of the permits
+    /// @return nonce Nonce of the next permit
+    /// @custom:selector 7ecebe00
+    function nonces(address owner) external view returns (uint256 nonce);
+
+    /// @notice Get the EIP-712 domain separator of the transfer permits
+    /// @return separator The domain separator
+    /// @custom:selector 3644e515
+    function DOMAIN_SEPARATOR() external view returns (bytes32 separator);
 }
@@ -18,20 +18,32 @@
 //!
 //! This precompile allows EVM smart contracts to transfer DataHaven native tokens
 //! to Ethereum via Snowbridge, and to manage the pallet's operational state.
+//!
+//! Transfers can also be made on behalf of an account holding a signed EIP-712 permit, the
+//! caller paying for the gas, and to several recipients at once.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
 use fp_evm::PrecompileHandle;
 use frame_support::dispatch::{GetDispatchInfo, PostDispatchInfo};
-use frame_support::traits::fungible::Inspect;
+use frame_support::{
+    ensure,
+    storage::types::{StorageMap, ValueQuery},
+    traits::{fungible::Inspect, ConstU32, Get, StorageInstance, Time},
+    Blake2_128Concat,
+};
+use frame_system::pallet_prelude::BlockNumberFor;
 use pallet_datahaven_native_transfer::{
-    Call as NativeTransferCall, Pallet as NativeTransferPallet,
+    Call as NativeTransferCall, Pallet as NativeTransferPallet, PendingTransfers,
+    PendingTransfersBySender, TransferStatus,
 };
 use pallet_evm::AddressMapping;
+use parity_scale_codec::MaxEncodedLen;
 use precompile_utils::prelude::*;
-use sp_core::{H160, U256};
-use sp_runtime::traits::Dispatchable;
-use sp_std::marker::PhantomData;
+use sp_core::{H160, H256, U256};
+use sp_io::hashing::keccak_256;
+use sp_runtime::traits::{Dispatchable, UniqueSaturatedInto};
+use sp_std::{marker::PhantomData, vec::Vec};
 
 /// Solidity selector for the TokensLocked event:
 /// keccak256("TokensLocked(address,uint256)")
@@ -42,6 +54,27 @@ pub const SELECTOR_LOG_TOKENS_LOCKED: [u8; 32] = keccak256!("TokensLocked(addres
 pub const SELECTOR_LOG_TOKENS_TRANSFERRED_TO_ETHEREUM: [u8; 32] =
     keccak256!("TokensTransferredToEthereum(address,address,uint256)");
 
+/// Solidity selector for the TransferPermitUsed event:
+/// keccak256("TransferPermitUsed(address,address,uint256)")
+pub const SELECTOR_LOG_TRANSFER_PERMIT_USED: [u8; 32] =
+    keccak256!("TransferPermitUsed(address,address,uint256)");
+
+/// EIP712 typehash of a transfer permit.
+pub const PERMIT_TYPEHASH: [u8; 32] = keccak256!(
+    "TransferToEthereum(address from,address recipient,uint256 amount,uint256 fee\
+,uint256 nonce,uint256 deadline)"
+);
+
+/// EIP712 permit domain used to compute an individualized domain separator.
+const PERMIT_DOMAIN: [u8; 32] = keccak256!(
+    "EIP712Domain(string name,string version,uint256 chainId,address verifyingContract)"
+);
+
+/// Maximum number of recipients of a batch transfer.
+pub const MAX_BATCH_TRANSFERS: u32 = 8;
+
+type GetBatchLimit = ConstU32<MAX_BATCH_TRANSFERS>;
+
 #[cfg(test)]
 mod mock;
 #[cfg(test)]
@@ -52,6 +85,44 @@ type BalanceOf<Runtime> =
         <Runtime as frame_system::Config>::AccountId,
     >>::Balance;
 
+/// Storage prefix for permit nonces.
+pub struct PermitNonces;
+
+impl StorageInstance for PermitNonces {
+    const STORAGE_PREFIX: &'static str = "PermitNonces";
+
+    fn pallet_prefix() -> &'static str {
+        "PrecompileDataHavenNativeTransfer"
+    }
+}
+
+/// Storage type used to store the nonces of the transfer permits.
+pub type PermitNoncesStorage = StorageMap<
+    PermitNonces,
+    // From
+    Blake2_128Concat,
+    H160,
+    // Nonce
+    U256,
+    ValueQuery,
+>;
+
+/// A transfer to Ethereum waiting for its delivery receipt.
+#[derive(Eq, PartialEq, Debug, solidity::Codec)]
+pub struct PendingTransfer {
+    /// Outbound message id, or transfer id for batched transfers
+    id: H256,
+    recipient: Address,
+    amount: U256,
+    /// Whether the outbound queue assigned a nonce to the message
+    has_nonce: bool,
+    nonce: u64,
+    /// Block at which the transfer is checked for a refund if its message is not committed
+    expires_at: U256,
+    /// Whether the refund of the transfer failed and waits to be retried
+    refund_failed: bool,
+}
+
 /// Precompile for DataHaven Native Transfer pallet
 pub struct DataHavenNativeTransferPrecompile<Runtime>(PhantomData<Runtime>);
 
@@ -65,6 +136,7 @@ where
         From<Option<Runtime::AccountId>>,
     <Runtime as frame_system::Config>::RuntimeCall: From<NativeTransferCall<Runtime>>,
     BalanceOf<Runtime>: TryFrom<U256> + Into<U256>,
+    BlockNumberFor<Runtime>: Into<U256>,
     <Runtime as pallet_evm::Config>::AddressMapping: AddressMapping<Runtime::AccountId>,
     Runtime::AccountId: Into<H160>,
 {
@@ -84,8 +156,140 @@ where
         amount: U256,
         fee: U256,
     ) -> EvmResult {
-        // Convert caller address to substrate account
-        let caller = Runtime::AddressMapping::into_account_id(handle.context().caller);
+        let caller = handle.context().caller;
+        Self::do_transfer_to_ethereum(handle, caller, recipient, amount, fee)
+    }
+
+    /// Transfer DataHaven native tokens to Ethereum on behalf of `from`
+    ///
+    /// `from` signs an EIP-712 permit for the transfer, which anyone can then submit and pay the
+    /// gas for. The amount and the fee are still taken from `from`.
+    ///
+    /// Parameters:
+    /// - `from`: Account the tokens are transferred from, signer of the permit
+    /// - `recipient`: Ethereum address to receive the tokens
+    /// - `amount`: Amount of tokens to transfer (in smallest unit)
+    /// - `fee`: Fee to incentivize relayers (in smallest unit)
+    /// - `deadline`: Timestamp, in seconds, after which the permit expires
+    /// - `v`, `r`, `s`: Signature of the permit
+    #[precompile::public(
+        "transferToEthereumWithPermit(address,address,uint256,uint256,uint256,uint8,bytes32,bytes32)"
+    )]
+    fn transfer_to_ethereum_with_permit(
+        handle: &mut impl PrecompileHandle,
+        from: Address,
+        recipient: Address,
+        amount: U256,
+        fee: U256,
+        deadline: U256,
+        v: u8,
+        r: H256,
+        s: H256,
+    ) -> EvmResult {
+        // Now: 8
+        handle.record_db_read::<Runtime>(8)?;
+        // PermitNoncesStorage: Blake2_128(16) + contract(20) + Blake2_128(16) + owner(20) + nonce(32)
+        handle.record_db_read::<Runtime>(104)?;
+        handle.record_cost(Self::permit_inherent_cost())?;
+
+        let from: H160 = from.into();
+
+        // Blockchain time is in ms while Ethereum use second timestamps.
+        let timestamp: u128 =
+            <Runtime as pallet_evm::Config>::Timestamp::now().unique_saturated_into();
+        let timestamp: U256 = U256::from(timestamp / 1000);
+
+        ensure!(deadline >= timestamp, revert("Permit expired"));
+
+        let nonce = PermitNoncesStorage::get(from);
+
+        let permit = Self::generate_permit(
+            handle.context().address,
+            from,
+            recipient.into(),
+            amount,
+            fee,
+            nonce,
+            deadline,
+        );
+
+        let mut sig = [0u8; 65];
+        sig[0..32].copy_from_slice(r.as_bytes());
+        sig[32..64].copy_from_slice(s.as_bytes());
+        sig[64] = v;
+
+        let signer = sp_io::crypto::secp256k1_ecdsa_recover(&sig, &permit)
+            .map_err(|_| revert("Invalid permit"))?;
+        let signer = H160::from(H256::from_slice(keccak_256(&signer).as_slice()));
+
+        ensure!(
+            signer != H160::zero() && signer == from,
+            revert("Invalid permit")
+        );
+
+        PermitNoncesStorage::insert(from, nonce + U256::one());
+
+        Self::do_transfer_to_ethereum(handle, from, recipient, amount, fee)?;
+
+        // Emit EVM log for the permit being spent by the caller
+        handle.record_log_costs_manual(3, 32)?;
+        log3(
+            handle.context().address,
+            SELECTOR_LOG_TRANSFER_PERMIT_USED,
+            from,
+            handle.context().caller,
+            solidity::encode_event_data(nonce),
+        )
+        .record(handle)?;
+
+        Ok(())
+    }
+
+    /// Transfer DataHaven native tokens to several Ethereum recipients
+    ///
+    /// Each recipient gets its own transfer, paying `fee`. The batch is reverted if any of them
+    /// fails.
+    ///
+    /// Parameters:
+    /// - `recipients`: Ethereum addresses to receive the tokens
+    /// - `amounts`: Amount of tokens to transfer to each recipient (in smallest unit)
+    /// - `fee`: Fee of each transfer (in smallest unit)
+    #[precompile::public("batchTransferToEthereum(address[],uint256[],uint256)")]
+    fn batch_transfer_to_ethereum(
+        handle: &mut impl PrecompileHandle,
+        recipients: BoundedVec<Address, GetBatchLimit>,
+        amounts: BoundedVec<U256, GetBatchLimit>,
+        fee: U256,
+    ) -> EvmResult {
+        let recipients: Vec<Address> = recipients.into();
+        let amounts: Vec<U256> = amounts.into();
+
+        if recipients.is_empty() {
+            return Err(RevertReason::custom("No recipients").in_field("recipients"));
+        }
+        if recipients.len() != amounts.len() {
+            return Err(
+                RevertReason::custom("Expected one amount per recipient").in_field("amounts")
+            );
+        }
+
+        let caller = handle.context().caller;
+        for (recipient, amount) in recipients.into_iter().zip(amounts) {
+            Self::do_transfer_to_ethereum(handle, caller, recipient, amount, fee)?;
+        }
+
+        Ok(())
+    }
+
+    fn do_transfer_to_ethereum(
+        handle: &mut impl PrecompileHandle,
+        from: H160,
+        recipient: Address,
+        amount: U256,
+        fee: U256,
+    ) -> EvmResult {
+        // Convert the sender address to substrate account
+        let sender = Runtime::AddressMapping::into_account_id(from);
 
         // Validate recipient is not zero address
         let recipient_h160: H160 = recipient.into();
@@ -126,13 +330,13 @@ where
         .into();
 
         // Dispatch the call - this will handle gas costs and error reporting
-        RuntimeHelper::<Runtime>::try_dispatch(handle, Some(caller).into(), call, 0)?;
+        RuntimeHelper::<Runtime>::try_dispatch(handle, Some(sender).into(), call, 0)?;
 
         // Emit EVM log mirroring the TokensLocked pallet event
         log2(
             handle.context().address,
             SELECTOR_LOG_TOKENS_LOCKED,
-            handle.context().caller,
+            from,
             solidity::encode_event_data(amount),
         )
         .record(handle)?;
@@ -141,7 +345,7 @@ where
         log3(
             handle.context().address,
             SELECTOR_LOG_TOKENS_TRANSFERRED_TO_ETHEREUM,
-            handle.context().caller,
+            from,
             recipient_h160,
             solidity::encode_event_data(amount),
         )
@@ -211,4 +415,149 @@ where
         // Convert to Address for the return
         Ok(Address(account_h160))
     }
+
+    /// Get the fee suggested for a number of transfers to Ethereum
+    ///
+    /// Parameters:
+    /// - `transfers`: Number of transfers, e.g. the recipients of a batch transfer
+    ///
+    /// Returns:
+    /// - The suggested fee in smallest unit, to be split evenly between the transfers
+    #[precompile::public("estimateFee(uint32)")]
+    #[precompile::view]
+    fn estimate_fee(handle: &mut impl PrecompileHandle, transfers: u32) -> EvmResult<U256> {
+        // Record the read of the suggested fee
+        handle.record_cost(RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+
+        Ok(NativeTransferPallet::<Runtime>::estimate_fee(transfers).into())
+    }
+
+    /// Get the transfers to Ethereum of an account waiting for their delivery receipt
+    ///
+    /// Parameters:
+    /// - `account`: Account the tokens were transferred from
+    ///
+    /// Returns:
+    /// - The pending transfers of the account
+    #[precompile::public("pendingTransfers(address)")]
+    #[precompile::view]
+    fn pending_transfers(
+        handle: &mut impl PrecompileHandle,
+        account: Address,
+    ) -> EvmResult<Vec<PendingTransfer>> {
+        let account = Runtime::AddressMapping::into_account_id(account.into());
+
+        // PendingTransfersBySender: Blake2_128(16) + AccountId(max_encoded_len) + Blake2_128(16)
+        // + transfer_id(32)
+        let index_entry_size = 64 + Runtime::AccountId::max_encoded_len();
+        // PendingTransfers: Blake2_128(16) + transfer_id(32) + PendingTransfer(max_encoded_len)
+        let entry_size = 48
+            + pallet_datahaven_native_transfer::PendingTransfer::<
+                Runtime::AccountId,
+                BalanceOf<Runtime>,
+                BlockNumberFor<Runtime>,
+            >::max_encoded_len();
+
+        // Only the transfers of the account are read, each read being paid before it is made
+        let mut transfer_ids = PendingTransfersBySender::<Runtime>::iter_key_prefix(&account);
+        let mut transfers = Vec::new();
+        loop {
+            handle.record_db_read::<Runtime>(index_entry_size)?;
+            let Some(id) = transfer_ids.next() else {
+                break;
+            };
+
+            handle.record_db_read::<Runtime>(entry_size)?;
+            let Some(transfer) = PendingTransfers::<Runtime>::get(id) else {
+                continue;
+            };
+            transfers.push(PendingTransfer {
+                id,
+                recipient: Address(transfer.recipient),
+                amount: transfer.amount.into(),
+                has_nonce: transfer.nonce.is_some(),
+                nonce: transfer.nonce.unwrap_or_default(),
+                expires_at: transfer.expires_at.into(),
+                refund_failed: matches!(transfer.status, TransferStatus::RefundFailed(_)),
+            });
+        }
+
+        Ok(transfers)
+    }
+
+    /// Get the nonce of the next transfer permit of an account
+    #[precompile::public("nonces(address)")]
+    #[precompile::view]
+    fn nonces(handle: &mut impl PrecompileHandle, owner: Address) -> EvmResult<U256> {
+        // PermitNoncesStorage: Blake2_128(16) + contract(20) + Blake2_128(16) + owner(20) + nonce(32)
+        handle.record_db_read::<Runtime>(104)?;
+
+        let owner: H160 = owner.into();
+
+        Ok(PermitNoncesStorage::get(owner))
+    }
+
+    /// Get the EIP-712 domain separator of the transfer permits
+    #[precompile::public("DOMAIN_SEPARATOR()")]
+    #[precompile::view]
+    fn domain_separator(handle: &mut impl PrecompileHandle) -> EvmResult<H256> {
+        // ChainId
+        handle.record_db_read::<Runtime>(8)?;
+
+        let domain_separator: H256 =
+            Self::compute_domain_separator(handle.context().address).into();
+
+        Ok(domain_separator)
+    }
+
+    fn compute_domain_separator(address: H160) -> [u8; 32] {
+        let name: H256 = keccak_256(b"DataHaven Native Transfer Precompile").into();
+        let version: H256 = keccak256!("1").into();
+        let chain_id: U256 = <Runtime as pallet_evm::Config>::ChainId::get().into();
+
+        let domain_separator_inner = solidity::encode_arguments((
+            H256::from(PERMIT_DOMAIN),
+            name,
+            version,
+            chain_id,
+            Address(address),
+        ));
+
+        keccak_256(&domain_separator_inner)
+    }
+
+    /// Digest of a transfer permit, to be signed by `from`
+    pub fn generate_permit(
+        address: H160,
+        from: H160,
+        recipient: H160,
+        amount: U256,
+        fee: U256,
+        nonce: U256,
+        deadline: U256,
+    ) -> [u8; 32] {
+        let domain_separator = Self::compute_domain_separator(address);
+
+        let permit_content = solidity::encode_arguments((
+            H256::from(PERMIT_TYPEHASH),
+            Address(from),
+            Address(recipient),
+            amount,
+            fee,
+            nonce,
+            deadline,
+        ));
+        let permit_content = keccak_256(&permit_content);
+        let mut pre_digest = Vec::with_capacity(2 + 32 + 32);
+        pre_digest.extend_from_slice(b"\x19\x01");
+        pre_digest.extend_from_slice(&domain_separator);
+        pre_digest.extend_from_slice(&permit_content);
+        keccak_256(&pre_digest)
+    }
+
+    /// Cost of verifying a permit, on top of the transfer
+    pub fn permit_inherent_cost() -> u64 {
+        3_000 // cost of ECRecover precompile for reference
+            + RuntimeHelper::<Runtime>::db_write_gas_cost() // we write nonce
+    }
 }
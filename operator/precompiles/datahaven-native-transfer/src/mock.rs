@@ -21,6 +21,7 @@ use super::*;
 use frame_support::traits::Everything;
 use frame_support::{construct_runtime, parameter_types, weights::Weight};
 use pallet_evm::{EnsureAddressNever, EnsureAddressRoot, FrameSystemAccountProvider};
+use pallet_external_validators::traits::{ActiveEraInfo, EraIndex, EraIndexProvider};
 use parity_scale_codec::{Decode, Encode};
 use precompile_utils::{mock_account, precompile_set::*, testing::MockAccount};
 use snowbridge_core::TokenId;
@@ -123,6 +124,7 @@ mock_account!(Bob, |_| MockAccount::from_u64(3));
 mock_account!(Root, |_| MockAccount::zero()); // Root account for sudo operations
 mock_account!(EthereumSovereign, |_| MockAccount::from_u64(100));
 mock_account!(FeeRecipient, |_| MockAccount::from_u64(101));
+mock_account!(HoldingAccount, |_| MockAccount::from_u64(102));
 
 const MAX_POV_SIZE: u64 = 5 * 1024 * 1024;
 const BLOCK_STORAGE_LIMIT: u64 = 40 * 1024;
@@ -206,9 +208,11 @@ impl Ticket for MockTicket {
 parameter_types! {
     pub EthereumSovereignAccountParam: AccountId = EthereumSovereign.into();
     pub FeeRecipientParam: AccountId = FeeRecipient.into();
+    pub HoldingAccountParam: AccountId = HoldingAccount.into();
     // Mock token ID - Some(TokenId) for testing
     // TokenId is H256, so we create it directly
     pub NativeTokenIdParam: Option<TokenId> = Some(H256([1u8; 32]));
+    pub const MaxFollowUpCallWeight: Weight = Weight::zero();
 }
 
 // Mock origin that allows account 0 to pause/unpause (for testing)
@@ -228,6 +232,20 @@ impl frame_support::traits::EnsureOrigin<RuntimeOrigin> for EnsureAccountZero {
     }
 }
 
+pub struct MockEraIndexProvider;
+impl EraIndexProvider for MockEraIndexProvider {
+    fn active_era() -> ActiveEraInfo {
+        ActiveEraInfo {
+            index: 0,
+            start: None,
+        }
+    }
+
+    fn era_to_session_start(_era_index: EraIndex) -> Option<u32> {
+        None
+    }
+}
+
 impl pallet_datahaven_native_transfer::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type Currency = Balances;
@@ -237,6 +255,16 @@ impl pallet_datahaven_native_transfer::Config for Runtime {
     type WeightInfo = ();
     type PauseOrigin = EnsureAccountZero;
     type NativeTokenId = NativeTokenIdParam;
+    type EraIndexProvider = MockEraIndexProvider;
+    type TransferTimeout = frame_support::traits::ConstU32<100>;
+    type MaxTransfersPerBlock = frame_support::traits::ConstU32<100>;
+    type HoldingAccount = HoldingAccountParam;
+    type RuntimeCall = RuntimeCall;
+    type FollowUpCallFilter = frame_support::traits::Nothing;
+    type MaxFollowUpCallWeight = MaxFollowUpCallWeight;
+    type DegradedMode = ();
+    type MaxBatchSize = frame_support::traits::ConstU32<8>;
+    type SuggestedFee = frame_support::traits::ConstU128<100>;
 }
 
 pub(crate) struct ExtBuilder {
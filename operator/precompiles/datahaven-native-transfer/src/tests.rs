@@ -18,11 +18,16 @@
 
 use crate::mock::{
     balance, precompiles, Alice, Bob, EthereumSovereign, ExistentialDeposit, ExtBuilder,
-    FeeRecipient, NativeTransferPrecompile, PCall,
+    FeeRecipient, NativeTransferPrecompile, PCall, Runtime, Timestamp,
 };
-use precompile_utils::prelude::Address;
+use crate::{
+    DataHavenNativeTransferPrecompile, PendingTransfer, SELECTOR_LOG_TOKENS_LOCKED,
+    SELECTOR_LOG_TOKENS_TRANSFERRED_TO_ETHEREUM, SELECTOR_LOG_TRANSFER_PERMIT_USED,
+};
+use libsecp256k1::{sign, Message, SecretKey};
+use precompile_utils::prelude::*;
 use precompile_utils::testing::*;
-use sp_core::{H160, U256};
+use sp_core::{H160, H256, U256};
 
 // Test helper to get the precompile address
 fn precompile_address() -> H160 {
@@ -39,6 +44,12 @@ fn test_selectors() {
     assert!(!PCall::transfer_to_ethereum_selectors().is_empty());
     assert!(!PCall::total_locked_balance_selectors().is_empty());
     assert!(!PCall::ethereum_sovereign_account_selectors().is_empty());
+    assert!(!PCall::transfer_to_ethereum_with_permit_selectors().is_empty());
+    assert!(!PCall::batch_transfer_to_ethereum_selectors().is_empty());
+    assert!(!PCall::estimate_fee_selectors().is_empty());
+    assert!(!PCall::pending_transfers_selectors().is_empty());
+    assert!(PCall::nonces_selectors().contains(&0x7ecebe00));
+    assert!(PCall::domain_separator_selectors().contains(&0x3644e515));
 }
 
 // ============================================================================
@@ -62,6 +73,16 @@ fn test_function_modifiers() {
 
             // ethereumSovereignAccount - view
             tester.test_view_modifier(PCall::ethereum_sovereign_account_selectors());
+
+            // Permit and batch transfers - non-view, non-payable
+            tester.test_default_modifier(PCall::transfer_to_ethereum_with_permit_selectors());
+            tester.test_default_modifier(PCall::batch_transfer_to_ethereum_selectors());
+
+            // Fee estimation, pending transfers and permit views
+            tester.test_view_modifier(PCall::estimate_fee_selectors());
+            tester.test_view_modifier(PCall::pending_transfers_selectors());
+            tester.test_view_modifier(PCall::nonces_selectors());
+            tester.test_view_modifier(PCall::domain_separator_selectors());
         });
 }
 
@@ -408,6 +429,327 @@ fn test_ethereum_sovereign_account() {
     });
 }
 
+#[test]
+fn test_estimate_fee() {
+    ExtBuilder::default().build().execute_with(|| {
+        precompiles()
+            .prepare_test(
+                Alice,
+                precompile_address(),
+                PCall::estimate_fee { transfers: 3 },
+            )
+            .execute_returns(U256::from(300));
+    });
+}
+
+#[test]
+fn test_pending_transfers() {
+    ExtBuilder::default()
+        .with_balances(vec![
+            (Alice.into(), 10000),
+            (EthereumSovereign.into(), ExistentialDeposit::get()),
+        ])
+        .build()
+        .execute_with(|| {
+            let recipient = H160::from_low_u64_be(0x1234);
+
+            precompiles()
+                .prepare_test(
+                    Alice,
+                    precompile_address(),
+                    PCall::transfer_to_ethereum {
+                        recipient: recipient.into(),
+                        amount: U256::from(1000),
+                        fee: U256::from(100),
+                    },
+                )
+                .execute_returns(());
+
+            precompiles()
+                .prepare_test(
+                    Alice,
+                    precompile_address(),
+                    PCall::pending_transfers {
+                        account: Address(Alice.into()),
+                    },
+                )
+                .execute_returns(vec![PendingTransfer {
+                    // Id of the messages of the mock outbound queue
+                    id: H256::zero(),
+                    recipient: Address(recipient),
+                    amount: U256::from(1000),
+                    has_nonce: false,
+                    nonce: 0,
+                    expires_at: U256::from(101),
+                    refund_failed: false,
+                }]);
+
+            precompiles()
+                .prepare_test(
+                    Alice,
+                    precompile_address(),
+                    PCall::pending_transfers {
+                        account: Address(Bob.into()),
+                    },
+                )
+                .execute_returns(Vec::<PendingTransfer>::new());
+        });
+}
+
+// ============================================================================
+// Batch Transfer Tests
+// ============================================================================
+
+#[test]
+fn test_batch_transfer_to_ethereum() {
+    ExtBuilder::default()
+        .with_balances(vec![
+            (Alice.into(), 10000),
+            (EthereumSovereign.into(), ExistentialDeposit::get()),
+        ])
+        .build()
+        .execute_with(|| {
+            let first = H160::from_low_u64_be(0x1234);
+            let second = H160::from_low_u64_be(0x5678);
+
+            precompiles()
+                .prepare_test(
+                    Alice,
+                    precompile_address(),
+                    PCall::batch_transfer_to_ethereum {
+                        recipients: vec![Address(first), Address(second)].into(),
+                        amounts: vec![U256::from(1000), U256::from(2000)].into(),
+                        fee: U256::from(100),
+                    },
+                )
+                .expect_log(log2(
+                    precompile_address(),
+                    SELECTOR_LOG_TOKENS_LOCKED,
+                    H160::from(Alice),
+                    solidity::encode_event_data(U256::from(1000)),
+                ))
+                .expect_log(log3(
+                    precompile_address(),
+                    SELECTOR_LOG_TOKENS_TRANSFERRED_TO_ETHEREUM,
+                    H160::from(Alice),
+                    first,
+                    solidity::encode_event_data(U256::from(1000)),
+                ))
+                .expect_log(log2(
+                    precompile_address(),
+                    SELECTOR_LOG_TOKENS_LOCKED,
+                    H160::from(Alice),
+                    solidity::encode_event_data(U256::from(2000)),
+                ))
+                .expect_log(log3(
+                    precompile_address(),
+                    SELECTOR_LOG_TOKENS_TRANSFERRED_TO_ETHEREUM,
+                    H160::from(Alice),
+                    second,
+                    solidity::encode_event_data(U256::from(2000)),
+                ))
+                .execute_returns(());
+
+            assert_eq!(balance(Alice), 10000 - 3000 - 200);
+            assert_eq!(balance(FeeRecipient), 200);
+            assert_eq!(balance(EthereumSovereign), ExistentialDeposit::get() + 3000);
+        });
+}
+
+#[test]
+fn test_batch_transfer_to_ethereum_reverts() {
+    ExtBuilder::default()
+        .with_balances(vec![
+            (Alice.into(), 10000),
+            (EthereumSovereign.into(), ExistentialDeposit::get()),
+        ])
+        .build()
+        .execute_with(|| {
+            let recipient = H160::from_low_u64_be(0x1234);
+
+            precompiles()
+                .prepare_test(
+                    Alice,
+                    precompile_address(),
+                    PCall::batch_transfer_to_ethereum {
+                        recipients: vec![Address(recipient)].into(),
+                        amounts: vec![U256::from(1000), U256::from(2000)].into(),
+                        fee: U256::from(100),
+                    },
+                )
+                .execute_reverts(|output| {
+                    from_utf8_lossy(output).contains("Expected one amount per recipient")
+                });
+
+            // Any failing transfer reverts the whole batch
+            precompiles()
+                .prepare_test(
+                    Alice,
+                    precompile_address(),
+                    PCall::batch_transfer_to_ethereum {
+                        recipients: vec![Address(recipient), Address(H160::zero())].into(),
+                        amounts: vec![U256::from(1000), U256::from(2000)].into(),
+                        fee: U256::from(100),
+                    },
+                )
+                .execute_reverts(|output| output == b"Recipient cannot be zero address");
+        });
+}
+
+// ============================================================================
+// Permit Tests
+// ============================================================================
+
+fn sign_permit(
+    recipient: H160,
+    amount: U256,
+    fee: U256,
+    nonce: U256,
+    deadline: U256,
+) -> (u8, H256, H256) {
+    let permit = DataHavenNativeTransferPrecompile::<Runtime>::generate_permit(
+        precompile_address(),
+        CryptoAlith.into(),
+        recipient,
+        amount,
+        fee,
+        nonce,
+        deadline,
+    );
+
+    let secret_key = SecretKey::parse(&alith_secret_key()).unwrap();
+    let (rs, v) = sign(&Message::parse(&permit), &secret_key);
+    (
+        v.serialize(),
+        H256::from(rs.r.b32()),
+        H256::from(rs.s.b32()),
+    )
+}
+
+#[test]
+fn test_transfer_to_ethereum_with_permit() {
+    ExtBuilder::default()
+        .with_balances(vec![
+            (CryptoAlith.into(), 10000),
+            (EthereumSovereign.into(), ExistentialDeposit::get()),
+        ])
+        .build()
+        .execute_with(|| {
+            let recipient = H160::from_low_u64_be(0x1234);
+            let amount = U256::from(1000);
+            let fee = U256::from(100);
+            let deadline = U256::from(1000);
+            let (v, r, s) = sign_permit(recipient, amount, fee, U256::zero(), deadline);
+            let permit_call = || PCall::transfer_to_ethereum_with_permit {
+                from: Address(CryptoAlith.into()),
+                recipient: Address(recipient),
+                amount,
+                fee,
+                deadline,
+                v,
+                r,
+                s,
+            };
+
+            // Bob submits the transfer of Alith and pays for the gas
+            precompiles()
+                .prepare_test(Bob, precompile_address(), permit_call())
+                .expect_log(log2(
+                    precompile_address(),
+                    SELECTOR_LOG_TOKENS_LOCKED,
+                    H160::from(CryptoAlith),
+                    solidity::encode_event_data(amount),
+                ))
+                .expect_log(log3(
+                    precompile_address(),
+                    SELECTOR_LOG_TOKENS_TRANSFERRED_TO_ETHEREUM,
+                    H160::from(CryptoAlith),
+                    recipient,
+                    solidity::encode_event_data(amount),
+                ))
+                .expect_log(log3(
+                    precompile_address(),
+                    SELECTOR_LOG_TRANSFER_PERMIT_USED,
+                    H160::from(CryptoAlith),
+                    H160::from(Bob),
+                    solidity::encode_event_data(U256::zero()),
+                ))
+                .execute_returns(());
+
+            assert_eq!(balance(CryptoAlith), 10000 - 1000 - 100);
+            assert_eq!(balance(FeeRecipient), 100);
+            precompiles()
+                .prepare_test(
+                    Bob,
+                    precompile_address(),
+                    PCall::nonces {
+                        owner: Address(CryptoAlith.into()),
+                    },
+                )
+                .execute_returns(U256::one());
+
+            // The permit can't be replayed
+            precompiles()
+                .prepare_test(Bob, precompile_address(), permit_call())
+                .execute_reverts(|output| output == b"Invalid permit");
+        });
+}
+
+#[test]
+fn test_transfer_to_ethereum_with_invalid_permit() {
+    ExtBuilder::default()
+        .with_balances(vec![
+            (CryptoAlith.into(), 10000),
+            (EthereumSovereign.into(), ExistentialDeposit::get()),
+        ])
+        .build()
+        .execute_with(|| {
+            let recipient = H160::from_low_u64_be(0x1234);
+            let fee = U256::from(100);
+            let deadline = U256::from(1000);
+            let (v, r, s) = sign_permit(recipient, U256::from(1000), fee, U256::zero(), deadline);
+
+            // The permit doesn't cover a different amount
+            precompiles()
+                .prepare_test(
+                    Bob,
+                    precompile_address(),
+                    PCall::transfer_to_ethereum_with_permit {
+                        from: Address(CryptoAlith.into()),
+                        recipient: Address(recipient),
+                        amount: U256::from(5000),
+                        fee,
+                        deadline,
+                        v,
+                        r,
+                        s,
+                    },
+                )
+                .execute_reverts(|output| output == b"Invalid permit");
+
+            // Nor a transfer after its deadline
+            Timestamp::set_timestamp(2_000_000);
+            precompiles()
+                .prepare_test(
+                    Bob,
+                    precompile_address(),
+                    PCall::transfer_to_ethereum_with_permit {
+                        from: Address(CryptoAlith.into()),
+                        recipient: Address(recipient),
+                        amount: U256::from(1000),
+                        fee,
+                        deadline,
+                        v,
+                        r,
+                        s,
+                    },
+                )
+                .execute_reverts(|output| output == b"Permit expired");
+
+            assert_eq!(balance(CryptoAlith), 10000);
+        });
+}
+
 // ============================================================================
 // Gas Accounting Tests
 // ============================================================================
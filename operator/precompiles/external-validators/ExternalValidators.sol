@@ -0,0 +1,96 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @dev The ExternalValidators contract's address.
+address constant EXTERNAL_VALIDATORS_ADDRESS = 0x000000000000000000000000000000000000081D;
+
+/// @dev The ExternalValidators contract's instance.
+ExternalValidators constant EXTERNAL_VALIDATORS_CONTRACT = ExternalValidators(
+    EXTERNAL_VALIDATORS_ADDRESS
+);
+
+/// @title ExternalValidators precompile
+/// Exposes the validator sets, era reward points and pending slashes of the DataHaven
+/// validators, which are EigenLayer operators.
+/// Address: 0x000000000000000000000000000000000000081D
+interface ExternalValidators {
+    /// @dev A slash deferred to a later era.
+    struct PendingSlash {
+        uint256 slashId;
+        /// Era at which the slash is applied.
+        uint32 era;
+        /// Slashed fraction, in parts per billion.
+        uint32 percentage;
+        /// Whether the slash went through its deferred period.
+        bool confirmed;
+    }
+
+    /// @dev Get the active era. Reverts before the first era.
+    ///
+    /// @return index Index of the active era.
+    /// @return start Start of the era in milliseconds since the Unix epoch, 0 until the
+    /// first block of the era is finalized.
+    ///
+    /// @custom:selector c2f192cb
+    function activeEra() external view returns (uint32 index, uint64 start);
+
+    /// @dev Check if a validator is in the validator set of the current session.
+    ///
+    /// @param validator Account of the validator.
+    ///
+    /// @custom:selector 40550a1c
+    function isActiveValidator(address validator) external view returns (bool);
+
+    /// @dev Check if a validator is in the latest validator set received from Ethereum.
+    ///
+    /// @param validator Account of the validator.
+    ///
+    /// @custom:selector 9d8b9393
+    function isExternalValidator(address validator) external view returns (bool);
+
+    /// @dev Check if a validator is whitelisted by governance.
+    ///
+    /// @param validator Account of the validator.
+    ///
+    /// @custom:selector 9b36d036
+    function isWhitelistedValidator(address validator)
+        external
+        view
+        returns (bool);
+
+    /// @dev Get the reward points of a validator in an era.
+    ///
+    /// @param era Index of the era.
+    /// @param validator Account of the validator.
+    /// @return points Reward points of the validator.
+    /// @return totalPoints Reward points of all the validators.
+    ///
+    /// @custom:selector 276a7124
+    function eraRewardPoints(uint32 era, address validator)
+        external
+        view
+        returns (uint32 points, uint32 totalPoints);
+
+    /// @dev Get the number of blocks authored by a validator in the current session.
+    ///
+    /// @param validator Account of the validator.
+    /// @return blocks Number of blocks authored.
+    ///
+    /// @custom:selector 5a446c68
+    function blocksAuthoredInSession(address validator)
+        external
+        view
+        returns (uint32 blocks);
+
+    /// @dev Get the slashes of a validator deferred to a later era, which can still be
+    /// cancelled by governance.
+    ///
+    /// @param validator Account of the validator.
+    /// @return slashes Pending slashes of the validator.
+    ///
+    /// @custom:selector 0726347e
+    function pendingSlashes(address validator)
+        external
+        view
+        returns (PendingSlash[] memory slashes);
+}
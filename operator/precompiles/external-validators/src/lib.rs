@@ -0,0 +1,220 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+//! Precompile exposing the external validators, their era reward points and their pending
+//! slashes, so contracts can react to the performance of the validators.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use core::marker::PhantomData;
+use frame_support::traits::{Get, ValidatorSet};
+use pallet_evm::AddressMapping;
+use pallet_external_validators::traits::EraIndexProvider;
+use parity_scale_codec::{Encode, MaxEncodedLen};
+use precompile_utils::prelude::*;
+use sp_core::U256;
+use sp_runtime::traits::Convert;
+use sp_std::vec::Vec;
+
+/// ActiveEra storage item size in bytes = EraIndex (4) + Option<Moment> (9) = 13
+pub const ACTIVE_ERA_SIZE: usize = 13;
+
+/// A slash deferred to a later era, as returned by `pendingSlashes`.
+#[derive(Eq, PartialEq, Debug, solidity::Codec)]
+pub struct PendingSlash {
+    pub slash_id: U256,
+    /// Era at which the slash is applied
+    pub era: u32,
+    /// Slashed fraction, in parts per billion
+    pub percentage: u32,
+    pub confirmed: bool,
+}
+
+/// Precompile for the external validators pallets
+pub struct ExternalValidatorsPrecompile<Runtime>(PhantomData<Runtime>);
+
+#[precompile_utils::precompile]
+impl<Runtime> ExternalValidatorsPrecompile<Runtime>
+where
+    Runtime: pallet_external_validators::Config
+        + pallet_external_validators_rewards::Config
+        + pallet_external_validator_slashes::Config
+        + pallet_evm::Config,
+    <Runtime as pallet_external_validator_slashes::Config>::SlashId: Into<U256>,
+    <Runtime as pallet_evm::Config>::AddressMapping: AddressMapping<Runtime::AccountId>,
+{
+    /// Index and start, in milliseconds since the Unix epoch, of the active era. The start is 0
+    /// until the first block of the era is finalized.
+    #[precompile::public("activeEra()")]
+    #[precompile::view]
+    fn active_era(handle: &mut impl PrecompileHandle) -> EvmResult<(u32, u64)> {
+        handle.record_db_read::<Runtime>(ACTIVE_ERA_SIZE)?;
+
+        let era = pallet_external_validators::ActiveEra::<Runtime>::get()
+            .ok_or_else(|| revert("No active era"))?;
+
+        Ok((era.index, era.start.unwrap_or_default()))
+    }
+
+    /// Whether `validator` is in the validator set of the current session.
+    #[precompile::public("isActiveValidator(address)")]
+    #[precompile::view]
+    fn is_active_validator(
+        handle: &mut impl PrecompileHandle,
+        validator: Address,
+    ) -> EvmResult<bool> {
+        // Validators: AccountId * (MaxWhitelistedValidators + MaxExternalValidators)
+        handle.record_db_read::<Runtime>(
+            Runtime::AccountId::max_encoded_len() * Self::max_validators(),
+        )?;
+
+        let account = Runtime::AddressMapping::into_account_id(validator.into());
+
+        Ok(
+            <Runtime as pallet_external_validators_rewards::Config>::ValidatorSet::validators()
+                .contains(&account),
+        )
+    }
+
+    /// Whether `validator` is in the latest external validator set received from Ethereum.
+    #[precompile::public("isExternalValidator(address)")]
+    #[precompile::view]
+    fn is_external_validator(
+        handle: &mut impl PrecompileHandle,
+        validator: Address,
+    ) -> EvmResult<bool> {
+        handle.record_db_read::<Runtime>(
+            <Runtime as pallet_external_validators::Config>::ValidatorId::max_encoded_len()
+                * (<Runtime as pallet_external_validators::Config>::MaxExternalValidators::get()
+                    as usize),
+        )?;
+
+        Ok(Self::validator_id(validator).is_some_and(|id| {
+            pallet_external_validators::ExternalValidators::<Runtime>::get().contains(&id)
+        }))
+    }
+
+    /// Whether `validator` is whitelisted by governance.
+    #[precompile::public("isWhitelistedValidator(address)")]
+    #[precompile::view]
+    fn is_whitelisted_validator(
+        handle: &mut impl PrecompileHandle,
+        validator: Address,
+    ) -> EvmResult<bool> {
+        handle.record_db_read::<Runtime>(
+            <Runtime as pallet_external_validators::Config>::ValidatorId::max_encoded_len()
+                * (<Runtime as pallet_external_validators::Config>::MaxWhitelistedValidators::get()
+                    as usize),
+        )?;
+
+        Ok(Self::validator_id(validator).is_some_and(|id| {
+            pallet_external_validators::WhitelistedValidators::<Runtime>::get().contains(&id)
+        }))
+    }
+
+    /// Reward points of `validator` in `era`, and the total reward points of the era.
+    #[precompile::public("eraRewardPoints(uint32,address)")]
+    #[precompile::view]
+    fn era_reward_points(
+        handle: &mut impl PrecompileHandle,
+        era: u32,
+        validator: Address,
+    ) -> EvmResult<(u32, u32)> {
+        // RewardPointsForEra: RewardPoints(4) + BTreeMap(AccountId + RewardPoints(4)) with one
+        // entry per validator
+        handle.record_db_read::<Runtime>(
+            4 + (Runtime::AccountId::max_encoded_len() + 4) * Self::max_validators(),
+        )?;
+
+        let account = Runtime::AddressMapping::into_account_id(validator.into());
+        let points = pallet_external_validators_rewards::RewardPointsForEra::<Runtime>::get(era);
+
+        Ok((
+            points.individual.get(&account).copied().unwrap_or_default(),
+            points.total,
+        ))
+    }
+
+    /// Number of blocks authored by `validator` in the current session.
+    #[precompile::public("blocksAuthoredInSession(address)")]
+    #[precompile::view]
+    fn blocks_authored_in_session(
+        handle: &mut impl PrecompileHandle,
+        validator: Address,
+    ) -> EvmResult<u32> {
+        // BlocksAuthoredInSession
+        handle.record_db_read::<Runtime>(4)?;
+
+        let account = Runtime::AddressMapping::into_account_id(validator.into());
+
+        Ok(pallet_external_validators_rewards::BlocksAuthoredInSession::<Runtime>::get(account))
+    }
+
+    /// Slashes of `validator` deferred to a later era, which can still be cancelled by
+    /// governance. Slashes recorded without being applied, while slashing is in log only mode,
+    /// are not included.
+    #[precompile::public("pendingSlashes(address)")]
+    #[precompile::view]
+    fn pending_slashes(
+        handle: &mut impl PrecompileHandle,
+        validator: Address,
+    ) -> EvmResult<Vec<PendingSlash>> {
+        let account = Runtime::AddressMapping::into_account_id(validator.into());
+
+        // Slashes are applied at the start of their era, at most `SlashDeferDuration + 1` eras
+        // after the active one.
+        handle.record_db_read::<Runtime>(ACTIVE_ERA_SIZE)?;
+        let active_era =
+            <Runtime as pallet_external_validator_slashes::Config>::EraIndexProvider::active_era()
+                .index;
+        let defer_duration =
+            <Runtime as pallet_external_validator_slashes::Config>::SlashDeferDuration::get();
+
+        let mut pending = Vec::new();
+        let last_era = active_era.saturating_add(defer_duration).saturating_add(1);
+        for era in active_era.saturating_add(1)..=last_era {
+            let slashes = pallet_external_validator_slashes::Slashes::<Runtime>::get(era);
+            // Slashes is unbounded, so its read is charged for its actual size.
+            handle.record_db_read::<Runtime>(slashes.encoded_size())?;
+
+            pending.extend(
+                slashes
+                    .into_iter()
+                    .filter(|slash| slash.validator == account)
+                    .map(|slash| PendingSlash {
+                        slash_id: slash.slash_id.into(),
+                        era,
+                        percentage: slash.percentage.deconstruct(),
+                        confirmed: slash.confirmed,
+                    }),
+            );
+        }
+
+        Ok(pending)
+    }
+
+    fn validator_id(
+        validator: Address,
+    ) -> Option<<Runtime as pallet_external_validators::Config>::ValidatorId> {
+        let account = Runtime::AddressMapping::into_account_id(validator.into());
+        <Runtime as pallet_external_validators::Config>::ValidatorIdOf::convert(account)
+    }
+
+    fn max_validators() -> usize {
+        (<Runtime as pallet_external_validators::Config>::MaxWhitelistedValidators::get()
+            + <Runtime as pallet_external_validators::Config>::MaxExternalValidators::get())
+            as usize
+    }
+}
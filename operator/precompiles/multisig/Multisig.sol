@@ -0,0 +1,119 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @dev The Multisig contract's address.
+address constant MULTISIG_ADDRESS = 0x000000000000000000000000000000000000081B;
+
+/// @dev The Multisig contract's instance.
+Multisig constant MULTISIG_CONTRACT = Multisig(MULTISIG_ADDRESS);
+
+/// @title Multisig precompile
+/// Allows to interact with Substrate pallet_multisig from the EVM.
+/// Address: 0x000000000000000000000000000000000000081B
+interface Multisig {
+    /// @dev Extrinsic opening a multisig operation.
+    struct Timepoint {
+        /// Block of the extrinsic. 0 when no timepoint is provided.
+        uint32 height;
+        /// Index of the extrinsic in its block.
+        uint32 index;
+    }
+
+    /// @dev A pending multisig operation.
+    struct PendingMultisig {
+        /// Extrinsic which opened the operation.
+        Timepoint when;
+        /// Amount reserved from the depositor until the operation is executed or cancelled.
+        uint256 deposit;
+        /// Account which opened the operation.
+        address depositor;
+        /// Signatories which approved the operation.
+        address[] approvals;
+    }
+
+    /// @dev Approve a multisig operation, and dispatch its call from the multisig account
+    /// if the approval reaches the threshold.
+    /// The signatories don't need to be sorted.
+    ///
+    /// @param threshold Number of approvals required to dispatch the call.
+    /// @param otherSignatories Signatories of the multisig, except the sender.
+    /// @param maybeTimepoint Timepoint of the first approval, or 0 for the first approval.
+    /// @param call SCALE-encoded Substrate call.
+    /// @param maxWeight Maximum amount of Substrate weight the call can use.
+    ///
+    /// @custom:selector 3a59e237
+    function asMulti(
+        uint16 threshold,
+        address[] memory otherSignatories,
+        Timepoint memory maybeTimepoint,
+        bytes memory call,
+        uint64 maxWeight
+    ) external;
+
+    /// @dev Approve a multisig operation by the hash of its call, without dispatching it.
+    /// The signatories don't need to be sorted.
+    ///
+    /// @param threshold Number of approvals required to dispatch the call.
+    /// @param otherSignatories Signatories of the multisig, except the sender.
+    /// @param maybeTimepoint Timepoint of the first approval, or 0 for the first approval.
+    /// @param callHash Hash of the SCALE-encoded Substrate call.
+    /// @param maxWeight Maximum amount of Substrate weight the call can use.
+    ///
+    /// @custom:selector 40759967
+    function approveAsMulti(
+        uint16 threshold,
+        address[] memory otherSignatories,
+        Timepoint memory maybeTimepoint,
+        bytes32 callHash,
+        uint64 maxWeight
+    ) external;
+
+    /// @dev Cancel a multisig operation and unreserve its deposit.
+    /// Only the account which opened the operation can cancel it.
+    ///
+    /// @param threshold Number of approvals required to dispatch the call.
+    /// @param otherSignatories Signatories of the multisig, except the sender.
+    /// @param timepoint Timepoint of the first approval.
+    /// @param callHash Hash of the SCALE-encoded Substrate call.
+    ///
+    /// @custom:selector 2a42b358
+    function cancelAsMulti(
+        uint16 threshold,
+        address[] memory otherSignatories,
+        Timepoint memory timepoint,
+        bytes32 callHash
+    ) external;
+
+    /// @dev Get a pending multisig operation. Reverts if the operation is unknown.
+    ///
+    /// @param multisigAccount Account of the multisig.
+    /// @param callHash Hash of the SCALE-encoded Substrate call.
+    /// @return multisig The pending operation.
+    ///
+    /// @custom:selector c727dc46
+    function multisig(address multisigAccount, bytes32 callHash)
+        external
+        view
+        returns (PendingMultisig memory multisig);
+
+    /// @dev Get the account of a multisig.
+    /// The signatories don't need to be sorted.
+    ///
+    /// @param signatories All the signatories of the multisig.
+    /// @param threshold Number of approvals required to dispatch a call.
+    /// @return multisigAccount Account of the multisig.
+    ///
+    /// @custom:selector 9d1c184a
+    function multiAccountId(address[] memory signatories, uint16 threshold)
+        external
+        view
+        returns (address multisigAccount);
+
+    /// @dev Compute the hash of a call, as used by approveAsMulti and cancelAsMulti.
+    ///
+    /// @param call SCALE-encoded Substrate call.
+    /// @return callHash Hash of the call.
+    ///
+    /// @custom:selector b9d04450
+    function callHash(bytes memory call) external view returns (bytes32 callHash);
+}
@@ -0,0 +1,287 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+//! Precompile to interact with pallet_multisig.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use core::marker::PhantomData;
+use frame_support::{
+    dispatch::{GetDispatchInfo, PostDispatchInfo},
+    traits::{ConstU32, Currency, Get},
+    weights::Weight,
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use pallet_evm::AddressMapping;
+use parity_scale_codec::{DecodeLimit as _, MaxEncodedLen};
+use precompile_utils::prelude::*;
+use sp_core::{H160, H256, U256};
+use sp_runtime::traits::Dispatchable;
+use sp_std::{boxed::Box, vec::Vec};
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+/// Call max proof size in bytes, used as the proof size of the weight bound of the calls.
+pub const CALL_MAX_PROOF_SIZE: u64 = 256 * 1024;
+
+/// Multisigs storage item size in bytes, without the approvals = Twox64Concat (8) +
+/// AccountId (20) + Blake2_128Concat (16) + CallHash (32) + Timepoint (8) + Balance (16) +
+/// AccountId (20) + BoundedVec length (4) = 124
+pub const MULTISIG_BASE_SIZE: usize = 124;
+
+/// Gas cost of a hash, as `KECCAK256`.
+pub const HASH_BASE_COST: u64 = 30;
+
+/// Gas cost of each 32 bytes word hashed, as `KECCAK256`.
+pub const HASH_WORD_COST: u64 = 6;
+
+type GetCallLimit = ConstU32<{ 2u32.pow(16) }>;
+type DecodeLimit = ConstU32<8>;
+
+type BalanceOf<Runtime> = <<Runtime as pallet_multisig::Config>::Currency as Currency<
+    <Runtime as frame_system::Config>::AccountId,
+>>::Balance;
+
+/// Extrinsic opening a multisig operation. A height of 0 stands for no timepoint.
+#[derive(Eq, PartialEq, Debug, Clone, Copy, solidity::Codec)]
+pub struct Timepoint {
+    height: u32,
+    index: u32,
+}
+
+/// A pending multisig operation, as returned by `multisig`.
+#[derive(Eq, PartialEq, Debug, solidity::Codec)]
+pub struct Multisig {
+    when: Timepoint,
+    deposit: U256,
+    depositor: Address,
+    approvals: Vec<Address>,
+}
+
+/// Precompile for the multisig pallet
+pub struct MultisigPrecompile<Runtime>(PhantomData<Runtime>);
+
+#[precompile_utils::precompile]
+impl<Runtime> MultisigPrecompile<Runtime>
+where
+    Runtime: pallet_multisig::Config + pallet_evm::Config,
+    <Runtime as frame_system::Config>::RuntimeCall:
+        Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo,
+    <Runtime as frame_system::Config>::RuntimeCall: From<pallet_multisig::Call<Runtime>>,
+    <<Runtime as frame_system::Config>::RuntimeCall as Dispatchable>::RuntimeOrigin:
+        From<Option<Runtime::AccountId>>,
+    Runtime::AccountId: Into<H160>,
+    BlockNumberFor<Runtime>: Into<u32> + From<u32>,
+    BalanceOf<Runtime>: Into<U256>,
+    <Runtime as pallet_evm::Config>::AddressMapping: AddressMapping<Runtime::AccountId>,
+{
+    /// Approve a multisig operation, and dispatch `call` from the multisig account if the
+    /// approval reaches `threshold`. The first approval must not provide a timepoint, later
+    /// ones must provide the timepoint of the first approval.
+    #[precompile::public("asMulti(uint16,address[],(uint32,uint32),bytes,uint64)")]
+    fn as_multi(
+        handle: &mut impl PrecompileHandle,
+        threshold: u16,
+        other_signatories: Vec<Address>,
+        maybe_timepoint: Timepoint,
+        call: BoundedBytes<GetCallLimit>,
+        max_weight: u64,
+    ) -> EvmResult {
+        let other_signatories = Self::accounts(other_signatories);
+        let call: Vec<_> = call.into();
+        let call = <Runtime as pallet_multisig::Config>::RuntimeCall::decode_with_depth_limit(
+            DecodeLimit::get(),
+            &mut &*call,
+        )
+        .map_err(|_| RevertReason::custom("Failed to decode call").in_field("call"))?;
+
+        let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+        RuntimeHelper::<Runtime>::try_dispatch(
+            handle,
+            Some(origin).into(),
+            pallet_multisig::Call::<Runtime>::as_multi {
+                threshold,
+                other_signatories,
+                maybe_timepoint: Self::maybe_timepoint(maybe_timepoint),
+                call: Box::new(call),
+                max_weight: Weight::from_parts(max_weight, CALL_MAX_PROOF_SIZE),
+            },
+            Self::multisig_size(threshold),
+        )?;
+
+        Ok(())
+    }
+
+    /// Approve a multisig operation by the hash of its call, without dispatching it.
+    #[precompile::public("approveAsMulti(uint16,address[],(uint32,uint32),bytes32,uint64)")]
+    fn approve_as_multi(
+        handle: &mut impl PrecompileHandle,
+        threshold: u16,
+        other_signatories: Vec<Address>,
+        maybe_timepoint: Timepoint,
+        call_hash: H256,
+        max_weight: u64,
+    ) -> EvmResult {
+        let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+        RuntimeHelper::<Runtime>::try_dispatch(
+            handle,
+            Some(origin).into(),
+            pallet_multisig::Call::<Runtime>::approve_as_multi {
+                threshold,
+                other_signatories: Self::accounts(other_signatories),
+                maybe_timepoint: Self::maybe_timepoint(maybe_timepoint),
+                call_hash: call_hash.to_fixed_bytes(),
+                max_weight: Weight::from_parts(max_weight, CALL_MAX_PROOF_SIZE),
+            },
+            Self::multisig_size(threshold),
+        )?;
+
+        Ok(())
+    }
+
+    /// Cancel a multisig operation. Only the account which opened it can cancel it.
+    #[precompile::public("cancelAsMulti(uint16,address[],(uint32,uint32),bytes32)")]
+    fn cancel_as_multi(
+        handle: &mut impl PrecompileHandle,
+        threshold: u16,
+        other_signatories: Vec<Address>,
+        timepoint: Timepoint,
+        call_hash: H256,
+    ) -> EvmResult {
+        let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+        RuntimeHelper::<Runtime>::try_dispatch(
+            handle,
+            Some(origin).into(),
+            pallet_multisig::Call::<Runtime>::cancel_as_multi {
+                threshold,
+                other_signatories: Self::accounts(other_signatories),
+                timepoint: pallet_multisig::Timepoint {
+                    height: timepoint.height.into(),
+                    index: timepoint.index,
+                },
+                call_hash: call_hash.to_fixed_bytes(),
+            },
+            0,
+        )?;
+
+        Ok(())
+    }
+
+    #[precompile::public("multisig(address,bytes32)")]
+    #[precompile::view]
+    fn multisig(
+        handle: &mut impl PrecompileHandle,
+        multisig_account: Address,
+        call_hash: H256,
+    ) -> EvmResult<Multisig> {
+        handle.record_db_read::<Runtime>(
+            MULTISIG_BASE_SIZE
+                + Runtime::AccountId::max_encoded_len()
+                    * (<Runtime as pallet_multisig::Config>::MaxSignatories::get() as usize),
+        )?;
+
+        let multisig_account = Runtime::AddressMapping::into_account_id(multisig_account.into());
+        let multisig = pallet_multisig::Multisigs::<Runtime>::get(
+            multisig_account,
+            call_hash.to_fixed_bytes(),
+        )
+        .ok_or_else(|| RevertReason::custom("Unknown multisig").in_field("callHash"))?;
+
+        Ok(Multisig {
+            when: Timepoint {
+                height: multisig.when.height.into(),
+                index: multisig.when.index,
+            },
+            deposit: multisig.deposit.into(),
+            depositor: Address(multisig.depositor.into()),
+            approvals: multisig
+                .approvals
+                .into_iter()
+                .map(|account| Address(account.into()))
+                .collect(),
+        })
+    }
+
+    /// Account of the multisig of `signatories` with `threshold`.
+    #[precompile::public("multiAccountId(address[],uint16)")]
+    #[precompile::view]
+    fn multi_account_id(
+        handle: &mut impl PrecompileHandle,
+        signatories: Vec<Address>,
+        threshold: u16,
+    ) -> EvmResult<Address> {
+        // The account is the hash of a prefix (16), the length of the signatories (up to 5), the
+        // signatories and the threshold (2)
+        Self::record_hash_cost(
+            handle,
+            23 + Runtime::AccountId::max_encoded_len() * signatories.len(),
+        )?;
+
+        let account = pallet_multisig::Pallet::<Runtime>::multi_account_id(
+            &Self::accounts(signatories),
+            threshold,
+        );
+
+        Ok(Address(account.into()))
+    }
+
+    /// Hash of a SCALE-encoded call, identifying it in the multisig operations.
+    #[precompile::public("callHash(bytes)")]
+    #[precompile::view]
+    fn call_hash(
+        handle: &mut impl PrecompileHandle,
+        call: BoundedBytes<GetCallLimit>,
+    ) -> EvmResult<H256> {
+        let call: Vec<_> = call.into();
+        Self::record_hash_cost(handle, call.len())?;
+
+        Ok(sp_io::hashing::blake2_256(&call).into())
+    }
+
+    /// Record the cost of hashing `len` bytes, priced as `KECCAK256`.
+    fn record_hash_cost(handle: &mut impl PrecompileHandle, len: usize) -> EvmResult {
+        let words = len.div_ceil(32) as u64;
+        handle.record_cost(HASH_BASE_COST.saturating_add(HASH_WORD_COST.saturating_mul(words)))?;
+
+        Ok(())
+    }
+
+    /// Accounts of `addresses`, sorted as the pallet expects them.
+    fn accounts(addresses: Vec<Address>) -> Vec<Runtime::AccountId> {
+        let mut accounts: Vec<_> = addresses
+            .into_iter()
+            .map(|address| Runtime::AddressMapping::into_account_id(address.into()))
+            .collect();
+        accounts.sort();
+        accounts
+    }
+
+    fn maybe_timepoint(
+        timepoint: Timepoint,
+    ) -> Option<pallet_multisig::Timepoint<BlockNumberFor<Runtime>>> {
+        (timepoint.height != 0).then(|| pallet_multisig::Timepoint {
+            height: timepoint.height.into(),
+            index: timepoint.index,
+        })
+    }
+
+    /// Storage growth of a multisig operation approved by up to `threshold` signatories.
+    fn multisig_size(threshold: u16) -> u64 {
+        (MULTISIG_BASE_SIZE + Runtime::AccountId::max_encoded_len() * threshold as usize) as u64
+    }
+}
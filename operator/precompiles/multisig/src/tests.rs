@@ -0,0 +1,298 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+use crate::{
+    mock::{
+        Balances, ExtBuilder, Multisig as MultisigPallet, PCall, Precompiles, PrecompilesValue,
+        Runtime, RuntimeCall,
+    },
+    Multisig, Timepoint, HASH_BASE_COST, HASH_WORD_COST,
+};
+use parity_scale_codec::Encode;
+use precompile_utils::{solidity::codec::Address, testing::*};
+use sp_core::{H160, H256};
+
+fn precompiles() -> Precompiles<Runtime> {
+    PrecompilesValue::get()
+}
+
+/// Transfer of 100 from the multisig account to Charlie
+fn transfer_call() -> Vec<u8> {
+    RuntimeCall::Balances(pallet_balances::Call::transfer_allow_death {
+        dest: Charlie.into(),
+        value: 100,
+    })
+    .encode()
+}
+
+fn call_hash(call: &[u8]) -> H256 {
+    sp_io::hashing::blake2_256(call).into()
+}
+
+/// Multisig account of Alice and Bob with a threshold of 2
+fn multisig_account() -> H160 {
+    let mut signatories = vec![Alice.into(), Bob.into()];
+    signatories.sort();
+    MultisigPallet::multi_account_id(&signatories, 2).into()
+}
+
+fn ext() -> sp_io::TestExternalities {
+    ExtBuilder::default()
+        .with_balances(vec![
+            (Alice.into(), 1000),
+            (Bob.into(), 1000),
+            (multisig_account().into(), 1000),
+        ])
+        .build()
+}
+
+fn no_timepoint() -> Timepoint {
+    Timepoint {
+        height: 0,
+        index: 0,
+    }
+}
+
+fn approve_as_alice(call_hash: H256) {
+    precompiles()
+        .prepare_test(
+            Alice,
+            Precompile1,
+            PCall::approve_as_multi {
+                threshold: 2,
+                other_signatories: vec![Address(Bob.into())],
+                maybe_timepoint: no_timepoint(),
+                call_hash,
+                max_weight: 0,
+            },
+        )
+        .expect_no_logs()
+        .execute_returns(());
+}
+
+#[test]
+fn test_solidity_interface_has_all_function_selectors_documented_and_implemented() {
+    check_precompile_implements_solidity_interfaces(&["Multisig.sol"], PCall::supports_selector)
+}
+
+#[test]
+fn selectors() {
+    assert!(PCall::as_multi_selectors().contains(&0x3a59e237));
+    assert!(PCall::approve_as_multi_selectors().contains(&0x40759967));
+    assert!(PCall::cancel_as_multi_selectors().contains(&0x2a42b358));
+    assert!(PCall::multisig_selectors().contains(&0xc727dc46));
+    assert!(PCall::multi_account_id_selectors().contains(&0x9d1c184a));
+    assert!(PCall::call_hash_selectors().contains(&0xb9d04450));
+}
+
+#[test]
+fn modifiers() {
+    ExtBuilder::default().build().execute_with(|| {
+        let mut tester = PrecompilesModifierTester::new(precompiles(), Alice, Precompile1);
+
+        tester.test_default_modifier(PCall::as_multi_selectors());
+        tester.test_default_modifier(PCall::approve_as_multi_selectors());
+        tester.test_default_modifier(PCall::cancel_as_multi_selectors());
+        tester.test_view_modifier(PCall::multisig_selectors());
+        tester.test_view_modifier(PCall::multi_account_id_selectors());
+        tester.test_view_modifier(PCall::call_hash_selectors());
+    });
+}
+
+#[test]
+fn multi_account_id_sorts_signatories() {
+    ExtBuilder::default().build().execute_with(|| {
+        precompiles()
+            .prepare_test(
+                Alice,
+                Precompile1,
+                PCall::multi_account_id {
+                    signatories: vec![Address(Bob.into()), Address(Alice.into())],
+                    threshold: 2,
+                },
+            )
+            .expect_cost(HASH_BASE_COST + HASH_WORD_COST * 2)
+            .expect_no_logs()
+            .execute_returns(Address(multisig_account()));
+    });
+}
+
+#[test]
+fn call_hash_is_blake2_256() {
+    ExtBuilder::default().build().execute_with(|| {
+        let call = transfer_call();
+
+        precompiles()
+            .prepare_test(
+                Alice,
+                Precompile1,
+                PCall::call_hash {
+                    call: call.clone().into(),
+                },
+            )
+            .expect_cost(HASH_BASE_COST + HASH_WORD_COST * call.len().div_ceil(32) as u64)
+            .expect_no_logs()
+            .execute_returns(call_hash(&call));
+    });
+}
+
+#[test]
+fn approve_as_multi_opens_a_multisig() {
+    ext().execute_with(|| {
+        let call_hash = call_hash(&transfer_call());
+        approve_as_alice(call_hash);
+
+        precompiles()
+            .prepare_test(
+                Alice,
+                Precompile1,
+                PCall::multisig {
+                    multisig_account: Address(multisig_account()),
+                    call_hash,
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(Multisig {
+                when: Timepoint {
+                    height: 1,
+                    index: 0,
+                },
+                // DepositBase + DepositFactor * threshold
+                deposit: 12.into(),
+                depositor: Address(Alice.into()),
+                approvals: vec![Address(Alice.into())],
+            });
+    });
+}
+
+#[test]
+fn as_multi_dispatches_the_call_at_threshold() {
+    ext().execute_with(|| {
+        let call = transfer_call();
+        approve_as_alice(call_hash(&call));
+
+        precompiles()
+            .prepare_test(
+                Bob,
+                Precompile1,
+                PCall::as_multi {
+                    threshold: 2,
+                    other_signatories: vec![Address(Alice.into())],
+                    maybe_timepoint: Timepoint {
+                        height: 1,
+                        index: 0,
+                    },
+                    call: call.clone().into(),
+                    max_weight: 1_000_000_000,
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(());
+
+        assert_eq!(Balances::free_balance(&Charlie.into()), 100);
+        assert_eq!(Balances::free_balance(&Alice.into()), 1000);
+        precompiles()
+            .prepare_test(
+                Alice,
+                Precompile1,
+                PCall::multisig {
+                    multisig_account: Address(multisig_account()),
+                    call_hash: call_hash(&call),
+                },
+            )
+            .execute_reverts(|output| output.ends_with(b"Unknown multisig"));
+    });
+}
+
+#[test]
+fn as_multi_with_invalid_call_fails() {
+    ext().execute_with(|| {
+        precompiles()
+            .prepare_test(
+                Alice,
+                Precompile1,
+                PCall::as_multi {
+                    threshold: 2,
+                    other_signatories: vec![Address(Bob.into())],
+                    maybe_timepoint: no_timepoint(),
+                    call: vec![0xff, 0xff].into(),
+                    max_weight: 0,
+                },
+            )
+            .execute_reverts(|output| output.ends_with(b"Failed to decode call"));
+    });
+}
+
+#[test]
+fn cancel_as_multi_unreserves_the_deposit() {
+    ext().execute_with(|| {
+        let call_hash = call_hash(&transfer_call());
+        approve_as_alice(call_hash);
+        assert_eq!(Balances::free_balance(&Alice.into()), 988);
+
+        precompiles()
+            .prepare_test(
+                Alice,
+                Precompile1,
+                PCall::cancel_as_multi {
+                    threshold: 2,
+                    other_signatories: vec![Address(Bob.into())],
+                    timepoint: Timepoint {
+                        height: 1,
+                        index: 0,
+                    },
+                    call_hash,
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(());
+
+        assert_eq!(Balances::free_balance(&Alice.into()), 1000);
+        precompiles()
+            .prepare_test(
+                Alice,
+                Precompile1,
+                PCall::multisig {
+                    multisig_account: Address(multisig_account()),
+                    call_hash,
+                },
+            )
+            .execute_reverts(|output| output.ends_with(b"Unknown multisig"));
+    });
+}
+
+#[test]
+fn only_the_depositor_can_cancel() {
+    ext().execute_with(|| {
+        let call_hash = call_hash(&transfer_call());
+        approve_as_alice(call_hash);
+
+        precompiles()
+            .prepare_test(
+                Bob,
+                Precompile1,
+                PCall::cancel_as_multi {
+                    threshold: 2,
+                    other_signatories: vec![Address(Alice.into())],
+                    timepoint: Timepoint {
+                        height: 1,
+                        index: 0,
+                    },
+                    call_hash,
+                },
+            )
+            .execute_reverts(|output| output.ends_with(b"NotOwner\") })"));
+    });
+}
@@ -0,0 +1,81 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @dev The Scheduler contract's address.
+address constant SCHEDULER_ADDRESS = 0x000000000000000000000000000000000000081C;
+
+/// @dev The Scheduler contract's instance.
+Scheduler constant SCHEDULER_CONTRACT = Scheduler(SCHEDULER_ADDRESS);
+
+/// @title Scheduler precompile
+/// Allows to interact with Substrate pallet_scheduler from the EVM.
+/// Calls are scheduled and cancelled with the origin of the sender, which must satisfy the
+/// schedule origin of the runtime, the calls of other senders being reverted. Scheduled calls are
+/// dispatched from the sender.
+/// Address: 0x000000000000000000000000000000000000081C
+interface Scheduler {
+    /// @dev Schedule a call.
+    ///
+    /// @param when Block at which the call is dispatched.
+    /// @param period Number of blocks between two dispatches, 0 to dispatch the call once.
+    /// @param repetitions Number of dispatches of a periodic call.
+    /// @param priority Priority of the call, 0 being the highest.
+    /// @param call SCALE-encoded Substrate call.
+    /// @return index Index of the task in the agenda of the block.
+    ///
+    /// @custom:selector 552e200d
+    function schedule(
+        uint32 when,
+        uint32 period,
+        uint32 repetitions,
+        uint8 priority,
+        bytes memory call
+    ) external returns (uint32 index);
+
+    /// @dev Schedule a call under a name identifying the task until it is dispatched or
+    /// cancelled.
+    ///
+    /// @param id Name of the task.
+    /// @param when Block at which the call is dispatched.
+    /// @param period Number of blocks between two dispatches, 0 to dispatch the call once.
+    /// @param repetitions Number of dispatches of a periodic call.
+    /// @param priority Priority of the call, 0 being the highest.
+    /// @param call SCALE-encoded Substrate call.
+    ///
+    /// @custom:selector 17fc8c21
+    function scheduleNamed(
+        bytes32 id,
+        uint32 when,
+        uint32 period,
+        uint32 repetitions,
+        uint8 priority,
+        bytes memory call
+    ) external;
+
+    /// @dev Cancel a task. Only the origin which scheduled the task can cancel it.
+    ///
+    /// @param when Block of the task.
+    /// @param index Index of the task in the agenda of the block.
+    ///
+    /// @custom:selector b13a05fd
+    function cancel(uint32 when, uint32 index) external;
+
+    /// @dev Cancel a named task. Only the origin which scheduled the task can cancel it.
+    ///
+    /// @param id Name of the task.
+    ///
+    /// @custom:selector 802f7948
+    function cancelNamed(bytes32 id) external;
+
+    /// @dev Get the address of a named task. Reverts if the task is unknown.
+    ///
+    /// @param id Name of the task.
+    /// @return when Block of the task.
+    /// @return index Index of the task in the agenda of the block.
+    ///
+    /// @custom:selector f39ec1f7
+    function lookup(bytes32 id)
+        external
+        view
+        returns (uint32 when, uint32 index);
+}
@@ -0,0 +1,214 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+//! Precompile to interact with pallet_scheduler.
+//!
+//! Calls are scheduled and cancelled with the signed origin of the caller, so they go through
+//! the `ScheduleOrigin` of the scheduler, which reverts the calls of the accounts it doesn't
+//! allow, and scheduled calls are dispatched from the caller.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use core::marker::PhantomData;
+use frame_support::{
+    dispatch::{GetDispatchInfo, PostDispatchInfo},
+    traits::{ConstU32, Get},
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use pallet_evm::AddressMapping;
+use pallet_scheduler::ScheduledOf;
+use parity_scale_codec::{DecodeLimit as _, MaxEncodedLen};
+use precompile_utils::prelude::*;
+use sp_core::H256;
+use sp_runtime::traits::Dispatchable;
+use sp_std::{boxed::Box, vec::Vec};
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+/// Lookup storage item size in bytes = Twox64Concat (8) + TaskName (32) +
+/// TaskAddress (BlockNumber (4) + Index (4)) = 48
+pub const LOOKUP_SIZE: usize = 48;
+
+type GetCallLimit = ConstU32<{ 2u32.pow(16) }>;
+type DecodeLimit = ConstU32<8>;
+
+/// Precompile for the scheduler pallet
+pub struct SchedulerPrecompile<Runtime>(PhantomData<Runtime>);
+
+#[precompile_utils::precompile]
+impl<Runtime> SchedulerPrecompile<Runtime>
+where
+    Runtime: pallet_scheduler::Config + pallet_evm::Config,
+    <Runtime as frame_system::Config>::RuntimeCall:
+        Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo,
+    <Runtime as frame_system::Config>::RuntimeCall: From<pallet_scheduler::Call<Runtime>>,
+    <<Runtime as frame_system::Config>::RuntimeCall as Dispatchable>::RuntimeOrigin:
+        From<Option<Runtime::AccountId>>,
+    BlockNumberFor<Runtime>: Into<u32> + From<u32>,
+    <Runtime as pallet_evm::Config>::AddressMapping: AddressMapping<Runtime::AccountId>,
+{
+    /// Schedule `call` at block `when`, repeated `repetitions` times every `period` blocks if
+    /// `period` is not 0. Returns the index of the task in the agenda of `when`. The caller must
+    /// satisfy the `ScheduleOrigin` of the scheduler.
+    #[precompile::public("schedule(uint32,uint32,uint32,uint8,bytes)")]
+    fn schedule(
+        handle: &mut impl PrecompileHandle,
+        when: u32,
+        period: u32,
+        repetitions: u32,
+        priority: u8,
+        call: BoundedBytes<GetCallLimit>,
+    ) -> EvmResult<u32> {
+        let call = Self::decode_call(call)?;
+        let index = Self::next_task_index(handle, when)?;
+
+        let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+        RuntimeHelper::<Runtime>::try_dispatch(
+            handle,
+            Some(origin).into(),
+            pallet_scheduler::Call::<Runtime>::schedule {
+                when: when.into(),
+                maybe_periodic: Self::maybe_periodic(period, repetitions),
+                priority,
+                call: Box::new(call),
+            },
+            ScheduledOf::<Runtime>::max_encoded_len() as u64,
+        )?;
+
+        Ok(index)
+    }
+
+    /// Schedule `call` at block `when` under the name `id`, which identifies the task until it
+    /// is dispatched or cancelled. The caller must satisfy the `ScheduleOrigin` of the scheduler.
+    #[precompile::public("scheduleNamed(bytes32,uint32,uint32,uint32,uint8,bytes)")]
+    fn schedule_named(
+        handle: &mut impl PrecompileHandle,
+        id: H256,
+        when: u32,
+        period: u32,
+        repetitions: u32,
+        priority: u8,
+        call: BoundedBytes<GetCallLimit>,
+    ) -> EvmResult {
+        let call = Self::decode_call(call)?;
+
+        let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+        RuntimeHelper::<Runtime>::try_dispatch(
+            handle,
+            Some(origin).into(),
+            pallet_scheduler::Call::<Runtime>::schedule_named {
+                id: id.to_fixed_bytes(),
+                when: when.into(),
+                maybe_periodic: Self::maybe_periodic(period, repetitions),
+                priority,
+                call: Box::new(call),
+            },
+            (ScheduledOf::<Runtime>::max_encoded_len() + LOOKUP_SIZE) as u64,
+        )?;
+
+        Ok(())
+    }
+
+    /// Cancel the task at `index` in the agenda of `when`. Only the origin which scheduled the
+    /// task can cancel it.
+    #[precompile::public("cancel(uint32,uint32)")]
+    fn cancel(handle: &mut impl PrecompileHandle, when: u32, index: u32) -> EvmResult {
+        let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+        RuntimeHelper::<Runtime>::try_dispatch(
+            handle,
+            Some(origin).into(),
+            pallet_scheduler::Call::<Runtime>::cancel {
+                when: when.into(),
+                index,
+            },
+            0,
+        )?;
+
+        Ok(())
+    }
+
+    /// Cancel the task named `id`. Only the origin which scheduled the task can cancel it.
+    #[precompile::public("cancelNamed(bytes32)")]
+    fn cancel_named(handle: &mut impl PrecompileHandle, id: H256) -> EvmResult {
+        let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+        RuntimeHelper::<Runtime>::try_dispatch(
+            handle,
+            Some(origin).into(),
+            pallet_scheduler::Call::<Runtime>::cancel_named {
+                id: id.to_fixed_bytes(),
+            },
+            0,
+        )?;
+
+        Ok(())
+    }
+
+    /// Block and agenda index of the task named `id`.
+    #[precompile::public("lookup(bytes32)")]
+    #[precompile::view]
+    fn lookup(handle: &mut impl PrecompileHandle, id: H256) -> EvmResult<(u32, u32)> {
+        handle.record_db_read::<Runtime>(LOOKUP_SIZE)?;
+
+        let (when, index) = pallet_scheduler::Lookup::<Runtime>::get(id.to_fixed_bytes())
+            .ok_or_else(|| RevertReason::custom("Unknown task").in_field("id"))?;
+
+        Ok((when.into(), index))
+    }
+
+    fn decode_call(
+        call: BoundedBytes<GetCallLimit>,
+    ) -> EvmResult<<Runtime as pallet_scheduler::Config>::RuntimeCall> {
+        let call: Vec<_> = call.into();
+        <Runtime as pallet_scheduler::Config>::RuntimeCall::decode_with_depth_limit(
+            DecodeLimit::get(),
+            &mut &*call,
+        )
+        .map_err(|_| {
+            RevertReason::custom("Failed to decode call")
+                .in_field("call")
+                .into()
+        })
+    }
+
+    fn maybe_periodic(period: u32, repetitions: u32) -> Option<(BlockNumberFor<Runtime>, u32)> {
+        (period != 0).then(|| (period.into(), repetitions))
+    }
+
+    /// Index the scheduler gives to the next task scheduled at `when`: tasks are appended to the
+    /// agenda while it has room, then take the first slot freed by a cancelled task.
+    fn next_task_index(handle: &mut impl PrecompileHandle, when: u32) -> EvmResult<u32> {
+        let max_scheduled = <Runtime as pallet_scheduler::Config>::MaxScheduledPerBlock::get();
+        // Agenda: Twox64Concat(8) + BlockNumber(4) + BoundedVec(Scheduled * MaxScheduledPerBlock)
+        handle.record_db_read::<Runtime>(
+            16 + ScheduledOf::<Runtime>::max_encoded_len() * (max_scheduled as usize),
+        )?;
+
+        let agenda =
+            pallet_scheduler::Agenda::<Runtime>::get(BlockNumberFor::<Runtime>::from(when));
+        let index = if (agenda.len() as u32) < max_scheduled {
+            agenda.len()
+        } else {
+            agenda
+                .iter()
+                .position(Option::is_none)
+                .unwrap_or(agenda.len())
+        };
+
+        Ok(index as u32)
+    }
+}
@@ -0,0 +1,250 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Test utilities
+use super::*;
+use frame_support::{
+    construct_runtime, parameter_types,
+    traits::{ConstU32, EqualPrivilegeOnly, Everything, OnInitialize, SortedMembers},
+    weights::Weight,
+};
+use frame_system::{EnsureRoot, EnsureSignedBy};
+use pallet_evm::{
+    EnsureAddressNever, EnsureAddressRoot, FrameSystemAccountProvider, SubstrateBlockHashMapping,
+};
+use precompile_utils::{
+    precompile_set::*,
+    testing::{Alice, Bob, MockAccount},
+};
+use sp_core::{H256, U256};
+use sp_runtime::{
+    traits::{BlakeTwo256, IdentityLookup},
+    BuildStorage,
+};
+
+pub type AccountId = MockAccount;
+pub type Balance = u128;
+
+type Block = frame_system::mocking::MockBlockU32<Runtime>;
+
+// Configure a mock runtime to test the pallet.
+construct_runtime!(
+    pub enum Runtime	{
+        System: frame_system,
+        Balances: pallet_balances,
+        Evm: pallet_evm,
+        Timestamp: pallet_timestamp,
+        Preimage: pallet_preimage,
+        Scheduler: pallet_scheduler,
+    }
+);
+
+parameter_types! {
+    pub const BlockHashCount: u32 = 250;
+    pub const SS58Prefix: u8 = 42;
+}
+impl frame_system::Config for Runtime {
+    type BaseCallFilter = Everything;
+    type DbWeight = ();
+    type RuntimeOrigin = RuntimeOrigin;
+    type RuntimeTask = RuntimeTask;
+    type Nonce = u64;
+    type Block = Block;
+    type RuntimeCall = RuntimeCall;
+    type Hash = H256;
+    type Hashing = BlakeTwo256;
+    type AccountId = AccountId;
+    type Lookup = IdentityLookup<Self::AccountId>;
+    type RuntimeEvent = RuntimeEvent;
+    type BlockHashCount = BlockHashCount;
+    type Version = ();
+    type PalletInfo = PalletInfo;
+    type AccountData = pallet_balances::AccountData<Balance>;
+    type OnNewAccount = ();
+    type OnKilledAccount = ();
+    type SystemWeightInfo = ();
+    type BlockWeights = ();
+    type BlockLength = ();
+    type SS58Prefix = SS58Prefix;
+    type OnSetCode = ();
+    type MaxConsumers = frame_support::traits::ConstU32<16>;
+    type SingleBlockMigrations = ();
+    type MultiBlockMigrator = ();
+    type PreInherents = ();
+    type PostInherents = ();
+    type PostTransactions = ();
+    type ExtensionsWeightInfo = ();
+}
+
+parameter_types! {
+    pub const ExistentialDeposit: u128 = 0;
+}
+
+impl pallet_balances::Config for Runtime {
+    type MaxReserves = ();
+    type ReserveIdentifier = ();
+    type MaxLocks = ();
+    type Balance = Balance;
+    type RuntimeEvent = RuntimeEvent;
+    type DustRemoval = ();
+    type ExistentialDeposit = ExistentialDeposit;
+    type AccountStore = System;
+    type WeightInfo = ();
+    type RuntimeHoldReason = ();
+    type FreezeIdentifier = ();
+    type MaxFreezes = ();
+    type RuntimeFreezeReason = ();
+    type DoneSlashHandler = ();
+}
+
+pub type Precompiles<R> =
+    PrecompileSetBuilder<R, (PrecompileAt<AddressU64<1>, SchedulerPrecompile<R>>,)>;
+
+pub type PCall = SchedulerPrecompileCall<Runtime>;
+
+/// Block storage limit in bytes. Set to 40 KB.
+const BLOCK_STORAGE_LIMIT: u64 = 40 * 1024;
+
+parameter_types! {
+    pub BlockGasLimit: U256 = U256::from(u64::MAX);
+    pub PrecompilesValue: Precompiles<Runtime> = Precompiles::new();
+    pub const WeightPerGas: Weight = Weight::from_parts(1, 0);
+    pub const GasLimitPovSizeRatio: u64 = 0;
+    pub GasLimitStorageGrowthRatio : u64 = {
+        let block_gas_limit = BlockGasLimit::get().min(u64::MAX.into()).low_u64();
+        block_gas_limit.saturating_div(BLOCK_STORAGE_LIMIT)
+    };
+}
+
+impl pallet_evm::Config for Runtime {
+    type FeeCalculator = ();
+    type GasWeightMapping = pallet_evm::FixedGasWeightMapping<Self>;
+    type WeightPerGas = WeightPerGas;
+    type CallOrigin = EnsureAddressRoot<AccountId>;
+    type WithdrawOrigin = EnsureAddressNever<AccountId>;
+    type AddressMapping = AccountId;
+    type Currency = Balances;
+    type RuntimeEvent = RuntimeEvent;
+    type Runner = pallet_evm::runner::stack::Runner<Self>;
+    type PrecompilesType = Precompiles<Self>;
+    type PrecompilesValue = PrecompilesValue;
+    type ChainId = ();
+    type OnChargeTransaction = ();
+    type BlockGasLimit = BlockGasLimit;
+    type BlockHashMapping = SubstrateBlockHashMapping<Self>;
+    type FindAuthor = ();
+    type OnCreate = ();
+    type GasLimitPovSizeRatio = GasLimitPovSizeRatio;
+    type GasLimitStorageGrowthRatio = GasLimitStorageGrowthRatio;
+    type Timestamp = Timestamp;
+    type WeightInfo = pallet_evm::weights::SubstrateWeight<Runtime>;
+    type AccountProvider = FrameSystemAccountProvider<Runtime>;
+}
+
+parameter_types! {
+    pub const MinimumPeriod: u64 = 5;
+}
+impl pallet_timestamp::Config for Runtime {
+    type Moment = u64;
+    type OnTimestampSet = ();
+    type MinimumPeriod = MinimumPeriod;
+    type WeightInfo = ();
+}
+
+impl pallet_preimage::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = ();
+    type Currency = Balances;
+    type ManagerOrigin = EnsureRoot<AccountId>;
+    type Consideration = ();
+}
+
+parameter_types! {
+    pub MaximumSchedulerWeight: Weight = Weight::from_parts(1_000_000_000_000, u64::MAX);
+}
+
+impl pallet_scheduler::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type RuntimeOrigin = RuntimeOrigin;
+    type PalletsOrigin = OriginCaller;
+    type RuntimeCall = RuntimeCall;
+    type MaximumWeight = MaximumSchedulerWeight;
+    type ScheduleOrigin = EnsureSignedBy<Schedulers, AccountId>;
+    type MaxScheduledPerBlock = ConstU32<MAX_SCHEDULED_PER_BLOCK>;
+    type OriginPrivilegeCmp = EqualPrivilegeOnly;
+    type Preimages = Preimage;
+    type WeightInfo = ();
+}
+
+/// Accounts allowed to schedule calls, Charlie being left out to test rejected callers
+pub struct Schedulers;
+impl SortedMembers<AccountId> for Schedulers {
+    fn sorted_members() -> Vec<AccountId> {
+        let mut members = vec![Alice.into(), Bob.into()];
+        members.sort();
+        members
+    }
+}
+
+/// Maximum number of tasks in the agenda of a block
+pub const MAX_SCHEDULED_PER_BLOCK: u32 = 2;
+
+/// Build test externalities, prepopulated with data for testing scheduler precompiles
+pub(crate) struct ExtBuilder {
+    /// Endowed accounts with balances
+    balances: Vec<(AccountId, Balance)>,
+}
+
+impl Default for ExtBuilder {
+    fn default() -> ExtBuilder {
+        ExtBuilder { balances: vec![] }
+    }
+}
+
+impl ExtBuilder {
+    /// Fund some accounts before starting the test
+    pub(crate) fn with_balances(mut self, balances: Vec<(AccountId, Balance)>) -> Self {
+        self.balances = balances;
+        self
+    }
+
+    /// Build the test externalities for use in tests
+    pub(crate) fn build(self) -> sp_io::TestExternalities {
+        let mut t = frame_system::GenesisConfig::<Runtime>::default()
+            .build_storage()
+            .expect("Frame system builds valid default genesis config");
+
+        pallet_balances::GenesisConfig::<Runtime> {
+            balances: self.balances.clone(),
+        }
+        .assimilate_storage(&mut t)
+        .expect("Pallet balances storage can be assimilated");
+
+        let mut ext = sp_io::TestExternalities::new(t);
+        ext.execute_with(|| {
+            System::set_block_number(1);
+        });
+        ext
+    }
+}
+
+/// Roll to block `n`, dispatching the scheduled tasks
+pub(crate) fn roll_to(n: u32) {
+    while System::block_number() < n {
+        System::set_block_number(System::block_number() + 1);
+        Scheduler::on_initialize(System::block_number());
+    }
+}
@@ -0,0 +1,211 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+use crate::mock::{
+    roll_to, Balances, ExtBuilder, PCall, Precompiles, PrecompilesValue, Runtime, RuntimeCall,
+};
+use parity_scale_codec::Encode;
+use precompile_utils::testing::*;
+use sp_core::H256;
+
+fn precompiles() -> Precompiles<Runtime> {
+    PrecompilesValue::get()
+}
+
+/// Transfer of 100 to Charlie
+fn transfer_call() -> Vec<u8> {
+    RuntimeCall::Balances(pallet_balances::Call::transfer_allow_death {
+        dest: Charlie.into(),
+        value: 100,
+    })
+    .encode()
+}
+
+fn ext() -> sp_io::TestExternalities {
+    ExtBuilder::default()
+        .with_balances(vec![(Alice.into(), 1000)])
+        .build()
+}
+
+fn schedule(when: u32) -> PCall {
+    PCall::schedule {
+        when,
+        period: 0,
+        repetitions: 0,
+        priority: 0,
+        call: transfer_call().into(),
+    }
+}
+
+fn schedule_named(id: H256, when: u32) -> PCall {
+    PCall::schedule_named {
+        id,
+        when,
+        period: 0,
+        repetitions: 0,
+        priority: 0,
+        call: transfer_call().into(),
+    }
+}
+
+#[test]
+fn test_solidity_interface_has_all_function_selectors_documented_and_implemented() {
+    check_precompile_implements_solidity_interfaces(&["Scheduler.sol"], PCall::supports_selector)
+}
+
+#[test]
+fn selectors() {
+    assert!(PCall::schedule_selectors().contains(&0x552e200d));
+    assert!(PCall::schedule_named_selectors().contains(&0x17fc8c21));
+    assert!(PCall::cancel_selectors().contains(&0xb13a05fd));
+    assert!(PCall::cancel_named_selectors().contains(&0x802f7948));
+    assert!(PCall::lookup_selectors().contains(&0xf39ec1f7));
+}
+
+#[test]
+fn modifiers() {
+    ExtBuilder::default().build().execute_with(|| {
+        let mut tester = PrecompilesModifierTester::new(precompiles(), Alice, Precompile1);
+
+        tester.test_default_modifier(PCall::schedule_selectors());
+        tester.test_default_modifier(PCall::schedule_named_selectors());
+        tester.test_default_modifier(PCall::cancel_selectors());
+        tester.test_default_modifier(PCall::cancel_named_selectors());
+        tester.test_view_modifier(PCall::lookup_selectors());
+    });
+}
+
+#[test]
+fn scheduled_call_is_dispatched_from_the_caller() {
+    ext().execute_with(|| {
+        precompiles()
+            .prepare_test(Alice, Precompile1, schedule(3))
+            .expect_no_logs()
+            .execute_returns(0u32);
+
+        roll_to(2);
+        assert_eq!(Balances::free_balance(&Charlie.into()), 0);
+
+        roll_to(3);
+        assert_eq!(Balances::free_balance(&Charlie.into()), 100);
+        assert_eq!(Balances::free_balance(&Alice.into()), 900);
+    });
+}
+
+#[test]
+fn schedule_in_the_past_fails() {
+    ext().execute_with(|| {
+        precompiles()
+            .prepare_test(Alice, Precompile1, schedule(1))
+            .execute_reverts(|output| output.ends_with(b"TargetBlockNumberInPast\") })"));
+    });
+}
+
+#[test]
+fn schedule_returns_the_agenda_index() {
+    ext().execute_with(|| {
+        precompiles()
+            .prepare_test(Alice, Precompile1, schedule(3))
+            .execute_returns(0u32);
+        precompiles()
+            .prepare_test(Alice, Precompile1, schedule(3))
+            .execute_returns(1u32);
+
+        // The agenda is full, so the next task takes the slot of the cancelled one.
+        precompiles()
+            .prepare_test(Alice, Precompile1, PCall::cancel { when: 3, index: 0 })
+            .expect_no_logs()
+            .execute_returns(());
+        precompiles()
+            .prepare_test(Alice, Precompile1, schedule(3))
+            .execute_returns(0u32);
+
+        roll_to(3);
+        assert_eq!(Balances::free_balance(&Charlie.into()), 200);
+    });
+}
+
+#[test]
+fn caller_not_allowed_by_the_schedule_origin_is_rejected() {
+    ext().execute_with(|| {
+        let id = H256::repeat_byte(0x01);
+
+        precompiles()
+            .prepare_test(Charlie, Precompile1, schedule(3))
+            .execute_reverts(|output| output.ends_with(b"BadOrigin"));
+        precompiles()
+            .prepare_test(Charlie, Precompile1, schedule_named(id, 3))
+            .execute_reverts(|output| output.ends_with(b"BadOrigin"));
+    });
+}
+
+#[test]
+fn only_the_scheduling_origin_can_cancel() {
+    ext().execute_with(|| {
+        precompiles()
+            .prepare_test(Alice, Precompile1, schedule(3))
+            .execute_returns(0u32);
+
+        precompiles()
+            .prepare_test(Bob, Precompile1, PCall::cancel { when: 3, index: 0 })
+            .execute_reverts(|output| output.ends_with(b"BadOrigin"));
+
+        roll_to(3);
+        assert_eq!(Balances::free_balance(&Charlie.into()), 100);
+    });
+}
+
+#[test]
+fn named_task_can_be_looked_up_and_cancelled() {
+    ext().execute_with(|| {
+        let id = H256::repeat_byte(0x01);
+
+        precompiles()
+            .prepare_test(Alice, Precompile1, schedule_named(id, 3))
+            .expect_no_logs()
+            .execute_returns(());
+
+        precompiles()
+            .prepare_test(Alice, Precompile1, PCall::lookup { id })
+            .expect_no_logs()
+            .execute_returns((3u32, 0u32));
+
+        precompiles()
+            .prepare_test(Alice, Precompile1, PCall::cancel_named { id })
+            .expect_no_logs()
+            .execute_returns(());
+
+        precompiles()
+            .prepare_test(Alice, Precompile1, PCall::lookup { id })
+            .execute_reverts(|output| output.ends_with(b"Unknown task"));
+
+        roll_to(3);
+        assert_eq!(Balances::free_balance(&Charlie.into()), 0);
+    });
+}
+
+#[test]
+fn schedule_named_with_a_taken_name_fails() {
+    ext().execute_with(|| {
+        let id = H256::repeat_byte(0x01);
+
+        precompiles()
+            .prepare_test(Alice, Precompile1, schedule_named(id, 3))
+            .execute_returns(());
+        precompiles()
+            .prepare_test(Alice, Precompile1, schedule_named(id, 4))
+            .execute_reverts(|output| output.ends_with(b"FailedToSchedule\") })"));
+    });
+}
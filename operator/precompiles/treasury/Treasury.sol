@@ -0,0 +1,139 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @dev The Treasury contract's address.
+address constant TREASURY_ADDRESS = 0x000000000000000000000000000000000000081A;
+
+/// @dev The Treasury contract's instance.
+Treasury constant TREASURY_CONTRACT = Treasury(TREASURY_ADDRESS);
+
+/// @title Treasury precompile
+/// Allows to interact with Substrate pallet_treasury from the EVM.
+/// Address: 0x000000000000000000000000000000000000081A
+interface Treasury {
+    /// @dev A spend approved by the treasury.
+    struct Spend {
+        /// SCALE-encoded asset kind of the runtime: 0x00 pays the account of the beneficiary,
+        /// 0x01 bridges the payout to the beneficiary's address on Ethereum.
+        bytes assetKind;
+        /// Amount of native tokens to pay out.
+        uint256 amount;
+        /// Account receiving the payout.
+        address beneficiary;
+        /// Block from which the spend can be paid out.
+        uint32 validFrom;
+        /// Block from which the spend can no longer be paid out.
+        uint32 expireAt;
+        /// 0: pending, 1: payout attempted, 2: payout failed.
+        uint8 status;
+    }
+
+    /// @dev A spending proposal, approved to be paid out at the next spend period.
+    struct Proposal {
+        address proposer;
+        uint256 value;
+        address beneficiary;
+        uint256 bond;
+    }
+
+    /// @dev Approve a spend of native tokens.
+    /// The sender must satisfy the spend origin of the treasury, for the amount spent.
+    /// Councils spend by proposing the Substrate `Treasury::spend` call through their collective.
+    ///
+    /// @param amount Amount to pay out.
+    /// @param beneficiary Account receiving the payout.
+    /// @param validFrom Block from which the spend can be paid out, 0 for the current block.
+    /// @return index Index of the new spend.
+    ///
+    /// @custom:selector 063abaab
+    function spend(
+        uint256 amount,
+        address beneficiary,
+        uint32 validFrom
+    ) external returns (uint32 index);
+
+    /// @dev Pay out an approved spend. Can be called by anyone.
+    ///
+    /// @param index Index of the spend.
+    ///
+    /// @custom:selector c18f0ccf
+    function payout(uint32 index) external;
+
+    /// @dev Check the status of a spend paid out, removing it once the payment
+    /// succeeded or the spend expired. Can be called by anyone.
+    ///
+    /// @param index Index of the spend.
+    ///
+    /// @custom:selector 3285b261
+    function checkStatus(uint32 index) external;
+
+    /// @dev Void a spend not paid out yet.
+    /// The sender must satisfy the reject origin of the treasury.
+    ///
+    /// @param index Index of the spend.
+    ///
+    /// @custom:selector ee0696ae
+    function voidSpend(uint32 index) external;
+
+    /// @dev Get the number of spends ever approved.
+    ///
+    /// @return count Number of spends, also the index of the next spend.
+    ///
+    /// @custom:selector 9d12d015
+    function spendCount() external view returns (uint32 count);
+
+    /// @dev Get a spend. Reverts if the spend is unknown.
+    ///
+    /// @param index Index of the spend.
+    /// @return spend The spend.
+    ///
+    /// @custom:selector 0624b7f0
+    function spends(uint32 index) external view returns (Spend memory spend);
+
+    /// @dev Get the number of spending proposals ever made.
+    ///
+    /// @return count Number of proposals, also the index of the next proposal.
+    ///
+    /// @custom:selector da35c664
+    function proposalCount() external view returns (uint32 count);
+
+    /// @dev Get a spending proposal. Reverts if the proposal is unknown.
+    ///
+    /// @param index Index of the proposal.
+    /// @return proposal The proposal.
+    ///
+    /// @custom:selector 2a4910ab
+    function proposals(uint32 index)
+        external
+        view
+        returns (Proposal memory proposal);
+
+    /// @dev Get the indices of the proposals approved for the next spend period.
+    ///
+    /// @return approvals Indices of the approved proposals.
+    ///
+    /// @custom:selector b05dba96
+    function approvals() external view returns (uint32[] memory approvals);
+
+    /// @dev Get the balance of the treasury available for spending.
+    ///
+    /// @return pot Spendable balance of the treasury.
+    ///
+    /// @custom:selector 4ba2363a
+    function pot() external view returns (uint256 pot);
+
+    /// @dev Get the number of blocks between two spend periods.
+    ///
+    /// @return period Spend period in blocks.
+    ///
+    /// @custom:selector 28538593
+    function spendPeriod() external view returns (uint32 period);
+
+    event SpendApproved(
+        uint32 indexed index,
+        address indexed beneficiary,
+        uint256 amount
+    );
+    event Paid(uint32 indexed index);
+    event SpendVoided(uint32 indexed index);
+}
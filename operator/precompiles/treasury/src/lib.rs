@@ -0,0 +1,314 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Precompile to interact with pallet_treasury.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use core::marker::PhantomData;
+use fp_evm::Log;
+use frame_support::{
+    dispatch::{GetDispatchInfo, PostDispatchInfo},
+    traits::{tokens::Pay, Get},
+};
+use pallet_evm::AddressMapping;
+use pallet_treasury::{BalanceOf, PaymentState};
+use parity_scale_codec::Encode;
+use precompile_utils::prelude::*;
+use sp_core::{H160, H256, U256};
+use sp_runtime::traits::{BlockNumberProvider, Dispatchable, StaticLookup};
+use sp_std::{boxed::Box, vec::Vec};
+
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+
+/// System account size in bytes = Pallet_Name_Hash (16) + Storage_name_hash (16) +
+/// Blake2_128Concat (16) + AccountId (20) + AccountInfo (4 + 12 + AccountData (4* 16)) = 148
+pub const SYSTEM_ACCOUNT_SIZE: u64 = 148;
+
+/// Spends storage item size in bytes = Twox64Concat (8) + SpendIndex (4) +
+/// SpendStatus (AssetKind (1) + AssetBalance (16) + Beneficiary (20) + 2 * BlockNumber (4) +
+/// PaymentState (1 + PaymentId (33))) = 91
+pub const SPEND_SIZE: usize = 91;
+
+/// Proposals storage item size in bytes = Twox64Concat (8) + ProposalIndex (4) +
+/// Proposal (2 * AccountId (20) + 2 * Balance (16)) = 84
+pub const PROPOSAL_SIZE: usize = 84;
+
+/// Solidity selector of the SpendApproved log.
+pub const SELECTOR_LOG_SPEND_APPROVED: [u8; 32] =
+    keccak256!("SpendApproved(uint32,address,uint256)");
+
+/// Solidity selector of the Paid log.
+pub const SELECTOR_LOG_PAID: [u8; 32] = keccak256!("Paid(uint32)");
+
+/// Solidity selector of the SpendVoided log.
+pub const SELECTOR_LOG_SPEND_VOIDED: [u8; 32] = keccak256!("SpendVoided(uint32)");
+
+pub fn log_spend_approved(
+    address: impl Into<H160>,
+    index: u32,
+    beneficiary: impl Into<H160>,
+    amount: U256,
+) -> Log {
+    log3(
+        address.into(),
+        SELECTOR_LOG_SPEND_APPROVED,
+        H256::from_slice(&solidity::encode_arguments(index)),
+        beneficiary.into(),
+        solidity::encode_arguments(amount),
+    )
+}
+
+pub fn log_paid(address: impl Into<H160>, index: u32) -> Log {
+    log2(
+        address.into(),
+        SELECTOR_LOG_PAID,
+        H256::from_slice(&solidity::encode_arguments(index)),
+        Vec::new(),
+    )
+}
+
+pub fn log_spend_voided(address: impl Into<H160>, index: u32) -> Log {
+    log2(
+        address.into(),
+        SELECTOR_LOG_SPEND_VOIDED,
+        H256::from_slice(&solidity::encode_arguments(index)),
+        Vec::new(),
+    )
+}
+
+type AssetBalanceOf<Runtime> = <<Runtime as pallet_treasury::Config>::Paymaster as Pay>::Balance;
+type BeneficiaryLookupOf<Runtime> =
+    <<Runtime as pallet_treasury::Config>::BeneficiaryLookup as StaticLookup>::Source;
+type BlockNumberOf<Runtime> =
+    <<Runtime as pallet_treasury::Config>::BlockNumberProvider as BlockNumberProvider>::BlockNumber;
+
+/// Status of a spend, as returned by `spends`.
+#[derive(Eq, PartialEq, Debug, solidity::Codec)]
+pub struct Spend {
+    /// SCALE-encoded asset kind of the runtime
+    asset_kind: UnboundedBytes,
+    amount: U256,
+    beneficiary: Address,
+    valid_from: u32,
+    expire_at: u32,
+    /// 0: pending, 1: payout attempted, 2: payout failed
+    status: u8,
+}
+
+/// A spending proposal, as returned by `proposals`.
+#[derive(Eq, PartialEq, Debug, solidity::Codec)]
+pub struct Proposal {
+    proposer: Address,
+    value: U256,
+    beneficiary: Address,
+    bond: U256,
+}
+
+/// Precompile for the treasury pallet
+pub struct TreasuryPrecompile<Runtime>(PhantomData<Runtime>);
+
+#[precompile_utils::precompile]
+impl<Runtime> TreasuryPrecompile<Runtime>
+where
+    Runtime: pallet_treasury::Config + pallet_evm::Config,
+    Runtime::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo,
+    Runtime::RuntimeCall: From<pallet_treasury::Call<Runtime>>,
+    <Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<Runtime::AccountId>>,
+    Runtime::AccountId: Into<H160>,
+    Runtime::AssetKind: Default,
+    Runtime::Beneficiary: Into<H160>,
+    BeneficiaryLookupOf<Runtime>: From<Runtime::AccountId>,
+    AssetBalanceOf<Runtime>: TryFrom<U256> + Into<U256>,
+    BalanceOf<Runtime>: Into<U256>,
+    BlockNumberOf<Runtime>: Into<u32> + From<u32>,
+    <Runtime as pallet_evm::Config>::AddressMapping: AddressMapping<Runtime::AccountId>,
+{
+    /// Approve a spend of the native token. The caller must satisfy the `SpendOrigin` of the
+    /// treasury; councils spend by proposing the `Treasury::spend` call through their collective.
+    #[precompile::public("spend(uint256,address,uint32)")]
+    fn spend(
+        handle: &mut impl PrecompileHandle,
+        amount: U256,
+        beneficiary: Address,
+        valid_from: u32,
+    ) -> EvmResult<u32> {
+        // SpendCount
+        handle.record_db_read::<Runtime>(4)?;
+        let index = pallet_treasury::SpendCount::<Runtime>::get();
+
+        let log = log_spend_approved(handle.context().address, index, beneficiary, amount);
+        handle.record_log_costs(&[&log])?;
+
+        let asset_amount: AssetBalanceOf<Runtime> = amount
+            .try_into()
+            .map_err(|_| RevertReason::value_is_too_large("balance type").in_field("amount"))?;
+        let beneficiary = Runtime::AddressMapping::into_account_id(beneficiary.into());
+        let valid_from = (valid_from != 0).then_some(valid_from.into());
+
+        let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+        RuntimeHelper::<Runtime>::try_dispatch(
+            handle,
+            Some(origin).into(),
+            pallet_treasury::Call::<Runtime>::spend {
+                asset_kind: Box::new(Runtime::AssetKind::default()),
+                amount: asset_amount,
+                beneficiary: Box::new(beneficiary.into()),
+                valid_from,
+            },
+            SYSTEM_ACCOUNT_SIZE,
+        )?;
+
+        log.record(handle)?;
+
+        Ok(index)
+    }
+
+    #[precompile::public("payout(uint32)")]
+    fn payout(handle: &mut impl PrecompileHandle, index: u32) -> EvmResult {
+        let log = log_paid(handle.context().address, index);
+        handle.record_log_costs(&[&log])?;
+
+        let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+        RuntimeHelper::<Runtime>::try_dispatch(
+            handle,
+            Some(origin).into(),
+            pallet_treasury::Call::<Runtime>::payout { index },
+            SYSTEM_ACCOUNT_SIZE,
+        )?;
+
+        log.record(handle)?;
+
+        Ok(())
+    }
+
+    #[precompile::public("checkStatus(uint32)")]
+    fn check_status(handle: &mut impl PrecompileHandle, index: u32) -> EvmResult {
+        let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+        RuntimeHelper::<Runtime>::try_dispatch(
+            handle,
+            Some(origin).into(),
+            pallet_treasury::Call::<Runtime>::check_status { index },
+            0,
+        )?;
+
+        Ok(())
+    }
+
+    /// Void a spend not paid out yet. The caller must satisfy the `RejectOrigin` of the treasury.
+    #[precompile::public("voidSpend(uint32)")]
+    fn void_spend(handle: &mut impl PrecompileHandle, index: u32) -> EvmResult {
+        let log = log_spend_voided(handle.context().address, index);
+        handle.record_log_costs(&[&log])?;
+
+        let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+        RuntimeHelper::<Runtime>::try_dispatch(
+            handle,
+            Some(origin).into(),
+            pallet_treasury::Call::<Runtime>::void_spend { index },
+            0,
+        )?;
+
+        log.record(handle)?;
+
+        Ok(())
+    }
+
+    #[precompile::public("spendCount()")]
+    #[precompile::view]
+    fn spend_count(handle: &mut impl PrecompileHandle) -> EvmResult<u32> {
+        // SpendCount
+        handle.record_db_read::<Runtime>(4)?;
+
+        Ok(pallet_treasury::SpendCount::<Runtime>::get())
+    }
+
+    #[precompile::public("spends(uint32)")]
+    #[precompile::view]
+    fn spends(handle: &mut impl PrecompileHandle, index: u32) -> EvmResult<Spend> {
+        handle.record_db_read::<Runtime>(SPEND_SIZE)?;
+
+        let spend = pallet_treasury::Spends::<Runtime>::get(index)
+            .ok_or_else(|| RevertReason::custom("Unknown spend").in_field("index"))?;
+        let status = match spend.status {
+            PaymentState::Pending => 0,
+            PaymentState::Attempted { .. } => 1,
+            PaymentState::Failed => 2,
+        };
+
+        Ok(Spend {
+            asset_kind: spend.asset_kind.encode().into(),
+            amount: spend.amount.into(),
+            beneficiary: Address(spend.beneficiary.into()),
+            valid_from: spend.valid_from.into(),
+            expire_at: spend.expire_at.into(),
+            status,
+        })
+    }
+
+    #[precompile::public("proposalCount()")]
+    #[precompile::view]
+    fn proposal_count(handle: &mut impl PrecompileHandle) -> EvmResult<u32> {
+        // ProposalCount
+        handle.record_db_read::<Runtime>(4)?;
+
+        Ok(pallet_treasury::ProposalCount::<Runtime>::get())
+    }
+
+    #[precompile::public("proposals(uint32)")]
+    #[precompile::view]
+    fn proposals(handle: &mut impl PrecompileHandle, index: u32) -> EvmResult<Proposal> {
+        handle.record_db_read::<Runtime>(PROPOSAL_SIZE)?;
+
+        let proposal = pallet_treasury::Proposals::<Runtime>::get(index)
+            .ok_or_else(|| RevertReason::custom("Unknown proposal").in_field("index"))?;
+
+        Ok(Proposal {
+            proposer: Address(proposal.proposer.into()),
+            value: proposal.value.into(),
+            beneficiary: Address(proposal.beneficiary.into()),
+            bond: proposal.bond.into(),
+        })
+    }
+
+    #[precompile::public("approvals()")]
+    #[precompile::view]
+    fn approvals(handle: &mut impl PrecompileHandle) -> EvmResult<Vec<u32>> {
+        // Approvals: BoundedVec(4 * MaxApprovals)
+        handle.record_db_read::<Runtime>(
+            4 * (<Runtime as pallet_treasury::Config>::MaxApprovals::get() as usize),
+        )?;
+
+        Ok(pallet_treasury::Approvals::<Runtime>::get().into_inner())
+    }
+
+    #[precompile::public("pot()")]
+    #[precompile::view]
+    fn pot(handle: &mut impl PrecompileHandle) -> EvmResult<U256> {
+        handle.record_db_read::<Runtime>(SYSTEM_ACCOUNT_SIZE as usize)?;
+
+        Ok(pallet_treasury::Pallet::<Runtime>::pot().into())
+    }
+
+    #[precompile::public("spendPeriod()")]
+    #[precompile::view]
+    fn spend_period(_handle: &mut impl PrecompileHandle) -> EvmResult<u32> {
+        Ok(<Runtime as pallet_treasury::Config>::SpendPeriod::get().into())
+    }
+}
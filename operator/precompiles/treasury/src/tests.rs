@@ -0,0 +1,273 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+use crate::{
+    log_paid, log_spend_approved, log_spend_voided,
+    mock::{
+        Balances, ExtBuilder, PCall, Precompiles, PrecompilesValue, Runtime, RuntimeOrigin,
+        Treasury, MAX_SPEND, TREASURY_BALANCE,
+    },
+    Proposal, Spend,
+};
+use frame_support::assert_ok;
+use parity_scale_codec::Encode;
+use precompile_utils::{solidity::codec::Address, testing::*};
+use sp_core::{H160, U256};
+
+fn precompiles() -> Precompiles<Runtime> {
+    PrecompilesValue::get()
+}
+
+fn spend(amount: u128) {
+    precompiles()
+        .prepare_test(
+            Alice,
+            Precompile1,
+            PCall::spend {
+                amount: amount.into(),
+                beneficiary: Address(Bob.into()),
+                valid_from: 0,
+            },
+        )
+        .execute_some();
+}
+
+#[test]
+fn test_solidity_interface_has_all_function_selectors_documented_and_implemented() {
+    check_precompile_implements_solidity_interfaces(&["Treasury.sol"], PCall::supports_selector)
+}
+
+#[test]
+fn selectors() {
+    assert!(PCall::spend_selectors().contains(&0x063abaab));
+    assert!(PCall::payout_selectors().contains(&0xc18f0ccf));
+    assert!(PCall::check_status_selectors().contains(&0x3285b261));
+    assert!(PCall::void_spend_selectors().contains(&0xee0696ae));
+    assert!(PCall::spend_count_selectors().contains(&0x9d12d015));
+    assert!(PCall::spends_selectors().contains(&0x0624b7f0));
+    assert!(PCall::proposal_count_selectors().contains(&0xda35c664));
+    assert!(PCall::proposals_selectors().contains(&0x2a4910ab));
+    assert!(PCall::approvals_selectors().contains(&0xb05dba96));
+    assert!(PCall::pot_selectors().contains(&0x4ba2363a));
+    assert!(PCall::spend_period_selectors().contains(&0x28538593));
+}
+
+#[test]
+fn modifiers() {
+    ExtBuilder::default().build().execute_with(|| {
+        let mut tester = PrecompilesModifierTester::new(precompiles(), Alice, Precompile1);
+
+        tester.test_default_modifier(PCall::spend_selectors());
+        tester.test_default_modifier(PCall::payout_selectors());
+        tester.test_default_modifier(PCall::check_status_selectors());
+        tester.test_default_modifier(PCall::void_spend_selectors());
+        tester.test_view_modifier(PCall::spend_count_selectors());
+        tester.test_view_modifier(PCall::spends_selectors());
+        tester.test_view_modifier(PCall::proposal_count_selectors());
+        tester.test_view_modifier(PCall::proposals_selectors());
+        tester.test_view_modifier(PCall::approvals_selectors());
+        tester.test_view_modifier(PCall::pot_selectors());
+        tester.test_view_modifier(PCall::spend_period_selectors());
+    });
+}
+
+#[test]
+fn spend_approves_a_spend() {
+    ExtBuilder::default().build().execute_with(|| {
+        precompiles()
+            .prepare_test(
+                Alice,
+                Precompile1,
+                PCall::spend {
+                    amount: 100.into(),
+                    beneficiary: Address(Bob.into()),
+                    valid_from: 0,
+                },
+            )
+            .expect_log(log_spend_approved(Precompile1, 0, Bob, 100.into()))
+            .execute_returns(0u32);
+
+        precompiles()
+            .prepare_test(Alice, Precompile1, PCall::spend_count {})
+            .expect_no_logs()
+            .execute_returns(1u32);
+
+        precompiles()
+            .prepare_test(Alice, Precompile1, PCall::spends { index: 0 })
+            .expect_no_logs()
+            .execute_returns(Spend {
+                asset_kind: ().encode().into(),
+                amount: 100.into(),
+                beneficiary: Address(Bob.into()),
+                valid_from: 1,
+                expire_at: 11,
+                status: 0,
+            });
+    });
+}
+
+#[test]
+fn spend_above_the_origin_limit_fails() {
+    ExtBuilder::default().build().execute_with(|| {
+        precompiles()
+            .prepare_test(
+                Alice,
+                Precompile1,
+                PCall::spend {
+                    amount: (MAX_SPEND + 1).into(),
+                    beneficiary: Address(Bob.into()),
+                    valid_from: 0,
+                },
+            )
+            .expect_no_logs()
+            .execute_reverts(|output| output.ends_with(b"InsufficientPermission\") })"));
+    });
+}
+
+#[test]
+fn payout_pays_the_beneficiary() {
+    ExtBuilder::default().build().execute_with(|| {
+        spend(100);
+
+        precompiles()
+            .prepare_test(Charlie, Precompile1, PCall::payout { index: 0 })
+            .expect_log(log_paid(Precompile1, 0))
+            .execute_returns(());
+
+        assert_eq!(Balances::free_balance(&Bob.into()), 100);
+        precompiles()
+            .prepare_test(Alice, Precompile1, PCall::spends { index: 0 })
+            .execute_returns(Spend {
+                asset_kind: ().encode().into(),
+                amount: 100.into(),
+                beneficiary: Address(Bob.into()),
+                valid_from: 1,
+                expire_at: 11,
+                status: 1,
+            });
+
+        // The payment succeeded, so checking its status removes the spend.
+        precompiles()
+            .prepare_test(Charlie, Precompile1, PCall::check_status { index: 0 })
+            .expect_no_logs()
+            .execute_returns(());
+
+        precompiles()
+            .prepare_test(Alice, Precompile1, PCall::spends { index: 0 })
+            .execute_reverts(|output| output.ends_with(b"Unknown spend"));
+    });
+}
+
+#[test]
+fn void_spend_removes_the_spend() {
+    ExtBuilder::default().build().execute_with(|| {
+        spend(100);
+
+        precompiles()
+            .prepare_test(Alice, Precompile1, PCall::void_spend { index: 0 })
+            .expect_log(log_spend_voided(Precompile1, 0))
+            .execute_returns(());
+
+        precompiles()
+            .prepare_test(Alice, Precompile1, PCall::spends { index: 0 })
+            .execute_reverts(|output| output.ends_with(b"Unknown spend"));
+        precompiles()
+            .prepare_test(Charlie, Precompile1, PCall::payout { index: 0 })
+            .execute_reverts(|output| output.ends_with(b"InvalidIndex\") })"));
+    });
+}
+
+#[test]
+fn view_pot_and_spend_period() {
+    ExtBuilder::default().build().execute_with(|| {
+        precompiles()
+            .prepare_test(Alice, Precompile1, PCall::pot {})
+            .expect_no_logs()
+            .execute_returns(U256::from(TREASURY_BALANCE));
+
+        precompiles()
+            .prepare_test(Alice, Precompile1, PCall::spend_period {})
+            .expect_no_logs()
+            .execute_returns(7u32);
+    });
+}
+
+#[test]
+#[allow(deprecated)]
+fn view_proposals_and_approvals() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert_ok!(Treasury::spend_local(
+            RuntimeOrigin::signed(Alice.into()),
+            50,
+            Bob.into()
+        ));
+
+        precompiles()
+            .prepare_test(Alice, Precompile1, PCall::proposal_count {})
+            .expect_no_logs()
+            .execute_returns(1u32);
+
+        precompiles()
+            .prepare_test(Alice, Precompile1, PCall::proposals { index: 0 })
+            .expect_no_logs()
+            .execute_returns(Proposal {
+                proposer: Address(Bob.into()),
+                value: 50.into(),
+                beneficiary: Address(Bob.into()),
+                bond: 0.into(),
+            });
+
+        precompiles()
+            .prepare_test(Alice, Precompile1, PCall::approvals {})
+            .expect_no_logs()
+            .execute_returns(vec![0u32]);
+
+        precompiles()
+            .prepare_test(Alice, Precompile1, PCall::proposals { index: 1 })
+            .execute_reverts(|output| output.ends_with(b"Unknown proposal"));
+    });
+}
+
+#[test]
+fn spend_valid_from_a_later_block() {
+    ExtBuilder::default().build().execute_with(|| {
+        let beneficiary = H160::repeat_byte(0x42);
+
+        precompiles()
+            .prepare_test(
+                Alice,
+                Precompile1,
+                PCall::spend {
+                    amount: 10.into(),
+                    beneficiary: Address(beneficiary),
+                    valid_from: 5,
+                },
+            )
+            .expect_log(log_spend_approved(Precompile1, 0, beneficiary, 10.into()))
+            .execute_returns(0u32);
+
+        precompiles()
+            .prepare_test(Alice, Precompile1, PCall::spends { index: 0 })
+            .execute_returns(Spend {
+                asset_kind: ().encode().into(),
+                amount: 10.into(),
+                beneficiary: Address(beneficiary),
+                valid_from: 5,
+                expire_at: 15,
+                status: 0,
+            });
+    });
+}
@@ -19,9 +19,10 @@
 use frame_support::pallet_prelude::*;
 use parity_scale_codec::DecodeAll;
 use snowbridge_inbound_queue_primitives::v2::{
-    EthereumAsset, Message as SnowbridgeMessage, MessageProcessor,
+    EthereumAsset, Message as SnowbridgeMessage, MessageProcessor, RefundMessage,
 };
-use sp_core::H160;
+use snowbridge_outbound_queue_primitives::v2::{Command, Message as OutboundMessage, SendMessage};
+use sp_core::{H160, H256};
 use sp_std::vec::Vec;
 
 // Message ID. This is not expected to change and its arbitrary bytes defined here.
@@ -138,8 +139,35 @@ where
     }
 }
 
+/// Recipient of an inbound native token transfer
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub enum Beneficiary {
+    /// 20-byte account, mapped from the Ethereum address
+    AccountKey20(H160),
+    /// 32-byte account. Runtimes with 20-byte accounts accept an Ethereum address left-padded
+    /// with zeros, as a Solidity `bytes32`
+    AccountId32([u8; 32]),
+}
+
+/// Claimer of an inbound native token transfer
+///
+/// A claimer of exactly 20 bytes is the legacy format, a SCALE-encoded `H160`.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug)]
+pub enum VersionedClaimer {
+    V1 {
+        beneficiary: Beneficiary,
+        /// SCALE-encoded runtime call dispatched on behalf of the beneficiary once the tokens
+        /// are unlocked, e.g. to top up a payment stream. Only dispatched when the beneficiary
+        /// is the Ethereum sender of the message.
+        call: Option<Vec<u8>>,
+    },
+}
+
 /// Native Token Transfer Message Processor
 /// Handles inbound messages for native token transfers from Ethereum back to DataHaven
+///
+/// Tokens whose claimer cannot be decoded are moved to the holding account of the native
+/// transfer pallet instead of failing the message.
 pub struct NativeTokenTransferMessageProcessor<T>(PhantomData<T>);
 
 impl<T> NativeTokenTransferMessageProcessor<T>
@@ -147,15 +175,56 @@ where
     T: pallet_datahaven_native_transfer::Config + frame_system::Config,
     T::AccountId: From<H160>,
 {
-    /// Extract account ID from claimer field
-    /// For native token transfers, the claimer contains an H160 Ethereum address
-    /// that needs to be converted to the runtime's AccountId format
-    fn extract_recipient_from_claimer(claimer: &[u8]) -> Result<T::AccountId, DispatchError> {
-        // For native token transfers, decode the claimer as an H160 Ethereum address
-        let eth_address = H160::decode(&mut &claimer[..])
-            .map_err(|_| DispatchError::Other("Invalid Ethereum address in claimer"))?;
-
-        Ok(T::AccountId::from(eth_address))
+    /// Extract the recipient and the follow-up call from the claimer field
+    /// Accepts a bare H160 Ethereum address (legacy format) or a [`VersionedClaimer`]
+    pub fn extract_recipient_from_claimer(
+        claimer: &[u8],
+    ) -> Result<(T::AccountId, Option<Vec<u8>>), DispatchError> {
+        if claimer.len() == H160::len_bytes() {
+            return Ok((H160::from_slice(claimer).into(), None));
+        }
+
+        let VersionedClaimer::V1 { beneficiary, call } =
+            VersionedClaimer::decode_all(&mut &claimer[..])
+                .map_err(|_| DispatchError::Other("Invalid claimer"))?;
+
+        let recipient = match beneficiary {
+            Beneficiary::AccountKey20(address) => address.into(),
+            Beneficiary::AccountId32(account) => Self::account_from_bytes32(account)
+                .ok_or(DispatchError::Other("Invalid 32-byte account in claimer"))?,
+        };
+
+        Ok((recipient, call))
+    }
+
+    /// Weight of the follow-up call of a native transfer, zero if it has none or it would not
+    /// be dispatched
+    pub fn follow_up_call_weight(message: &SnowbridgeMessage) -> Weight {
+        let Some(claimer) = message.claimer.as_ref() else {
+            return Weight::zero();
+        };
+        match Self::extract_recipient_from_claimer(claimer.as_slice()) {
+            Ok((recipient, Some(call))) => {
+                pallet_datahaven_native_transfer::Pallet::<T>::follow_up_call_weight(
+                    &message.origin.into(),
+                    &recipient,
+                    &call,
+                )
+            }
+            _ => Weight::zero(),
+        }
+    }
+
+    fn account_from_bytes32(account: [u8; 32]) -> Option<T::AccountId> {
+        if let Ok(account) = T::AccountId::decode_all(&mut &account[..]) {
+            return Some(account);
+        }
+
+        let (padding, address) = account.split_at(32 - H160::len_bytes());
+        padding
+            .iter()
+            .all(|byte| *byte == 0)
+            .then(|| H160::from_slice(address).into())
     }
 }
 
@@ -210,19 +279,101 @@ where
             .as_ref()
             .ok_or(DispatchError::Other("No claimer specified in message"))?;
 
-        let recipient = Self::extract_recipient_from_claimer(claimer.as_slice())?;
-
         // Convert amount to balance type
         let balance_amount = token_amount
             .try_into()
             .map_err(|_| DispatchError::Other("Amount conversion failed"))?;
 
-        // Unlock tokens from the sovereign account
-        pallet_datahaven_native_transfer::Pallet::<T>::unlock_tokens(&recipient, balance_amount)?;
+        match Self::extract_recipient_from_claimer(claimer.as_slice()) {
+            Ok((recipient, call)) => {
+                // Unlock tokens from the sovereign account
+                pallet_datahaven_native_transfer::Pallet::<T>::unlock_tokens(
+                    &recipient,
+                    balance_amount,
+                )?;
+
+                if let Some(call) = call {
+                    pallet_datahaven_native_transfer::Pallet::<T>::dispatch_follow_up_call(
+                        &snow_msg.origin.into(),
+                        &recipient,
+                        &call,
+                    );
+                }
+            }
+            // Keep the tokens until governance finds out who they belong to
+            Err(_) => pallet_datahaven_native_transfer::Pallet::<T>::hold_tokens(
+                claimer.as_slice(),
+                balance_amount,
+            )?,
+        }
 
         // Return a 32-byte identifier using the native transfer message type ID
         let mut id = [0u8; 32];
         id[..NATIVE_TRANSFER_MESSAGE_ID.len()].copy_from_slice(&NATIVE_TRANSFER_MESSAGE_ID);
         Ok(id)
     }
+
+    fn processing_weight(message: &SnowbridgeMessage) -> Weight {
+        if !Self::can_process_message(&(), message) {
+            return Weight::zero();
+        }
+        Self::follow_up_call_weight(message)
+    }
+}
+
+/// Gives back the assets of an inbound message that failed processing to its sender on Ethereum
+///
+/// Ether and Ethereum tokens are unlocked by the agent of `AgentOrigin`, while the native tokens
+/// are minted again, as they are still locked in the Ethereum sovereign account.
+pub struct EthereumRefund<OutboundQueue, AgentOrigin>(PhantomData<(OutboundQueue, AgentOrigin)>);
+
+impl<OutboundQueue, AgentOrigin> RefundMessage for EthereumRefund<OutboundQueue, AgentOrigin>
+where
+    OutboundQueue: SendMessage,
+    AgentOrigin: Get<H256>,
+{
+    fn refund(message: &SnowbridgeMessage) -> Result<(), DispatchError> {
+        let recipient = message.origin;
+
+        let mut commands = Vec::new();
+        if message.value > 0 {
+            // The zero token address stands for Ether
+            commands.push(Command::UnlockNativeToken {
+                token: H160::zero(),
+                recipient,
+                amount: message.value,
+            });
+        }
+        commands.extend(message.assets.iter().map(|asset| match asset {
+            EthereumAsset::NativeTokenERC20 { token_id, value } => Command::UnlockNativeToken {
+                token: *token_id,
+                recipient,
+                amount: *value,
+            },
+            EthereumAsset::ForeignTokenERC20 { token_id, value } => Command::MintForeignToken {
+                token_id: *token_id,
+                recipient,
+                amount: *value,
+            },
+        }));
+
+        if commands.is_empty() {
+            return Err(DispatchError::Other("Nothing to refund"));
+        }
+        let commands = BoundedVec::try_from(commands)
+            .map_err(|_| DispatchError::Other("Too many assets to refund"))?;
+
+        let refund = OutboundMessage {
+            origin: AgentOrigin::get(),
+            id: frame_system::unique((message.nonce, &commands).encode()).into(),
+            fee: 0,
+            commands,
+        };
+        let ticket = OutboundQueue::validate(&refund)
+            .map_err(|_| DispatchError::Other("Invalid refund message"))?;
+        OutboundQueue::deliver(ticket)
+            .map_err(|_| DispatchError::Other("Failed to send refund message"))?;
+
+        Ok(())
+    }
 }
@@ -0,0 +1,73 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! EVM tracing for the DataHaven runtimes.
+//!
+//! The runtimes replay the traced transactions on top of the state of their parent block and
+//! return the traces through [`EvmTracingApi`], which backs the `debug_*` and `trace_*` RPC
+//! methods of the node. The tracers themselves are only built with the `tracer` feature, which
+//! is enabled by the `evm-tracing` feature of the runtimes: nodes serving these methods run such
+//! a runtime through `--wasm-runtime-overrides`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+pub mod opcodes;
+#[cfg(feature = "tracer")]
+pub mod tracer;
+pub mod types;
+
+pub use types::*;
+
+use sp_core::{H160, H256, U256};
+use sp_runtime::DispatchError;
+use sp_std::vec::Vec;
+
+sp_api::decl_runtime_apis! {
+    /// Replays Ethereum transactions with a tracer.
+    pub trait EvmTracingApi {
+        /// Trace the Ethereum transaction `transaction_hash`, applying the `extrinsics` of the
+        /// block of `header` up to it.
+        fn trace_transaction(
+            extrinsics: Vec<Block::Extrinsic>,
+            transaction_hash: H256,
+            header: &Block::Header,
+            tracer: TracerKind,
+        ) -> Result<TransactionTrace, DispatchError>;
+
+        /// Trace every Ethereum transaction in the `extrinsics` of the block of `header`.
+        fn trace_block(
+            extrinsics: Vec<Block::Extrinsic>,
+            header: &Block::Header,
+            tracer: TracerKind,
+        ) -> Result<Vec<(H256, TransactionTrace)>, DispatchError>;
+
+        /// Trace a call on top of the block of `header`, without submitting it.
+        fn trace_call(
+            header: &Block::Header,
+            from: H160,
+            to: H160,
+            data: Vec<u8>,
+            value: U256,
+            gas_limit: U256,
+            max_fee_per_gas: Option<U256>,
+            max_priority_fee_per_gas: Option<U256>,
+            nonce: Option<U256>,
+            tracer: TracerKind,
+        ) -> Result<TransactionTrace, DispatchError>;
+    }
+}
@@ -0,0 +1,723 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Listeners turning the tracing events of the EVM into [`TransactionTrace`]s.
+//!
+//! The EVM reports the frames it enters and exits, the opcodes it steps through and the gas it
+//! records. Each frame is sized by the gas its parent reserves for it, and its gas usage is read
+//! from the snapshots of its own gasometer, which are told apart by their gas limit.
+
+use crate::types::*;
+use alloc::{format, rc::Rc, string::String};
+use core::cell::RefCell;
+use evm::{tracing as evm_tracing, ExitError, ExitReason};
+use evm_gasometer::tracing as gasometer_tracing;
+use evm_runtime::tracing as runtime_tracing;
+use frame_support::storage::{with_transaction, TransactionOutcome};
+use sp_core::{H160, H256, U256};
+use sp_runtime::DispatchError;
+use sp_std::{
+    collections::{btree_map::BTreeMap, btree_set::BTreeSet},
+    vec::Vec,
+};
+
+/// Selector of the `Error(string)` revert payload emitted by `require` and `revert`.
+const ERROR_SELECTOR: [u8; 4] = [0x08, 0xc3, 0x79, 0xa0];
+
+/// Run `apply` while tracing the Ethereum transaction it executes with `kind`.
+///
+/// The prestate tracer runs `apply` twice: once in a discarded storage layer to find the accounts
+/// the transaction touches, whose state is then read, and once more to actually apply it.
+/// Returns `None` as trace when `apply` did not run the EVM.
+pub fn trace<T: pallet_evm::Config, R>(
+    kind: TracerKind,
+    apply: impl Fn() -> R,
+) -> (R, Option<TransactionTrace>) {
+    if kind == TracerKind::Prestate {
+        let ((), state) = listen(kind, || {
+            let _ = with_transaction(|| {
+                let _ = apply();
+                TransactionOutcome::Rollback(Ok::<_, DispatchError>(()))
+            });
+        });
+        let prestate = state.prestate::<T>();
+        return (apply(), Some(TransactionTrace::Prestate(prestate)));
+    }
+
+    let (result, state) = listen(kind, apply);
+    (result, state.into_trace())
+}
+
+/// Run `f` with the listeners of the EVM, its runtime and its gasometer all feeding one state.
+fn listen<R>(kind: TracerKind, f: impl FnOnce() -> R) -> (R, State) {
+    let state = Rc::new(RefCell::new(State::new(kind)));
+    let mut evm_listener = Listener(state.clone());
+    let mut runtime_listener = Listener(state.clone());
+    let mut gasometer_listener = Listener(state.clone());
+
+    let result = evm_tracing::using(&mut evm_listener, || {
+        runtime_tracing::using(&mut runtime_listener, || {
+            gasometer_tracing::using(&mut gasometer_listener, f)
+        })
+    });
+
+    (result, state.replace(State::new(kind)))
+}
+
+struct Listener(Rc<RefCell<State>>);
+
+impl evm_tracing::EventListener for Listener {
+    fn event(&mut self, event: evm_tracing::Event) {
+        self.0.borrow_mut().on_evm_event(event);
+    }
+}
+
+impl runtime_tracing::EventListener for Listener {
+    fn event(&mut self, event: runtime_tracing::Event) {
+        self.0.borrow_mut().on_runtime_event(event);
+    }
+}
+
+impl gasometer_tracing::EventListener for Listener {
+    fn event(&mut self, event: gasometer_tracing::Event) {
+        self.0.borrow_mut().on_gasometer_event(event);
+    }
+}
+
+/// A call frame being executed.
+struct Frame {
+    trace: CallTrace,
+    /// Gas limit of the gasometer of the frame, known once its parent reserved the gas
+    gasometer_limit: Option<u64>,
+    /// Gas left to the frame
+    remaining_gas: u64,
+}
+
+struct State {
+    kind: TracerKind,
+    frames: Vec<Frame>,
+    root: Option<CallTrace>,
+    /// Set by the events entering a frame that the EVM may report a second time, as the
+    /// transaction entry points and the calls made by precompiles
+    may_repeat_frame: bool,
+    /// Gas charged to the transaction before its root frame runs
+    intrinsic_gas: u64,
+    struct_logs: Vec<StructLog>,
+    storage: BTreeMap<H160, BTreeMap<H256, H256>>,
+    touched: BTreeMap<H160, BTreeSet<H256>>,
+}
+
+impl State {
+    fn new(kind: TracerKind) -> Self {
+        Self {
+            kind,
+            frames: Vec::new(),
+            root: None,
+            may_repeat_frame: false,
+            intrinsic_gas: 0,
+            struct_logs: Vec::new(),
+            storage: BTreeMap::new(),
+            touched: BTreeMap::new(),
+        }
+    }
+
+    fn on_evm_event(&mut self, event: evm_tracing::Event) {
+        use evm_tracing::Event;
+
+        match event {
+            Event::TransactCall {
+                caller,
+                address,
+                value,
+                data,
+                gas_limit,
+            } => self.enter(
+                CallType::Call,
+                caller,
+                address,
+                value,
+                data,
+                gas_limit,
+                true,
+            ),
+            Event::TransactCreate {
+                caller,
+                value,
+                init_code,
+                gas_limit,
+                address,
+            } => self.enter(
+                CallType::Create,
+                caller,
+                address,
+                value,
+                init_code,
+                gas_limit,
+                true,
+            ),
+            Event::TransactCreate2 {
+                caller,
+                value,
+                init_code,
+                gas_limit,
+                address,
+                ..
+            } => self.enter(
+                CallType::Create2,
+                caller,
+                address,
+                value,
+                init_code,
+                gas_limit,
+                true,
+            ),
+            Event::Call {
+                code_address,
+                transfer,
+                input,
+                is_static,
+                context,
+                ..
+            } => self.enter_call(code_address, transfer, input, is_static, context, false),
+            Event::PrecompileSubcall {
+                code_address,
+                transfer,
+                input,
+                is_static,
+                context,
+                ..
+            } => self.enter_call(code_address, transfer, input, is_static, context, true),
+            Event::Create {
+                caller,
+                address,
+                scheme,
+                value,
+                init_code,
+                ..
+            } => {
+                let call_type = match scheme {
+                    evm::CreateScheme::Create2 { .. } => CallType::Create2,
+                    _ => CallType::Create,
+                };
+                self.enter(call_type, caller, address, value, init_code, 0, false);
+            }
+            Event::Suicide {
+                address, target, ..
+            } => {
+                self.touch(address);
+                self.touch(target);
+            }
+            Event::Exit {
+                reason,
+                return_value,
+            } => self.exit(reason, return_value),
+        }
+    }
+
+    fn on_runtime_event(&mut self, event: runtime_tracing::Event) {
+        use runtime_tracing::Event;
+
+        self.may_repeat_frame = false;
+        match event {
+            Event::Step {
+                context,
+                opcode,
+                position,
+                stack,
+                memory,
+            } => {
+                let TracerKind::Raw(config) = self.kind else {
+                    return;
+                };
+                let storage = (!config.disable_storage).then(|| {
+                    self.storage
+                        .get(&context.address)
+                        .cloned()
+                        .unwrap_or_default()
+                });
+                self.struct_logs.push(StructLog {
+                    pc: position.as_ref().map_or(0, |pc| *pc as u64),
+                    op: opcode.0,
+                    gas: self.frames.last().map_or(0, |frame| frame.remaining_gas),
+                    gas_cost: 0,
+                    depth: self.frames.len() as u32,
+                    stack: (!config.disable_stack).then(|| stack.data().clone()),
+                    memory: (!config.disable_memory).then(|| memory.data().clone()),
+                    storage,
+                });
+            }
+            Event::SLoad {
+                address,
+                index,
+                value,
+            }
+            | Event::SStore {
+                address,
+                index,
+                value,
+            } => {
+                self.touched.entry(address).or_default().insert(index);
+                self.storage
+                    .entry(address)
+                    .or_default()
+                    .insert(index, value);
+            }
+            _ => {}
+        }
+    }
+
+    fn on_gasometer_event(&mut self, event: gasometer_tracing::Event) {
+        use gasometer_tracing::Event;
+
+        let snapshot = match event {
+            Event::RecordTransaction { cost, .. } => {
+                self.intrinsic_gas = cost;
+                return;
+            }
+            Event::RecordCost { cost, snapshot } => {
+                // The first cost recorded after entering a frame is the gas its parent reserves
+                // for it, which is the gas limit of its own gasometer
+                if let Some(frame) = self.frames.last_mut() {
+                    if frame.gasometer_limit.is_none() {
+                        frame.gasometer_limit = Some(cost);
+                        frame.remaining_gas = cost;
+                        if frame.trace.gas == 0 {
+                            frame.trace.gas = cost;
+                        }
+                        return;
+                    }
+                }
+                snapshot
+            }
+            Event::RecordRefund { snapshot, .. }
+            | Event::RecordStipend { snapshot, .. }
+            | Event::RecordDynamicCost { snapshot, .. } => snapshot,
+        };
+
+        let (Some(snapshot), Some(frame)) = (snapshot, self.frames.last_mut()) else {
+            return;
+        };
+        if frame.gasometer_limit != Some(snapshot.gas_limit) {
+            return;
+        }
+
+        let used_gas = snapshot.used_gas.saturating_add(snapshot.memory_gas);
+        frame.trace.gas_used = used_gas;
+        frame.remaining_gas = snapshot.gas_limit.saturating_sub(used_gas);
+
+        // The gas recorded after a step is the cost of its opcode
+        let depth = self.frames.len() as u32;
+        if let Some(log) = self.struct_logs.last_mut() {
+            if log.depth == depth {
+                log.gas_cost = log.gas.saturating_sub(frame.remaining_gas);
+            }
+        }
+    }
+
+    fn enter_call(
+        &mut self,
+        code_address: H160,
+        transfer: &Option<evm::Transfer>,
+        input: &[u8],
+        is_static: bool,
+        context: &evm::Context,
+        repeatable: bool,
+    ) {
+        let (call_type, from, to) = if is_static {
+            (CallType::StaticCall, context.caller, context.address)
+        } else if context.address == code_address {
+            (CallType::Call, context.caller, context.address)
+        } else if context.caller == context.address {
+            (CallType::CallCode, context.address, code_address)
+        } else {
+            (CallType::DelegateCall, context.address, code_address)
+        };
+        let value = transfer
+            .as_ref()
+            .map_or(context.apparent_value, |transfer| transfer.value);
+
+        self.enter(call_type, from, to, value, input, 0, repeatable);
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn enter(
+        &mut self,
+        call_type: CallType,
+        from: H160,
+        to: H160,
+        value: U256,
+        input: &[u8],
+        gas: u64,
+        repeatable: bool,
+    ) {
+        // A frame reported twice in a row is only entered once
+        let repeated = core::mem::replace(&mut self.may_repeat_frame, repeatable)
+            && self.frames.last().is_some_and(|frame| {
+                frame.trace.to == to && frame.trace.input == input && frame.trace.calls.is_empty()
+            });
+        if repeated {
+            return;
+        }
+
+        self.touch(from);
+        self.touch(to);
+        self.frames.push(Frame {
+            trace: CallTrace {
+                call_type,
+                from,
+                to,
+                value,
+                gas,
+                gas_used: 0,
+                input: input.to_vec(),
+                output: Vec::new(),
+                error: None,
+                revert_reason: None,
+                calls: Vec::new(),
+            },
+            gasometer_limit: None,
+            remaining_gas: gas,
+        });
+    }
+
+    fn exit(&mut self, reason: &ExitReason, return_value: &[u8]) {
+        self.may_repeat_frame = false;
+        let Some(Frame { mut trace, .. }) = self.frames.pop() else {
+            return;
+        };
+
+        trace.output = return_value.to_vec();
+        match reason {
+            ExitReason::Succeed(_) => {}
+            ExitReason::Revert(_) => {
+                trace.error = Some("execution reverted".into());
+                trace.revert_reason = revert_reason(return_value);
+            }
+            ExitReason::Error(ExitError::OutOfGas) => trace.error = Some("out of gas".into()),
+            ExitReason::Error(error) => trace.error = Some(format!("{:?}", error)),
+            ExitReason::Fatal(fatal) => trace.error = Some(format!("{:?}", fatal)),
+        }
+
+        match self.frames.last_mut() {
+            Some(parent) => parent.trace.calls.push(trace),
+            None => {
+                trace.gas_used = trace.gas_used.saturating_add(self.intrinsic_gas);
+                self.root = Some(trace);
+            }
+        }
+    }
+
+    fn touch(&mut self, address: H160) {
+        self.touched.entry(address).or_default();
+    }
+
+    fn into_trace(mut self) -> Option<TransactionTrace> {
+        // Frames the EVM never exited, as when the transaction ran out of gas before its root
+        // frame started, are closed as they are
+        while let Some(Frame { trace, .. }) = self.frames.pop() {
+            match self.frames.last_mut() {
+                Some(parent) => parent.trace.calls.push(trace),
+                None => self.root = Some(trace),
+            }
+        }
+        let root = self.root?;
+
+        match self.kind {
+            TracerKind::Call => Some(TransactionTrace::Call(root)),
+            TracerKind::Raw(_) => Some(TransactionTrace::Raw(RawTrace {
+                gas: root.gas_used,
+                failed: root.error.is_some(),
+                return_value: root.output,
+                struct_logs: self.struct_logs,
+            })),
+            TracerKind::Prestate => None,
+        }
+    }
+
+    /// Read the current state of the touched accounts.
+    fn prestate<T: pallet_evm::Config>(&self) -> BTreeMap<H160, PrestateAccount> {
+        self.touched
+            .iter()
+            .map(|(address, slots)| {
+                let (account, _) = pallet_evm::Pallet::<T>::account_basic(address);
+                let storage = slots
+                    .iter()
+                    .map(|slot| (*slot, pallet_evm::AccountStorages::<T>::get(address, slot)))
+                    .collect();
+                let prestate = PrestateAccount {
+                    balance: account.balance,
+                    nonce: account.nonce.low_u64(),
+                    code: pallet_evm::AccountCodes::<T>::get(address),
+                    storage,
+                };
+                (*address, prestate)
+            })
+            .collect()
+    }
+}
+
+/// Decode the message of an `Error(string)` revert payload.
+fn revert_reason(output: &[u8]) -> Option<String> {
+    let data = output.strip_prefix(&ERROR_SELECTOR)?;
+    let length = U256::from_big_endian(data.get(32..64)?);
+    if length > U256::from(data.len()) {
+        return None;
+    }
+    let message = data.get(64..64 + length.low_u64() as usize)?;
+    String::from_utf8(message.to_vec()).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use evm::{
+        backend::{MemoryAccount, MemoryBackend, MemoryVicinity},
+        executor::stack::{MemoryStackState, StackExecutor, StackSubstateMetadata},
+        Config,
+    };
+
+    const GAS_LIMIT: u64 = 1_000_000;
+
+    fn caller() -> H160 {
+        H160::repeat_byte(0xaa)
+    }
+
+    fn contract() -> H160 {
+        H160::repeat_byte(0xbb)
+    }
+
+    fn callee() -> H160 {
+        H160::repeat_byte(0xcc)
+    }
+
+    /// Returns 42 as a word.
+    fn return_42() -> Vec<u8> {
+        // PUSH1 0x2a PUSH1 0 MSTORE PUSH1 0x20 PUSH1 0 RETURN
+        vec![0x60, 0x2a, 0x60, 0x00, 0x52, 0x60, 0x20, 0x60, 0x00, 0xf3]
+    }
+
+    /// Reverts with `Error("nope")`.
+    fn revert_nope() -> Vec<u8> {
+        let mut payload = ERROR_SELECTOR.to_vec();
+        payload.extend(H256::from_low_u64_be(0x20).as_bytes());
+        payload.extend(H256::from_low_u64_be(4).as_bytes());
+        payload.extend(b"nope");
+        payload.resize(4 + 32 * 3, 0);
+
+        // Copy the payload appended to the code and revert with it:
+        // PUSH1 len PUSH1 12 PUSH1 0 CODECOPY PUSH1 len PUSH1 0 REVERT
+        let len = payload.len() as u8;
+        let mut code = vec![
+            0x60, len, 0x60, 12, 0x60, 0x00, 0x39, 0x60, len, 0x60, 0x00, 0xfd,
+        ];
+        code.extend(payload);
+        code
+    }
+
+    /// Calls `callee` without data nor value, then stops.
+    fn call_callee() -> Vec<u8> {
+        // PUSH1 0 PUSH1 0 PUSH1 0 PUSH1 0 PUSH1 0 PUSH20 callee GAS CALL STOP
+        let mut code = vec![
+            0x60, 0x00, 0x60, 0x00, 0x60, 0x00, 0x60, 0x00, 0x60, 0x00, 0x73,
+        ];
+        code.extend(callee().as_bytes());
+        code.extend([0x5a, 0xf1, 0x00]);
+        code
+    }
+
+    fn account(code: Vec<u8>) -> MemoryAccount {
+        MemoryAccount {
+            nonce: U256::one(),
+            balance: U256::from(1_000_000_000u64),
+            storage: BTreeMap::new(),
+            code,
+        }
+    }
+
+    /// Trace a call from `caller` to `contract` running `code`, `callee` running `callee_code`.
+    fn trace_call(
+        kind: TracerKind,
+        code: Vec<u8>,
+        callee_code: Vec<u8>,
+    ) -> (ExitReason, TransactionTrace) {
+        let config = Config::london();
+        let vicinity = MemoryVicinity {
+            gas_price: U256::zero(),
+            origin: caller(),
+            chain_id: U256::one(),
+            block_hashes: Vec::new(),
+            block_number: U256::zero(),
+            block_coinbase: H160::zero(),
+            block_timestamp: U256::zero(),
+            block_difficulty: U256::zero(),
+            block_randomness: None,
+            block_gas_limit: U256::from(GAS_LIMIT),
+            block_base_fee_per_gas: U256::zero(),
+        };
+        let backend = MemoryBackend::new(
+            &vicinity,
+            BTreeMap::from([
+                (caller(), account(Vec::new())),
+                (contract(), account(code)),
+                (callee(), account(callee_code)),
+            ]),
+        );
+        let metadata = StackSubstateMetadata::new(GAS_LIMIT, &config);
+        let state = MemoryStackState::new(metadata, &backend);
+        let mut executor = StackExecutor::new_with_precompiles(state, &config, &());
+
+        let ((reason, _), state) = listen(kind, || {
+            executor.transact_call(
+                caller(),
+                contract(),
+                U256::zero(),
+                Vec::new(),
+                GAS_LIMIT,
+                Vec::new(),
+            )
+        });
+        (reason, state.into_trace().expect("The call ran in the EVM"))
+    }
+
+    fn call_trace(code: Vec<u8>, callee_code: Vec<u8>) -> CallTrace {
+        match trace_call(TracerKind::Call, code, callee_code) {
+            (_, TransactionTrace::Call(trace)) => trace,
+            (_, trace) => panic!("Unexpected trace {trace:?}"),
+        }
+    }
+
+    fn raw_trace(config: RawConfig, code: Vec<u8>, callee_code: Vec<u8>) -> RawTrace {
+        match trace_call(TracerKind::Raw(config), code, callee_code) {
+            (_, TransactionTrace::Raw(trace)) => trace,
+            (_, trace) => panic!("Unexpected trace {trace:?}"),
+        }
+    }
+
+    #[test]
+    fn call_tracer_traces_the_root_frame_once() {
+        let trace = call_trace(return_42(), Vec::new());
+
+        assert_eq!(trace.call_type, CallType::Call);
+        assert_eq!(trace.from, caller());
+        assert_eq!(trace.to, contract());
+        assert_eq!(trace.gas, GAS_LIMIT);
+        assert_eq!(trace.output, H256::from_low_u64_be(0x2a).as_bytes());
+        assert_eq!(trace.error, None);
+        assert!(trace.calls.is_empty());
+        // The gas used includes the intrinsic gas of the transaction
+        assert!(trace.gas_used > 21_000 && trace.gas_used < GAS_LIMIT);
+    }
+
+    #[test]
+    fn call_tracer_decodes_revert_reasons() {
+        let (reason, _) = trace_call(TracerKind::Call, revert_nope(), Vec::new());
+        assert!(matches!(reason, ExitReason::Revert(_)));
+
+        let trace = call_trace(revert_nope(), Vec::new());
+        assert_eq!(trace.error.as_deref(), Some("execution reverted"));
+        assert_eq!(trace.revert_reason.as_deref(), Some("nope"));
+    }
+
+    #[test]
+    fn call_tracer_nests_subcalls_under_their_caller() {
+        let trace = call_trace(call_callee(), revert_nope());
+
+        // The reverted subcall does not revert its caller
+        assert_eq!(trace.error, None);
+        assert_eq!(trace.calls.len(), 1);
+        let subcall = &trace.calls[0];
+        assert_eq!(subcall.call_type, CallType::Call);
+        assert_eq!(subcall.from, contract());
+        assert_eq!(subcall.to, callee());
+        assert_eq!(subcall.error.as_deref(), Some("execution reverted"));
+        assert_eq!(subcall.revert_reason.as_deref(), Some("nope"));
+        assert!(subcall.gas > 0 && subcall.gas < trace.gas);
+        assert!(subcall.gas_used > 0 && subcall.gas_used < subcall.gas);
+    }
+
+    #[test]
+    fn struct_logger_traces_every_opcode() {
+        let trace = raw_trace(RawConfig::default(), return_42(), Vec::new());
+
+        assert!(!trace.failed);
+        assert_eq!(trace.return_value, H256::from_low_u64_be(0x2a).as_bytes());
+        let steps: Vec<_> = trace
+            .struct_logs
+            .iter()
+            .map(|log| (log.pc, log.op, log.depth))
+            .collect();
+        assert_eq!(
+            steps,
+            vec![
+                (0, 0x60, 1),
+                (2, 0x60, 1),
+                (4, 0x52, 1),
+                (5, 0x60, 1),
+                (7, 0x60, 1),
+                (9, 0xf3, 1),
+            ]
+        );
+
+        let push = &trace.struct_logs[1];
+        assert_eq!(push.gas_cost, 3);
+        assert_eq!(push.gas, trace.struct_logs[0].gas - 3);
+        assert_eq!(push.stack, Some(vec![H256::from_low_u64_be(0x2a)]));
+        assert_eq!(push.memory, Some(Vec::new()));
+        // Memory is read before the opcode runs
+        let mut memory = vec![0; 32];
+        memory[31] = 0x2a;
+        assert_eq!(trace.struct_logs[3].memory, Some(memory));
+    }
+
+    #[test]
+    fn struct_logger_leaves_out_disabled_state() {
+        let config = RawConfig {
+            disable_storage: true,
+            disable_memory: true,
+            disable_stack: true,
+        };
+        let trace = raw_trace(config, return_42(), Vec::new());
+
+        assert_eq!(trace.struct_logs.len(), 6);
+        assert!(trace
+            .struct_logs
+            .iter()
+            .all(|log| log.stack.is_none() && log.memory.is_none() && log.storage.is_none()));
+    }
+
+    #[test]
+    fn struct_logger_traces_subcalls_one_level_deeper() {
+        let trace = raw_trace(RawConfig::default(), call_callee(), revert_nope());
+
+        assert!(!trace.failed);
+        let depths: Vec<_> = trace.struct_logs.iter().map(|log| log.depth).collect();
+        // 5 PUSH1, PUSH20, GAS and CALL in the caller, the reverting callee, then STOP
+        assert_eq!(depths, [vec![1; 8], vec![2; 7], vec![1]].concat());
+        assert_eq!(trace.struct_logs[14].op, 0xfd);
+    }
+
+    #[test]
+    fn revert_reason_is_only_decoded_from_error_payloads() {
+        let code = revert_nope();
+        let payload = &code[12..];
+        assert_eq!(revert_reason(payload).as_deref(), Some("nope"));
+
+        // Custom errors and truncated payloads have no reason
+        assert_eq!(revert_reason(&[0x12, 0x34, 0x56, 0x78]), None);
+        assert_eq!(revert_reason(&payload[..40]), None);
+        assert_eq!(revert_reason(&[]), None);
+    }
+}
@@ -0,0 +1,332 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Traces returned by the runtime, serialized in the format of the geth tracers.
+
+use alloc::string::String;
+use codec::{Decode, Encode};
+use scale_info::TypeInfo;
+use sp_core::{H160, H256, U256};
+use sp_runtime::RuntimeDebug;
+use sp_std::{collections::btree_map::BTreeMap, vec::Vec};
+
+#[cfg(feature = "std")]
+use serde::Serialize;
+
+/// Tracer run by the runtime over the traced transactions.
+#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum TracerKind {
+    /// Tree of the calls made by the transaction, as geth `callTracer`.
+    Call,
+    /// Accounts touched by the transaction before it ran, as geth `prestateTracer`.
+    Prestate,
+    /// Every executed opcode, as the default geth struct logger.
+    Raw(RawConfig),
+}
+
+/// Parts of the EVM state left out of the raw traces.
+#[derive(Clone, Copy, Default, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct RawConfig {
+    pub disable_storage: bool,
+    pub disable_memory: bool,
+    pub disable_stack: bool,
+}
+
+/// Kind of a traced call frame.
+#[derive(Clone, Copy, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize), serde(rename_all = "UPPERCASE"))]
+pub enum CallType {
+    Call,
+    StaticCall,
+    DelegateCall,
+    CallCode,
+    Create,
+    Create2,
+}
+
+/// A call frame and the calls it made.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize), serde(rename_all = "camelCase"))]
+pub struct CallTrace {
+    #[cfg_attr(feature = "std", serde(rename = "type"))]
+    pub call_type: CallType,
+    pub from: H160,
+    pub to: H160,
+    pub value: U256,
+    #[cfg_attr(feature = "std", serde(serialize_with = "serialization::quantity"))]
+    pub gas: u64,
+    #[cfg_attr(feature = "std", serde(serialize_with = "serialization::quantity"))]
+    pub gas_used: u64,
+    #[cfg_attr(feature = "std", serde(serialize_with = "serialization::bytes"))]
+    pub input: Vec<u8>,
+    #[cfg_attr(feature = "std", serde(serialize_with = "serialization::bytes"))]
+    pub output: Vec<u8>,
+    #[cfg_attr(feature = "std", serde(skip_serializing_if = "Option::is_none"))]
+    pub error: Option<String>,
+    #[cfg_attr(feature = "std", serde(skip_serializing_if = "Option::is_none"))]
+    pub revert_reason: Option<String>,
+    #[cfg_attr(feature = "std", serde(skip_serializing_if = "Vec::is_empty"))]
+    pub calls: Vec<CallTrace>,
+}
+
+/// State of an account before the traced transaction.
+#[derive(Clone, Default, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize))]
+pub struct PrestateAccount {
+    pub balance: U256,
+    pub nonce: u64,
+    #[cfg_attr(
+        feature = "std",
+        serde(
+            skip_serializing_if = "Vec::is_empty",
+            serialize_with = "serialization::bytes"
+        )
+    )]
+    pub code: Vec<u8>,
+    #[cfg_attr(feature = "std", serde(skip_serializing_if = "BTreeMap::is_empty"))]
+    pub storage: BTreeMap<H256, H256>,
+}
+
+/// An executed opcode.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize), serde(rename_all = "camelCase"))]
+pub struct StructLog {
+    pub pc: u64,
+    #[cfg_attr(feature = "std", serde(serialize_with = "serialization::opcode"))]
+    pub op: u8,
+    pub gas: u64,
+    pub gas_cost: u64,
+    pub depth: u32,
+    #[cfg_attr(feature = "std", serde(skip_serializing_if = "Option::is_none"))]
+    pub stack: Option<Vec<H256>>,
+    #[cfg_attr(
+        feature = "std",
+        serde(
+            skip_serializing_if = "Option::is_none",
+            serialize_with = "serialization::memory"
+        )
+    )]
+    pub memory: Option<Vec<u8>>,
+    #[cfg_attr(feature = "std", serde(skip_serializing_if = "Option::is_none"))]
+    pub storage: Option<BTreeMap<H256, H256>>,
+}
+
+/// Opcodes executed by a transaction.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize), serde(rename_all = "camelCase"))]
+pub struct RawTrace {
+    pub gas: u64,
+    pub failed: bool,
+    #[cfg_attr(
+        feature = "std",
+        serde(serialize_with = "serialization::unprefixed_bytes")
+    )]
+    pub return_value: Vec<u8>,
+    pub struct_logs: Vec<StructLog>,
+}
+
+/// Trace of a transaction, depending on the [`TracerKind`] that produced it.
+#[derive(Clone, Encode, Decode, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize), serde(untagged))]
+pub enum TransactionTrace {
+    Call(CallTrace),
+    Prestate(BTreeMap<H160, PrestateAccount>),
+    Raw(RawTrace),
+}
+
+#[cfg(feature = "std")]
+mod serialization {
+    use crate::opcodes::opcode_name;
+    use serde::{ser::SerializeSeq, Serializer};
+
+    pub fn quantity<S: Serializer>(value: &u64, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&format!("0x{:x}", value))
+    }
+
+    pub fn bytes<S: Serializer, T: AsRef<[u8]>>(
+        value: &T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&format!("0x{}", hex::encode(value)))
+    }
+
+    pub fn unprefixed_bytes<S: Serializer, T: AsRef<[u8]>>(
+        value: &T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&hex::encode(value))
+    }
+
+    pub fn opcode<S: Serializer>(value: &u8, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(opcode_name(*value))
+    }
+
+    /// Memory is shown in words of 32 bytes.
+    pub fn memory<S: Serializer>(
+        value: &Option<Vec<u8>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        let memory = value.as_deref().unwrap_or_default();
+        let mut seq = serializer.serialize_seq(Some(memory.len().div_ceil(32)))?;
+        for word in memory.chunks(32) {
+            seq.serialize_element(&hex::encode(word))?;
+        }
+        seq.end()
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn call_trace(calls: Vec<CallTrace>) -> CallTrace {
+        CallTrace {
+            call_type: CallType::Call,
+            from: H160::repeat_byte(1),
+            to: H160::repeat_byte(2),
+            value: U256::from(10),
+            gas: 30_000,
+            gas_used: 21_000,
+            input: vec![0xab, 0xcd],
+            output: vec![],
+            error: None,
+            revert_reason: None,
+            calls,
+        }
+    }
+
+    #[test]
+    fn call_trace_is_serialized_as_geth_call_tracer() {
+        let mut subcall = call_trace(vec![]);
+        subcall.call_type = CallType::StaticCall;
+        subcall.error = Some("execution reverted".into());
+        subcall.revert_reason = Some("nope".into());
+        let trace = TransactionTrace::Call(call_trace(vec![subcall]));
+
+        assert_eq!(
+            serde_json::to_value(trace).unwrap(),
+            json!({
+                "type": "CALL",
+                "from": "0x0101010101010101010101010101010101010101",
+                "to": "0x0202020202020202020202020202020202020202",
+                "value": "0xa",
+                "gas": "0x7530",
+                "gasUsed": "0x5208",
+                "input": "0xabcd",
+                "output": "0x",
+                "calls": [{
+                    "type": "STATICCALL",
+                    "from": "0x0101010101010101010101010101010101010101",
+                    "to": "0x0202020202020202020202020202020202020202",
+                    "value": "0xa",
+                    "gas": "0x7530",
+                    "gasUsed": "0x5208",
+                    "input": "0xabcd",
+                    "output": "0x",
+                    "error": "execution reverted",
+                    "revertReason": "nope",
+                }],
+            })
+        );
+    }
+
+    #[test]
+    fn raw_trace_is_serialized_as_geth_struct_logger() {
+        let mut memory = vec![0u8; 33];
+        memory[31] = 0x2a;
+        let trace = TransactionTrace::Raw(RawTrace {
+            gas: 21_018,
+            failed: false,
+            return_value: vec![0x2a],
+            struct_logs: vec![
+                StructLog {
+                    pc: 0,
+                    op: 0x60,
+                    gas: 9_000,
+                    gas_cost: 3,
+                    depth: 1,
+                    stack: Some(vec![]),
+                    memory: Some(memory),
+                    storage: Some(BTreeMap::from([(H256::zero(), H256::from_low_u64_be(1))])),
+                },
+                StructLog {
+                    pc: 2,
+                    op: 0xf3,
+                    gas: 8_997,
+                    gas_cost: 0,
+                    depth: 1,
+                    stack: Some(vec![H256::from_low_u64_be(0x2a)]),
+                    memory: None,
+                    storage: None,
+                },
+            ],
+        });
+
+        let zero = "0x0000000000000000000000000000000000000000000000000000000000000000";
+        let one = "0x0000000000000000000000000000000000000000000000000000000000000001";
+        assert_eq!(
+            serde_json::to_value(trace).unwrap(),
+            json!({
+                "gas": 21_018,
+                "failed": false,
+                "returnValue": "2a",
+                "structLogs": [
+                    {
+                        "pc": 0,
+                        "op": "PUSH1",
+                        "gas": 9_000,
+                        "gasCost": 3,
+                        "depth": 1,
+                        "stack": [],
+                        "memory": [
+                            "000000000000000000000000000000000000000000000000000000000000002a",
+                            "00",
+                        ],
+                        "storage": { zero: one },
+                    },
+                    {
+                        "pc": 2,
+                        "op": "RETURN",
+                        "gas": 8_997,
+                        "gasCost": 0,
+                        "depth": 1,
+                        "stack": [
+                            "0x000000000000000000000000000000000000000000000000000000000000002a",
+                        ],
+                    },
+                ],
+            })
+        );
+    }
+
+    #[test]
+    fn prestate_leaves_out_empty_code_and_storage() {
+        let account = PrestateAccount {
+            balance: U256::from(1),
+            nonce: 2,
+            ..Default::default()
+        };
+        let trace = TransactionTrace::Prestate(BTreeMap::from([(H160::repeat_byte(1), account)]));
+
+        assert_eq!(
+            serde_json::to_value(trace).unwrap(),
+            json!({
+                "0x0101010101010101010101010101010101010101": { "balance": "0x1", "nonce": 2 },
+            })
+        );
+    }
+}
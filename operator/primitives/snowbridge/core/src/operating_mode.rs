@@ -42,3 +42,15 @@ impl BasicOperatingMode {
 pub trait ExportPausedQuery {
     fn is_paused() -> bool;
 }
+
+/// Check whether the bridge runs in degraded mode, because the Ethereum light client is falling
+/// behind the beacon chain.
+pub trait DegradedModeQuery {
+    fn is_degraded() -> bool;
+}
+
+impl DegradedModeQuery for () {
+    fn is_degraded() -> bool {
+        false
+    }
+}
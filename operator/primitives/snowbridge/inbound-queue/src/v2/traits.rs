@@ -2,6 +2,7 @@
 // SPDX-FileCopyrightText: 2025 Snowfork <hello@snowfork.com>
 // SPDX-FileCopyrightText: 2021-2025 Parity Technologies (UK) Ltd.
 use super::Message;
+use frame_support::weights::Weight;
 use sp_core::RuntimeDebug;
 use sp_runtime::DispatchError;
 use xcm::latest::Xcm;
@@ -28,6 +29,11 @@ pub trait MessageProcessor<AccountId> {
     fn can_process_message(who: &AccountId, message: &Message) -> bool;
     /// Process the message and return the message ID
     fn process_message(who: AccountId, message: Message) -> Result<[u8; 32], DispatchError>;
+    /// Weight of processing the message not covered by the submission, such as the weight of a
+    /// call it dispatches. Zero for the messages this processor does not handle.
+    fn processing_weight(_message: &Message) -> Weight {
+        Weight::zero()
+    }
 }
 
 #[impl_trait_for_tuples::impl_for_tuples(10)]
@@ -57,4 +63,24 @@ impl<AccountId> MessageProcessor<AccountId> for Tuple {
 
         Err(DispatchError::Other("No handler found for message!"))
     }
+
+    fn processing_weight(message: &Message) -> Weight {
+        let mut weight = Weight::zero();
+        for_tuples!( #(
+			weight = weight.max(<Tuple as MessageProcessor<AccountId>>::processing_weight(message));
+		)* );
+
+        weight
+    }
+}
+
+/// Gives back the assets of an inbound message that could not be processed.
+pub trait RefundMessage {
+    fn refund(message: &Message) -> Result<(), DispatchError>;
+}
+
+impl RefundMessage for () {
+    fn refund(_message: &Message) -> Result<(), DispatchError> {
+        Err(DispatchError::Other("Refunds are not supported"))
+    }
 }
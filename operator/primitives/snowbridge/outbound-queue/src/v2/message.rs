@@ -293,6 +293,27 @@ pub trait SendMessage {
     fn deliver(ticket: Self::Ticket) -> Result<H256, SendError>;
 }
 
+/// Notified of the progress of the messages sent to Ethereum.
+pub trait MessageDeliveryHandler {
+    /// The message `id` was assigned `nonce` and will be committed in the current block.
+    fn on_message_accepted(id: H256, nonce: u64);
+
+    /// A delivery receipt was verified for the message `nonce`, dispatched on Ethereum with
+    /// `success`.
+    fn on_message_delivered(nonce: u64, success: bool);
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(5)]
+impl MessageDeliveryHandler for Tuple {
+    fn on_message_accepted(id: H256, nonce: u64) {
+        for_tuples!( #( Tuple::on_message_accepted(id, nonce); )* );
+    }
+
+    fn on_message_delivered(nonce: u64, success: bool) {
+        for_tuples!( #( Tuple::on_message_delivered(nonce, success); )* );
+    }
+}
+
 pub trait GasMeter {
     /// Measures the maximum amount of gas a command payload will require to *dispatch*, NOT
     /// including validation & verification.
@@ -326,6 +347,19 @@ impl GasMeter for ConstantGasMeter {
     }
 }
 
+/// Estimated cost of a message, as reported by a dry run of the outbound queue
+#[derive(Encode, Decode, TypeInfo, PartialEq, Clone, RuntimeDebug)]
+pub struct GasEstimate<Balance> {
+    /// Dispatch gas of each command, as it would be committed
+    pub commands: Vec<u64>,
+    /// Total dispatch gas of the message
+    pub gas: u64,
+    /// Estimated fee on Ethereum in wei, at the configured gas price
+    pub remote_fee: u128,
+    /// The local component of the message processing fees in native currency
+    pub local_fee: Balance,
+}
+
 impl GasMeter for () {
     fn maximum_dispatch_gas_used_at_most(_: &Command) -> u64 {
         1
@@ -14,15 +14,16 @@
 // You should have received a copy of the GNU General Public License
 // along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
 
+use crate::treasury::TreasuryAssetKind;
 use fp_account::AccountId20;
 use frame_support::traits::Currency;
 use pallet_treasury::ArgumentsFactory;
 use sp_runtime::traits::Zero;
 
 pub struct BenchmarkHelper;
-impl ArgumentsFactory<(), AccountId20> for BenchmarkHelper {
-    fn create_asset_kind(_seed: u32) -> () {
-        ()
+impl ArgumentsFactory<TreasuryAssetKind, AccountId20> for BenchmarkHelper {
+    fn create_asset_kind(_seed: u32) -> TreasuryAssetKind {
+        TreasuryAssetKind::Native
     }
 
     fn create_beneficiary(seed: [u8; 32]) -> AccountId20 {
@@ -0,0 +1,140 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! The bridge events of a block, as read by the node to index the messages crossing the bridge.
+//!
+//! An outbound message is queued by an extrinsic under its id, accepted with a nonce when the
+//! message queue services it, and committed at the end of that block. The delivery receipt later
+//! refers to it by nonce. Inbound messages are identified by their nonce on both outcomes.
+
+use codec::{Decode, Encode};
+use frame_system::Phase;
+use scale_info::TypeInfo;
+use snowbridge_pallet_inbound_queue_v2::Event as InboundEvent;
+use snowbridge_pallet_outbound_queue_v2::Event as OutboundEvent;
+use sp_core::H256;
+use sp_runtime::{DispatchError, RuntimeDebug};
+use sp_std::vec::Vec;
+
+/// A bridge event relevant to follow the messages.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub enum BridgeEvent {
+    /// An outbound message was queued.
+    OutboundMessageQueued { id: H256 },
+    /// An outbound message was given its nonce, to be committed at the end of the block.
+    OutboundMessageAccepted { id: H256, nonce: u64 },
+    /// The outbound messages accepted in the block were committed.
+    OutboundMessagesCommitted { root: H256, count: u64 },
+    /// The delivery receipt of an outbound message was received.
+    OutboundMessageDelivered { nonce: u64 },
+    /// A delivered outbound message failed to dispatch on Ethereum.
+    OutboundMessageDispatchFailed { nonce: u64 },
+    /// An inbound message was processed.
+    InboundMessageReceived { nonce: u64, message_id: [u8; 32] },
+    /// An inbound message failed processing and was kept as a dead letter.
+    InboundMessageFailed { nonce: u64, reason: DispatchError },
+    /// A dead letter was processed successfully.
+    InboundDeadLetterRetried { nonce: u64, message_id: [u8; 32] },
+    /// The assets of a dead letter were given back.
+    InboundDeadLetterRefunded { nonce: u64 },
+}
+
+/// A bridge event with the index of the extrinsic emitting it, if any.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct BridgeEventRecord {
+    pub extrinsic_index: Option<u32>,
+    pub event: BridgeEvent,
+}
+
+/// Reads the bridge events deposited in the current block.
+///
+/// Meant to be called from a runtime API, the events of the block being in its state.
+pub fn bridge_events<T>() -> Vec<BridgeEventRecord>
+where
+    T: snowbridge_pallet_outbound_queue_v2::Config + snowbridge_pallet_inbound_queue_v2::Config,
+    <T as frame_system::Config>::RuntimeEvent:
+        TryInto<OutboundEvent<T>> + TryInto<InboundEvent<T>> + Clone,
+{
+    frame_system::Pallet::<T>::read_events_no_consensus()
+        .filter_map(|record| {
+            let event = outbound_event::<T>(record.event.clone())
+                .or_else(|| inbound_event::<T>(record.event))?;
+            let extrinsic_index = match record.phase {
+                Phase::ApplyExtrinsic(index) => Some(index),
+                _ => None,
+            };
+            Some(BridgeEventRecord {
+                extrinsic_index,
+                event,
+            })
+        })
+        .collect()
+}
+
+fn outbound_event<T>(event: <T as frame_system::Config>::RuntimeEvent) -> Option<BridgeEvent>
+where
+    T: snowbridge_pallet_outbound_queue_v2::Config,
+    <T as frame_system::Config>::RuntimeEvent: TryInto<OutboundEvent<T>>,
+{
+    match TryInto::<OutboundEvent<T>>::try_into(event).ok()? {
+        OutboundEvent::MessageQueued { message } => {
+            Some(BridgeEvent::OutboundMessageQueued { id: message.id })
+        }
+        OutboundEvent::MessageAccepted { id, nonce } => {
+            Some(BridgeEvent::OutboundMessageAccepted { id, nonce })
+        }
+        OutboundEvent::MessagesCommitted { root, count } => {
+            Some(BridgeEvent::OutboundMessagesCommitted { root, count })
+        }
+        OutboundEvent::MessageDeliveryProofReceived { nonce } => {
+            Some(BridgeEvent::OutboundMessageDelivered { nonce })
+        }
+        OutboundEvent::MessageDispatchFailed { nonce } => {
+            Some(BridgeEvent::OutboundMessageDispatchFailed { nonce })
+        }
+        _ => None,
+    }
+}
+
+fn inbound_event<T>(event: <T as frame_system::Config>::RuntimeEvent) -> Option<BridgeEvent>
+where
+    T: snowbridge_pallet_inbound_queue_v2::Config,
+    <T as frame_system::Config>::RuntimeEvent: TryInto<InboundEvent<T>>,
+{
+    match TryInto::<InboundEvent<T>>::try_into(event).ok()? {
+        InboundEvent::MessageReceived { nonce, message_id } => {
+            Some(BridgeEvent::InboundMessageReceived { nonce, message_id })
+        }
+        InboundEvent::MessageFailed { nonce, reason } => {
+            Some(BridgeEvent::InboundMessageFailed { nonce, reason })
+        }
+        InboundEvent::DeadLetterRetried { nonce, message_id } => {
+            Some(BridgeEvent::InboundDeadLetterRetried { nonce, message_id })
+        }
+        InboundEvent::DeadLetterRefunded { nonce } => {
+            Some(BridgeEvent::InboundDeadLetterRefunded { nonce })
+        }
+        _ => None,
+    }
+}
+
+sp_api::decl_runtime_apis! {
+    /// API of the runtime for the node to index the messages crossing the bridge.
+    pub trait BridgeTrackingApi {
+        /// The bridge events deposited in the block.
+        fn bridge_events() -> Vec<BridgeEventRecord>;
+    }
+}
@@ -0,0 +1,163 @@
+//! GRANDPA and BEEFY justifications as a liveness signal for the operator rewards.
+//!
+//! Block authors put the latest justifications their node has seen in the
+//! `ExternalValidatorsRewards::note_finality_proofs` inherent. The signatures are checked against
+//! the current authority sets, and each valid signer is mapped to its validator account through
+//! the session keys.
+
+use codec::{Decode, DecodeWithMemTracking, Encode};
+use frame_support::traits::Get;
+use frame_support::weights::{constants::WEIGHT_REF_TIME_PER_MICROS, Weight};
+use pallet_external_validators_rewards::liveness::{FinalityGadget, FinalityProofVerifier};
+use scale_info::TypeInfo;
+use sp_consensus_beefy::{
+    ecdsa_crypto::{AuthorityId as BeefyId, Signature as BeefySignature},
+    BeefyAuthorityId, BeefySignatureHasher, Commitment, SignedCommitment,
+};
+use sp_consensus_grandpa::GrandpaJustification;
+use sp_core::crypto::KeyTypeId;
+use sp_runtime::{RuntimeAppPublic, RuntimeDebug};
+use sp_std::{marker::PhantomData, vec::Vec};
+
+use crate::{AccountId, BlockNumber, Header};
+
+/// Weight of verifying one ed25519 or ecdsa signature, excluding the owner lookup.
+const SIGNATURE_VERIFICATION_WEIGHT: u64 = 60 * WEIGHT_REF_TIME_PER_MICROS;
+
+/// A BEEFY signed commitment, with a signature slot per authority of the validator set.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, DecodeWithMemTracking, RuntimeDebug, TypeInfo)]
+pub struct BeefyFinalityProof {
+    pub commitment: Commitment<BlockNumber>,
+    pub signatures: Vec<Option<BeefySignature>>,
+}
+
+impl From<SignedCommitment<BlockNumber, BeefySignature>> for BeefyFinalityProof {
+    fn from(signed: SignedCommitment<BlockNumber, BeefySignature>) -> Self {
+        Self {
+            commitment: signed.commitment,
+            signatures: signed.signatures,
+        }
+    }
+}
+
+/// Finality proof noted through the inherent.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, DecodeWithMemTracking, RuntimeDebug, TypeInfo)]
+pub enum FinalityProof {
+    Grandpa(GrandpaJustification<Header>),
+    Beefy(BeefyFinalityProof),
+}
+
+/// Verifies GRANDPA justifications and BEEFY commitments signed by the current authority sets.
+///
+/// A proof is only accepted if more than 2/3 of the authorities signed it, like the gadgets
+/// themselves require, so an author can't make up participation. Only current set proofs are
+/// accepted, proofs from a previous set are ignored.
+pub struct GrandpaBeefyProofVerifier<Runtime>(PhantomData<Runtime>);
+
+impl<Runtime> GrandpaBeefyProofVerifier<Runtime>
+where
+    Runtime: pallet_grandpa::Config
+        + pallet_beefy::Config<BeefyId = BeefyId>
+        + pallet_session::Config<ValidatorId = AccountId>,
+{
+    fn key_owner(key_type: KeyTypeId, key: &impl RuntimeAppPublic) -> Option<AccountId> {
+        pallet_session::Pallet::<Runtime>::key_owner(key_type, &key.to_raw_vec())
+    }
+
+    fn grandpa_signers(justification: &GrandpaJustification<Header>) -> Option<Vec<AccountId>> {
+        let set_id = pallet_grandpa::Pallet::<Runtime>::current_set_id();
+        let authorities = pallet_grandpa::Pallet::<Runtime>::grandpa_authorities();
+
+        let mut signers: Vec<AccountId> = justification
+            .commit
+            .precommits
+            .iter()
+            .filter(|signed| authorities.iter().any(|(id, _)| *id == signed.id))
+            .filter(|signed| {
+                let message =
+                    sp_consensus_grandpa::Message::<Header>::Precommit(signed.precommit.clone());
+                let payload =
+                    sp_consensus_grandpa::localized_payload(justification.round, set_id, &message);
+                RuntimeAppPublic::verify(&signed.id, &payload, &signed.signature)
+            })
+            .filter_map(|signed| Self::key_owner(sp_consensus_grandpa::KEY_TYPE, &signed.id))
+            .collect();
+        signers.sort();
+        signers.dedup();
+
+        Self::has_supermajority(signers.len(), authorities.len()).then_some(signers)
+    }
+
+    fn beefy_signers(proof: &BeefyFinalityProof) -> Option<Vec<AccountId>> {
+        let validator_set = pallet_beefy::Pallet::<Runtime>::validator_set()?;
+        if proof.commitment.validator_set_id != validator_set.id() {
+            return None;
+        }
+
+        let message = proof.commitment.encode();
+        let authorities = validator_set.validators();
+        let mut signers: Vec<AccountId> = authorities
+            .iter()
+            .zip(proof.signatures.iter())
+            .filter_map(|(authority, signature)| Some((authority, signature.as_ref()?)))
+            .filter(|(authority, signature)| {
+                <BeefyId as BeefyAuthorityId<BeefySignatureHasher>>::verify(
+                    authority, signature, &message,
+                )
+            })
+            .filter_map(|(authority, _)| Self::key_owner(sp_consensus_beefy::KEY_TYPE, authority))
+            .collect();
+        signers.sort();
+        signers.dedup();
+
+        Self::has_supermajority(signers.len(), authorities.len()).then_some(signers)
+    }
+
+    fn has_supermajority(signers: usize, authorities: usize) -> bool {
+        signers.saturating_mul(3) > authorities.saturating_mul(2)
+    }
+}
+
+impl<Runtime> FinalityProofVerifier<AccountId> for GrandpaBeefyProofVerifier<Runtime>
+where
+    Runtime: pallet_grandpa::Config
+        + pallet_beefy::Config<BeefyId = BeefyId>
+        + pallet_session::Config<ValidatorId = AccountId>,
+{
+    type Proof = FinalityProof;
+
+    fn target(proof: &Self::Proof) -> (FinalityGadget, u32) {
+        match proof {
+            FinalityProof::Grandpa(justification) => {
+                (FinalityGadget::Grandpa, justification.commit.target_number)
+            }
+            FinalityProof::Beefy(proof) => (FinalityGadget::Beefy, proof.commitment.block_number),
+        }
+    }
+
+    fn signature_count(proof: &Self::Proof) -> u32 {
+        let count = match proof {
+            FinalityProof::Grandpa(justification) => justification.commit.precommits.len(),
+            FinalityProof::Beefy(proof) => proof.signatures.iter().flatten().count(),
+        };
+        count.try_into().unwrap_or(u32::MAX)
+    }
+
+    fn verification_weight(proof: &Self::Proof) -> Weight {
+        let signatures = Self::signature_count(proof) as u64;
+        // Authority set and set id, then the owner of each signing key.
+        <Runtime as frame_system::Config>::DbWeight::get()
+            .reads(2u64.saturating_add(signatures))
+            .saturating_add(Weight::from_parts(
+                SIGNATURE_VERIFICATION_WEIGHT.saturating_mul(signatures),
+                0,
+            ))
+    }
+
+    fn signers(proof: &Self::Proof) -> Option<Vec<AccountId>> {
+        match proof {
+            FinalityProof::Grandpa(justification) => Self::grandpa_signers(justification),
+            FinalityProof::Beefy(proof) => Self::beefy_signers(proof),
+        }
+    }
+}
@@ -0,0 +1,185 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+/// Expands to an `EvmTracing` helper holding the bodies of the `dhp_evm_tracing::EvmTracingApi`
+/// runtime API, so each runtime's `impl_runtime_apis!` only has to forward to it.
+///
+/// Expects `Runtime`, `Executive`, `RuntimeCall` and `Block` to be in scope. The tracing bodies
+/// are only compiled when the runtime enables its `evm-tracing` feature.
+#[macro_export]
+macro_rules! impl_evm_tracing {
+	{} => {
+		pub struct EvmTracing;
+
+		impl EvmTracing {
+			pub fn trace_transaction(
+				extrinsics: sp_std::vec::Vec<<Block as sp_runtime::traits::Block>::Extrinsic>,
+				transaction_hash: sp_core::H256,
+				header: &<Block as sp_runtime::traits::Block>::Header,
+				tracer: dhp_evm_tracing::TracerKind,
+			) -> Result<dhp_evm_tracing::TransactionTrace, sp_runtime::DispatchError> {
+				#[cfg(feature = "evm-tracing")]
+				{
+					Executive::initialize_block(header);
+
+					for ext in extrinsics.into_iter() {
+						if Self::ethereum_transaction_hash(&ext.0.function) == Some(transaction_hash) {
+							let (_, trace) = dhp_evm_tracing::tracer::trace::<Runtime, _>(tracer, || {
+								Executive::apply_extrinsic(ext.clone())
+							});
+							return trace.ok_or(sp_runtime::DispatchError::Other(
+								"Transaction did not run in the EVM",
+							));
+						}
+
+						let _ = Executive::apply_extrinsic(ext);
+					}
+
+					Err(sp_runtime::DispatchError::Other("Transaction not found in the block"))
+				}
+				#[cfg(not(feature = "evm-tracing"))]
+				{
+					let _ = (extrinsics, transaction_hash, header, tracer);
+					Err(sp_runtime::DispatchError::Other(
+						"Runtime built without the evm-tracing feature",
+					))
+				}
+			}
+
+			pub fn trace_block(
+				extrinsics: sp_std::vec::Vec<<Block as sp_runtime::traits::Block>::Extrinsic>,
+				header: &<Block as sp_runtime::traits::Block>::Header,
+				tracer: dhp_evm_tracing::TracerKind,
+			) -> Result<
+				sp_std::vec::Vec<(sp_core::H256, dhp_evm_tracing::TransactionTrace)>,
+				sp_runtime::DispatchError,
+			> {
+				#[cfg(feature = "evm-tracing")]
+				{
+					Executive::initialize_block(header);
+
+					let mut traces = sp_std::vec::Vec::new();
+					for ext in extrinsics.into_iter() {
+						match Self::ethereum_transaction_hash(&ext.0.function) {
+							Some(hash) => {
+								let (_, trace) = dhp_evm_tracing::tracer::trace::<Runtime, _>(tracer, || {
+									Executive::apply_extrinsic(ext.clone())
+								});
+								traces.extend(trace.map(|trace| (hash, trace)));
+							}
+							None => {
+								let _ = Executive::apply_extrinsic(ext);
+							}
+						}
+					}
+
+					Ok(traces)
+				}
+				#[cfg(not(feature = "evm-tracing"))]
+				{
+					let _ = (extrinsics, header, tracer);
+					Err(sp_runtime::DispatchError::Other(
+						"Runtime built without the evm-tracing feature",
+					))
+				}
+			}
+
+			#[allow(clippy::too_many_arguments)]
+			pub fn trace_call(
+				header: &<Block as sp_runtime::traits::Block>::Header,
+				from: sp_core::H160,
+				to: sp_core::H160,
+				data: sp_std::vec::Vec<u8>,
+				value: sp_core::U256,
+				gas_limit: sp_core::U256,
+				max_fee_per_gas: Option<sp_core::U256>,
+				max_priority_fee_per_gas: Option<sp_core::U256>,
+				nonce: Option<sp_core::U256>,
+				tracer: dhp_evm_tracing::TracerKind,
+			) -> Result<dhp_evm_tracing::TransactionTrace, sp_runtime::DispatchError> {
+				#[cfg(feature = "evm-tracing")]
+				{
+					use pallet_evm::{GasWeightMapping as _, Runner as _};
+
+					Executive::initialize_block(header);
+
+					let is_transactional = false;
+					let validate = true;
+
+					let gas_limit = gas_limit.min(u64::MAX.into()).low_u64();
+					let without_base_extrinsic_weight = true;
+
+					let weight_limit = <Runtime as pallet_evm::Config>::GasWeightMapping::gas_to_weight(
+						gas_limit,
+						without_base_extrinsic_weight,
+					);
+
+					let (result, trace) = dhp_evm_tracing::tracer::trace::<Runtime, _>(tracer, || {
+						<Runtime as pallet_evm::Config>::Runner::call(
+							from,
+							to,
+							data.clone(),
+							value,
+							gas_limit,
+							max_fee_per_gas,
+							max_priority_fee_per_gas,
+							nonce,
+							sp_std::vec::Vec::new(),
+							is_transactional,
+							validate,
+							Some(weight_limit),
+							None,
+							<Runtime as pallet_evm::Config>::config(),
+						)
+					});
+					if let Err(err) = result {
+						return Err(err.error.into());
+					}
+
+					trace.ok_or(sp_runtime::DispatchError::Other("Call did not run in the EVM"))
+				}
+				#[cfg(not(feature = "evm-tracing"))]
+				{
+					let _ = (
+						header,
+						from,
+						to,
+						data,
+						value,
+						gas_limit,
+						max_fee_per_gas,
+						max_priority_fee_per_gas,
+						nonce,
+						tracer,
+					);
+					Err(sp_runtime::DispatchError::Other(
+						"Runtime built without the evm-tracing feature",
+					))
+				}
+			}
+
+			#[cfg(feature = "evm-tracing")]
+			fn ethereum_transaction_hash(call: &RuntimeCall) -> Option<sp_core::H256> {
+				match call {
+					RuntimeCall::Ethereum(pallet_ethereum::Call::transact { transaction }) => {
+						Some(transaction.hash())
+					}
+					_ => None,
+				}
+			}
+		}
+	};
+}
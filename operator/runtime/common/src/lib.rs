@@ -20,15 +20,20 @@ pub mod constants;
 pub use constants::*;
 #[cfg(feature = "runtime-benchmarks")]
 pub mod benchmarking;
+pub mod bridge_tracking;
 pub mod deal_with_fees;
+pub mod finality_liveness;
+pub mod impl_evm_tracing;
 pub mod impl_on_charge_evm_transaction;
 pub mod inflation;
 pub mod migrations;
 pub use migrations::*;
+pub mod outbound_gas;
 pub mod rewards_adapter;
 pub mod safe_mode;
 pub use safe_mode::*;
 pub mod slashes_adapter;
+pub mod treasury;
 
 use fp_account::EthereumSignature;
 pub use sp_runtime::OpaqueExtrinsic as UncheckedExtrinsic;
@@ -0,0 +1,194 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Gas metering of the outbound messages sent to Ethereum.
+//!
+//! The calls to the DataHaven ServiceManager are metered from their contents: the gas of a
+//! rewards submission grows with the number of rewarded operators, and the gas of a slashing
+//! request with the number of slashed operators. The coefficients come from
+//! [`OutboundGasConfig`], so runtimes can tune them through governance. Every other command is
+//! metered by [`ConstantGasMeter`].
+
+use crate::{rewards_adapter::submitRewardsCall, slashes_adapter::slashValidatorsOperatorCall};
+use alloy_core::sol_types::SolCall;
+use snowbridge_outbound_queue_primitives::v2::{Command, ConstantGasMeter, GasMeter};
+use sp_core::H160;
+
+/// Coefficients of the gas metered for the calls to the DataHaven ServiceManager.
+pub trait OutboundGasConfig {
+    /// Get the DataHaven ServiceManager contract address on Ethereum.
+    fn service_manager_address() -> H160;
+
+    /// Gas of a `submitRewards` call, regardless of the number of operators.
+    fn submit_rewards_base_gas() -> u64;
+
+    /// Additional gas of a `submitRewards` call for each rewarded operator.
+    fn submit_rewards_gas_per_operator() -> u64;
+
+    /// Gas of a `slashValidatorsOperator` call, regardless of the number of slashes.
+    fn slash_validators_base_gas() -> u64;
+
+    /// Additional gas of a `slashValidatorsOperator` call for each slashed operator.
+    fn slash_validators_gas_per_slash() -> u64;
+}
+
+/// Gas meter scaling the calls to the DataHaven ServiceManager with their contents.
+///
+/// Calls it doesn't recognise keep the gas limit set in the command.
+pub struct DynamicGasMeter<C>(core::marker::PhantomData<C>);
+
+impl<C: OutboundGasConfig> GasMeter for DynamicGasMeter<C> {
+    fn maximum_dispatch_gas_used_at_most(command: &Command) -> u64 {
+        match command {
+            Command::CallContract {
+                target, calldata, ..
+            } if *target == C::service_manager_address() => service_manager_call_gas::<C>(calldata)
+                .unwrap_or_else(|| ConstantGasMeter::maximum_dispatch_gas_used_at_most(command)),
+            _ => ConstantGasMeter::maximum_dispatch_gas_used_at_most(command),
+        }
+    }
+}
+
+/// Gas of a known ServiceManager call, or `None` if the calldata isn't one.
+fn service_manager_call_gas<C: OutboundGasConfig>(calldata: &[u8]) -> Option<u64> {
+    let selector: [u8; 4] = calldata.get(..4)?.try_into().ok()?;
+    let (base, per_item, items) = match selector {
+        submitRewardsCall::SELECTOR => {
+            let call = submitRewardsCall::abi_decode(calldata, true).ok()?;
+            (
+                C::submit_rewards_base_gas(),
+                C::submit_rewards_gas_per_operator(),
+                call.submission.operatorRewards.len(),
+            )
+        }
+        slashValidatorsOperatorCall::SELECTOR => {
+            let call = slashValidatorsOperatorCall::abi_decode(calldata, true).ok()?;
+            (
+                C::slash_validators_base_gas(),
+                C::slash_validators_gas_per_slash(),
+                call.slashings.len(),
+            )
+        }
+        _ => return None,
+    };
+
+    Some(base.saturating_add(per_item.saturating_mul(items as u64)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{rewards_adapter::encode_rewards_calldata, slashes_adapter::SlashingRequest};
+    use alloy_core::primitives::Address;
+
+    struct TestGasConfig;
+
+    impl OutboundGasConfig for TestGasConfig {
+        fn service_manager_address() -> H160 {
+            H160::from_low_u64_be(0x5678)
+        }
+
+        fn submit_rewards_base_gas() -> u64 {
+            1_000_000
+        }
+
+        fn submit_rewards_gas_per_operator() -> u64 {
+            20_000
+        }
+
+        fn slash_validators_base_gas() -> u64 {
+            300_000
+        }
+
+        fn slash_validators_gas_per_slash() -> u64 {
+            150_000
+        }
+    }
+
+    type Meter = DynamicGasMeter<TestGasConfig>;
+
+    fn call_contract(target: H160, calldata: Vec<u8>) -> Command {
+        Command::CallContract {
+            target,
+            calldata,
+            gas: 42,
+            value: 0,
+        }
+    }
+
+    #[test]
+    fn submit_rewards_gas_scales_with_operators() {
+        let operator_rewards: Vec<(H160, u128)> =
+            (1..=3).map(|i| (H160::from_low_u64_be(i), 1_000)).collect();
+        let calldata = encode_rewards_calldata(
+            H160::from_low_u64_be(0x1234),
+            &[(H160::from_low_u64_be(0x9999), 1)],
+            &operator_rewards,
+            0,
+            86_400,
+            "test",
+        )
+        .expect("Encoding should succeed");
+
+        let command = call_contract(TestGasConfig::service_manager_address(), calldata);
+        assert_eq!(
+            Meter::maximum_dispatch_gas_used_at_most(&command),
+            1_000_000 + 3 * 20_000
+        );
+    }
+
+    #[test]
+    fn slash_validators_gas_scales_with_slashes() {
+        let slashings = (1..=2)
+            .map(|i| SlashingRequest {
+                operator: Address::from(H160::from_low_u64_be(i).as_fixed_bytes()),
+                strategies: vec![],
+                wadsToSlash: vec![],
+                description: "test".into(),
+            })
+            .collect();
+        let calldata = slashValidatorsOperatorCall { slashings }.abi_encode();
+
+        let command = call_contract(TestGasConfig::service_manager_address(), calldata);
+        assert_eq!(
+            Meter::maximum_dispatch_gas_used_at_most(&command),
+            300_000 + 2 * 150_000
+        );
+    }
+
+    #[test]
+    fn other_calls_keep_their_gas_limit() {
+        let calldata = slashValidatorsOperatorCall { slashings: vec![] }.abi_encode();
+        let other_target = call_contract(H160::from_low_u64_be(0x1111), calldata);
+        assert_eq!(Meter::maximum_dispatch_gas_used_at_most(&other_target), 42);
+
+        let unknown_call = call_contract(
+            TestGasConfig::service_manager_address(),
+            vec![0xde, 0xad, 0xbe, 0xef],
+        );
+        assert_eq!(Meter::maximum_dispatch_gas_used_at_most(&unknown_call), 42);
+
+        let command = Command::MintForeignToken {
+            token_id: Default::default(),
+            recipient: H160::zero(),
+            amount: 1,
+        };
+        assert_eq!(
+            Meter::maximum_dispatch_gas_used_at_most(&command),
+            ConstantGasMeter::maximum_dispatch_gas_used_at_most(&command)
+        );
+    }
+}
@@ -25,7 +25,9 @@ use alloy_core::{
     sol,
     sol_types::SolCall,
 };
-use pallet_external_validators_rewards::types::{EraRewardsUtils, SendMessage};
+use pallet_external_validators_rewards::types::{
+    split_inflation, EraRewardsUtils, RewardsSplitError, SendMessage,
+};
 use snowbridge_outbound_queue_primitives::v2::{
     Command, Message as OutboundMessage, SendMessage as SnowbridgeSendMessage,
 };
@@ -41,6 +43,9 @@ pub const REWARDS_DESCRIPTION: &str = "DataHaven validator rewards";
 const LOG_TARGET: &str = "rewards_adapter";
 
 /// Gas limit for the submitRewards call on Ethereum.
+///
+/// Runtimes metering the call with [`crate::outbound_gas::DynamicGasMeter`] replace it with a
+/// limit scaling with the number of operators.
 pub const SUBMIT_REWARDS_GAS_LIMIT: u64 = 2_000_000;
 
 /// Error type for rewards adapter operations.
@@ -54,6 +59,15 @@ pub enum RewardsAdapterError {
     DivisionByZero,
 }
 
+impl From<RewardsSplitError> for RewardsAdapterError {
+    fn from(error: RewardsSplitError) -> Self {
+        match error {
+            RewardsSplitError::MultiplicationOverflow => Self::MultiplicationOverflow,
+            RewardsSplitError::DivisionByZero => Self::DivisionByZero,
+        }
+    }
+}
+
 sol! {
     /// EigenLayer strategy and multiplier tuple.
     /// Maps to `IRewardsCoordinatorTypes.StrategyAndMultiplier`.
@@ -220,7 +234,8 @@ fn build_rewards_message<C: RewardsSubmissionConfig>(
 /// Returns a sorted list of (operator_address, amount) tuples and the remainder (dust).
 ///
 /// The remainder is the amount left over due to integer division truncation.
-/// Callers can decide how to handle it (e.g., send to treasury, burn, etc.).
+/// Callers can decide how to handle it (e.g., send to treasury, burn, etc.). The split itself is
+/// [`split_inflation`], shared with the rewards preview of the pallet.
 ///
 /// # Arguments
 /// * `points` - List of (operator, points) tuples
@@ -240,29 +255,18 @@ pub fn points_to_rewards(
     total_points: u128,
     inflation: u128,
 ) -> Result<(Vec<(H160, u128)>, u128), RewardsAdapterError> {
-    let mut rewards = Vec::with_capacity(points.len());
-    let mut distributed = 0u128;
-
-    for &(operator, points) in points {
-        // Use checked_mul to detect overflow in points * inflation.
-        let product = (points as u128)
-            .checked_mul(inflation)
-            .ok_or(RewardsAdapterError::MultiplicationOverflow)?;
-
-        let amount = product
-            .checked_div(total_points)
-            .ok_or(RewardsAdapterError::DivisionByZero)?;
-
-        if amount > 0 {
-            rewards.push((operator, amount));
-            distributed = distributed.saturating_add(amount);
-        }
-    }
+    let (amounts, remainder) = split_inflation(points, total_points, inflation)?;
+
+    let mut rewards: Vec<_> = points
+        .iter()
+        .zip(amounts)
+        .filter(|(_, amount)| *amount > 0)
+        .map(|(&(operator, _), amount)| (operator, amount))
+        .collect();
 
     // Sort by operator address (required by EigenLayer)
     rewards.sort_by_key(|(operator, _)| *operator);
 
-    let remainder = inflation.saturating_sub(distributed);
     Ok((rewards, remainder))
 }
 
@@ -47,6 +47,8 @@ where
             (b"Sudo", _) => true,
             // SafeMode calls
             (b"SafeMode", _) => true,
+            // EvmPause calls, to lift a pause of the EVM
+            (b"EvmPause", _) => true,
             _ => false,
         }
     }
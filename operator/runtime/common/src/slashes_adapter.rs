@@ -26,7 +26,10 @@ sol! {
     function slashValidatorsOperator(SlashingRequest[] calldata slashings) external;
 }
 
-/// Gas limit for the submitRewards call on Ethereum.
+/// Gas limit for the slashValidatorsOperator call on Ethereum.
+///
+/// Runtimes metering the call with [`crate::outbound_gas::DynamicGasMeter`] replace it with a
+/// limit scaling with the number of slashes.
 pub const SLASH_VALIDATORS_GAS_LIMIT: u64 = 1_000_000;
 
 /// Configuration for slashes submission.
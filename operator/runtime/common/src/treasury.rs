@@ -0,0 +1,223 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Treasury spends in the assets supported by DataHaven.
+//!
+//! The treasury pays out HAVE in two ways, selected by the [`TreasuryAssetKind`] of the spend:
+//! - to the account of the beneficiary,
+//! - to the beneficiary's address on Ethereum, bridged as wrapped HAVE.
+//!
+//! Payouts to Ethereum complete once their delivery receipt is received, the others right away.
+
+use crate::AccountId;
+use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
+use frame_support::{
+    pallet_prelude::*,
+    traits::{
+        fungible::Mutate,
+        tokens::{Pay, PaymentStatus, Preservation},
+        OnRuntimeUpgrade,
+    },
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use pallet_treasury::{PaymentState, SpendStatus};
+use scale_info::TypeInfo;
+use sp_core::{H160, H256};
+use sp_runtime::{traits::Saturating, DispatchError, RuntimeDebug};
+use sp_std::marker::PhantomData;
+
+/// Log target for treasury messages.
+const LOG_TARGET: &str = "runtime::treasury";
+
+/// How a treasury spend is paid out.
+#[derive(
+    Clone,
+    Copy,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    PartialEq,
+    Eq,
+    RuntimeDebug,
+    TypeInfo,
+    MaxEncodedLen,
+    Default,
+)]
+pub enum TreasuryAssetKind {
+    /// HAVE paid to the account of the beneficiary.
+    #[default]
+    Native,
+    /// HAVE bridged to the address of the beneficiary on Ethereum.
+    Ethereum,
+}
+
+/// Identifier of a treasury payout.
+#[derive(
+    Clone,
+    Copy,
+    Encode,
+    Decode,
+    DecodeWithMemTracking,
+    PartialEq,
+    Eq,
+    RuntimeDebug,
+    TypeInfo,
+    MaxEncodedLen,
+)]
+pub enum TreasuryPaymentId {
+    /// The payout completed right away.
+    Paid,
+    /// The payout is a transfer to Ethereum with this id.
+    Bridged(H256),
+}
+
+/// Pays treasury spends from the `Source` account.
+///
+/// - `Currency` pays native HAVE.
+/// - `Bridge` pays to Ethereum, e.g. `pallet_datahaven_native_transfer::PayToEthereum`.
+pub struct TreasuryPaymaster<Currency, Source, Bridge>(PhantomData<(Currency, Source, Bridge)>);
+
+impl<Currency, Source, Bridge> Pay for TreasuryPaymaster<Currency, Source, Bridge>
+where
+    Currency: Mutate<AccountId>,
+    Source: Get<AccountId>,
+    Bridge: Pay<Beneficiary = H160, AssetKind = (), Balance = Currency::Balance, Id = H256>,
+    Bridge::Error: Into<DispatchError>,
+{
+    type Balance = Currency::Balance;
+    type Beneficiary = AccountId;
+    type AssetKind = TreasuryAssetKind;
+    type Id = TreasuryPaymentId;
+    type Error = DispatchError;
+
+    fn pay(
+        who: &Self::Beneficiary,
+        asset_kind: Self::AssetKind,
+        amount: Self::Balance,
+    ) -> Result<Self::Id, Self::Error> {
+        match asset_kind {
+            TreasuryAssetKind::Native => {
+                Currency::transfer(&Source::get(), who, amount, Preservation::Expendable)?;
+                Ok(TreasuryPaymentId::Paid)
+            }
+            TreasuryAssetKind::Ethereum => Bridge::pay(&(*who).into(), (), amount)
+                .map(TreasuryPaymentId::Bridged)
+                .map_err(Into::into),
+        }
+    }
+
+    fn check_status(id: Self::Id) -> PaymentStatus {
+        match id {
+            TreasuryPaymentId::Paid => PaymentStatus::Success,
+            TreasuryPaymentId::Bridged(transfer_id) => Bridge::check_status(transfer_id),
+        }
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn ensure_successful(
+        who: &Self::Beneficiary,
+        asset_kind: Self::AssetKind,
+        amount: Self::Balance,
+    ) {
+        match asset_kind {
+            TreasuryAssetKind::Native => {
+                let _ = Currency::mint_into(&Source::get(), amount);
+            }
+            TreasuryAssetKind::Ethereum => Bridge::ensure_successful(&(*who).into(), (), amount),
+        }
+    }
+
+    #[cfg(feature = "runtime-benchmarks")]
+    fn ensure_concluded(id: Self::Id) {
+        if let TreasuryPaymentId::Bridged(transfer_id) = id {
+            Bridge::ensure_concluded(transfer_id);
+        }
+    }
+}
+
+type AssetBalanceOf<T> = <<T as pallet_treasury::Config>::Paymaster as Pay>::Balance;
+
+/// Spend of the treasury before [`TreasuryAssetKind`], paid out from the treasury account.
+type OldSpendStatus<T> = SpendStatus<
+    (),
+    AssetBalanceOf<T>,
+    <T as pallet_treasury::Config>::Beneficiary,
+    BlockNumberFor<T>,
+    (),
+>;
+
+/// Set once the spends of the treasury are migrated to [`TreasuryAssetKind`].
+#[frame_support::storage_alias]
+type TreasurySpendsMigrated = StorageValue<DataHavenMigrations, bool, ValueQuery>;
+
+/// Migrates the spends of the treasury to [`TreasuryAssetKind`] and [`TreasuryPaymentId`].
+///
+/// The spends become native ones, and their payouts, completed right away, become
+/// [`TreasuryPaymentId::Paid`]. The migration marks itself done on-chain, so that later upgrades
+/// skip it instead of translating the spends again.
+pub struct MigrateTreasurySpendsToAssetKind<T>(PhantomData<T>);
+
+impl<T> OnRuntimeUpgrade for MigrateTreasurySpendsToAssetKind<T>
+where
+    T: pallet_treasury::Config<AssetKind = TreasuryAssetKind>,
+    T::Paymaster: Pay<Id = TreasuryPaymentId>,
+{
+    fn on_runtime_upgrade() -> Weight {
+        if TreasurySpendsMigrated::get() {
+            log::info!(target: LOG_TARGET, "Treasury spends already migrated, skipping");
+            return T::DbWeight::get().reads(1);
+        }
+
+        let mut translated = 0u64;
+        pallet_treasury::Spends::<T>::translate::<OldSpendStatus<T>, _>(|_, spend| {
+            translated.saturating_inc();
+            Some(SpendStatus {
+                asset_kind: TreasuryAssetKind::Native,
+                amount: spend.amount,
+                beneficiary: spend.beneficiary,
+                valid_from: spend.valid_from,
+                expire_at: spend.expire_at,
+                status: match spend.status {
+                    PaymentState::Pending => PaymentState::Pending,
+                    PaymentState::Attempted { .. } => PaymentState::Attempted {
+                        id: TreasuryPaymentId::Paid,
+                    },
+                    PaymentState::Failed => PaymentState::Failed,
+                },
+            })
+        });
+        TreasurySpendsMigrated::put(true);
+        log::info!(target: LOG_TARGET, "Migrated {} treasury spends", translated);
+
+        T::DbWeight::get().reads_writes(translated.saturating_add(1), translated.saturating_add(1))
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn pre_upgrade() -> Result<sp_std::vec::Vec<u8>, sp_runtime::TryRuntimeError> {
+        Ok((pallet_treasury::Spends::<T>::iter_keys().count() as u32).encode())
+    }
+
+    #[cfg(feature = "try-runtime")]
+    fn post_upgrade(state: sp_std::vec::Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+        let spends = u32::decode(&mut &state[..])
+            .map_err(|_| sp_runtime::TryRuntimeError::Other("Invalid pre-upgrade state"))?;
+        ensure!(
+            pallet_treasury::Spends::<T>::iter().count() as u32 == spends,
+            "Treasury spends were lost"
+        );
+        Ok(())
+    }
+}
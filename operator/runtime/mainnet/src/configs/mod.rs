@@ -21,14 +21,15 @@ mod storagehub;
 use super::{
     currency::*,
     precompiles::{DataHavenPrecompiles, PrecompileName},
-    AccountId, Babe, Balance, Balances, BeefyMmrLeaf, Block, BlockNumber, EthereumBeaconClient,
-    EthereumOutboundQueueV2, EvmChainId, ExistentialDeposit, ExternalValidators,
-    ExternalValidatorsRewards, ExternalValidatorsSlashes, Hash, Historical, ImOnline, MessageQueue,
-    MultiBlockMigrations, Nonce, Offences, OriginCaller, OutboundCommitmentStore, PalletInfo,
-    Preimage, Referenda, Runtime, RuntimeCall, RuntimeEvent, RuntimeFreezeReason,
-    RuntimeHoldReason, RuntimeOrigin, RuntimeTask, SafeMode, Scheduler, Session, SessionKeys,
-    Signature, System, Timestamp, Treasury, TxPause, BLOCK_HASH_COUNT, EXTRINSIC_BASE_WEIGHT,
-    MAXIMUM_BLOCK_WEIGHT, NORMAL_BLOCK_WEIGHT, NORMAL_DISPATCH_RATIO, SLOT_DURATION, VERSION,
+    AccountId, Babe, Balance, Balances, BeefyMmrLeaf, Block, BlockNumber, DataHavenNativeTransfer,
+    EthereumBeaconClient, EthereumOutboundQueueV2, EvmChainId, ExistentialDeposit,
+    ExternalValidators, ExternalValidatorsRewards, ExternalValidatorsSlashes, Hash, Historical,
+    ImOnline, MessageQueue, MultiBlockMigrations, Nonce, Offences, OriginCaller,
+    OutboundCommitmentStore, PalletInfo, Preimage, Referenda, Runtime, RuntimeCall, RuntimeEvent,
+    RuntimeFreezeReason, RuntimeHoldReason, RuntimeOrigin, RuntimeTask, SafeMode, Scheduler,
+    Session, SessionKeys, Signature, System, Timestamp, Treasury, TxPause, BLOCK_HASH_COUNT,
+    EXTRINSIC_BASE_WEIGHT, MAXIMUM_BLOCK_WEIGHT, NORMAL_BLOCK_WEIGHT, NORMAL_DISPATCH_RATIO,
+    SLOT_DURATION, VERSION,
 };
 use alloy_core::primitives::Address;
 use codec::{Decode, Encode, MaxEncodedLen};
@@ -85,11 +86,13 @@ use datahaven_runtime_common::{
         FailedMigrationHandler, MigrationCursorMaxLen, MigrationIdentifierMaxLen,
         MigrationStatusHandler,
     },
+    outbound_gas::{DynamicGasMeter, OutboundGasConfig},
     safe_mode::{
         ReleaseDelayNone, RuntimeCallFilter, SafeModeDuration, SafeModeEnterDeposit,
         SafeModeExtendDeposit, TxPauseWhitelistedCalls,
     },
-    time::{EpochDurationInBlocks, SessionsPerEra, DAYS, MILLISECS_PER_BLOCK},
+    time::{EpochDurationInBlocks, SessionsPerEra, DAYS, MILLISECS_PER_BLOCK, MINUTES},
+    treasury::{TreasuryAssetKind, TreasuryPaymaster},
 };
 use frame_support::{
     derive_impl,
@@ -98,7 +101,7 @@ use frame_support::{
     parameter_types,
     traits::{
         fungible::{Balanced, Credit, HoldConsideration, Inspect},
-        tokens::{PayFromAccount, UnityAssetBalanceConversion},
+        tokens::UnityAssetBalanceConversion,
         ConstU128, ConstU32, ConstU64, ConstU8, Contains, EitherOfDiverse, EqualPrivilegeOnly,
         FindAuthor, KeyOwnerProofSystem, LinearStoragePrice, OnUnbalanced, VariantCountOf,
     },
@@ -113,6 +116,7 @@ use pallet_evm::{
     FrameSystemAccountProvider, IdentityAddressMapping,
     OnChargeEVMTransaction as OnChargeEVMTransactionT,
 };
+use pallet_evm_pause::{PausablePrecompiles, PausableRunner};
 use pallet_grandpa::AuthorityId as GrandpaId;
 use pallet_im_online::sr25519::AuthorityId as ImOnlineId;
 use pallet_transaction_payment::{
@@ -122,10 +126,8 @@ use polkadot_primitives::Moment;
 use runtime_params::RuntimeParameters;
 use snowbridge_beacon_primitives::{Fork, ForkVersions};
 use snowbridge_core::{gwei, meth, AgentIdOf, PricingParameters, Rewards, TokenId};
-use snowbridge_inbound_queue_primitives::RewardLedger;
 use snowbridge_outbound_queue_primitives::{
     v1::{Fee, Message, SendMessage},
-    v2::ConstantGasMeter,
     SendError, SendMessageFeeProvider,
 };
 use snowbridge_pallet_outbound_queue_v2::OnNewCommitment;
@@ -240,6 +242,8 @@ impl Contains<RuntimeCall> for SafeModeWhitelistedCalls {
             RuntimeCall::SafeMode(_) => true,
             // Transaction pause management
             RuntimeCall::TxPause(_) => true,
+            // EVM pause list management
+            RuntimeCall::EvmPause(_) => true,
             // Emergency admin access (testnet/dev only)
             RuntimeCall::Sudo(_) => true,
             // Governance infrastructure - critical for emergency responses
@@ -401,7 +405,8 @@ impl pallet_im_online::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type ValidatorSet = Historical;
     type NextSessionRotation = Babe;
-    type ReportUnresponsiveness = Offences;
+    type ReportUnresponsiveness =
+        pallet_external_validators_rewards::liveness::RecordUnresponsiveness<Runtime, Offences>;
     type UnsignedPriority = ImOnlineUnsignedPriority;
     type WeightInfo = crate::weights::pallet_im_online::WeightInfo<Runtime>;
 }
@@ -921,10 +926,14 @@ impl pallet_treasury::Config for Runtime {
     type SpendFunds = ();
     type SpendOrigin =
         frame_system::EnsureWithSuccess<RootOrTreasuryCouncilOrigin, AccountId, MaxSpendBalance>;
-    type AssetKind = ();
+    type AssetKind = TreasuryAssetKind;
     type Beneficiary = AccountId;
     type BeneficiaryLookup = IdentityLookup<AccountId>;
-    type Paymaster = PayFromAccount<Balances, TreasuryAccount>;
+    type Paymaster = TreasuryPaymaster<
+        Balances,
+        TreasuryAccount,
+        pallet_datahaven_native_transfer::PayToEthereum<Runtime, TreasuryAccount>,
+    >;
     type BalanceConverter = UnityAssetBalanceConversion;
     type PayoutPeriod = ConstU32<{ 30 * DAYS }>;
     #[cfg(feature = "runtime-benchmarks")]
@@ -984,12 +993,14 @@ where
 
 datahaven_runtime_common::impl_on_charge_evm_transaction!();
 
-pub type Precompiles = DataHavenPrecompiles<Runtime>;
+/// The precompiles of the runtime, reverting when paused through the EvmPause pallet.
+pub type Precompiles = PausablePrecompiles<Runtime, DataHavenPrecompiles<Runtime>>;
 
 parameter_types! {
     pub BlockGasLimit: U256
         = U256::from(NORMAL_DISPATCH_RATIO * MAXIMUM_BLOCK_WEIGHT.ref_time() / WEIGHT_PER_GAS);
-    pub PrecompilesValue: Precompiles = DataHavenPrecompiles::<Runtime>::new();
+    pub PrecompilesValue: Precompiles
+        = PausablePrecompiles::new(DataHavenPrecompiles::<Runtime>::new());
     pub WeightPerGas: Weight = Weight::from_parts(WEIGHT_PER_GAS, 0);
     pub SuicideQuickClearLimit: u32 = 0;
     /// The amount of gas per pov. Set to 0 because DataHaven is a solo chain and we don't
@@ -1016,7 +1027,7 @@ impl pallet_evm::Config for Runtime {
     type PrecompilesValue = PrecompilesValue;
     type ChainId = EvmChainId;
     type BlockGasLimit = BlockGasLimit;
-    type Runner = pallet_evm::runner::stack::Runner<Self>;
+    type Runner = PausableRunner<Self, pallet_evm::runner::stack::Runner<Self>>;
     type OnChargeTransaction = OnChargeEVMTransaction<
         DealWithEthereumBaseFees<
             Runtime,
@@ -1197,6 +1208,13 @@ impl snowbridge_pallet_ethereum_client::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type ForkVersions = ChainForkVersions;
     type FreeHeadersInterval = FreeHeadersInterval;
+    type UnixTime = Timestamp;
+    type BeaconGenesisTime =
+        runtime_params::dynamic_params::runtime_config::EthereumBeaconGenesisTime;
+    type LaggingThreshold =
+        runtime_params::dynamic_params::runtime_config::EthereumClientLaggingThreshold;
+    type DegradedThreshold =
+        runtime_params::dynamic_params::runtime_config::EthereumClientDegradedThreshold;
     type WeightInfo = mainnet_weights::snowbridge_pallet_ethereum_client::WeightInfo<Runtime>;
 }
 
@@ -1226,15 +1244,14 @@ impl snowbridge_inbound_queue_primitives::v2::MessageProcessor<AccountId> for No
 }
 
 parameter_types! {
-    pub DefaultRewardKind: () = ();
-}
-
-// Dummy RewardPayment implementation
-pub struct DummyRewardPayment;
-impl RewardLedger<AccountId, (), u128> for DummyRewardPayment {
-    fn register_reward(_who: &AccountId, _reward: (), _amount: u128) {
-        // Empty implementation for dummy struct
-    }
+    /// Relayer fees of the messages coming from Ethereum are paid in Ether
+    pub const InboundRewardKind: pallet_relayer_rewards::RewardKind =
+        pallet_relayer_rewards::RewardKind::Ether;
+    /// Fees of the messages sent to Ethereum are paid in native tokens
+    pub const OutboundRewardKind: pallet_relayer_rewards::RewardKind =
+        pallet_relayer_rewards::RewardKind::Native;
+    /// Assets of the inbound messages that failed processing are given back by the root agent
+    pub const DeadLetterRefundOrigin: H256 = H256::zero();
 }
 
 impl snowbridge_pallet_inbound_queue_v2::Config for Runtime {
@@ -1248,9 +1265,13 @@ impl snowbridge_pallet_inbound_queue_v2::Config for Runtime {
     );
     #[cfg(feature = "runtime-benchmarks")]
     type MessageProcessor = NoOpMessageProcessor;
-    type RewardKind = ();
-    type DefaultRewardKind = DefaultRewardKind;
-    type RewardPayment = DummyRewardPayment;
+    type RewardKind = pallet_relayer_rewards::RewardKind;
+    type DefaultRewardKind = InboundRewardKind;
+    type RewardPayment = RelayerRewards;
+    type DeadLetterRefund =
+        dhp_bridge::EthereumRefund<EthereumOutboundQueueV2, DeadLetterRefundOrigin>;
+    type MaxDeadLetters = ConstU32<100>;
+    type DegradedMode = EthereumBeaconClient;
     type WeightInfo = mainnet_weights::snowbridge_pallet_inbound_queue_v2::WeightInfo<Runtime>;
     #[cfg(feature = "runtime-benchmarks")]
     type Helper = Runtime;
@@ -1271,21 +1292,45 @@ impl OnNewCommitment for CommitmentHandler {
     }
 }
 
+pub struct MainnetOutboundGasConfig;
+impl OutboundGasConfig for MainnetOutboundGasConfig {
+    fn service_manager_address() -> H160 {
+        runtime_params::dynamic_params::runtime_config::DatahavenServiceManagerAddress::get()
+    }
+
+    fn submit_rewards_base_gas() -> u64 {
+        runtime_params::dynamic_params::runtime_config::SubmitRewardsBaseGas::get()
+    }
+
+    fn submit_rewards_gas_per_operator() -> u64 {
+        runtime_params::dynamic_params::runtime_config::SubmitRewardsGasPerOperator::get()
+    }
+
+    fn slash_validators_base_gas() -> u64 {
+        runtime_params::dynamic_params::runtime_config::SlashValidatorsBaseGas::get()
+    }
+
+    fn slash_validators_gas_per_slash() -> u64 {
+        runtime_params::dynamic_params::runtime_config::SlashValidatorsGasPerSlash::get()
+    }
+}
+
 impl snowbridge_pallet_outbound_queue_v2::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type Hashing = Keccak256;
     type MessageQueue = MessageQueue;
-    type GasMeter = ConstantGasMeter;
+    type GasMeter = DynamicGasMeter<MainnetOutboundGasConfig>;
     type Balance = Balance;
     type MaxMessagePayloadSize = ConstU32<2048>;
     type MaxMessagesPerBlock = ConstU32<32>;
     type OnNewCommitment = CommitmentHandler;
+    type DeliveryHandler = (DataHavenNativeTransfer, ExternalValidatorsSlashes);
     type WeightToFee = IdentityFee<Balance>;
     type Verifier = EthereumBeaconClient;
     type GatewayAddress = runtime_params::dynamic_params::runtime_config::EthereumGatewayAddress;
-    type RewardKind = ();
-    type DefaultRewardKind = DefaultRewardKind;
-    type RewardPayment = DummyRewardPayment;
+    type RewardKind = pallet_relayer_rewards::RewardKind;
+    type DefaultRewardKind = OutboundRewardKind;
+    type RewardPayment = RelayerRewards;
     type EthereumNetwork = EthereumNetwork;
     type ConvertAssetId = ();
     type WeightInfo = mainnet_weights::snowbridge_pallet_outbound_queue_v2::WeightInfo<Runtime>;
@@ -1525,10 +1570,8 @@ pub type RewardsSendAdapter =
 /// Wrapper to check if a validator has been slashed in a given era
 pub struct ValidatorSlashChecker;
 impl pallet_external_validators_rewards::SlashingCheck<AccountId> for ValidatorSlashChecker {
-    fn is_slashed(era_index: u32, validator: &AccountId) -> bool {
-        pallet_external_validator_slashes::ValidatorSlashInEra::<Runtime>::contains_key(
-            era_index, validator,
-        )
+    fn slash_fraction(era_index: u32, validator: &AccountId) -> Option<Perbill> {
+        pallet_external_validator_slashes::ValidatorSlashInEra::<Runtime>::get(era_index, validator)
     }
 }
 
@@ -1545,6 +1588,11 @@ parameter_types! {
     pub const MaxInflationPercent: u32 = 100;
 }
 
+parameter_types! {
+    /// Enough for a GRANDPA justification and a BEEFY commitment signed by every authority.
+    pub MaxFinalitySignaturesPerBlock: u32 = 2 * MaxAuthorities::get();
+}
+
 impl pallet_external_validators_rewards::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type EraIndexProvider = ExternalValidators;
@@ -1559,6 +1607,17 @@ impl pallet_external_validators_rewards::Config for Runtime {
         runtime_params::dynamic_params::runtime_config::OperatorRewardsBlockAuthoringWeight;
     type LivenessWeight =
         runtime_params::dynamic_params::runtime_config::OperatorRewardsLivenessWeight;
+    type HeartbeatLiveness =
+        pallet_external_validators_rewards::liveness::ImOnlineHeartbeats<Runtime>;
+    type HeartbeatLivenessWeight =
+        runtime_params::dynamic_params::runtime_config::OperatorRewardsHeartbeatLivenessWeight;
+    type FinalityLiveness =
+        pallet_external_validators_rewards::liveness::FinalitySignatures<Runtime>;
+    type FinalityLivenessWeight =
+        runtime_params::dynamic_params::runtime_config::OperatorRewardsFinalityLivenessWeight;
+    type FinalityProofVerifier =
+        datahaven_runtime_common::finality_liveness::GrandpaBeefyProofVerifier<Runtime>;
+    type MaxFinalitySignaturesPerBlock = MaxFinalitySignaturesPerBlock;
     type FairShareCap = runtime_params::dynamic_params::runtime_config::OperatorRewardsFairShareCap;
     type ExpectedBlocksPerEra = ExpectedBlocksPerEra;
     type MinInflationPercent = MinInflationPercent;
@@ -1606,6 +1665,27 @@ impl Get<Option<TokenId>> for MockNativeTokenId {
     }
 }
 
+parameter_types! {
+    /// Transfers to Ethereum without delivery receipt after this delay are refunded
+    pub const NativeTransferTimeout: BlockNumber = 7 * DAYS;
+    /// Account keeping the tokens from Ethereum whose recipient could not be decoded
+    pub const NativeTransferHoldingId: PalletId = PalletId(*b"dh/nthld");
+    pub NativeTransferHoldingAccount: AccountId = NativeTransferHoldingId::get().into_account_truncating();
+    /// Maximum weight of the call following an inbound native transfer
+    pub NativeTransferMaxFollowUpCallWeight: Weight = Perbill::from_percent(1) * RuntimeBlockWeights::get().max_block;
+}
+
+/// Calls that can follow an inbound native transfer, on behalf of its recipient
+///
+/// Calls dispatching other calls, such as proxy calls, are not allowed: their inner call would
+/// escape this filter.
+pub struct NativeTransferFollowUpCalls;
+impl Contains<RuntimeCall> for NativeTransferFollowUpCalls {
+    fn contains(call: &RuntimeCall) -> bool {
+        matches!(call, RuntimeCall::PaymentStreams(_))
+    }
+}
+
 impl pallet_datahaven_native_transfer::Config for Runtime {
     type RuntimeEvent = RuntimeEvent;
     type Currency = Balances;
@@ -1618,6 +1698,31 @@ impl pallet_datahaven_native_transfer::Config for Runtime {
     type FeeRecipient = TreasuryAccount;
     type PauseOrigin = EnsureRoot<AccountId>;
     type WeightInfo = mainnet_weights::pallet_datahaven_native_transfer::WeightInfo<Runtime>;
+    type EraIndexProvider = ExternalValidators;
+    type TransferTimeout = NativeTransferTimeout;
+    type MaxTransfersPerBlock = ConstU32<100>;
+    type HoldingAccount = NativeTransferHoldingAccount;
+    type RuntimeCall = RuntimeCall;
+    type FollowUpCallFilter = NativeTransferFollowUpCalls;
+    type MaxFollowUpCallWeight = NativeTransferMaxFollowUpCallWeight;
+    type DegradedMode = EthereumBeaconClient;
+    // Must not exceed the number of commands of an outbound message
+    type MaxBatchSize = ConstU32<8>;
+    type SuggestedFee = runtime_params::dynamic_params::runtime_config::NativeTransferSuggestedFee;
+}
+
+parameter_types! {
+    /// Ether rewards are paid by the root agent, which holds the Ether of the bridge
+    pub const RelayerRewardsEtherPayoutOrigin: H256 = H256::zero();
+}
+
+impl pallet_relayer_rewards::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type Currency = Balances;
+    type RewardSource = TreasuryAccount;
+    type OutboundQueue = EthereumOutboundQueueV2;
+    type EtherPayoutOrigin = RelayerRewardsEtherPayoutOrigin;
+    type WeightInfo = mainnet_weights::pallet_relayer_rewards::WeightInfo<Runtime>;
 }
 
 //╔══════════════════════════════════════════════════════════════════════════════════════════════════════════════════╗
@@ -1652,6 +1757,13 @@ impl pallet_tx_pause::Config for Runtime {
     type WeightInfo = mainnet_weights::pallet_tx_pause::WeightInfo<Runtime>;
 }
 
+impl pallet_evm_pause::Config for Runtime {
+    type RuntimeEvent = RuntimeEvent;
+    type PauseOrigin = EnsureRoot<AccountId>;
+    type UnpauseOrigin = EnsureRoot<AccountId>;
+    type WeightInfo = mainnet_weights::pallet_evm_pause::WeightInfo<Runtime>;
+}
+
 /// Mainnet slashes configuration for EigenLayer submission.
 pub struct MainnetSlashesConfig;
 
@@ -1696,12 +1808,22 @@ impl pallet_external_validator_slashes::Config for Runtime {
     type InvulnerablesProvider = ExternalValidators;
     type ExternalIndexProvider = ExternalValidators;
     type QueuedSlashesProcessedPerBlock = ConstU32<10>;
+    type Currency = Balances;
+    type ReporterBountySource = TreasuryAccount;
+    type OnSlashCancelled = ExternalValidatorsRewards;
+    type SlashRetryDelay = SlashRetryDelay;
+    type MaxSlashDeliveryAttempts = ConstU32<5>;
+    type SlashDeliveryTimeout = SlashDeliveryTimeout;
     type WeightInfo = mainnet_weights::pallet_external_validator_slashes::WeightInfo<Runtime>;
     type SendMessage = SlashesSendAdapter;
 }
 
 parameter_types! {
     pub const SlashDeferDuration: EraIndex = polkadot_runtime_common::prod_or_fast!(0, 0);
+    /// Blocks to wait before sending a failed slash again, doubled at every new failure.
+    pub const SlashRetryDelay: BlockNumber = 10 * MINUTES;
+    /// Blocks to wait for the delivery receipt of a slash before sending it again.
+    pub const SlashDeliveryTimeout: BlockNumber = DAYS;
 }
 
 #[cfg(test)]
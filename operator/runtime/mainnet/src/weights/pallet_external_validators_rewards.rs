@@ -74,4 +74,35 @@ impl<T: frame_system::Config> pallet_external_validators_rewards::WeightInfo for
 			.saturating_add(T::DbWeight::get().reads(9_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
+	/// Storage: `ExternalValidatorsRewards::RewardPenaltyMode` (r:0 w:1)
+	/// Proof: `ExternalValidatorsRewards::RewardPenaltyMode` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	fn set_reward_penalty_mode() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 3_950_000 picoseconds.
+		Weight::from_parts(4_120_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `ExternalValidatorsRewards::LastFinalityProofBlock` (r:2 w:2)
+	/// Proof: `ExternalValidatorsRewards::LastFinalityProofBlock` (`max_values`: None, `max_size`: Some(21), added: 2496, mode: `MaxEncodedLen`)
+	/// Storage: `ExternalValidatorsRewards::FinalityProofsInSession` (r:1 w:1)
+	/// Proof: `ExternalValidatorsRewards::FinalityProofsInSession` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `ExternalValidatorsRewards::FinalitySignaturesInSession` (r:100 w:100)
+	/// Proof: `ExternalValidatorsRewards::FinalitySignaturesInSession` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `s` is `[0, 100]`.
+	fn note_finality_proofs(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `101 + s * (44 ±0)`
+		//  Estimated: `5976 + s * (2519 ±0)`
+		// Minimum execution time: 9_310_000 picoseconds.
+		Weight::from_parts(9_870_000, 5976)
+			// Standard Error: 1_212
+			.saturating_add(Weight::from_parts(3_184_000, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(s.into())))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(s.into())))
+			.saturating_add(Weight::from_parts(0, 2519).saturating_mul(s.into()))
+	}
 }
@@ -0,0 +1,191 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Tests of the EVM pause list enforced by the precompile set and the EVM runner
+
+mod common;
+use common::*;
+
+use datahaven_mainnet_runtime::{
+    configs::PrecompilesValue, currency::*, AccountId, EvmPause, Runtime, RuntimeCall,
+    RuntimeOrigin,
+};
+use fp_evm::{ExitReason, IsPrecompileResult};
+use frame_support::{assert_ok, traits::Get};
+use pallet_evm::Runner;
+use precompile_utils::precompile_set::IsActivePrecompile;
+use sp_core::{H160, U256};
+use sp_runtime::traits::Dispatchable;
+
+/// Address of the DataHavenNativeTransferPrecompile
+const NATIVE_TRANSFER_PRECOMPILE: u64 = 2073;
+
+fn evm_transfer(target: H160) -> RuntimeCall {
+    RuntimeCall::EVM(pallet_evm::Call::<Runtime>::call {
+        source: H160::from(BOB),
+        target,
+        input: Vec::new(),
+        value: (1 * HAVE).into(),
+        gas_limit: 21_000u64,
+        max_fee_per_gas: U256::from(300 * MILLIHAVE),
+        max_priority_fee_per_gas: None,
+        nonce: None,
+        access_list: Vec::new(),
+    })
+}
+
+/// Code calling ALICE, and reverting if the call fails
+fn call_alice_or_revert() -> Vec<u8> {
+    // PUSH1 0 (x5) PUSH20 ALICE
+    let mut code = vec![
+        0x60, 0x00, 0x60, 0x00, 0x60, 0x00, 0x60, 0x00, 0x60, 0x00, 0x73,
+    ];
+    code.extend_from_slice(&ALICE);
+    // GAS CALL PUSH1 0x28 JUMPI PUSH1 0 DUP1 REVERT JUMPDEST PUSH1 0 DUP1 RETURN
+    code.extend_from_slice(&[
+        0x5a, 0xf1, 0x60, 0x28, 0x57, 0x60, 0x00, 0x80, 0xfd, 0x5b, 0x60, 0x00, 0x80, 0xf3,
+    ]);
+    code
+}
+
+fn evm_call(target: H160) -> ExitReason {
+    <Runtime as pallet_evm::Config>::Runner::call(
+        H160::from(BOB),
+        target,
+        Vec::new(),
+        U256::zero(),
+        100_000,
+        None,
+        None,
+        None,
+        Vec::new(),
+        false,
+        false,
+        None,
+        None,
+        <Runtime as pallet_evm::Config>::config(),
+    )
+    .map_err(|e| e.error)
+    .expect("Call is executed")
+    .exit_reason
+}
+
+fn evm_create(init: Vec<u8>) -> ExitReason {
+    <Runtime as pallet_evm::Config>::Runner::create(
+        H160::from(BOB),
+        init,
+        U256::zero(),
+        100_000,
+        None,
+        None,
+        None,
+        Vec::new(),
+        false,
+        false,
+        None,
+        None,
+        <Runtime as pallet_evm::Config>::config(),
+    )
+    .map_err(|e| e.error)
+    .expect("Create is executed")
+    .exit_reason
+}
+
+fn is_active_precompile(address: H160) -> bool {
+    match PrecompilesValue::get().is_active_precompile(address, u64::MAX) {
+        IsPrecompileResult::Answer { is_precompile, .. } => is_precompile,
+        IsPrecompileResult::OutOfGas => panic!("Unexpected out of gas"),
+    }
+}
+
+#[test]
+fn evm_call_to_paused_address_fails() {
+    ExtBuilder::default()
+        .with_balances(vec![(
+            AccountId::from(BOB),
+            2 * ((1 * HAVE) + (21_000 * (300 * MILLIHAVE))),
+        )])
+        .build()
+        .execute_with(|| {
+            let target = H160::from(ALICE);
+            assert_ok!(EvmPause::pause_address(RuntimeOrigin::root(), target));
+
+            let result = evm_transfer(target).dispatch(RuntimeOrigin::root());
+            assert_eq!(
+                result.map_err(|e| e.error),
+                Err(pallet_evm_pause::Error::<Runtime>::CallPaused.into())
+            );
+
+            assert_ok!(EvmPause::unpause_address(RuntimeOrigin::root(), target));
+            assert_ok!(evm_transfer(target).dispatch(RuntimeOrigin::root()));
+        });
+}
+
+#[test]
+fn paused_precompile_is_inactive() {
+    ExtBuilder::default().build().execute_with(|| {
+        let precompile = H160::from_low_u64_be(NATIVE_TRANSFER_PRECOMPILE);
+        assert!(is_active_precompile(precompile));
+
+        // Pausing a single function keeps the precompile active
+        assert_ok!(EvmPause::pause_selector(
+            RuntimeOrigin::root(),
+            precompile,
+            [0xde, 0xad, 0xbe, 0xef]
+        ));
+        assert!(is_active_precompile(precompile));
+
+        assert_ok!(EvmPause::pause_address(RuntimeOrigin::root(), precompile));
+        assert!(!is_active_precompile(precompile));
+
+        assert_ok!(EvmPause::unpause_address(RuntimeOrigin::root(), precompile));
+        assert!(is_active_precompile(precompile));
+    });
+}
+
+#[test]
+fn contract_call_to_paused_address_reverts() {
+    ExtBuilder::default().build().execute_with(|| {
+        let contract = H160::repeat_byte(0xcc);
+        pallet_evm::AccountCodes::<Runtime>::insert(contract, call_alice_or_revert());
+        assert!(matches!(evm_call(contract), ExitReason::Succeed(_)));
+
+        assert_ok!(EvmPause::pause_address(
+            RuntimeOrigin::root(),
+            H160::from(ALICE)
+        ));
+        assert!(matches!(evm_call(contract), ExitReason::Revert(_)));
+    });
+}
+
+#[test]
+fn constructor_call_to_paused_address_reverts() {
+    ExtBuilder::default().build().execute_with(|| {
+        assert!(matches!(
+            evm_create(call_alice_or_revert()),
+            ExitReason::Succeed(_)
+        ));
+
+        assert_ok!(EvmPause::pause_address(
+            RuntimeOrigin::root(),
+            H160::from(ALICE)
+        ));
+        assert!(matches!(
+            evm_create(call_alice_or_revert()),
+            ExitReason::Revert(_)
+        ));
+    });
+}
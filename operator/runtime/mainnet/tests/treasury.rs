@@ -376,7 +376,10 @@ fn fees_burned_when_block_author_not_set() {
 #[cfg(test)]
 mod treasury_tests {
     use super::*;
-    use frame_support::traits::Hooks;
+    use datahaven_runtime_common::treasury::{
+        MigrateTreasurySpendsToAssetKind, TreasuryAssetKind, TreasuryPaymentId,
+    };
+    use frame_support::traits::{Hooks, OnRuntimeUpgrade};
 
     /// Helper function to create an origin for an account
     fn origin_of(account_id: AccountId) -> RuntimeOrigin {
@@ -421,7 +424,7 @@ mod treasury_tests {
                     root_origin(),
                     Box::new(RuntimeCall::Treasury(pallet_treasury::Call::spend {
                         amount: spend_amount,
-                        asset_kind: Box::new(()),
+                        asset_kind: Box::new(TreasuryAssetKind::Native),
                         beneficiary: Box::new(AccountId::from(BOB)),
                         valid_from: Some(valid_from),
                     }))
@@ -432,7 +435,7 @@ mod treasury_tests {
                 let expected_events = [RuntimeEvent::Treasury(
                     pallet_treasury::Event::AssetSpendApproved {
                         index: 0,
-                        asset_kind: (),
+                        asset_kind: TreasuryAssetKind::Native,
                         amount: spend_amount,
                         beneficiary: spend_beneficiary,
                         valid_from,
@@ -451,7 +454,7 @@ mod treasury_tests {
                 let expected_events = [
                     RuntimeEvent::Treasury(pallet_treasury::Event::Paid {
                         index: 0,
-                        payment_id: (),
+                        payment_id: TreasuryPaymentId::Paid,
                     }),
                     RuntimeEvent::Balances(pallet_balances::Event::Transfer {
                         from: Treasury::account_id(),
@@ -493,7 +496,7 @@ mod treasury_tests {
                 let valid_from = System::block_number() + 5u32;
                 let proposal = RuntimeCall::Treasury(pallet_treasury::Call::spend {
                     amount: spend_amount,
-                    asset_kind: Box::new(()),
+                    asset_kind: Box::new(TreasuryAssetKind::Native),
                     beneficiary: Box::new(AccountId::from(BOB)),
                     valid_from: Some(valid_from),
                 });
@@ -509,7 +512,7 @@ mod treasury_tests {
                 let expected_events = [
                     RuntimeEvent::Treasury(pallet_treasury::Event::AssetSpendApproved {
                         index: 0,
-                        asset_kind: (),
+                        asset_kind: TreasuryAssetKind::Native,
                         amount: spend_amount,
                         beneficiary: spend_beneficiary,
                         valid_from,
@@ -532,7 +535,7 @@ mod treasury_tests {
                 let expected_events = [
                     RuntimeEvent::Treasury(pallet_treasury::Event::Paid {
                         index: 0,
-                        payment_id: (),
+                        payment_id: TreasuryPaymentId::Paid,
                     }),
                     RuntimeEvent::Balances(pallet_balances::Event::Transfer {
                         from: Treasury::account_id(),
@@ -544,4 +547,54 @@ mod treasury_tests {
                 expect_events(expected_events);
             });
     }
+
+    #[test]
+    fn test_treasury_native_spend_is_concluded_once_paid() {
+        ExtBuilder::default()
+            .with_balances(vec![(Treasury::account_id(), 1_000 * HAVE)])
+            .build()
+            .execute_with(|| {
+                let spend_beneficiary = AccountId::from(BOB);
+                let beneficiary_balance = Balances::free_balance(spend_beneficiary);
+                assert_ok!(Treasury::spend(
+                    root_origin(),
+                    Box::new(TreasuryAssetKind::Native),
+                    100 * HAVE,
+                    Box::new(spend_beneficiary),
+                    None,
+                ));
+                assert_ok!(Treasury::payout(origin_of(spend_beneficiary), 0));
+                assert_eq!(
+                    Balances::free_balance(spend_beneficiary),
+                    beneficiary_balance + 100 * HAVE
+                );
+
+                assert_ok!(Treasury::check_status(origin_of(spend_beneficiary), 0));
+                assert!(pallet_treasury::Spends::<Runtime>::get(0).is_none());
+                expect_events(vec![RuntimeEvent::Treasury(
+                    pallet_treasury::Event::SpendProcessed { index: 0 },
+                )]);
+            });
+    }
+
+    #[test]
+    fn test_treasury_spends_migration_runs_once() {
+        ExtBuilder::default().build().execute_with(|| {
+            MigrateTreasurySpendsToAssetKind::<Runtime>::on_runtime_upgrade();
+
+            assert_ok!(Treasury::spend(
+                root_origin(),
+                Box::new(TreasuryAssetKind::Ethereum),
+                100 * HAVE,
+                Box::new(AccountId::from(BOB)),
+                None,
+            ));
+            let spend = pallet_treasury::Spends::<Runtime>::get(0);
+            assert!(spend.is_some());
+
+            // Translating the spend again would read it in the layout prior to the asset kinds
+            MigrateTreasurySpendsToAssetKind::<Runtime>::on_runtime_upgrade();
+            assert_eq!(pallet_treasury::Spends::<Runtime>::get(0), spend);
+        });
+    }
 }
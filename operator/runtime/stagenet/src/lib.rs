@@ -245,7 +245,11 @@ pub type SignedPayload = generic::SignedPayload<RuntimeCall, SignedExtra>;
 ///
 /// This can be a tuple of types, each implementing `OnRuntimeUpgrade`.
 #[allow(unused_parens)]
-type Migrations = (pallet_file_system::migrations::v1::MigrateV0ToV1<Runtime>,);
+type Migrations = (
+    pallet_file_system::migrations::v1::MigrateV0ToV1<Runtime>,
+    pallet_external_validator_slashes::migration::v1::MigrateToV1<Runtime>,
+    datahaven_runtime_common::treasury::MigrateTreasurySpendsToAssetKind<Runtime>,
+);
 
 /// Executive: handles dispatch to the various modules.
 pub type Executive = frame_executive::Executive<
@@ -505,6 +509,12 @@ mod runtime {
 
     #[runtime::pallet_index(106)]
     pub type ProxyGenesisCompanion = pallet_proxy_genesis_companion;
+
+    #[runtime::pallet_index(107)]
+    pub type RelayerRewards = pallet_relayer_rewards;
+
+    #[runtime::pallet_index(108)]
+    pub type EvmPause = pallet_evm_pause;
     // ╚═══════════════════ DataHaven-specific Pallets ══════════════════╝
 }
 
@@ -589,6 +599,8 @@ impl fp_rpc::ConvertTransaction<UncheckedExtrinsic> for TransactionConverter {
     }
 }
 
+datahaven_runtime_common::impl_evm_tracing!();
+
 impl_runtime_apis! {
     impl sp_api::Core<Block> for Runtime {
         fn version() -> RuntimeVersion {
@@ -935,10 +947,40 @@ impl_runtime_apis! {
         }
     }
 
+    impl snowbridge_ethereum_client_runtime_api::EthereumClientApi<Block> for Runtime {
+        fn light_client_health() -> snowbridge_ethereum_client_runtime_api::LightClientHealth {
+            EthereumBeaconClient::light_client_health()
+        }
+    }
+
+    impl datahaven_runtime_common::bridge_tracking::BridgeTrackingApi<Block> for Runtime {
+        fn bridge_events() -> Vec<datahaven_runtime_common::bridge_tracking::BridgeEventRecord> {
+            datahaven_runtime_common::bridge_tracking::bridge_events::<Runtime>()
+        }
+    }
+
+    impl snowbridge_inbound_queue_v2_runtime_api::InboundQueueV2Api<Block, AccountId, BlockNumber> for Runtime {
+        fn dead_letters() -> Vec<(u64, snowbridge_inbound_queue_v2_runtime_api::DeadLetter<AccountId, BlockNumber>)> {
+            EthereumInboundQueueV2::dead_letters()
+        }
+    }
+
     impl snowbridge_outbound_queue_v2_runtime_api::OutboundQueueV2Api<Block, Balance> for Runtime {
         fn prove_message(leaf_index: u64) -> Option<snowbridge_merkle_tree::MerkleProof> {
             snowbridge_pallet_outbound_queue_v2::api::prove_message::<Runtime>(leaf_index)
         }
+
+        fn dry_run(
+            message: snowbridge_outbound_queue_primitives::v2::Message,
+        ) -> Result<
+            snowbridge_outbound_queue_primitives::v2::GasEstimate<Balance>,
+            snowbridge_outbound_queue_primitives::SendError,
+        > {
+            snowbridge_pallet_outbound_queue_v2::api::dry_run::<Runtime>(
+                message,
+                configs::runtime_params::dynamic_params::runtime_config::EthereumGasPrice::get(),
+            )
+        }
     }
 
     impl snowbridge_system_v2_runtime_api::ControlV2Api<Block> for Runtime {
@@ -947,6 +989,76 @@ impl_runtime_apis! {
         }
     }
 
+    impl pallet_external_validators_runtime_api::ExternalValidatorsApi<Block, AccountId> for Runtime {
+        fn era_summary() -> pallet_external_validators::EraSummary {
+            ExternalValidators::era_summary()
+        }
+
+        fn era_start_session_index(
+            era: pallet_external_validators::traits::EraIndex,
+        ) -> Option<sp_staking::SessionIndex> {
+            ExternalValidators::eras_start_session_index(era)
+        }
+
+        fn whitelisted_validators() -> Vec<AccountId> {
+            ExternalValidators::whitelisted_validators()
+        }
+
+        fn external_validators() -> Vec<AccountId> {
+            ExternalValidators::external_validators()
+        }
+
+        fn next_era_validators() -> Vec<AccountId> {
+            ExternalValidators::validators()
+        }
+
+        fn external_index() -> pallet_external_validators::ExternalIndexInfo {
+            ExternalValidators::external_index_info()
+        }
+    }
+
+    impl pallet_external_validators_rewards_runtime_api::ExternalValidatorsRewardsApi<Block> for Runtime {
+        fn era_rewards_preview(
+            era: pallet_external_validators_rewards::EraIndex,
+        ) -> Option<pallet_external_validators_rewards::types::EraRewardsPreview> {
+            ExternalValidatorsRewards::era_rewards_preview(era)
+        }
+    }
+
+    impl pallet_external_validator_slashes_runtime_api::ExternalValidatorSlashesApi<Block, u32, BlockNumber> for Runtime {
+        fn pending_slash_deliveries() -> Vec<(u32, pallet_external_validator_slashes::SlashDelivery<BlockNumber>)> {
+            ExternalValidatorsSlashes::pending_slash_deliveries()
+        }
+
+        fn slash_delivery(
+            slash_id: u32,
+        ) -> Option<pallet_external_validator_slashes::SlashDelivery<BlockNumber>> {
+            pallet_external_validator_slashes::SlashDeliveries::<Runtime>::get(slash_id)
+        }
+    }
+
+    impl pallet_relayer_rewards_runtime_api::RelayerRewardsApi<Block, AccountId> for Runtime {
+        fn rewards(relayer: AccountId) -> Vec<(pallet_relayer_rewards::RewardKind, u128)> {
+            RelayerRewards::rewards(&relayer)
+        }
+    }
+
+    impl pallet_datahaven_native_transfer_runtime_api::NativeTransferApi<Block, AccountId, Balance, BlockNumber> for Runtime {
+        fn pending_transfers(
+            account: AccountId,
+            start_after: Option<H256>,
+            limit: u32,
+        ) -> Vec<(H256, pallet_datahaven_native_transfer::PendingTransfer<AccountId, Balance, BlockNumber>)> {
+            DataHavenNativeTransfer::pending_transfers(&account, start_after, limit)
+        }
+
+        fn pending_transfer(
+            message_id: H256,
+        ) -> Option<pallet_datahaven_native_transfer::PendingTransfer<AccountId, Balance, BlockNumber>> {
+            pallet_datahaven_native_transfer::PendingTransfers::<Runtime>::get(message_id)
+        }
+    }
+
     #[cfg(feature = "runtime-benchmarks")]
     impl frame_benchmarking::Benchmark<Block> for Runtime {
         fn benchmark_metadata(extra: bool) -> (
@@ -1218,6 +1330,51 @@ impl_runtime_apis! {
         }
     }
 
+    impl dhp_evm_tracing::EvmTracingApi<Block> for Runtime {
+        fn trace_transaction(
+            extrinsics: Vec<<Block as BlockT>::Extrinsic>,
+            transaction_hash: H256,
+            header: &<Block as BlockT>::Header,
+            tracer: dhp_evm_tracing::TracerKind,
+        ) -> Result<dhp_evm_tracing::TransactionTrace, sp_runtime::DispatchError> {
+            EvmTracing::trace_transaction(extrinsics, transaction_hash, header, tracer)
+        }
+
+        fn trace_block(
+            extrinsics: Vec<<Block as BlockT>::Extrinsic>,
+            header: &<Block as BlockT>::Header,
+            tracer: dhp_evm_tracing::TracerKind,
+        ) -> Result<Vec<(H256, dhp_evm_tracing::TransactionTrace)>, sp_runtime::DispatchError> {
+            EvmTracing::trace_block(extrinsics, header, tracer)
+        }
+
+        fn trace_call(
+            header: &<Block as BlockT>::Header,
+            from: H160,
+            to: H160,
+            data: Vec<u8>,
+            value: U256,
+            gas_limit: U256,
+            max_fee_per_gas: Option<U256>,
+            max_priority_fee_per_gas: Option<U256>,
+            nonce: Option<U256>,
+            tracer: dhp_evm_tracing::TracerKind,
+        ) -> Result<dhp_evm_tracing::TransactionTrace, sp_runtime::DispatchError> {
+            EvmTracing::trace_call(
+                header,
+                from,
+                to,
+                data,
+                value,
+                gas_limit,
+                max_fee_per_gas,
+                max_priority_fee_per_gas,
+                nonce,
+                tracer,
+            )
+        }
+    }
+
     //╔═══════════════════════════════════════════════════════════════════════════════════════════════════════════════╗
     //║                                        STORAGEHUB APIS                                                        ║
     //╚═══════════════════════════════════════════════════════════════════════════════════════════════════════════════╝
@@ -65,14 +65,39 @@ impl<T: frame_system::Config> pallet_datahaven_native_transfer::WeightInfo for W
 	/// Proof: `MessageQueue::ServiceHead` (`max_values`: Some(1), `max_size`: Some(33), added: 528, mode: `MaxEncodedLen`)
 	/// Storage: `MessageQueue::Pages` (r:0 w:1)
 	/// Proof: `MessageQueue::Pages` (`max_values`: None, `max_size`: Some(32845), added: 35320, mode: `MaxEncodedLen`)
+	/// Storage: `DataHavenNativeTransfer::Limits` (r:1 w:0)
+	/// Proof: `DataHavenNativeTransfer::Limits` (`max_values`: Some(1), `max_size`: Some(103), added: 598, mode: `MaxEncodedLen`)
+	/// Storage: `ExternalValidators::ActiveEra` (r:1 w:0)
+	/// Proof: `ExternalValidators::ActiveEra` (`max_values`: Some(1), `max_size`: Some(13), added: 508, mode: `MaxEncodedLen`)
+	/// Storage: `DataHavenNativeTransfer::OutboundBlockVolume` (r:1 w:1)
+	/// Proof: `DataHavenNativeTransfer::OutboundBlockVolume` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
+	/// Storage: `DataHavenNativeTransfer::OutboundEraVolume` (r:1 w:1)
+	/// Proof: `DataHavenNativeTransfer::OutboundEraVolume` (`max_values`: Some(1), `max_size`: Some(20), added: 515, mode: `MaxEncodedLen`)
+	/// Storage: `DataHavenNativeTransfer::AccountOutboundVolume` (r:1 w:1)
+	/// Proof: `DataHavenNativeTransfer::AccountOutboundVolume` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `DataHavenNativeTransfer::ExpectedWrappedSupply` (r:1 w:1)
+	/// Proof: `DataHavenNativeTransfer::ExpectedWrappedSupply` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `DataHavenNativeTransfer::TransferTimeouts` (r:1 w:1)
+	/// Proof: `DataHavenNativeTransfer::TransferTimeouts` (`max_values`: None, `max_size`: Some(3206), added: 5681, mode: `MaxEncodedLen`)
+	/// Storage: `DataHavenNativeTransfer::PendingTransfers` (r:0 w:1)
+	/// Proof: `DataHavenNativeTransfer::PendingTransfers` (`max_values`: None, `max_size`: Some(110), added: 2585, mode: `MaxEncodedLen`)
+	/// Storage: `DataHavenNativeTransfer::NextMessageIndex` (r:1 w:1)
+	/// Proof: `DataHavenNativeTransfer::NextMessageIndex` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `DataHavenNativeTransfer::PendingTransfersBySender` (r:0 w:1)
+	/// Proof: `DataHavenNativeTransfer::PendingTransfersBySender` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// ESTIMATED: the message and sender indexes above are not benchmarked yet, they are added
+	/// on top of the benchmarked transfer until the benchmark is re-run.
 	fn transfer_to_ethereum() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `467`
-		//  Estimated: `8763`
+		//  Estimated: `19622`
 		// Minimum execution time: 140_933_000 picoseconds.
-		Weight::from_parts(142_617_000, 8763)
-			.saturating_add(T::DbWeight::get().reads(6_u64))
-			.saturating_add(T::DbWeight::get().writes(6_u64))
+		Weight::from_parts(142_617_000, 19622)
+			.saturating_add(T::DbWeight::get().reads(13_u64))
+			.saturating_add(T::DbWeight::get().writes(12_u64))
+			// Estimated message and sender indexes
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `DataHavenNativeTransfer::Paused` (r:0 w:1)
 	/// Proof: `DataHavenNativeTransfer::Paused` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
@@ -94,4 +119,191 @@ impl<T: frame_system::Config> pallet_datahaven_native_transfer::WeightInfo for W
 		Weight::from_parts(7_153_000, 0)
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `DataHavenNativeTransfer::Limits` (r:0 w:1)
+	/// Proof: `DataHavenNativeTransfer::Limits` (`max_values`: Some(1), `max_size`: Some(103), added: 598, mode: `MaxEncodedLen`)
+	fn set_transfer_limits() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 7_412_000 picoseconds.
+		Weight::from_parts(7_689_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DataHavenNativeTransfer::Paused` (r:1 w:1)
+	/// Proof: `DataHavenNativeTransfer::Paused` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `DataHavenNativeTransfer::ExpectedWrappedSupply` (r:1 w:0)
+	/// Proof: `DataHavenNativeTransfer::ExpectedWrappedSupply` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:0)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	fn check_locked_balance() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `178`
+		//  Estimated: `3581`
+		// Minimum execution time: 11_347_000 picoseconds.
+		Weight::from_parts(11_802_000, 3581)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DataHavenNativeTransfer::TransferTimeouts` (r:1 w:1)
+	/// Proof: `DataHavenNativeTransfer::TransferTimeouts` (`max_values`: None, `max_size`: Some(3206), added: 5681, mode: `MaxEncodedLen`)
+	/// Storage: `DataHavenNativeTransfer::PendingTransfers` (r:100 w:100)
+	/// Proof: `DataHavenNativeTransfer::PendingTransfers` (`max_values`: None, `max_size`: Some(110), added: 2585, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:101 w:101)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `DataHavenNativeTransfer::ExpectedWrappedSupply` (r:1 w:1)
+	/// Proof: `DataHavenNativeTransfer::ExpectedWrappedSupply` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 100]`.
+	/// Storage: `DataHavenNativeTransfer::LastAcceptedMessageIndex` (r:1 w:0)
+	/// Proof: `DataHavenNativeTransfer::LastAcceptedMessageIndex` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `DataHavenNativeTransfer::PendingTransfersBySender` (r:0 w:100)
+	/// Proof: `DataHavenNativeTransfer::PendingTransfersBySender` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// ESTIMATED: the message and sender indexes above are not benchmarked yet, they are added
+	/// on top of the benchmarked refunds until the benchmark is re-run.
+	fn refund_expired_transfers(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `263 + n * (196 ±0)`
+		//  Estimated: `6671 + n * (2591 ±0)`
+		// Minimum execution time: 9_542_000 picoseconds.
+		Weight::from_parts(10_118_000, 6671)
+			// Standard Error: 21_318
+			.saturating_add(Weight::from_parts(48_764_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2591).saturating_mul(n.into()))
+			// Estimated message and sender indexes
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `DataHavenNativeTransfer::PendingTransfers` (r:1 w:1)
+	/// Proof: `DataHavenNativeTransfer::PendingTransfers` (`max_values`: None, `max_size`: Some(110), added: 2585, mode: `MaxEncodedLen`)
+	/// Storage: `DataHavenNativeTransfer::PendingTransferIds` (r:0 w:1)
+	/// Proof: `DataHavenNativeTransfer::PendingTransferIds` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `DataHavenNativeTransfer::LastAcceptedMessageIndex` (r:1 w:1)
+	/// Proof: `DataHavenNativeTransfer::LastAcceptedMessageIndex` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// ESTIMATED: the message index above is not benchmarked yet, it is added on top of the
+	/// benchmarked notification until the benchmark is re-run.
+	fn on_message_accepted() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `251`
+		//  Estimated: `3587`
+		// Minimum execution time: 10_231_000 picoseconds.
+		Weight::from_parts(10_604_000, 3587)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			// Estimated message index
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DataHavenNativeTransfer::PendingTransferIds` (r:1 w:1)
+	/// Proof: `DataHavenNativeTransfer::PendingTransferIds` (`max_values`: None, `max_size`: Some(56), added: 2531, mode: `MaxEncodedLen`)
+	/// Storage: `DataHavenNativeTransfer::PendingTransfers` (r:1 w:1)
+	/// Proof: `DataHavenNativeTransfer::PendingTransfers` (`max_values`: None, `max_size`: Some(110), added: 2585, mode: `MaxEncodedLen`)
+	/// Storage: `DataHavenNativeTransfer::TransferTimeouts` (r:1 w:1)
+	/// Proof: `DataHavenNativeTransfer::TransferTimeouts` (`max_values`: None, `max_size`: Some(3206), added: 5681, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `DataHavenNativeTransfer::ExpectedWrappedSupply` (r:1 w:1)
+	/// Proof: `DataHavenNativeTransfer::ExpectedWrappedSupply` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `DataHavenNativeTransfer::PendingTransfersBySender` (r:0 w:1)
+	/// Proof: `DataHavenNativeTransfer::PendingTransfersBySender` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// ESTIMATED: the sender index above is not benchmarked yet, it is added on top of the
+	/// benchmarked notification until the benchmark is re-run.
+	fn on_message_delivered() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3678`
+		//  Estimated: `6671`
+		// Minimum execution time: 64_385_000 picoseconds.
+		Weight::from_parts(66_012_000, 6671)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+			// Estimated sender index
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DataHavenNativeTransfer::HeldTransfers` (r:1 w:1)
+	/// Proof: `DataHavenNativeTransfer::HeldTransfers` (`max_values`: None, `max_size`: Some(550), added: 3025, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	fn release_held_tokens() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `6172`
+		// Minimum execution time: 58_117_000 picoseconds.
+		Weight::from_parts(59_846_000, 6172)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `DataHavenNativeTransfer::BatchingPeriod` (r:0 w:1)
+	/// Proof: `DataHavenNativeTransfer::BatchingPeriod` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// ESTIMATED: not benchmarked yet, one storage write. Replace with the output of the
+	/// `set_batching_period` benchmark.
+	fn set_batching_period() -> Weight {
+		Weight::from_parts(5_317_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `DataHavenNativeTransfer::CurrentBatch` (r:1 w:1)
+	/// Proof: `DataHavenNativeTransfer::CurrentBatch` (`max_values`: Some(1), `max_size`: Some(689), added: 1184, mode: `MaxEncodedLen`)
+	/// Storage: `SnowbridgeOutboundQueue::MessageLeaves` (r:1 w:1)
+	/// Proof: `SnowbridgeOutboundQueue::MessageLeaves` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `DataHavenNativeTransfer::TransferTimeouts` (r:1 w:1)
+	/// Proof: `DataHavenNativeTransfer::TransferTimeouts` (`max_values`: None, `max_size`: Some(3206), added: 5681, mode: `MaxEncodedLen`)
+	/// Storage: `DataHavenNativeTransfer::PendingTransfers` (r:0 w:8)
+	/// Proof: `DataHavenNativeTransfer::PendingTransfers` (`max_values`: None, `max_size`: Some(122), added: 2597, mode: `MaxEncodedLen`)
+	/// Storage: `DataHavenNativeTransfer::SentBatches` (r:0 w:1)
+	/// Proof: `DataHavenNativeTransfer::SentBatches` (`max_values`: None, `max_size`: Some(305), added: 2780, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 8]`.
+	/// Storage: `DataHavenNativeTransfer::NextMessageIndex` (r:1 w:1)
+	/// Proof: `DataHavenNativeTransfer::NextMessageIndex` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `DataHavenNativeTransfer::PendingTransfersBySender` (r:0 w:8)
+	/// Proof: `DataHavenNativeTransfer::PendingTransfersBySender` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// ESTIMATED: not benchmarked yet, modelled on `transfer_to_ethereum` with a mint command and
+	/// a tracked transfer per transfer of the batch. Replace with the output of the `send_batch`
+	/// benchmark.
+	fn send_batch(n: u32, ) -> Weight {
+		Weight::from_parts(50_874_000, 6671)
+			.saturating_add(Weight::from_parts(6_913_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			// Estimated message and sender indexes
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+	/// Storage: `DataHavenNativeTransfer::CurrentBatch` (r:1 w:1)
+	/// Proof: `DataHavenNativeTransfer::CurrentBatch` (`max_values`: Some(1), `max_size`: Some(689), added: 1184, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:9 w:9)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `DataHavenNativeTransfer::ExpectedWrappedSupply` (r:1 w:1)
+	/// Proof: `DataHavenNativeTransfer::ExpectedWrappedSupply` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 8]`.
+	/// ESTIMATED: not benchmarked yet, modelled on a refund per transfer of the batch. Replace with
+	/// the output of the `cancel_batch` benchmark.
+	fn cancel_batch(n: u32, ) -> Weight {
+		Weight::from_parts(9_827_000, 3593)
+			.saturating_add(Weight::from_parts(47_125_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2591).saturating_mul(n.into()))
+	}
+	/// Storage: `DataHavenNativeTransfer::PendingTransfers` (r:1 w:1)
+	/// Proof: `DataHavenNativeTransfer::PendingTransfers` (`max_values`: None, `max_size`: Some(110), added: 2585, mode: `MaxEncodedLen`)
+	/// Storage: `DataHavenNativeTransfer::PendingTransfersBySender` (r:0 w:1)
+	/// Proof: `DataHavenNativeTransfer::PendingTransfersBySender` (`max_values`: None, `max_size`: Some(84), added: 2559, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `DataHavenNativeTransfer::ExpectedWrappedSupply` (r:1 w:1)
+	/// Proof: `DataHavenNativeTransfer::ExpectedWrappedSupply` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `DataHavenNativeTransfer::PaymentOutcomes` (r:1 w:1)
+	/// Proof: `DataHavenNativeTransfer::PaymentOutcomes` (`max_values`: None, `max_size`: Some(49), added: 2524, mode: `MaxEncodedLen`)
+	/// ESTIMATED: not benchmarked yet, modelled on `release_held_tokens`. Replace with the output
+	/// of the `retry_refund` benchmark.
+	fn retry_refund() -> Weight {
+		Weight::from_parts(62_000_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
 }
@@ -0,0 +1,243 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Verification of the GRANDPA and BEEFY proofs noted as validator liveness
+
+mod common;
+use common::*;
+
+use codec::Encode;
+use datahaven_runtime_common::finality_liveness::{
+    BeefyFinalityProof, FinalityProof, GrandpaBeefyProofVerifier,
+};
+use datahaven_stagenet_runtime::{AccountId, BlockNumber, Header, Runtime, RuntimeOrigin, Session};
+use frame_support::{assert_ok, traits::OneSessionHandler};
+use pallet_external_validators_rewards::liveness::{FinalityGadget, FinalityProofVerifier};
+use sp_consensus_beefy::{known_payloads::MMR_ROOT_ID, Commitment, Payload};
+use sp_consensus_grandpa::GrandpaJustification;
+use sp_core::{ecdsa, ed25519, keccak_256, Pair, H256};
+
+type Verifier = GrandpaBeefyProofVerifier<Runtime>;
+
+const ROUND: u64 = 1;
+
+/// A validator with the keys it signs GRANDPA and BEEFY votes with.
+struct FinalityValidator {
+    account: AccountId,
+    grandpa: ed25519::Pair,
+    beefy: ecdsa::Pair,
+}
+
+/// Registers session keys for `accounts` and makes them the GRANDPA and BEEFY authorities.
+fn set_finality_authorities(accounts: &[AccountId]) -> Vec<FinalityValidator> {
+    let validators: Vec<FinalityValidator> = accounts
+        .iter()
+        .enumerate()
+        .map(|(i, account)| {
+            let seed = [i as u8 + 1; 32];
+            FinalityValidator {
+                account: account.clone(),
+                grandpa: ed25519::Pair::from_seed(&seed),
+                beefy: ecdsa::Pair::from_seed(&seed),
+            }
+        })
+        .collect();
+
+    for validator in validators.iter() {
+        let mut keys = generate_session_keys(validator.account.clone());
+        keys.grandpa = validator.grandpa.public().into();
+        keys.beefy = validator.beefy.public().into();
+        assert_ok!(Session::set_keys(
+            RuntimeOrigin::signed(validator.account.clone()),
+            keys,
+            vec![]
+        ));
+    }
+
+    // Replace the authorities set at genesis
+    for pallet in [&b"Grandpa"[..], &b"Beefy"[..]] {
+        frame_support::storage::unhashed::kill(&frame_support::storage::storage_prefix(
+            pallet,
+            b"Authorities",
+        ));
+    }
+    <pallet_grandpa::Pallet<Runtime> as OneSessionHandler<AccountId>>::on_genesis_session(
+        validators
+            .iter()
+            .map(|v| (&v.account, v.grandpa.public().into())),
+    );
+    <pallet_beefy::Pallet<Runtime> as OneSessionHandler<AccountId>>::on_genesis_session(
+        validators
+            .iter()
+            .map(|v| (&v.account, v.beefy.public().into())),
+    );
+
+    validators
+}
+
+/// A GRANDPA justification of round `ROUND` with precommits by `signers`.
+fn grandpa_proof(
+    signers: &[&FinalityValidator],
+    set_id: u64,
+    target_number: BlockNumber,
+) -> FinalityProof {
+    let target_hash = H256::repeat_byte(1);
+    let precommit = sp_consensus_grandpa::Precommit::<Header> {
+        target_hash,
+        target_number,
+    };
+    let payload = sp_consensus_grandpa::localized_payload(
+        ROUND,
+        set_id,
+        &sp_consensus_grandpa::Message::<Header>::Precommit(precommit.clone()),
+    );
+    let precommits = signers
+        .iter()
+        .map(|v| sp_consensus_grandpa::SignedPrecommit::<Header> {
+            precommit: precommit.clone(),
+            signature: v.grandpa.sign(&payload).into(),
+            id: v.grandpa.public().into(),
+        })
+        .collect();
+
+    FinalityProof::Grandpa(GrandpaJustification {
+        round: ROUND,
+        commit: sp_consensus_grandpa::Commit::<Header> {
+            target_hash,
+            target_number,
+            precommits,
+        },
+        votes_ancestries: vec![],
+    })
+}
+
+/// A BEEFY commitment with a signature slot per authority, filled by `signers`.
+fn beefy_proof(
+    validators: &[FinalityValidator],
+    signers: &[&FinalityValidator],
+    validator_set_id: u64,
+    block_number: BlockNumber,
+) -> FinalityProof {
+    let commitment = Commitment {
+        payload: Payload::from_single_entry(MMR_ROOT_ID, vec![]),
+        block_number,
+        validator_set_id,
+    };
+    let hash = keccak_256(&commitment.encode());
+    let signatures = validators
+        .iter()
+        .enumerate()
+        .map(|(i, _)| {
+            signers
+                .get(i)
+                .map(|signer| signer.beefy.sign_prehashed(&hash).into())
+        })
+        .collect();
+
+    FinalityProof::Beefy(BeefyFinalityProof {
+        commitment,
+        signatures,
+    })
+}
+
+fn sorted(validators: &[&FinalityValidator]) -> Vec<AccountId> {
+    let mut accounts: Vec<AccountId> = validators.iter().map(|v| v.account.clone()).collect();
+    accounts.sort();
+    accounts
+}
+
+#[test]
+fn grandpa_proof_signed_by_a_supermajority_is_verified() {
+    ExtBuilder::default().build().execute_with(|| {
+        let validators = set_finality_authorities(&[alice(), charlie(), dave()]);
+        let all: Vec<&FinalityValidator> = validators.iter().collect();
+
+        let proof = grandpa_proof(&all, 0, 10);
+        assert_eq!(Verifier::target(&proof), (FinalityGadget::Grandpa, 10));
+        assert_eq!(Verifier::signature_count(&proof), 3);
+        assert_eq!(Verifier::signers(&proof), Some(sorted(&all)));
+
+        // Two thirds of the authorities are not enough
+        let proof = grandpa_proof(&all[..2], 0, 10);
+        assert_eq!(Verifier::signers(&proof), None);
+    });
+}
+
+#[test]
+fn grandpa_proof_with_invalid_signatures_is_rejected() {
+    ExtBuilder::default().build().execute_with(|| {
+        let validators = set_finality_authorities(&[alice(), charlie(), dave()]);
+        let all: Vec<&FinalityValidator> = validators.iter().collect();
+
+        // Signed for another authority set
+        let proof = grandpa_proof(&all, 1, 10);
+        assert_eq!(Verifier::signers(&proof), None);
+
+        // Precommits by keys that are not authorities are not counted
+        let outsider = FinalityValidator {
+            account: bob(),
+            grandpa: ed25519::Pair::from_seed(&[9; 32]),
+            beefy: ecdsa::Pair::from_seed(&[9; 32]),
+        };
+        let proof = grandpa_proof(&[all[0], all[1], &outsider], 0, 10);
+        assert_eq!(Verifier::signers(&proof), None);
+
+        // A precommit whose signature does not match its key is not counted
+        let FinalityProof::Grandpa(mut justification) = grandpa_proof(&all, 0, 10) else {
+            unreachable!()
+        };
+        justification.commit.precommits[2].signature =
+            justification.commit.precommits[1].signature.clone();
+        assert_eq!(
+            Verifier::signers(&FinalityProof::Grandpa(justification)),
+            None
+        );
+    });
+}
+
+#[test]
+fn beefy_proof_signed_by_a_supermajority_is_verified() {
+    ExtBuilder::default().build().execute_with(|| {
+        let validators = set_finality_authorities(&[alice(), charlie(), dave()]);
+        let all: Vec<&FinalityValidator> = validators.iter().collect();
+
+        let proof = beefy_proof(&validators, &all, 0, 10);
+        assert_eq!(Verifier::target(&proof), (FinalityGadget::Beefy, 10));
+        assert_eq!(Verifier::signature_count(&proof), 3);
+        assert_eq!(Verifier::signers(&proof), Some(sorted(&all)));
+
+        // Two thirds of the authorities are not enough
+        let proof = beefy_proof(&validators, &all[..2], 0, 10);
+        assert_eq!(Verifier::signature_count(&proof), 2);
+        assert_eq!(Verifier::signers(&proof), None);
+    });
+}
+
+#[test]
+fn beefy_proof_with_invalid_signatures_is_rejected() {
+    ExtBuilder::default().build().execute_with(|| {
+        let validators = set_finality_authorities(&[alice(), charlie(), dave()]);
+        let all: Vec<&FinalityValidator> = validators.iter().collect();
+
+        // Signed for another validator set
+        let proof = beefy_proof(&validators, &all, 1, 10);
+        assert_eq!(Verifier::signers(&proof), None);
+
+        // Signatures in the slot of another authority are not counted
+        let proof = beefy_proof(&validators, &[all[1], all[0], all[2]], 0, 10);
+        assert_eq!(Verifier::signers(&proof), None);
+    });
+}
@@ -19,17 +19,24 @@ mod common;
 
 use codec::Encode;
 use common::*;
+use datahaven_runtime_common::treasury::{TreasuryAssetKind, TreasuryPaymentId};
 use datahaven_stagenet_runtime::{
-    configs::EthereumSovereignAccount, currency::HAVE, AccountId, Balance, Balances,
-    DataHavenNativeTransfer, Runtime, RuntimeEvent, RuntimeOrigin, SnowbridgeSystemV2, System,
+    configs::{EthereumSovereignAccount, NativeTransferHoldingAccount, ProxyType},
+    currency::HAVE,
+    AccountId, Balance, Balances, DataHavenNativeTransfer, Runtime, RuntimeCall, RuntimeEvent,
+    RuntimeOrigin, SnowbridgeSystemV2, System, Treasury,
 };
-use dhp_bridge::NativeTokenTransferMessageProcessor;
+use dhp_bridge::{Beneficiary, NativeTokenTransferMessageProcessor, VersionedClaimer};
 use frame_support::{assert_noop, assert_ok, traits::fungible::Inspect};
-use pallet_datahaven_native_transfer::Event as NativeTransferEvent;
+use pallet_datahaven_native_transfer::{
+    Event as NativeTransferEvent, FollowUpCallError, PendingTransfers,
+};
+use pallet_treasury::PaymentState;
 use snowbridge_core::TokenIdOf;
 use snowbridge_inbound_queue_primitives::v2::{
     EthereumAsset, Message as SnowbridgeMessage, MessageProcessor, Payload,
 };
+use snowbridge_outbound_queue_primitives::v2::MessageDeliveryHandler;
 use snowbridge_pallet_outbound_queue_v2::Event as OutboundQueueEvent;
 use snowbridge_pallet_system::NativeToForeignId;
 use sp_core::Get;
@@ -381,6 +388,134 @@ fn processing_fails_with_insufficient_sovereign_balance() {
     });
 }
 
+// === Claimer Tests ===
+
+fn create_message_with_claimer(token_id: H256, claimer: Vec<u8>) -> SnowbridgeMessage {
+    let mut message = create_message(token_id, TRANSFER_AMOUNT, ETH_ALICE, 1);
+    message.claimer = Some(claimer);
+    message
+}
+
+#[test]
+fn inbound_message_with_versioned_claimer_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        let token_id = register_native_token();
+        let alice = account_id(ALICE);
+        setup_sovereign_balance(TRANSFER_AMOUNT * 2);
+
+        // 32-byte account holding a left-padded Ethereum address
+        let mut account = [0u8; 32];
+        account[12..].copy_from_slice(ETH_BOB.as_bytes());
+        let claimer = VersionedClaimer::V1 {
+            beneficiary: Beneficiary::AccountId32(account),
+            call: None,
+        };
+        let message = create_message_with_claimer(token_id, claimer.encode());
+
+        assert_ok!(
+            snowbridge_pallet_inbound_queue_v2::Pallet::<Runtime>::process_message(alice, message)
+        );
+
+        let recipient: AccountId = ETH_BOB.into();
+        assert_eq!(Balances::balance(&recipient), TRANSFER_AMOUNT);
+    });
+}
+
+#[test]
+fn inbound_message_with_follow_up_call_works() {
+    ExtBuilder::default().build().execute_with(|| {
+        let token_id = register_native_token();
+        let alice = account_id(ALICE);
+        setup_sovereign_balance(TRANSFER_AMOUNT * 2);
+
+        let call =
+            RuntimeCall::PaymentStreams(pallet_payment_streams::Call::pay_outstanding_debt {
+                provider_ids: vec![],
+            });
+        let claimer = VersionedClaimer::V1 {
+            beneficiary: Beneficiary::AccountKey20(ETH_ALICE),
+            call: Some(call.encode()),
+        };
+        let mut message = create_message_with_claimer(token_id, claimer.encode());
+        message.origin = ETH_ALICE;
+
+        assert_ok!(
+            snowbridge_pallet_inbound_queue_v2::Pallet::<Runtime>::process_message(alice, message)
+        );
+
+        let recipient: AccountId = ETH_ALICE.into();
+        assert!(System::events().iter().any(|e| matches!(
+            &e.event,
+            RuntimeEvent::DataHavenNativeTransfer(NativeTransferEvent::FollowUpCallDispatched {
+                account,
+                ..
+            }) if *account == recipient
+        )));
+    });
+}
+
+#[test]
+fn inbound_message_with_proxy_follow_up_call_is_skipped() {
+    ExtBuilder::default().build().execute_with(|| {
+        let token_id = register_native_token();
+        let alice = account_id(ALICE);
+        setup_sovereign_balance(TRANSFER_AMOUNT * 2);
+
+        // The inner call of a proxy call would escape the filter
+        let call = RuntimeCall::Proxy(pallet_proxy::Call::add_proxy {
+            delegate: ETH_BOB.into(),
+            proxy_type: ProxyType::Any,
+            delay: 0,
+        });
+        let claimer = VersionedClaimer::V1 {
+            beneficiary: Beneficiary::AccountKey20(ETH_ALICE),
+            call: Some(call.encode()),
+        };
+        let mut message = create_message_with_claimer(token_id, claimer.encode());
+        message.origin = ETH_ALICE;
+
+        assert_ok!(
+            snowbridge_pallet_inbound_queue_v2::Pallet::<Runtime>::process_message(alice, message)
+        );
+
+        let recipient: AccountId = ETH_ALICE.into();
+        assert_eq!(Balances::balance(&recipient), TRANSFER_AMOUNT);
+        assert!(System::events().iter().any(|e| e.event
+            == RuntimeEvent::DataHavenNativeTransfer(NativeTransferEvent::FollowUpCallSkipped {
+                account: recipient.clone(),
+                reason: FollowUpCallError::Filtered,
+            })));
+    });
+}
+
+#[test]
+fn inbound_message_with_invalid_claimer_is_held() {
+    ExtBuilder::default().build().execute_with(|| {
+        let token_id = register_native_token();
+        let alice = account_id(ALICE);
+        setup_sovereign_balance(TRANSFER_AMOUNT * 2);
+        let message = create_message_with_claimer(token_id, vec![0x01, 0x02, 0x03]);
+
+        assert_ok!(
+            snowbridge_pallet_inbound_queue_v2::Pallet::<Runtime>::process_message(alice, message)
+        );
+
+        assert_eq!(
+            Balances::balance(&NativeTransferHoldingAccount::get()),
+            TRANSFER_AMOUNT
+        );
+
+        // Governance releases the tokens once the recipient is known
+        let recipient: AccountId = ETH_BOB.into();
+        assert_ok!(DataHavenNativeTransfer::release_held_tokens(
+            root_origin(),
+            0,
+            recipient
+        ));
+        assert_eq!(Balances::balance(&recipient), TRANSFER_AMOUNT);
+    });
+}
+
 // === Integration Tests ===
 
 #[test]
@@ -442,3 +577,48 @@ fn message_routing_works_correctly() {
         );
     });
 }
+
+// === Treasury Payout Tests ===
+
+#[test]
+fn treasury_spend_is_paid_out_to_ethereum() {
+    ExtBuilder::default().build().execute_with(|| {
+        let _token_id = register_native_token();
+        let treasury_account = datahaven_stagenet_runtime::configs::TreasuryAccount::get();
+        let _ = Balances::force_set_balance(root_origin(), treasury_account, 10 * TRANSFER_AMOUNT);
+        let alice = account_id(ALICE);
+
+        assert_ok!(Treasury::spend(
+            root_origin(),
+            Box::new(TreasuryAssetKind::Ethereum),
+            TRANSFER_AMOUNT,
+            Box::new(AccountId::from(ETH_ALICE)),
+            None,
+        ));
+        assert_ok!(Treasury::payout(RuntimeOrigin::signed(alice), 0));
+
+        let transfer_id = match pallet_treasury::Spends::<Runtime>::get(0).map(|spend| spend.status)
+        {
+            Some(PaymentState::Attempted {
+                id: TreasuryPaymentId::Bridged(transfer_id),
+            }) => transfer_id,
+            status => panic!("Unexpected payout status: {:?}", status),
+        };
+        let transfer = PendingTransfers::<Runtime>::get(transfer_id).unwrap();
+        assert_eq!(transfer.sender, treasury_account);
+        assert_eq!(transfer.recipient, ETH_ALICE);
+        assert_eq!(transfer.amount, TRANSFER_AMOUNT);
+
+        // The spend is processed once the tokens are minted on Ethereum
+        assert_noop!(
+            Treasury::check_status(RuntimeOrigin::signed(alice), 0),
+            pallet_treasury::Error::<Runtime>::Inconclusive
+        );
+
+        DataHavenNativeTransfer::on_message_accepted(transfer_id, 1);
+        DataHavenNativeTransfer::on_message_delivered(1, true);
+
+        assert_ok!(Treasury::check_status(RuntimeOrigin::signed(alice), 0));
+        assert!(pallet_treasury::Spends::<Runtime>::get(0).is_none());
+    });
+}
@@ -66,6 +66,8 @@ frame_benchmarking::define_benchmarks!(
     [pallet_external_validators_rewards, ExternalValidatorsRewards]
     [pallet_external_validator_slashes, ExternalValidatorsSlashes]
     [pallet_datahaven_native_transfer, DataHavenNativeTransfer]
+    [pallet_relayer_rewards, RelayerRewards]
+    [pallet_evm_pause, EvmPause]
 
     // Snowbridge pallets
     [snowbridge_pallet_ethereum_client, EthereumBeaconClient]
@@ -376,7 +376,9 @@ pub mod dynamic_params {
 
         #[codec(index = 40)]
         #[allow(non_upper_case_globals)]
-        /// Weight of liveness (heartbeat/block authorship) in the operator rewards formula.
+        /// Weight of liveness in the operator rewards formula.
+        /// Split between block authorship, heartbeats and finality signatures, see
+        /// OperatorRewardsHeartbeatLivenessWeight and OperatorRewardsFinalityLivenessWeight.
         /// Default: 30% of base points are allocated based on validator online status.
         /// Combined with OperatorRewardsBlockAuthoringWeight, the sum should not exceed 100%.
         /// The remainder (100% - block - liveness) is the unconditional base reward.
@@ -392,6 +394,22 @@ pub mod dynamic_params {
         /// gets full credit (150%), but one producing 20 blocks is capped at 15 blocks credit.
         pub static OperatorRewardsFairShareCap: Perbill = Perbill::from_percent(50);
 
+        #[codec(index = 46)]
+        #[allow(non_upper_case_globals)]
+        /// Share of the liveness score given to validators that im-online did not report as
+        /// unresponsive during the session. The rest of the score comes from block authorship.
+        /// Default: 0%, liveness is only based on block authorship.
+        pub static OperatorRewardsHeartbeatLivenessWeight: Perbill = Perbill::zero();
+
+        #[codec(index = 47)]
+        #[allow(non_upper_case_globals)]
+        /// Share of the liveness score given to validators that signed the GRANDPA/BEEFY
+        /// justifications noted during the session.
+        /// Combined with OperatorRewardsHeartbeatLivenessWeight, the sum should not exceed 100%.
+        /// If the sum exceeds 100%, values are proportionally scaled down.
+        /// Default: 0%, liveness is only based on block authorship.
+        pub static OperatorRewardsFinalityLivenessWeight: Perbill = Perbill::zero();
+
         // ╚══════════════════════ Validator Rewards Inflation ═══════════════════════╝
 
         // ╔══════════════════════ EigenLayer Rewards V2 ═══════════════════════╗
@@ -419,6 +437,71 @@ pub mod dynamic_params {
             BoundedVec::truncate_from(vec![]);
 
         // ╚══════════════════════ EigenLayer Rewards V2 ═══════════════════════╝
+
+        // ╔══════════════════════ Ethereum Light Client Health ═══════════════════════╗
+
+        #[codec(index = 48)]
+        #[allow(non_upper_case_globals)]
+        /// Time of the beacon chain genesis, in seconds since the Unix epoch, from which the
+        /// current beacon slot is derived. Default: Hoodi genesis.
+        pub static EthereumBeaconGenesisTime: u64 = 1742213400;
+
+        #[codec(index = 49)]
+        #[allow(non_upper_case_globals)]
+        /// Number of slots the latest finalized beacon header may be behind before the light
+        /// client is reported as lagging. Default: 256 slots, ~51 minutes.
+        pub static EthereumClientLaggingThreshold: u64 = 256;
+
+        #[codec(index = 50)]
+        #[allow(non_upper_case_globals)]
+        /// Number of slots the latest finalized beacon header may be behind before the light
+        /// client is reported as degraded, and bridge pallets switch to degraded mode if enabled.
+        /// Default: 4096 slots, half a sync committee period (~13.6 hours).
+        pub static EthereumClientDegradedThreshold: u64 = 4096;
+
+        // ╚══════════════════════ Ethereum Light Client Health ═══════════════════════╝
+
+        // ╔══════════════════════ Outbound Gas Metering ═══════════════════════╗
+
+        #[codec(index = 51)]
+        #[allow(non_upper_case_globals)]
+        /// Gas metered for a rewards submission to the DataHavenServiceManager, regardless of
+        /// the number of operators.
+        pub static SubmitRewardsBaseGas: u64 = 1_000_000;
+
+        #[codec(index = 52)]
+        #[allow(non_upper_case_globals)]
+        /// Additional gas metered for a rewards submission for each rewarded operator.
+        /// Default: 20k, a submission for 50 operators is metered 2M gas.
+        pub static SubmitRewardsGasPerOperator: u64 = 20_000;
+
+        #[codec(index = 53)]
+        #[allow(non_upper_case_globals)]
+        /// Gas metered for a slashing request to the DataHavenServiceManager, regardless of the
+        /// number of slashes.
+        pub static SlashValidatorsBaseGas: u64 = 300_000;
+
+        #[codec(index = 54)]
+        #[allow(non_upper_case_globals)]
+        /// Additional gas metered for a slashing request for each slashed operator.
+        pub static SlashValidatorsGasPerSlash: u64 = 150_000;
+
+        #[codec(index = 55)]
+        #[allow(non_upper_case_globals)]
+        /// Ethereum gas price, in wei, used to estimate the remote fee of outbound messages.
+        pub static EthereumGasPrice: u128 = 20 * GIGAWEI;
+
+        // ╚══════════════════════ Outbound Gas Metering ═══════════════════════╝
+
+        // ╔══════════════════════ Native Transfers ═══════════════════════╗
+
+        #[codec(index = 56)]
+        #[allow(non_upper_case_globals)]
+        /// Fee suggested to senders for each transfer of native tokens to Ethereum, paying the
+        /// relayers for delivering it. Default: 0.1 HAVE.
+        pub static NativeTransferSuggestedFee: Balance = HAVE / 10;
+
+        // ╚══════════════════════ Native Transfers ═══════════════════════╝
     }
 }
 
@@ -25,15 +25,19 @@ use pallet_evm_precompile_call_permit::CallPermitPrecompile;
 use pallet_evm_precompile_collective::CollectivePrecompile;
 use pallet_evm_precompile_conviction_voting::ConvictionVotingPrecompile;
 use pallet_evm_precompile_datahaven_native_transfer::DataHavenNativeTransferPrecompile;
+use pallet_evm_precompile_external_validators::ExternalValidatorsPrecompile;
 use pallet_evm_precompile_file_system::FileSystemPrecompile;
 use pallet_evm_precompile_identity::IdentityPrecompile;
 use pallet_evm_precompile_modexp::Modexp;
+use pallet_evm_precompile_multisig::MultisigPrecompile;
 use pallet_evm_precompile_preimage::PreimagePrecompile;
 use pallet_evm_precompile_proxy::{OnlyIsProxyAndProxy, ProxyPrecompile};
 use pallet_evm_precompile_referenda::ReferendaPrecompile;
 use pallet_evm_precompile_registry::PrecompileRegistry;
+use pallet_evm_precompile_scheduler::SchedulerPrecompile;
 use pallet_evm_precompile_sha3fips::Sha3FIPS256;
 use pallet_evm_precompile_simple::{ECRecover, ECRecoverPublicKey, Identity, Ripemd160, Sha256};
+use pallet_evm_precompile_treasury::TreasuryPrecompile;
 use precompile_utils::precompile_set::*;
 
 type EthereumPrecompilesChecks = (AcceptDelegateCall, CallableByContract, CallableByPrecompile);
@@ -147,6 +151,26 @@ type DataHavenPrecompilesAt<R> = (
         DataHavenNativeTransferPrecompile<R>,
         (CallableByContract, CallableByPrecompile),
     >,
+    PrecompileAt<
+        AddressU64<2074>,
+        TreasuryPrecompile<R>,
+        (CallableByContract, CallableByPrecompile),
+    >,
+    PrecompileAt<
+        AddressU64<2075>,
+        MultisigPrecompile<R>,
+        (CallableByContract, CallableByPrecompile),
+    >,
+    PrecompileAt<
+        AddressU64<2076>,
+        SchedulerPrecompile<R>,
+        (CallableByContract, CallableByPrecompile),
+    >,
+    PrecompileAt<
+        AddressU64<2077>,
+        ExternalValidatorsPrecompile<R>,
+        (CallableByContract, CallableByPrecompile),
+    >,
     PrecompileAt<AddressU64<1028>, FileSystemPrecompile<R>>,
 );
 
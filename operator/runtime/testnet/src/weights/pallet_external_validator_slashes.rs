@@ -100,7 +100,17 @@ impl<T: frame_system::Config> pallet_external_validator_slashes::WeightInfo for
 	/// Proof: `MessageQueue::ServiceHead` (`max_values`: Some(1), `max_size`: Some(33), added: 528, mode: `MaxEncodedLen`)
 	/// Storage: `MessageQueue::Pages` (r:0 w:1)
 	/// Proof: `MessageQueue::Pages` (`max_values`: None, `max_size`: Some(32845), added: 35320, mode: `MaxEncodedLen`)
+	/// Storage: `ExternalValidatorsSlashes::ReporterBounty` (r:1 w:0)
+	/// Proof: `ExternalValidatorsSlashes::ReporterBounty` (`max_values`: Some(1), `max_size`: Some(17), added: 512, mode: `MaxEncodedLen`)
+	/// Storage: `ExternalValidatorsSlashes::ReporterBountyEraCap` (r:1 w:0)
+	/// Proof: `ExternalValidatorsSlashes::ReporterBountyEraCap` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `ExternalValidatorsSlashes::ReporterBountiesInEra` (r:1 w:200)
+	/// Proof: `ExternalValidatorsSlashes::ReporterBountiesInEra` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `ExternalValidatorsSlashes::PendingReporterBounties` (r:200 w:200)
+	/// Proof: `ExternalValidatorsSlashes::PendingReporterBounties` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
 	/// The range of component `s` is `[1, 200]`.
+	/// ESTIMATED: the reporter bounty storage above is not benchmarked yet. It is added on top of
+	/// the benchmarked queue processing, one reporter per slash, until the benchmark is re-run.
 	fn process_slashes_queue(s: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `541 + s * (38 ±0)`
@@ -111,18 +121,128 @@ impl<T: frame_system::Config> pallet_external_validator_slashes::WeightInfo for
 			.saturating_add(Weight::from_parts(42_753, 0).saturating_mul(s.into()))
 			.saturating_add(T::DbWeight::get().reads(6_u64))
 			.saturating_add(T::DbWeight::get().writes(4_u64))
+			// Estimated reporter bounty accrual
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(s.into())))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(s.into())))
+			.saturating_add(Weight::from_parts(0, 2519).saturating_mul(s.into()))
 			.saturating_add(Weight::from_parts(0, 38).saturating_mul(s.into()))
 	}
+	/// Storage: `ExternalValidators::ActiveEra` (r:1 w:0)
+	/// Proof: `ExternalValidators::ActiveEra` (`max_values`: Some(1), `max_size`: Some(13), added: 508, mode: `MaxEncodedLen`)
 	/// Storage: `ExternalValidatorsSlashes::SlashingMode` (r:0 w:1)
 	/// Proof: `ExternalValidatorsSlashes::SlashingMode` (`max_values`: Some(1), `max_size`: Some(1), added: 496, mode: `MaxEncodedLen`)
+	/// Storage: `ExternalValidatorsSlashes::SlashingModes` (r:0 w:1)
+	/// Proof: `ExternalValidatorsSlashes::SlashingModes` (`max_values`: None, `max_size`: Some(13), added: 2488, mode: `MaxEncodedLen`)
 	fn set_slashing_mode() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `0`
-		//  Estimated: `0`
+		//  Estimated: `1498`
 		// Minimum execution time: 3_857_000 picoseconds.
-		Weight::from_parts(3_986_000, 0)
+		Weight::from_parts(3_986_000, 1498)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `ExternalValidatorsSlashes::ReporterBounty` (r:0 w:1)
+	/// Proof: `ExternalValidatorsSlashes::ReporterBounty` (`max_values`: Some(1), `max_size`: Some(17), added: 512, mode: `MaxEncodedLen`)
+	/// Storage: `ExternalValidatorsSlashes::ReporterBountyEraCap` (r:0 w:1)
+	/// Proof: `ExternalValidatorsSlashes::ReporterBountyEraCap` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// ESTIMATED: not benchmarked yet, modelled on `set_slashing_mode`. Replace with the output of
+	/// the `set_reporter_bounty` benchmark.
+	fn set_reporter_bounty() -> Weight {
+		Weight::from_parts(4_500_000, 0)
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `ExternalValidatorsSlashes::PendingReporterBounties` (r:1 w:1)
+	/// Proof: `ExternalValidatorsSlashes::PendingReporterBounties` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:2 w:2)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// ESTIMATED: not benchmarked yet, modelled on a balance transfer. Replace with the output of
+	/// the `claim_reporter_bounty` benchmark.
+	fn claim_reporter_bounty() -> Weight {
+		Weight::from_parts(60_000_000, 6196)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `ExternalValidators::ActiveEra` (r:1 w:0)
+	/// Proof: `ExternalValidators::ActiveEra` (`max_values`: Some(1), `max_size`: Some(13), added: 508, mode: `MaxEncodedLen`)
+	/// Storage: `ExternalValidatorsSlashes::ScheduledSlashingModes` (r:0 w:1)
+	/// Proof: `ExternalValidatorsSlashes::ScheduledSlashingModes` (`max_values`: None, `max_size`: Some(13), added: 2488, mode: `MaxEncodedLen`)
+	fn schedule_slashing_mode() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `1498`
+		// Minimum execution time: 6_102_000 picoseconds.
+		Weight::from_parts(6_384_000, 1498)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `ExternalValidatorsSlashes::LoggedSlashes` (r:1 w:1)
+	/// Proof: `ExternalValidatorsSlashes::LoggedSlashes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ExternalValidators::ActiveEra` (r:1 w:0)
+	/// Proof: `ExternalValidators::ActiveEra` (`max_values`: Some(1), `max_size`: Some(13), added: 508, mode: `MaxEncodedLen`)
+	/// Storage: `ExternalValidatorsSlashes::Slashes` (r:1 w:1)
+	/// Proof: `ExternalValidatorsSlashes::Slashes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ExternalValidatorsSlashes::ValidatorSlashInEra` (r:1 w:1)
+	/// Proof: `ExternalValidatorsSlashes::ValidatorSlashInEra` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	fn promote_logged_slash() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `512`
+		//  Estimated: `3977`
+		// Minimum execution time: 17_845_000 picoseconds.
+		Weight::from_parts(18_502_000, 3977)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `ExternalValidatorsSlashes::FailedSlashes` (r:1 w:1)
+	/// Proof: `ExternalValidatorsSlashes::FailedSlashes` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ExternalValidatorsSlashes::SlashDeliveries` (r:1 w:1)
+	/// Proof: `ExternalValidatorsSlashes::SlashDeliveries` (`max_values`: None, `max_size`: Some(31), added: 2506, mode: `MaxEncodedLen`)
+	/// Storage: `ExternalValidatorsSlashes::UnreportedSlashesQueue` (r:1 w:1)
+	/// Proof: `ExternalValidatorsSlashes::UnreportedSlashesQueue` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	fn retry_slash_delivery() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `226`
+		//  Estimated: `3691`
+		// Minimum execution time: 14_211_000 picoseconds.
+		Weight::from_parts(14_730_000, 3691)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	/// Storage: `ExternalValidatorsSlashes::SentSlashMessages` (r:1 w:1)
+	/// Proof: `ExternalValidatorsSlashes::SentSlashMessages` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ExternalValidatorsSlashes::AcceptedSlashMessages` (r:0 w:1)
+	/// Proof: `ExternalValidatorsSlashes::AcceptedSlashMessages` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn on_message_accepted() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `262`
+		//  Estimated: `3727`
+		// Minimum execution time: 11_382_000 picoseconds.
+		Weight::from_parts(11_795_000, 3727)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `ExternalValidatorsSlashes::AcceptedSlashMessages` (r:1 w:1)
+	/// Proof: `ExternalValidatorsSlashes::AcceptedSlashMessages` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `ExternalValidatorsSlashes::SlashDeliveries` (r:200 w:200)
+	/// Proof: `ExternalValidatorsSlashes::SlashDeliveries` (`max_values`: None, `max_size`: Some(31), added: 2506, mode: `MaxEncodedLen`)
+	/// Storage: `ExternalValidatorsSlashes::SlashRetries` (r:1 w:1)
+	/// Proof: `ExternalValidatorsSlashes::SlashRetries` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// The range of component `s` is `[1, 200]`.
+	fn on_message_delivered(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `260 + s * (98 ±0)`
+		//  Estimated: `3725 + s * (2506 ±0)`
+		// Minimum execution time: 24_532_000 picoseconds.
+		Weight::from_parts(17_904_211, 3725)
+			// Standard Error: 1_406
+			.saturating_add(Weight::from_parts(6_013_552, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(s.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(s.into())))
+			.saturating_add(Weight::from_parts(0, 2506).saturating_mul(s.into()))
+	}
 	
 	fn root_test_send_msg_to_eth() -> Weight {
 		// Proof Size summary in bytes:
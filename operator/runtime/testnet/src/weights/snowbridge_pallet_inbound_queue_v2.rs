@@ -74,4 +74,36 @@ impl<T: frame_system::Config> snowbridge_pallet_inbound_queue_v2::WeightInfo for
 			.saturating_add(T::DbWeight::get().reads(5_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `EthereumInboundQueueV2::DeadLetters` (r:1 w:1)
+	/// Proof: `EthereumInboundQueueV2::DeadLetters` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EthereumInboundQueueV2::CounterForDeadLetters` (r:1 w:1)
+	/// Proof: `EthereumInboundQueueV2::CounterForDeadLetters` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn retry_dead_letter() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `412`
+		//  Estimated: `3877`
+		// Minimum execution time: 24_000_000 picoseconds.
+		Weight::from_parts(25_000_000, 3877)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `EthereumInboundQueueV2::DeadLetters` (r:1 w:1)
+	/// Proof: `EthereumInboundQueueV2::DeadLetters` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `EthereumInboundQueueV2::CounterForDeadLetters` (r:1 w:1)
+	/// Proof: `EthereumInboundQueueV2::CounterForDeadLetters` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `MessageQueue::BookStateFor` (r:1 w:1)
+	/// Proof: `MessageQueue::BookStateFor` (`max_values`: None, `max_size`: Some(136), added: 2611, mode: `MaxEncodedLen`)
+	/// Storage: `MessageQueue::ServiceHead` (r:1 w:1)
+	/// Proof: `MessageQueue::ServiceHead` (`max_values`: Some(1), `max_size`: Some(33), added: 528, mode: `MaxEncodedLen`)
+	/// Storage: `MessageQueue::Pages` (r:0 w:1)
+	/// Proof: `MessageQueue::Pages` (`max_values`: None, `max_size`: Some(32845), added: 35320, mode: `MaxEncodedLen`)
+	fn refund_dead_letter() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `468`
+		//  Estimated: `3933`
+		// Minimum execution time: 41_000_000 picoseconds.
+		Weight::from_parts(42_000_000, 3933)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
 }
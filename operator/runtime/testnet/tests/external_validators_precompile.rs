@@ -0,0 +1,208 @@
+// Copyright 2025 DataHaven
+// This file is part of DataHaven.
+
+// DataHaven is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// DataHaven is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with DataHaven.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Tests of the ExternalValidatorsPrecompile against the runtime pallets
+
+mod common;
+use common::*;
+
+use datahaven_testnet_runtime::{configs::PrecompilesValue, AccountId, Runtime};
+use pallet_evm_precompile_external_validators::{ExternalValidatorsPrecompileCall, PendingSlash};
+use pallet_external_validator_slashes::Slash;
+use pallet_external_validators::traits::ActiveEraInfo;
+use pallet_external_validators_rewards::EraRewardPoints;
+use precompile_utils::{solidity::codec::Address, testing::*};
+use sp_core::H160;
+use sp_runtime::{BoundedVec, Perbill};
+
+type PCall = ExternalValidatorsPrecompileCall<Runtime>;
+
+/// Address of the ExternalValidatorsPrecompile
+const EXTERNAL_VALIDATORS_PRECOMPILE: u64 = 2077;
+
+fn precompile() -> H160 {
+    H160::from_low_u64_be(EXTERNAL_VALIDATORS_PRECOMPILE)
+}
+
+fn address(account: [u8; 20]) -> Address {
+    Address(H160(account))
+}
+
+fn set_active_era(index: u32) {
+    pallet_external_validators::ActiveEra::<Runtime>::put(ActiveEraInfo {
+        index,
+        start: Some(1_000),
+    });
+}
+
+fn slash(validator: AccountId, slash_id: u32) -> Slash<AccountId, u32> {
+    Slash {
+        validator,
+        reporters: vec![],
+        slash_id,
+        percentage: Perbill::from_percent(10),
+        offence_era: 0,
+        confirmed: false,
+    }
+}
+
+#[test]
+fn test_solidity_interface_has_all_function_selectors_documented_and_implemented() {
+    check_precompile_implements_solidity_interfaces(
+        &["../../precompiles/external-validators/ExternalValidators.sol"],
+        PCall::supports_selector,
+    )
+}
+
+#[test]
+fn active_era_is_returned() {
+    ExtBuilder::default().build().execute_with(|| {
+        pallet_external_validators::ActiveEra::<Runtime>::kill();
+        PrecompilesValue::get()
+            .prepare_test(ALICE, precompile(), PCall::active_era {})
+            .execute_reverts(|output| output == b"No active era");
+
+        set_active_era(3);
+        PrecompilesValue::get()
+            .prepare_test(ALICE, precompile(), PCall::active_era {})
+            .expect_no_logs()
+            .execute_returns((3u32, 1_000u64));
+    });
+}
+
+#[test]
+fn validator_sets_are_checked() {
+    ExtBuilder::default().build().execute_with(|| {
+        pallet_external_validators::ExternalValidators::<Runtime>::put(BoundedVec::truncate_from(
+            vec![charlie()],
+        ));
+        pallet_external_validators::WhitelistedValidators::<Runtime>::put(
+            BoundedVec::truncate_from(vec![dave()]),
+        );
+
+        // Charlie and Dave are the session validators of the default ExtBuilder
+        for (validator, active, external, whitelisted) in [
+            (CHARLIE, true, true, false),
+            (DAVE, true, false, true),
+            (BOB, false, false, false),
+        ] {
+            PrecompilesValue::get()
+                .prepare_test(
+                    ALICE,
+                    precompile(),
+                    PCall::is_active_validator {
+                        validator: address(validator),
+                    },
+                )
+                .execute_returns(active);
+            PrecompilesValue::get()
+                .prepare_test(
+                    ALICE,
+                    precompile(),
+                    PCall::is_external_validator {
+                        validator: address(validator),
+                    },
+                )
+                .execute_returns(external);
+            PrecompilesValue::get()
+                .prepare_test(
+                    ALICE,
+                    precompile(),
+                    PCall::is_whitelisted_validator {
+                        validator: address(validator),
+                    },
+                )
+                .execute_returns(whitelisted);
+        }
+    });
+}
+
+#[test]
+fn performance_of_a_validator_is_returned() {
+    ExtBuilder::default().build().execute_with(|| {
+        pallet_external_validators_rewards::RewardPointsForEra::<Runtime>::insert(
+            3,
+            EraRewardPoints {
+                total: 30,
+                individual: [(charlie(), 20), (dave(), 10)].into_iter().collect(),
+            },
+        );
+        pallet_external_validators_rewards::BlocksAuthoredInSession::<Runtime>::insert(
+            charlie(),
+            5,
+        );
+
+        PrecompilesValue::get()
+            .prepare_test(
+                ALICE,
+                precompile(),
+                PCall::era_reward_points {
+                    era: 3,
+                    validator: address(CHARLIE),
+                },
+            )
+            .execute_returns((20u32, 30u32));
+        PrecompilesValue::get()
+            .prepare_test(
+                ALICE,
+                precompile(),
+                PCall::era_reward_points {
+                    era: 3,
+                    validator: address(BOB),
+                },
+            )
+            .execute_returns((0u32, 30u32));
+
+        PrecompilesValue::get()
+            .prepare_test(
+                ALICE,
+                precompile(),
+                PCall::blocks_authored_in_session {
+                    validator: address(CHARLIE),
+                },
+            )
+            .execute_returns(5u32);
+    });
+}
+
+#[test]
+fn only_deferred_slashes_are_pending() {
+    ExtBuilder::default().build().execute_with(|| {
+        set_active_era(3);
+        // Slashes of the active era were already applied
+        pallet_external_validator_slashes::Slashes::<Runtime>::insert(3, vec![slash(charlie(), 1)]);
+        pallet_external_validator_slashes::Slashes::<Runtime>::insert(
+            4,
+            vec![slash(dave(), 2), slash(charlie(), 3)],
+        );
+
+        PrecompilesValue::get()
+            .prepare_test(
+                ALICE,
+                precompile(),
+                PCall::pending_slashes {
+                    validator: address(CHARLIE),
+                },
+            )
+            .expect_no_logs()
+            .execute_returns(vec![PendingSlash {
+                slash_id: 3.into(),
+                era: 4,
+                percentage: 100_000_000,
+                confirmed: false,
+            }]);
+    });
+}